@@ -1,10 +1,10 @@
 use pyo3::prelude::*;
 
 use crate::infrastructure::qos_policy::{
-    DeadlineQosPolicy, DestinationOrderQosPolicy, DurabilityQosPolicy, GroupDataQosPolicy,
-    HistoryQosPolicy, LatencyBudgetQosPolicy, LifespanQosPolicy, LivelinessQosPolicy,
-    OwnershipQosPolicy, PartitionQosPolicy, PresentationQosPolicy, ReliabilityQosPolicy,
-    ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
+    DeadlineQosPolicy, DestinationOrderQosPolicy, DurabilityQosPolicy, DurabilityServiceQosPolicy,
+    GroupDataQosPolicy, HistoryQosPolicy, LatencyBudgetQosPolicy, LifespanQosPolicy,
+    LivelinessQosPolicy, OwnershipQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
+    ReliabilityQosPolicy, ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
     TransportPriorityQosPolicy, UserDataQosPolicy,
 };
 
@@ -151,6 +151,10 @@ impl PublicationBuiltinTopicData {
         self.0.durability().clone().into()
     }
 
+    pub fn get_durability_service(&self) -> DurabilityServiceQosPolicy {
+        self.0.durability_service().clone().into()
+    }
+
     pub fn get_deadline(&self) -> DeadlineQosPolicy {
         self.0.deadline().clone().into()
     }
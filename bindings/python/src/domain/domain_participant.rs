@@ -323,6 +323,13 @@ impl DomainParticipant {
         }
     }
 
+    pub fn announce(&self) -> PyResult<()> {
+        match self.0.announce() {
+            Ok(_) => Ok(()),
+            Err(e) => Err(into_pyerr(e)),
+        }
+    }
+
     pub fn set_qos(&self, qos: Option<DomainParticipantQos>) -> PyResult<()> {
         let qos = match qos {
             Some(q) => dust_dds::infrastructure::qos::QosKind::Specific(q.into()),
@@ -2,12 +2,12 @@ use pyo3::prelude::*;
 
 use super::qos_policy::{
     DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy, DurabilityQosPolicy,
-    EntityFactoryQosPolicy, GroupDataQosPolicy, HistoryQosPolicy, LatencyBudgetQosPolicy,
-    LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy,
-    PartitionQosPolicy, PresentationQosPolicy, ReaderDataLifecycleQosPolicy, ReliabilityQosPolicy,
-    ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
-    TransportPriorityQosPolicy, UserDataQosPolicy, WriterDataLifecycleQosPolicy,
-    DEFAULT_RELIABILITY_QOS_POLICY_DATA_READER_AND_TOPICS,
+    DurabilityServiceQosPolicy, EntityFactoryQosPolicy, GroupDataQosPolicy, HistoryQosPolicy,
+    LatencyBudgetQosPolicy, LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy,
+    OwnershipStrengthQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
+    ReaderDataLifecycleQosPolicy, ReliabilityQosPolicy, ResourceLimitsQosPolicy,
+    TimeBasedFilterQosPolicy, TopicDataQosPolicy, TransportPriorityQosPolicy, UserDataQosPolicy,
+    WriterDataLifecycleQosPolicy, DEFAULT_RELIABILITY_QOS_POLICY_DATA_READER_AND_TOPICS,
     DEFAULT_RELIABILITY_QOS_POLICY_DATA_WRITER,
 };
 
@@ -331,6 +331,7 @@ impl DataWriterQos {
     #[new]
     #[pyo3(signature = (
         durability = DurabilityQosPolicy::default(),
+        durability_service = DurabilityServiceQosPolicy::default(),
         deadline = DeadlineQosPolicy::default(),
         latency_budget = LatencyBudgetQosPolicy::default(),
         liveliness = LivelinessQosPolicy::default(),
@@ -349,6 +350,7 @@ impl DataWriterQos {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         durability: DurabilityQosPolicy,
+        durability_service: DurabilityServiceQosPolicy,
         deadline: DeadlineQosPolicy,
         latency_budget: LatencyBudgetQosPolicy,
         liveliness: LivelinessQosPolicy,
@@ -366,6 +368,7 @@ impl DataWriterQos {
     ) -> Self {
         Self(dust_dds::infrastructure::qos::DataWriterQos {
             durability: durability.into(),
+            durability_service: durability_service.into(),
             deadline: deadline.into(),
             latency_budget: latency_budget.into(),
             liveliness: liveliness.into(),
@@ -387,6 +390,10 @@ impl DataWriterQos {
         self.0.durability.clone().into()
     }
 
+    fn get_durability_service(&self) -> DurabilityServiceQosPolicy {
+        self.0.durability_service.clone().into()
+    }
+
     fn get_deadline(&self) -> DeadlineQosPolicy {
         self.0.deadline.clone().into()
     }
@@ -645,6 +645,90 @@ impl ResourceLimitsQosPolicy {
     }
 }
 
+#[pyclass]
+#[derive(Clone, Default)]
+pub struct DurabilityServiceQosPolicy(
+    dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy,
+);
+
+impl From<DurabilityServiceQosPolicy>
+    for dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy
+{
+    fn from(value: DurabilityServiceQosPolicy) -> Self {
+        value.0
+    }
+}
+
+impl From<dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy>
+    for DurabilityServiceQosPolicy
+{
+    fn from(value: dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl DurabilityServiceQosPolicy {
+    #[new]
+    pub fn new(
+        service_cleanup_delay: DurationKind,
+        history_kind: HistoryQosPolicyKind,
+        max_samples: Length,
+        max_instances: Length,
+        max_samples_per_instance: Length,
+    ) -> Self {
+        Self(
+            dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy {
+                service_cleanup_delay: service_cleanup_delay.into(),
+                history_kind: history_kind.into(),
+                max_samples: max_samples.into(),
+                max_instances: max_instances.into(),
+                max_samples_per_instance: max_samples_per_instance.into(),
+            },
+        )
+    }
+
+    pub fn get_service_cleanup_delay(&self) -> DurationKind {
+        self.0.service_cleanup_delay.into()
+    }
+
+    pub fn set_service_cleanup_delay(&mut self, value: DurationKind) {
+        self.0.service_cleanup_delay = value.into()
+    }
+
+    pub fn get_history_kind(&self) -> HistoryQosPolicyKind {
+        self.0.history_kind.into()
+    }
+
+    pub fn set_history_kind(&mut self, value: HistoryQosPolicyKind) {
+        self.0.history_kind = value.into()
+    }
+
+    pub fn get_max_samples(&self) -> Length {
+        self.0.max_samples.into()
+    }
+
+    pub fn set_max_samples(&mut self, value: Length) {
+        self.0.max_samples = value.into()
+    }
+
+    pub fn get_max_instances(&self) -> Length {
+        self.0.max_instances.into()
+    }
+
+    pub fn set_max_instances(&mut self, value: Length) {
+        self.0.max_instances = value.into()
+    }
+
+    pub fn get_max_samples_per_instance(&self) -> Length {
+        self.0.max_samples_per_instance.into()
+    }
+
+    pub fn set_max_samples_per_instance(&mut self, value: Length) {
+        self.0.max_samples_per_instance = value.into()
+    }
+}
+
 #[pyclass]
 #[derive(Clone, Default)]
 pub struct TransportPriorityQosPolicy(
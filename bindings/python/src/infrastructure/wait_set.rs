@@ -53,7 +53,7 @@ impl WaitSet {
         self.0.attach_condition(cond.into()).map_err(into_pyerr)
     }
 
-    pub fn detach_condition(&self, cond: Condition) -> PyResult<()> {
+    pub fn detach_condition(&mut self, cond: Condition) -> PyResult<()> {
         self.0.detach_condition(cond.into()).map_err(into_pyerr)
     }
 
@@ -39,6 +39,7 @@ fn dust_dds(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<infrastructure::qos_policy::DestinationOrderQosPolicyKind>()?;
     m.add_class::<infrastructure::qos_policy::DurabilityQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::DurabilityQosPolicyKind>()?;
+    m.add_class::<infrastructure::qos_policy::DurabilityServiceQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::EntityFactoryQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::GroupDataQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::HistoryQosPolicy>()?;
@@ -85,7 +85,7 @@ impl DataWriter {
     pub fn register_instance(&self, instance: Py<PyAny>) -> PyResult<Option<InstanceHandle>> {
         Ok(self
             .0
-            .register_instance(&PythonDdsData::from_py_object(instance)?)
+            .register_instance(PythonDdsData::from_py_object(instance)?)
             .map_err(into_pyerr)?
             .map(InstanceHandle::from))
     }
@@ -98,7 +98,7 @@ impl DataWriter {
         Ok(self
             .0
             .register_instance_w_timestamp(
-                &PythonDdsData::from_py_object(instance)?,
+                PythonDdsData::from_py_object(instance)?,
                 timestamp.into(),
             )
             .map_err(into_pyerr)?
@@ -1,18 +1,11 @@
 use super::infrastructure::qos_policy::{
     DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy, DurabilityQosPolicy,
-    GroupDataQosPolicy, HistoryQosPolicy, LatencyBudgetQosPolicy, LifespanQosPolicy,
-    LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy, PartitionQosPolicy,
-    PresentationQosPolicy, ReliabilityQosPolicy, ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy,
-    TopicDataQosPolicy, TransportPriorityQosPolicy, UserDataQosPolicy,
+    DurabilityServiceQosPolicy, GroupDataQosPolicy, HistoryQosPolicy, LatencyBudgetQosPolicy,
+    LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy,
+    PartitionQosPolicy, PresentationQosPolicy, ReliabilityQosPolicy, ResourceLimitsQosPolicy,
+    TimeBasedFilterQosPolicy, TopicDataQosPolicy, TransportPriorityQosPolicy, UserDataQosPolicy,
 };
 use crate::{
-    dcps::data_representation_builtin_endpoints::parameter_id_values::{
-        PID_DATA_REPRESENTATION, PID_DEADLINE, PID_DESTINATION_ORDER, PID_DURABILITY,
-        PID_ENDPOINT_GUID, PID_GROUP_DATA, PID_HISTORY, PID_LATENCY_BUDGET, PID_LIFESPAN,
-        PID_LIVELINESS, PID_OWNERSHIP, PID_OWNERSHIP_STRENGTH, PID_PARTICIPANT_GUID, PID_PARTITION,
-        PID_PRESENTATION, PID_RELIABILITY, PID_RESOURCE_LIMITS, PID_TIME_BASED_FILTER,
-        PID_TOPIC_DATA, PID_TOPIC_NAME, PID_TRANSPORT_PRIORITY, PID_TYPE_NAME, PID_USER_DATA,
-    },
     infrastructure::{
         qos_policy::{
             DEFAULT_RELIABILITY_QOS_POLICY_DATA_READER_AND_TOPICS,
@@ -200,6 +193,8 @@ pub struct PublicationBuiltinTopicData {
     pub(crate) type_name: String,
     #[dust_dds(id = 0x0029, optional)]
     pub(crate) durability: DurabilityQosPolicy,
+    #[dust_dds(id = 0x001E, optional)]
+    pub(crate) durability_service: DurabilityServiceQosPolicy,
     #[dust_dds(id = 0x0023, optional)]
     pub(crate) deadline: DeadlineQosPolicy,
     #[dust_dds(id = 0x0025, optional)]
@@ -256,6 +251,11 @@ impl PublicationBuiltinTopicData {
         &self.durability
     }
 
+    /// Get the durability service QoS policy of the discovered writer.
+    pub fn durability_service(&self) -> &DurabilityServiceQosPolicy {
+        &self.durability_service
+    }
+
     /// Get the deadline QoS policy of the discovered writer.
     pub fn deadline(&self) -> &DeadlineQosPolicy {
         &self.deadline
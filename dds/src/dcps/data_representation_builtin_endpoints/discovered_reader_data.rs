@@ -347,10 +347,18 @@ mod tests {
     use super::*;
     use crate::{
         builtin_topics::BuiltInTopicKey,
-        infrastructure::type_support::TypeSupport,
+        infrastructure::{
+            qos_policy::{
+                DestinationOrderQosPolicyKind, DurabilityQosPolicyKind, LivelinessQosPolicyKind,
+                OwnershipQosPolicyKind, PresentationQosPolicyAccessScopeKind, ReliabilityQosPolicy,
+                ReliabilityQosPolicyKind, XCDR2_DATA_REPRESENTATION,
+            },
+            time::{Duration, DurationKind},
+            type_support::TypeSupport,
+        },
         transport::types::{
-            EntityId, Guid, BUILT_IN_WRITER_WITH_KEY, USER_DEFINED_READER_WITH_KEY,
-            USER_DEFINED_UNKNOWN,
+            EntityId, Guid, BUILT_IN_WRITER_WITH_KEY, LOCATOR_KIND_UDP_V4,
+            USER_DEFINED_READER_WITH_KEY, USER_DEFINED_UNKNOWN,
         },
         xtypes::pl_cdr_serializer::PlCdrLeSerializer,
     };
@@ -544,7 +552,7 @@ mod tests {
             },
         };
 
-        let mut data = &[
+        let data = &[
             0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
             0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
             21, 22, 23, 0xc2, // u8[3], u8
@@ -566,7 +574,123 @@ mod tests {
             b'c', b'd', 0, 0x00, // string + padding (1 byte)
             0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
         ][..];
-        let result = DiscoveredReaderData::deserialize_data(&mut data).unwrap();
+        let result = DiscoveredReaderData::deserialize_data(data).unwrap();
         assert_eq!(result, expected);
     }
+    #[test]
+    fn all_policies_round_trip() {
+        let data = DiscoveredReaderData {
+            dds_subscription_data: SubscriptionBuiltinTopicData {
+                key: BuiltInTopicKey {
+                    value: [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0],
+                },
+                participant_key: BuiltInTopicKey {
+                    value: [6, 0, 0, 0, 7, 0, 0, 0, 8, 0, 0, 0, 9, 0, 0, 0],
+                },
+                topic_name: "ab".to_string(),
+                type_name: "cd".to_string(),
+                durability: DurabilityQosPolicy {
+                    kind: DurabilityQosPolicyKind::TransientLocal,
+                },
+                deadline: DeadlineQosPolicy {
+                    period: DurationKind::Finite(Duration::new(1, 2)),
+                },
+                latency_budget: LatencyBudgetQosPolicy {
+                    duration: DurationKind::Finite(Duration::new(3, 4)),
+                },
+                liveliness: LivelinessQosPolicy {
+                    kind: LivelinessQosPolicyKind::ManualByTopic,
+                    lease_duration: DurationKind::Finite(Duration::new(5, 6)),
+                },
+                reliability: ReliabilityQosPolicy {
+                    kind: ReliabilityQosPolicyKind::Reliable,
+                    max_blocking_time: DurationKind::Finite(Duration::new(7, 8)),
+                },
+                ownership: OwnershipQosPolicy {
+                    kind: OwnershipQosPolicyKind::Exclusive,
+                },
+                destination_order: DestinationOrderQosPolicy {
+                    kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
+                },
+                user_data: UserDataQosPolicy {
+                    value: vec![1, 2, 3],
+                },
+                time_based_filter: TimeBasedFilterQosPolicy {
+                    minimum_separation: DurationKind::Finite(Duration::new(9, 10)),
+                },
+                presentation: PresentationQosPolicy {
+                    access_scope: PresentationQosPolicyAccessScopeKind::Topic,
+                    coherent_access: true,
+                    ordered_access: true,
+                },
+                partition: PartitionQosPolicy {
+                    name: vec![String::from("one"), String::from("two")],
+                },
+                topic_data: TopicDataQosPolicy {
+                    value: vec![4, 5, 6, 7],
+                },
+                group_data: GroupDataQosPolicy { value: vec![8, 9] },
+                representation: DataRepresentationQosPolicy {
+                    value: vec![XCDR2_DATA_REPRESENTATION],
+                },
+            },
+            reader_proxy: ReaderProxy {
+                remote_reader_guid: Guid::new(
+                    [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0],
+                    EntityId::new([4, 0, 0], USER_DEFINED_UNKNOWN),
+                ),
+                remote_group_entity_id: EntityId::new([21, 22, 23], BUILT_IN_WRITER_WITH_KEY),
+                unicast_locator_list: vec![Locator::new(LOCATOR_KIND_UDP_V4, 7400, [1; 16])],
+                multicast_locator_list: vec![Locator::new(LOCATOR_KIND_UDP_V4, 7401, [2; 16])],
+                expects_inline_qos: true,
+            },
+        };
+
+        let mut buffer = vec![0x00, 0x03, 0x00, 0x00];
+        data.clone()
+            .create_dynamic_sample()
+            .serialize(&mut PlCdrLeSerializer::new(&mut buffer))
+            .unwrap();
+
+        let result = DiscoveredReaderData::deserialize_data(&buffer).unwrap();
+        assert_eq!(result, data);
+    }
+    #[test]
+    fn deserialize_big_endian_policies() {
+        let data = &[
+            0x00, 0x02, 0x00, 0x00, // PL_CDR_BE
+            0x00, 0x5a, 0, 16, //PID_ENDPOINT_GUID, length
+            1, 0, 0, 0, // ,
+            2, 0, 0, 0, // ,
+            3, 0, 0, 0, // ,
+            4, 0, 0, 0, // ,
+            0x00, 0x05, 0x00, 0x08, // PID_TOPIC_NAME, Length: 8
+            0x00, 0x00, 0x00, 3, // string length (incl. terminator)
+            b'a', b'b', 0, 0x00, // string + padding (1 byte)
+            0x00, 0x07, 0x00, 0x08, // PID_TYPE_NAME, Length: 8
+            0x00, 0x00, 0x00, 3, // string length (incl. terminator)
+            b'c', b'd', 0, 0x00, // string + padding (1 byte)
+            0x00, 0x1d, 0, 4, // PID_DURABILITY, length
+            0, 0, 0, 1, // TransientLocal
+            0x00, 0x21, 0, 8, // PID_PRESENTATION, length
+            0, 0, 0, 1, // Topic
+            1, 1, 0, 0, // coherent_access | ordered_access | padding
+            0x00, 0x01, 0x00, 0x00, // PID_SENTINEL, length
+        ][..];
+        let result = DiscoveredReaderData::deserialize_data(data).unwrap();
+        assert_eq!(
+            result.dds_subscription_data.durability,
+            DurabilityQosPolicy {
+                kind: DurabilityQosPolicyKind::TransientLocal
+            }
+        );
+        assert_eq!(
+            result.dds_subscription_data.presentation,
+            PresentationQosPolicy {
+                access_scope: PresentationQosPolicyAccessScopeKind::Topic,
+                coherent_access: true,
+                ordered_access: true,
+            }
+        );
+    }
 }
@@ -1,10 +1,11 @@
 use super::{
     parameter_id_values::{
         PID_DATA_REPRESENTATION, PID_DEADLINE, PID_DESTINATION_ORDER, PID_DURABILITY,
-        PID_ENDPOINT_GUID, PID_GROUP_DATA, PID_GROUP_ENTITYID, PID_LATENCY_BUDGET, PID_LIFESPAN,
-        PID_LIVELINESS, PID_MULTICAST_LOCATOR, PID_OWNERSHIP, PID_OWNERSHIP_STRENGTH,
-        PID_PARTICIPANT_GUID, PID_PARTITION, PID_PRESENTATION, PID_RELIABILITY, PID_TOPIC_DATA,
-        PID_TOPIC_NAME, PID_TYPE_NAME, PID_UNICAST_LOCATOR, PID_USER_DATA,
+        PID_DURABILITY_SERVICE, PID_ENDPOINT_GUID, PID_GROUP_DATA, PID_GROUP_ENTITYID,
+        PID_LATENCY_BUDGET, PID_LIFESPAN, PID_LIVELINESS, PID_MULTICAST_LOCATOR, PID_OWNERSHIP,
+        PID_OWNERSHIP_STRENGTH, PID_PARTICIPANT_GUID, PID_PARTITION, PID_PRESENTATION,
+        PID_RELIABILITY, PID_TOPIC_DATA, PID_TOPIC_NAME, PID_TYPE_NAME, PID_UNICAST_LOCATOR,
+        PID_USER_DATA,
     },
     payload_serializer_deserializer::parameter_list_deserializer::ParameterListCdrDeserializer,
 };
@@ -14,10 +15,10 @@ use crate::{
         error::DdsResult,
         qos_policy::{
             DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy,
-            DurabilityQosPolicy, GroupDataQosPolicy, LatencyBudgetQosPolicy, LifespanQosPolicy,
-            LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy,
-            PartitionQosPolicy, PresentationQosPolicy, TopicDataQosPolicy, UserDataQosPolicy,
-            DEFAULT_RELIABILITY_QOS_POLICY_DATA_WRITER,
+            DurabilityQosPolicy, DurabilityServiceQosPolicy, GroupDataQosPolicy,
+            LatencyBudgetQosPolicy, LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy,
+            OwnershipStrengthQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
+            TopicDataQosPolicy, UserDataQosPolicy, DEFAULT_RELIABILITY_QOS_POLICY_DATA_WRITER,
         },
         type_support::{DdsDeserialize, TypeSupport},
     },
@@ -148,6 +149,11 @@ impl TypeSupport for DiscoveredWriterData {
             PID_DURABILITY,
             DurabilityQosPolicy::default(),
         );
+        builder.add_member_with_default(
+            "durability_service",
+            PID_DURABILITY_SERVICE,
+            DurabilityServiceQosPolicy::default(),
+        );
         builder.add_member_with_default("deadline", PID_DEADLINE, DeadlineQosPolicy::default());
         builder.add_member_with_default(
             "latency_budget",
@@ -231,6 +237,10 @@ impl TypeSupport for DiscoveredWriterData {
             .set_value(PID_TOPIC_NAME as u32, self.dds_publication_data.topic_name)
             .set_value(PID_TYPE_NAME as u32, self.dds_publication_data.type_name)
             .set_value(PID_DURABILITY as u32, self.dds_publication_data.durability)
+            .set_value(
+                PID_DURABILITY_SERVICE as u32,
+                self.dds_publication_data.durability_service,
+            )
             .set_value(PID_DEADLINE as u32, self.dds_publication_data.deadline)
             .set_value(
                 PID_LATENCY_BUDGET as u32,
@@ -289,6 +299,8 @@ impl<'de> DdsDeserialize<'de> for PublicationBuiltinTopicData {
             topic_name: pl_deserializer.read(PID_TOPIC_NAME)?,
             type_name: pl_deserializer.read(PID_TYPE_NAME)?,
             durability: pl_deserializer.read_with_default(PID_DURABILITY, Default::default())?,
+            durability_service: pl_deserializer
+                .read_with_default(PID_DURABILITY_SERVICE, Default::default())?,
             deadline: pl_deserializer.read_with_default(PID_DEADLINE, Default::default())?,
             latency_budget: pl_deserializer
                 .read_with_default(PID_LATENCY_BUDGET, Default::default())?,
@@ -336,9 +348,18 @@ mod tests {
     use super::*;
     use crate::{
         builtin_topics::BuiltInTopicKey,
+        infrastructure::{
+            qos_policy::{
+                DestinationOrderQosPolicyKind, DurabilityQosPolicyKind, HistoryQosPolicyKind,
+                Length, LivelinessQosPolicyKind, OwnershipQosPolicyKind,
+                PresentationQosPolicyAccessScopeKind, ReliabilityQosPolicy,
+                ReliabilityQosPolicyKind, XCDR2_DATA_REPRESENTATION,
+            },
+            time::{Duration, DurationKind},
+        },
         transport::types::{
             EntityId, Guid, BUILT_IN_PARTICIPANT, BUILT_IN_READER_GROUP, BUILT_IN_WRITER_WITH_KEY,
-            USER_DEFINED_UNKNOWN,
+            LOCATOR_KIND_UDP_V4, USER_DEFINED_UNKNOWN,
         },
         xtypes::pl_cdr_serializer::PlCdrLeSerializer,
     };
@@ -356,6 +377,7 @@ mod tests {
                 topic_name: "ab".to_string(),
                 type_name: "cd".to_string(),
                 durability: Default::default(),
+                durability_service: Default::default(),
                 deadline: Default::default(),
                 latency_budget: Default::default(),
                 liveliness: Default::default(),
@@ -426,6 +448,7 @@ mod tests {
                 topic_name: "ab".to_string(),
                 type_name: "cd".to_string(),
                 durability: Default::default(),
+                durability_service: Default::default(),
                 deadline: Default::default(),
                 latency_budget: Default::default(),
                 liveliness: Default::default(),
@@ -453,7 +476,7 @@ mod tests {
             },
         };
 
-        let mut data = &[
+        let data = &[
             0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
             0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
             21, 22, 23, 0xc1, // u8[3], u8
@@ -475,7 +498,164 @@ mod tests {
             b'c', b'd', 0, 0x00, // string + padding (1 byte)
             0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
         ][..];
-        let result = DiscoveredWriterData::deserialize_data(&mut data).unwrap();
+        let result = DiscoveredWriterData::deserialize_data(data).unwrap();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn durability_service_round_trip() {
+        let durability_service = DurabilityServiceQosPolicy {
+            service_cleanup_delay: DurationKind::Finite(Duration::new(2, 3)),
+            history_kind: HistoryQosPolicyKind::KeepLast(4),
+            max_samples: Length::Limited(10),
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(5),
+        };
+        let data = DiscoveredWriterData {
+            dds_publication_data: PublicationBuiltinTopicData {
+                key: BuiltInTopicKey {
+                    value: [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0],
+                },
+                participant_key: BuiltInTopicKey::default(),
+                topic_name: "ab".to_string(),
+                type_name: "cd".to_string(),
+                durability: Default::default(),
+                durability_service: durability_service.clone(),
+                deadline: Default::default(),
+                latency_budget: Default::default(),
+                liveliness: Default::default(),
+                reliability: DEFAULT_RELIABILITY_QOS_POLICY_DATA_WRITER,
+                lifespan: Default::default(),
+                user_data: Default::default(),
+                ownership: Default::default(),
+                ownership_strength: Default::default(),
+                destination_order: Default::default(),
+                presentation: Default::default(),
+                partition: Default::default(),
+                topic_data: Default::default(),
+                group_data: Default::default(),
+                representation: Default::default(),
+            },
+            writer_proxy: WriterProxy {
+                remote_writer_guid: Guid::new(
+                    [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0],
+                    EntityId::new([4, 0, 0], USER_DEFINED_UNKNOWN),
+                ),
+                remote_group_entity_id: ENTITYID_UNKNOWN,
+                unicast_locator_list: vec![],
+                multicast_locator_list: vec![],
+            },
+        };
+
+        let expected_parameter = [
+            0x1e, 0x00, 28, 0, // PID_DURABILITY_SERVICE, length
+            2, 0, 0, 0, // service_cleanup_delay sec
+            3, 0, 0, 0, // service_cleanup_delay nanosec
+            0, 0, 0, 0, // history_kind: KeepLast
+            4, 0, 0, 0, // history_depth
+            10, 0, 0, 0, // max_samples
+            0xff, 0xff, 0xff, 0xff, // max_instances: Unlimited
+            5, 0, 0, 0, // max_samples_per_instance
+        ];
+        let mut buffer = vec![0x00, 0x03, 0x00, 0x00];
+        data.clone()
+            .create_dynamic_sample()
+            .serialize(&mut PlCdrLeSerializer::new(&mut buffer))
+            .unwrap();
+        assert!(buffer
+            .windows(expected_parameter.len())
+            .any(|w| w == expected_parameter));
+
+        let result = DiscoveredWriterData::deserialize_data(&buffer).unwrap();
+        assert_eq!(
+            result.dds_publication_data.durability_service(),
+            &durability_service
+        );
+        assert_eq!(result, data);
+    }
+    #[test]
+    fn all_policies_round_trip() {
+        let data = DiscoveredWriterData {
+            dds_publication_data: PublicationBuiltinTopicData {
+                key: BuiltInTopicKey {
+                    value: [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0],
+                },
+                participant_key: BuiltInTopicKey {
+                    value: [6, 0, 0, 0, 7, 0, 0, 0, 8, 0, 0, 0, 9, 0, 0, 0],
+                },
+                topic_name: "ab".to_string(),
+                type_name: "cd".to_string(),
+                durability: DurabilityQosPolicy {
+                    kind: DurabilityQosPolicyKind::TransientLocal,
+                },
+                durability_service: DurabilityServiceQosPolicy {
+                    service_cleanup_delay: DurationKind::Finite(Duration::new(2, 3)),
+                    history_kind: HistoryQosPolicyKind::KeepLast(4),
+                    max_samples: Length::Limited(10),
+                    max_instances: Length::Unlimited,
+                    max_samples_per_instance: Length::Limited(5),
+                },
+                deadline: DeadlineQosPolicy {
+                    period: DurationKind::Finite(Duration::new(1, 2)),
+                },
+                latency_budget: LatencyBudgetQosPolicy {
+                    duration: DurationKind::Finite(Duration::new(3, 4)),
+                },
+                liveliness: LivelinessQosPolicy {
+                    kind: LivelinessQosPolicyKind::ManualByParticipant,
+                    lease_duration: DurationKind::Finite(Duration::new(5, 6)),
+                },
+                reliability: ReliabilityQosPolicy {
+                    kind: ReliabilityQosPolicyKind::BestEffort,
+                    max_blocking_time: DurationKind::Finite(Duration::new(7, 8)),
+                },
+                lifespan: LifespanQosPolicy {
+                    duration: DurationKind::Finite(Duration::new(9, 10)),
+                },
+                user_data: UserDataQosPolicy {
+                    value: vec![1, 2, 3],
+                },
+                ownership: OwnershipQosPolicy {
+                    kind: OwnershipQosPolicyKind::Exclusive,
+                },
+                ownership_strength: OwnershipStrengthQosPolicy { value: 11 },
+                destination_order: DestinationOrderQosPolicy {
+                    kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
+                },
+                presentation: PresentationQosPolicy {
+                    access_scope: PresentationQosPolicyAccessScopeKind::Topic,
+                    coherent_access: true,
+                    ordered_access: true,
+                },
+                partition: PartitionQosPolicy {
+                    name: vec![String::from("one"), String::from("two")],
+                },
+                topic_data: TopicDataQosPolicy {
+                    value: vec![4, 5, 6, 7],
+                },
+                group_data: GroupDataQosPolicy { value: vec![8, 9] },
+                representation: DataRepresentationQosPolicy {
+                    value: vec![XCDR2_DATA_REPRESENTATION],
+                },
+            },
+            writer_proxy: WriterProxy {
+                remote_writer_guid: Guid::new(
+                    [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0],
+                    EntityId::new([4, 0, 0], USER_DEFINED_UNKNOWN),
+                ),
+                remote_group_entity_id: EntityId::new([21, 22, 23], BUILT_IN_READER_GROUP),
+                unicast_locator_list: vec![Locator::new(LOCATOR_KIND_UDP_V4, 7400, [1; 16])],
+                multicast_locator_list: vec![Locator::new(LOCATOR_KIND_UDP_V4, 7401, [2; 16])],
+            },
+        };
+
+        let mut buffer = vec![0x00, 0x03, 0x00, 0x00];
+        data.clone()
+            .create_dynamic_sample()
+            .serialize(&mut PlCdrLeSerializer::new(&mut buffer))
+            .unwrap();
+
+        let result = DiscoveredWriterData::deserialize_data(&buffer).unwrap();
+        assert_eq!(result, data);
+    }
 }
@@ -14,7 +14,7 @@ pub const PID_TYPE_NAME: ParameterId = 0x0007;
 pub const PID_GROUP_DATA: ParameterId = 0x002d;
 pub const PID_TOPIC_DATA: ParameterId = 0x002e;
 pub const PID_DURABILITY: ParameterId = 0x001d;
-pub const _PID_DURABILITY_SERVICE: ParameterId = 0x001e;
+pub const PID_DURABILITY_SERVICE: ParameterId = 0x001e;
 pub const PID_DEADLINE: ParameterId = 0x0023;
 pub const PID_LATENCY_BUDGET: ParameterId = 0x0027;
 pub const PID_LIVELINESS: ParameterId = 0x001b;
@@ -130,6 +130,17 @@ impl<'de> ParameterListCdrDeserializer<'de> {
         Ok(collection)
     }
 
+    pub fn count(&self, pid: ParameterId) -> Result<usize, RtpsError> {
+        let mut count = 0;
+        let mut iterator = self.iter();
+        while let Some(parameter) = iterator.next()? {
+            if parameter.pid == pid {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
     pub fn read_with_default<T>(&self, pid: ParameterId, default: T) -> Result<T, RtpsError>
     where
         T: XTypesDeserialize<'de>,
@@ -143,3 +154,75 @@ impl<'de> ParameterListCdrDeserializer<'de> {
         Ok(default)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dcps::data_representation_builtin_endpoints::parameter_id_values::{
+            PID_METATRAFFIC_MULTICAST_LOCATOR, PID_METATRAFFIC_UNICAST_LOCATOR,
+        },
+        transport::types::Locator,
+    };
+
+    #[test]
+    fn read_collection_of_repeated_locators() {
+        #[rustfmt::skip]
+        let data = [
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            0x32, 0x00, 24, 0x00, // PID_METATRAFFIC_UNICAST_LOCATOR
+            0x01, 0x00, 0x00, 0x00, // Locator{kind
+            0x01, 0x00, 0x00, 0x00, // port,
+            1, 1, 1, 1, //
+            1, 1, 1, 1, //
+            1, 1, 1, 1, //
+            1, 1, 1, 1, // address
+            0x33, 0x00, 24, 0x00, // PID_METATRAFFIC_MULTICAST_LOCATOR
+            0x01, 0x00, 0x00, 0x00, // Locator{kind
+            0x04, 0x00, 0x00, 0x00, // port,
+            4, 4, 4, 4, //
+            4, 4, 4, 4, //
+            4, 4, 4, 4, //
+            4, 4, 4, 4, // address
+            0x32, 0x00, 24, 0x00, // PID_METATRAFFIC_UNICAST_LOCATOR
+            0x01, 0x00, 0x00, 0x00, // Locator{kind
+            0x02, 0x00, 0x00, 0x00, // port,
+            2, 2, 2, 2, //
+            2, 2, 2, 2, //
+            2, 2, 2, 2, //
+            2, 2, 2, 2, // address
+            0x32, 0x00, 24, 0x00, // PID_METATRAFFIC_UNICAST_LOCATOR
+            0x01, 0x00, 0x00, 0x00, // Locator{kind
+            0x03, 0x00, 0x00, 0x00, // port,
+            3, 3, 3, 3, //
+            3, 3, 3, 3, //
+            3, 3, 3, 3, //
+            3, 3, 3, 3, // address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL
+        ];
+        let deserializer = ParameterListCdrDeserializer::new(&data).unwrap();
+
+        let locators: Vec<Locator> = deserializer
+            .read_collection(PID_METATRAFFIC_UNICAST_LOCATOR)
+            .unwrap();
+
+        assert_eq!(
+            locators,
+            vec![
+                Locator::new(1, 1, [1; 16]),
+                Locator::new(1, 2, [2; 16]),
+                Locator::new(1, 3, [3; 16]),
+            ]
+        );
+        assert_eq!(
+            deserializer.count(PID_METATRAFFIC_UNICAST_LOCATOR).unwrap(),
+            3
+        );
+        assert_eq!(
+            deserializer
+                .count(PID_METATRAFFIC_MULTICAST_LOCATOR)
+                .unwrap(),
+            1
+        );
+    }
+}
@@ -24,9 +24,11 @@ use crate::{
             ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR, ENTITYID_SEDP_BUILTIN_TOPICS_ANNOUNCER,
-            ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR,
+            ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR, ENTITYID_SPDP_BUILTIN_PARTICIPANT_READER,
+            ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER,
         },
         listeners::domain_participant_listener::ListenerMail,
+        serialization_buffer::SerializationBuffer,
         status_condition::DcpsStatusCondition,
         status_condition_mail::DcpsStatusConditionMail,
         xtypes_glue::key_and_instance_handle::{
@@ -49,17 +51,22 @@ use crate::{
         },
         qos_policy::{
             DestinationOrderQosPolicyKind, DurabilityQosPolicyKind, HistoryQosPolicy,
-            HistoryQosPolicyKind, Length, LifespanQosPolicy, OwnershipQosPolicyKind, QosPolicyId,
-            ReliabilityQosPolicyKind, ResourceLimitsQosPolicy, TransportPriorityQosPolicy,
-            BUILT_IN_DATA_REPRESENTATION, DATA_REPRESENTATION_QOS_POLICY_ID,
-            DEADLINE_QOS_POLICY_ID, DESTINATIONORDER_QOS_POLICY_ID, DURABILITY_QOS_POLICY_ID,
-            LATENCYBUDGET_QOS_POLICY_ID, LIVELINESS_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID,
-            PRESENTATION_QOS_POLICY_ID, RELIABILITY_QOS_POLICY_ID, XCDR2_DATA_REPRESENTATION,
-            XCDR_DATA_REPRESENTATION,
+            HistoryQosPolicyKind, Length, LifespanQosPolicy, LivelinessQosPolicyKind,
+            OwnershipQosPolicyKind, PartitionQosPolicy, QosPolicyId, ReliabilityQosPolicyKind,
+            ResourceLimitsQosPolicy, TransportPriorityQosPolicy, BUILT_IN_DATA_REPRESENTATION,
+            DATA_REPRESENTATION_QOS_POLICY_ID, DEADLINE_QOS_POLICY_ID,
+            DESTINATIONORDER_QOS_POLICY_ID, DURABILITYSERVICE_QOS_POLICY_ID,
+            DURABILITY_QOS_POLICY_ID, LATENCYBUDGET_QOS_POLICY_ID, LIVELINESS_QOS_POLICY_ID,
+            OWNERSHIP_QOS_POLICY_ID, PRESENTATION_QOS_POLICY_ID, RELIABILITY_QOS_POLICY_ID,
+            XCDR2_DATA_REPRESENTATION, XCDR_DATA_REPRESENTATION,
+        },
+        sample_info::{
+            InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind, ANY_INSTANCE_STATE,
+            ANY_VIEW_STATE,
         },
-        sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
         status::{
-            InconsistentTopicStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
+            InconsistentTopicStatus, LivelinessLostStatus, MatchDiagnostic,
+            OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, ParticipantStatistics,
             PublicationMatchedStatus, QosPolicyCount, RequestedDeadlineMissedStatus,
             RequestedIncompatibleQosStatus, SampleRejectedStatus, SampleRejectedStatusKind,
             StatusKind, SubscriptionMatchedStatus,
@@ -76,8 +83,8 @@ use crate::{
             TransportStatelessWriter,
         },
         types::{
-            CacheChange, ChangeKind, DurabilityKind, EntityId, Guid, ReliabilityKind, TopicKind,
-            ENTITYID_UNKNOWN, USER_DEFINED_READER_GROUP, USER_DEFINED_READER_NO_KEY,
+            CacheChange, ChangeKind, DurabilityKind, EntityId, Guid, Locator, ReliabilityKind,
+            TopicKind, ENTITYID_UNKNOWN, USER_DEFINED_READER_GROUP, USER_DEFINED_READER_NO_KEY,
             USER_DEFINED_READER_WITH_KEY, USER_DEFINED_TOPIC, USER_DEFINED_WRITER_GROUP,
             USER_DEFINED_WRITER_NO_KEY, USER_DEFINED_WRITER_WITH_KEY,
         },
@@ -98,23 +105,29 @@ use alloc::{
     vec::Vec,
 };
 use core::{
+    any::{Any, TypeId},
     future::{poll_fn, Future},
     pin::{pin, Pin},
     task::Poll,
 };
 use regex::Regex;
 
+// Interval between the checks of the acknowledgments while waiting for them
+pub const ACKNOWLEDGMENT_POLL_INTERVAL: core::time::Duration =
+    core::time::Duration::from_millis(10);
+
 pub fn poll_timeout<T>(
     mut timer_handle: impl Timer,
     duration: core::time::Duration,
     mut future: Pin<Box<dyn Future<Output = T> + Send>>,
 ) -> impl Future<Output = DdsResult<T>> {
+    // The timeout is created once so that it is not restarted every time the future is polled
+    let mut timeout = Box::pin(async move { timer_handle.delay(duration).await });
     poll_fn(move |cx| {
-        let timeout = timer_handle.delay(duration);
         if let Poll::Ready(t) = pin!(&mut future).poll(cx) {
             return Poll::Ready(Ok(t));
         }
-        if pin!(timeout).poll(cx).is_ready() {
+        if timeout.as_mut().poll(cx).is_ready() {
             return Poll::Ready(Err(DdsError::Timeout));
         }
 
@@ -133,6 +146,8 @@ pub struct DcpsDomainParticipant<R: DdsRuntime, T: TransportParticipantFactory>
     clock_handle: R::ClockHandle,
     timer_handle: R::TimerHandle,
     spawner_handle: R::SpawnerHandle,
+    cache_full_drops: u64,
+    deleted: bool,
 }
 
 impl<R, T> DcpsDomainParticipant<R, T>
@@ -158,9 +173,15 @@ where
             clock_handle,
             timer_handle,
             spawner_handle,
+            cache_full_drops: 0,
+            deleted: false,
         }
     }
 
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
     fn get_participant_async(
         &self,
         participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
@@ -258,6 +279,36 @@ where
         ))
     }
 
+    fn check_user_defined_entity_id(&self, entity_id: EntityId, entity_kind: u8) -> DdsResult<()> {
+        if entity_id.entity_kind() != entity_kind {
+            return Err(DdsError::BadParameter);
+        }
+        let entity_handle_key = [
+            entity_id.entity_key()[0],
+            entity_id.entity_key()[1],
+            entity_id.entity_key()[2],
+            entity_id.entity_kind(),
+        ];
+        let is_in_use = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter()
+            .flat_map(|p| p.data_writer_list.iter().map(|w| w.instance_handle))
+            .chain(
+                self.domain_participant
+                    .user_defined_subscriber_list
+                    .iter()
+                    .flat_map(|s| s.data_reader_list.iter().map(|r| r.instance_handle)),
+            )
+            .any(|h| h.as_ref()[12..16] == entity_handle_key);
+        if is_in_use {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "Entity id {entity_id:?} is already in use in this participant"
+            )));
+        }
+        Ok(())
+    }
+
     fn get_topic_description_async(
         &self,
         participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
@@ -853,6 +904,16 @@ where
         }
     }
 
+    // The types are registered together with the topics created on the participant
+    #[tracing::instrument(skip(self))]
+    pub fn lookup_registered_type(&self, type_name: String) -> Option<DynamicType> {
+        self.domain_participant
+            .topic_list
+            .iter()
+            .find(|x| x.type_name == type_name)
+            .map(|x| x.type_support.as_ref().clone())
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn ignore_participant(&mut self, handle: InstanceHandle) -> DdsResult<()> {
         if self.domain_participant.enabled {
@@ -870,6 +931,18 @@ where
         }
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn ignore_topic(&mut self, handle: InstanceHandle) -> DdsResult<()> {
+        if self.domain_participant.enabled {
+            if !self.domain_participant.ignored_topic_list.contains(&handle) {
+                self.domain_participant.ignored_topic_list.push(handle);
+            }
+            Ok(())
+        } else {
+            Err(DdsError::NotEnabled)
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn ignore_subscription(&mut self, handle: InstanceHandle) -> DdsResult<()> {
         if self.domain_participant.enabled {
@@ -1047,6 +1120,25 @@ where
         self.clock_handle.now()
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn get_statistics(&self) -> ParticipantStatistics {
+        let transport_statistics = self.transport.statistics();
+        ParticipantStatistics {
+            malformed_packets: transport_statistics.malformed_packets,
+            unknown_submessages: transport_statistics.unknown_submessages,
+            cache_full_drops: self.cache_full_drops,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn announce(&mut self) -> DdsResult<()> {
+        if !self.domain_participant.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+        self.announce_participant().await;
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn set_domain_participant_qos(
         &mut self,
@@ -1121,6 +1213,7 @@ where
         subscriber_handle: InstanceHandle,
         topic_name: String,
         qos: QosKind<DataReaderQos>,
+        entity_id: Option<EntityId>,
         status_condition: Actor<R, DcpsStatusCondition<R>>,
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         mask: Vec<StatusKind>,
@@ -1180,6 +1273,15 @@ where
         let type_name = topic.type_name.clone();
 
         let type_support = topic.type_support.clone();
+
+        let entity_kind = match topic_kind {
+            TopicKind::NoKey => USER_DEFINED_READER_NO_KEY,
+            TopicKind::WithKey => USER_DEFINED_READER_WITH_KEY,
+        };
+        if let Some(entity_id) = entity_id {
+            self.check_user_defined_entity_id(entity_id, entity_kind)?;
+        }
+
         let Some(subscriber) = self
             .domain_participant
             .user_defined_subscriber_list
@@ -1200,18 +1302,16 @@ where
             }
         };
 
-        let entity_kind = match topic_kind {
-            TopicKind::NoKey => USER_DEFINED_READER_NO_KEY,
-            TopicKind::WithKey => USER_DEFINED_READER_WITH_KEY,
-        };
-        let entity_id = EntityId::new(
-            [
-                subscriber.instance_handle[12],
-                self.reader_counter.to_ne_bytes()[0],
-                self.reader_counter.to_ne_bytes()[1],
-            ],
-            entity_kind,
-        );
+        let entity_id = entity_id.unwrap_or_else(|| {
+            EntityId::new(
+                [
+                    subscriber.instance_handle[12],
+                    self.reader_counter.to_ne_bytes()[0],
+                    self.reader_counter.to_ne_bytes()[1],
+                ],
+                entity_kind,
+            )
+        });
         let reader_handle = InstanceHandle::new([
             self.domain_participant.instance_handle[0],
             self.domain_participant.instance_handle[1],
@@ -1239,6 +1339,7 @@ where
             self.transport
                 .create_stateful_reader(
                     entity_id,
+                    &topic_name,
                     reliablity_kind,
                     Box::new(UserDefinedReaderHistoryCache::<R> {
                         domain_participant_address: domain_participant_address.clone(),
@@ -1451,6 +1552,59 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn notify_data_readers(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .user_defined_subscriber_list
+            .iter()
+            .find(|x| x.instance_handle == subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let data_reader_handle_list: Vec<_> = subscriber
+            .data_reader_list
+            .iter()
+            .filter(|x| {
+                x.listener_mask.contains(&StatusKind::DataAvailable) && x.has_unread_samples()
+            })
+            .map(|x| x.instance_handle)
+            .collect();
+
+        for data_reader_handle in data_reader_handle_list {
+            let the_reader = self.get_data_reader_async(
+                participant_address.clone(),
+                subscriber_handle,
+                data_reader_handle,
+            )?;
+            let Some(data_reader) = self
+                .domain_participant
+                .user_defined_subscriber_list
+                .iter()
+                .find(|x| x.instance_handle == subscriber_handle)
+                .and_then(|s| {
+                    s.data_reader_list
+                        .iter()
+                        .find(|x| x.instance_handle == data_reader_handle)
+                })
+            else {
+                continue;
+            };
+            if let Some(l) = &data_reader.listener_sender {
+                l.send(ListenerMail::DataAvailable { the_reader })
+                    .await
+                    .ok();
+            }
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[tracing::instrument(skip(self, status_condition, listener_sender, participant_address))]
     pub async fn create_data_writer(
@@ -1458,6 +1612,7 @@ where
         publisher_handle: InstanceHandle,
         topic_name: String,
         qos: QosKind<DataWriterQos>,
+        entity_id: Option<EntityId>,
         status_condition: Actor<R, DcpsStatusCondition<R>>,
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         mask: Vec<StatusKind>,
@@ -1476,6 +1631,14 @@ where
         let type_support = topic.type_support.clone();
         let type_name = topic.type_name.clone();
 
+        let entity_kind = match topic_kind {
+            TopicKind::WithKey => USER_DEFINED_WRITER_WITH_KEY,
+            TopicKind::NoKey => USER_DEFINED_WRITER_NO_KEY,
+        };
+        if let Some(entity_id) = entity_id {
+            self.check_user_defined_entity_id(entity_id, entity_kind)?;
+        }
+
         let Some(publisher) = self
             .domain_participant
             .user_defined_publisher_list
@@ -1485,19 +1648,16 @@ where
             return Err(DdsError::AlreadyDeleted);
         };
 
-        let entity_kind = match topic_kind {
-            TopicKind::WithKey => USER_DEFINED_WRITER_WITH_KEY,
-            TopicKind::NoKey => USER_DEFINED_WRITER_NO_KEY,
-        };
-
-        let entity_id = EntityId::new(
-            [
-                publisher.instance_handle[12],
-                self.writer_counter.to_le_bytes()[0],
-                self.writer_counter.to_le_bytes()[1],
-            ],
-            entity_kind,
-        );
+        let entity_id = entity_id.unwrap_or_else(|| {
+            EntityId::new(
+                [
+                    publisher.instance_handle[12],
+                    self.writer_counter.to_le_bytes()[0],
+                    self.writer_counter.to_le_bytes()[1],
+                ],
+                entity_kind,
+            )
+        });
 
         let writer_handle = InstanceHandle::new([
             self.domain_participant.instance_handle[0],
@@ -1535,10 +1695,10 @@ where
         };
         let transport_writer = self
             .transport
-            .create_stateful_writer(entity_id, reliablity_kind)
+            .create_stateful_writer(entity_id, &topic_name, reliablity_kind)
             .await;
 
-        let data_writer = DataWriterEntity::new(
+        let mut data_writer = DataWriterEntity::new(
             writer_handle,
             TransportWriterKind::Stateful(transport_writer),
             topic_name,
@@ -1548,7 +1708,15 @@ where
             listener_sender,
             mask,
             qos,
+            SerializationBuffer::new(
+                self.domain_participant.serialization_buffer_capacity,
+                self.domain_participant
+                    .serialization_buffer_shrink_after_idle,
+            ),
         );
+        if publisher.is_coherent_set_open() {
+            data_writer.begin_coherent_set();
+        }
         let data_writer_handle = data_writer.instance_handle;
 
         publisher.data_writer_list.push(data_writer);
@@ -1652,6 +1820,58 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn begin_coherent_changes(&mut self, publisher_handle: InstanceHandle) -> DdsResult<()> {
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter_mut()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !publisher.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        publisher.coherent_set_depth += 1;
+        if publisher.is_coherent_set_open() {
+            for data_writer in publisher.data_writer_list.iter_mut() {
+                data_writer.begin_coherent_set();
+            }
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn end_coherent_changes(
+        &mut self,
+        publisher_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter_mut()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if publisher.coherent_set_depth == 0 {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "No matching begin_coherent_changes",
+            )));
+        }
+
+        // Nested coherent sets only terminate with the outermost end_coherent_changes
+        publisher.coherent_set_depth -= 1;
+        if publisher.coherent_set_depth == 0 {
+            for data_writer in publisher.data_writer_list.iter_mut() {
+                data_writer.end_coherent_set().await;
+            }
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn get_publisher_qos(
         &mut self,
@@ -1752,6 +1972,59 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn get_data_writer_listener(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<Option<R::ChannelSender<ListenerMail<R>>>> {
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        Ok(data_writer.listener_sender.clone())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_data_writer_listener_mask(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<Option<Vec<StatusKind>>> {
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        Ok(data_writer
+            .listener_sender
+            .as_ref()
+            .map(|_| data_writer.listener_mask.clone()))
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn get_data_writer_qos(
         &mut self,
@@ -1835,6 +2108,57 @@ where
             .cloned()
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn get_subscription_match_diagnostic(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        subscription_handle: InstanceHandle,
+    ) -> DdsResult<MatchDiagnostic> {
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(discovered_reader_data) = self
+            .domain_participant
+            .discovered_reader_list
+            .iter()
+            .find(|x| subscription_handle.as_ref() == &x.dds_subscription_data.key().value)
+        else {
+            return Err(DdsError::BadParameter);
+        };
+        let is_ignored = self
+            .domain_participant
+            .ignored_subcriptions
+            .contains(&subscription_handle)
+            || self
+                .domain_participant
+                .ignored_participants
+                .contains(&InstanceHandle::new(
+                    discovered_reader_data
+                        .dds_subscription_data
+                        .participant_key()
+                        .value,
+                ));
+        Ok(get_discovered_reader_match_diagnostic(
+            data_writer,
+            &publisher.qos,
+            &discovered_reader_data.dds_subscription_data,
+            is_ignored,
+        ))
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn unregister_instance(
         &mut self,
@@ -1985,37 +2309,30 @@ where
             }
         }
 
-        if let DurationKind::Finite(deadline_missed_period) = data_writer.qos.deadline.period {
-            let mut timer_handle = self.timer_handle.clone();
-            self.spawner_handle.spawn(async move {
-                loop {
-                    timer_handle.delay(deadline_missed_period.into()).await;
-                    participant_address
-                        .send(DcpsDomainParticipantMail::Event(
-                            EventServiceMail::OfferedDeadlineMissed {
-                                publisher_handle,
-                                data_writer_handle,
-                                change_instance_handle: instance_handle,
-                                participant_address: participant_address.clone(),
-                            },
-                        ))
-                        .await
-                        .ok();
-                }
-            });
+        if let Some(deadline_missed_period) =
+            data_writer.start_offered_deadline_check(instance_handle)
+        {
+            self.spawn_offered_deadline_check(
+                deadline_missed_period,
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+                instance_handle,
+            );
         }
 
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn dispose_w_timestamp(
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn register_instance_w_timestamp(
         &mut self,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
         dynamic_data: DynamicData,
         timestamp: Time,
-    ) -> DdsResult<()> {
+    ) -> DdsResult<Option<InstanceHandle>> {
         let Some(publisher) = self
             .domain_participant
             .user_defined_publisher_list
@@ -2031,10 +2348,84 @@ where
         else {
             return Err(DdsError::AlreadyDeleted);
         };
+        let instance_handle = match get_instance_handle_from_dynamic_data(dynamic_data) {
+            Ok(k) => k,
+            Err(e) => {
+                return Err(e.into());
+            }
+        };
 
-        data_writer
-            .dispose_w_timestamp(dynamic_data, timestamp)
-            .await
+        data_writer.register_instance_w_timestamp(instance_handle, timestamp)?;
+
+        if let Some(deadline_missed_period) =
+            data_writer.start_offered_deadline_check(instance_handle)
+        {
+            self.spawn_offered_deadline_check(
+                deadline_missed_period,
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+                instance_handle,
+            );
+        }
+
+        Ok(Some(instance_handle))
+    }
+
+    fn spawn_offered_deadline_check(
+        &self,
+        deadline_missed_period: Duration,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        instance_handle: InstanceHandle,
+    ) {
+        let mut timer_handle = self.timer_handle.clone();
+        self.spawner_handle.spawn(async move {
+            loop {
+                timer_handle.delay(deadline_missed_period.into()).await;
+                participant_address
+                    .send(DcpsDomainParticipantMail::Event(
+                        EventServiceMail::OfferedDeadlineMissed {
+                            publisher_handle,
+                            data_writer_handle,
+                            change_instance_handle: instance_handle,
+                            participant_address: participant_address.clone(),
+                        },
+                    ))
+                    .await
+                    .ok();
+            }
+        });
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn dispose_w_timestamp(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        dynamic_data: DynamicData,
+        timestamp: Time,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter_mut()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        data_writer
+            .dispose_w_timestamp(dynamic_data, timestamp)
+            .await
     }
 
     //#[tracing::instrument(skip(self, participant_address))]
@@ -2046,6 +2437,7 @@ where
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = DdsResult<()>> + Send>> {
         let timer_handle = self.timer_handle.clone();
+        let mut poll_timer_handle = self.timer_handle.clone();
         Box::pin(async move {
             poll_timeout(
                 timer_handle,
@@ -2067,7 +2459,9 @@ where
                         match reply {
                             Ok(are_changes_acknowledged) => match are_changes_acknowledged {
                                 Ok(true) => return Ok(()),
-                                Ok(false) => (),
+                                Ok(false) => {
+                                    poll_timer_handle.delay(ACKNOWLEDGMENT_POLL_INTERVAL).await
+                                }
                                 Err(e) => return Err(e),
                             },
                             Err(_) => return Err(DdsError::Error(String::from("Channel error"))),
@@ -2111,6 +2505,7 @@ where
         data_writer_handle: InstanceHandle,
         participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
     ) -> DdsResult<()> {
+        let current_time = self.get_current_time();
         let Some(publisher) = self
             .domain_participant
             .user_defined_publisher_list
@@ -2128,6 +2523,8 @@ where
         };
         if !data_writer.enabled {
             data_writer.enabled = true;
+            data_writer.assert_liveliness(current_time);
+            let liveliness = data_writer.qos.liveliness.clone();
 
             let discovered_reader_list: Vec<_> =
                 self.domain_participant.discovered_reader_list.to_vec();
@@ -2143,7 +2540,90 @@ where
 
             self.announce_data_writer(publisher_handle, data_writer_handle)
                 .await;
+
+            // With automatic liveliness the service asserts the liveliness of the writer
+            // so only the manual kinds need to be checked
+            if let (
+                LivelinessQosPolicyKind::ManualByParticipant
+                | LivelinessQosPolicyKind::ManualByTopic,
+                DurationKind::Finite(lease_duration),
+            ) = (liveliness.kind, liveliness.lease_duration)
+            {
+                let mut timer_handle = self.timer_handle.clone();
+                self.spawner_handle.spawn(async move {
+                    loop {
+                        timer_handle.delay(lease_duration.into()).await;
+                        let result = participant_address
+                            .send(DcpsDomainParticipantMail::Event(
+                                EventServiceMail::LivelinessLost {
+                                    publisher_handle,
+                                    data_writer_handle,
+                                    participant_address: participant_address.clone(),
+                                },
+                            ))
+                            .await;
+                        if result.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_liveliness_lost_status(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<LivelinessLostStatus> {
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter_mut()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        Ok(data_writer.get_liveliness_lost_status().await)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn assert_data_writer_liveliness(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let current_time = self.get_current_time();
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter_mut()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_writer.enabled {
+            return Err(DdsError::NotEnabled);
         }
+
+        data_writer.assert_liveliness(current_time);
         Ok(())
     }
 
@@ -2223,7 +2703,7 @@ where
         view_states: Vec<ViewStateKind>,
         instance_states: Vec<InstanceStateKind>,
         specific_instance_handle: Option<InstanceHandle>,
-    ) -> DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>> {
+    ) -> DdsResult<DeserializedSampleList> {
         let subscriber = if subscriber_handle == self.domain_participant.instance_handle {
             Some(&mut self.domain_participant.builtin_subscriber)
         } else {
@@ -2256,6 +2736,157 @@ where
             .await
     }
 
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    #[tracing::instrument(skip(self, sample_filter))]
+    pub async fn read_with_filter(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        sample_filter: SampleFilterFn,
+    ) -> DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>> {
+        let subscriber = if subscriber_handle == self.domain_participant.instance_handle {
+            Some(&mut self.domain_participant.builtin_subscriber)
+        } else {
+            self.domain_participant
+                .user_defined_subscriber_list
+                .iter_mut()
+                .find(|x| x.instance_handle == subscriber_handle)
+        };
+
+        let Some(subscriber) = subscriber else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        data_reader
+            .read_with_filter(
+                max_samples,
+                &sample_states,
+                &view_states,
+                &instance_states,
+                sample_filter,
+            )
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    #[tracing::instrument(skip(self, deserialize_data))]
+    pub async fn read_deserialized(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        deserialized_type: TypeId,
+        deserialize_data: DeserializeDataFn,
+    ) -> DdsResult<DeserializedSampleList> {
+        let subscriber = if subscriber_handle == self.domain_participant.instance_handle {
+            Some(&mut self.domain_participant.builtin_subscriber)
+        } else {
+            self.domain_participant
+                .user_defined_subscriber_list
+                .iter_mut()
+                .find(|x| x.instance_handle == subscriber_handle)
+        };
+
+        let Some(subscriber) = subscriber else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        data_reader
+            .read_deserialized(
+                max_samples,
+                &sample_states,
+                &view_states,
+                &instance_states,
+                deserialized_type,
+                deserialize_data,
+            )
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn peek_next_info(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<SampleInfo> {
+        let subscriber = if subscriber_handle == self.domain_participant.instance_handle {
+            Some(&mut self.domain_participant.builtin_subscriber)
+        } else {
+            self.domain_participant
+                .user_defined_subscriber_list
+                .iter_mut()
+                .find(|x| x.instance_handle == subscriber_handle)
+        };
+
+        let Some(subscriber) = subscriber else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        data_reader.peek_next_info()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_received_sequence_numbers(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<Vec<(i64, InstanceStateKind)>> {
+        let subscriber = if subscriber_handle == self.domain_participant.instance_handle {
+            Some(&self.domain_participant.builtin_subscriber)
+        } else {
+            self.domain_participant
+                .user_defined_subscriber_list
+                .iter()
+                .find(|x| x.instance_handle == subscriber_handle)
+        };
+
+        let Some(subscriber) = subscriber else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        data_reader.get_received_sequence_numbers(publication_handle)
+    }
+
     #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     #[tracing::instrument(skip(self))]
     pub async fn take(
@@ -2401,6 +3032,37 @@ where
         Ok(status)
     }
 
+    #[tracing::instrument(skip(self))]
+    pub async fn get_requested_deadline_missed_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<RequestedDeadlineMissedStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .user_defined_subscriber_list
+            .iter_mut()
+            .find(|x| x.instance_handle == subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let status = data_reader.get_requested_deadline_missed_status();
+        data_reader
+            .status_condition
+            .send_actor_mail(DcpsStatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::RequestedDeadlineMissed,
+            })
+            .await;
+        Ok(status)
+    }
+
     //#[tracing::instrument(skip(self, participant_address))]
     pub fn wait_for_historical_data(
         &mut self,
@@ -2477,6 +3139,57 @@ where
             .ok_or(DdsError::BadParameter)
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn get_publication_match_diagnostic(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<MatchDiagnostic> {
+        let Some(subscriber) = self
+            .domain_participant
+            .user_defined_subscriber_list
+            .iter()
+            .find(|x| x.instance_handle == subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(discovered_writer_data) = self
+            .domain_participant
+            .discovered_writer_list
+            .iter()
+            .find(|x| publication_handle.as_ref() == &x.dds_publication_data.key().value)
+        else {
+            return Err(DdsError::BadParameter);
+        };
+        let is_ignored = self
+            .domain_participant
+            .ignored_publications
+            .contains(&publication_handle)
+            || self
+                .domain_participant
+                .ignored_participants
+                .contains(&InstanceHandle::new(
+                    discovered_writer_data
+                        .dds_publication_data
+                        .participant_key()
+                        .value,
+                ));
+        Ok(get_discovered_writer_match_diagnostic(
+            data_reader,
+            &subscriber.qos,
+            &discovered_writer_data.dds_publication_data,
+            is_ignored,
+        ))
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn get_matched_publications(
         &mut self,
@@ -2498,10 +3211,41 @@ where
         else {
             return Err(DdsError::AlreadyDeleted);
         };
+        if !data_reader.enabled {
+            return Err(DdsError::NotEnabled);
+        }
 
         Ok(data_reader.get_matched_publications())
     }
 
+    pub fn get_data_reader_instance_serialized_data(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        instance_handle: InstanceHandle,
+    ) -> DdsResult<Arc<[u8]>> {
+        let Some(subscriber) = self
+            .domain_participant
+            .user_defined_subscriber_list
+            .iter_mut()
+            .find(|x| x.instance_handle == subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        data_reader.get_instance_serialized_data(instance_handle)
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn set_data_reader_qos(
         &mut self,
@@ -2713,14 +3457,14 @@ where
                     .transport
                     .default_multicast_locator_list()
                     .to_vec(),
-                available_builtin_endpoints: BuiltinEndpointSet::default(),
+                available_builtin_endpoints: self.domain_participant.available_builtin_endpoints(),
                 manual_liveliness_count: 0,
                 builtin_endpoint_qos: BuiltinEndpointQos::default(),
             };
             let spdp_discovered_participant_data = SpdpDiscoveredParticipantData {
                 dds_participant_data: participant_builtin_topic_data,
                 participant_proxy,
-                lease_duration: Duration::new(100, 0),
+                lease_duration: self.domain_participant.participant_lease_duration,
                 discovered_participant_list: self
                     .domain_participant
                     .discovered_participant_list
@@ -2750,6 +3494,10 @@ where
 
     #[tracing::instrument(skip(self))]
     pub async fn announce_deleted_participant(&mut self) {
+        // Dispose every endpoint before the participant itself so that the peers unmatch
+        // them right away instead of waiting for the participant lease to expire
+        self.delete_participant_contained_entities().await.ok();
+        self.deleted = true;
         if self.domain_participant.enabled {
             let timestamp = self.get_current_time();
             if let Some(dw) = self
@@ -2816,6 +3564,7 @@ where
             topic_name: data_writer.topic_name.clone(),
             type_name: data_writer.type_name.clone(),
             durability: data_writer.qos.durability.clone(),
+            durability_service: data_writer.qos.durability_service.clone(),
             deadline: data_writer.qos.deadline.clone(),
             latency_budget: data_writer.qos.latency_budget.clone(),
             liveliness: data_writer.qos.liveliness.clone(),
@@ -2833,8 +3582,8 @@ where
         };
         let writer_proxy = WriterProxy {
             remote_writer_guid: data_writer.transport_writer.guid(),
-            remote_group_entity_id: ENTITYID_UNKNOWN,
-            unicast_locator_list: vec![],
+            remote_group_entity_id: Guid::from(publisher.instance_handle).entity_id(),
+            unicast_locator_list: data_writer.transport_writer.unicast_locator_list().to_vec(),
             multicast_locator_list: vec![],
         };
         let discovered_writer_data = DiscoveredWriterData {
@@ -2938,8 +3687,8 @@ where
         };
         let reader_proxy = ReaderProxy {
             remote_reader_guid: data_reader.transport_reader.guid(),
-            remote_group_entity_id: ENTITYID_UNKNOWN,
-            unicast_locator_list: vec![],
+            remote_group_entity_id: Guid::from(subscriber.instance_handle).entity_id(),
+            unicast_locator_list: data_reader.transport_reader.unicast_locator_list().to_vec(),
             multicast_locator_list: vec![],
             expects_inline_qos: false,
         };
@@ -3080,6 +3829,7 @@ where
         } else {
             vec![]
         };
+        let max_matched_readers_per_writer = self.domain_participant.max_matched_readers_per_writer;
         let Some(publisher) = self
             .domain_participant
             .user_defined_publisher_list
@@ -3089,49 +3839,10 @@ where
             return;
         };
 
-        let is_any_name_matched = discovered_reader_data
-            .dds_subscription_data
-            .partition
-            .name
-            .iter()
-            .any(|n| publisher.qos.partition.name.contains(n));
-
-        let is_any_received_regex_matched_with_partition_qos = discovered_reader_data
-            .dds_subscription_data
-            .partition
-            .name
-            .iter()
-            .filter_map(|n| Regex::new(&fnmatch_to_regex(n)).ok())
-            .any(|regex| {
-                publisher
-                    .qos
-                    .partition
-                    .name
-                    .iter()
-                    .any(|n| regex.is_match(n))
-            });
-
-        let is_any_local_regex_matched_with_received_partition_qos = publisher
-            .qos
-            .partition
-            .name
-            .iter()
-            .filter_map(|n| Regex::new(&fnmatch_to_regex(n)).ok())
-            .any(|regex| {
-                discovered_reader_data
-                    .dds_subscription_data
-                    .partition
-                    .name
-                    .iter()
-                    .any(|n| regex.is_match(n))
-            });
-
-        let is_partition_matched = discovered_reader_data.dds_subscription_data.partition
-            == publisher.qos.partition
-            || is_any_name_matched
-            || is_any_received_regex_matched_with_partition_qos
-            || is_any_local_regex_matched_with_received_partition_qos;
-        if is_partition_matched {
+        if is_partition_matched(
+            &publisher.qos.partition,
+            &discovered_reader_data.dds_subscription_data.partition,
+        ) {
             let publisher_qos = publisher.qos.clone();
             let Some(data_writer) = publisher
                 .data_writer_list
@@ -3154,6 +3865,18 @@ where
                         &publisher_qos,
                     );
                 if incompatible_qos_policy_list.is_empty() {
+                    if !data_writer.has_room_for_matched_subscription(
+                        &discovered_reader_data.dds_subscription_data,
+                        max_matched_readers_per_writer,
+                    ) {
+                        data_writer.rejected_matched_reader_count += 1;
+                        tracing::warn!(
+                            reader = ?discovered_reader_data.reader_proxy.remote_reader_guid,
+                            rejected_matched_reader_count = data_writer.rejected_matched_reader_count,
+                            "Data writer reached the maximum number of matched readers"
+                        );
+                        return;
+                    }
                     data_writer.add_matched_subscription(
                         discovered_reader_data.dds_subscription_data.clone(),
                     );
@@ -3546,49 +4269,10 @@ where
         else {
             return;
         };
-        let is_any_name_matched = discovered_writer_data
-            .dds_publication_data
-            .partition
-            .name
-            .iter()
-            .any(|n| subscriber.qos.partition.name.contains(n));
-
-        let is_any_received_regex_matched_with_partition_qos = discovered_writer_data
-            .dds_publication_data
-            .partition
-            .name
-            .iter()
-            .filter_map(|n| Regex::new(&fnmatch_to_regex(n)).ok())
-            .any(|regex| {
-                subscriber
-                    .qos
-                    .partition
-                    .name
-                    .iter()
-                    .any(|n| regex.is_match(n))
-            });
-
-        let is_any_local_regex_matched_with_received_partition_qos = subscriber
-            .qos
-            .partition
-            .name
-            .iter()
-            .filter_map(|n| Regex::new(&fnmatch_to_regex(n)).ok())
-            .any(|regex| {
-                discovered_writer_data
-                    .dds_publication_data
-                    .partition
-                    .name
-                    .iter()
-                    .any(|n| regex.is_match(n))
-            });
-
-        let is_partition_matched = discovered_writer_data.dds_publication_data.partition
-            == subscriber.qos.partition
-            || is_any_name_matched
-            || is_any_received_regex_matched_with_partition_qos
-            || is_any_local_regex_matched_with_received_partition_qos;
-        if is_partition_matched {
+        if is_partition_matched(
+            &subscriber.qos.partition,
+            &discovered_writer_data.dds_publication_data.partition,
+        ) {
             let subscriber_qos = subscriber.qos.clone();
             let Some(data_reader) = subscriber
                 .data_reader_list
@@ -3953,6 +4637,11 @@ where
                         cache_change.data_value.as_ref(),
                     )
                 {
+                    if self.domain_participant.is_participant_ignored(
+                        &discovered_participant_data.dds_participant_data.key().value,
+                    ) {
+                        return;
+                    }
                     self.add_discovered_participant(discovered_participant_data)
                         .await;
                 }
@@ -3961,7 +4650,8 @@ where
                 if let Ok(discovered_participant_handle) =
                     InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
                 {
-                    self.remove_discovered_participant(discovered_participant_handle);
+                    self.remove_discovered_participant(discovered_participant_handle)
+                        .await;
                 }
             }
             ChangeKind::AliveFiltered
@@ -3994,6 +4684,12 @@ where
                 if let Ok(discovered_writer_data) =
                     DiscoveredWriterData::deserialize_data(cache_change.data_value.as_ref())
                 {
+                    if self
+                        .domain_participant
+                        .is_publication_ignored(&discovered_writer_data.dds_publication_data)
+                    {
+                        return;
+                    }
                     let publication_builtin_topic_data =
                         &discovered_writer_data.dds_publication_data;
                     if self
@@ -4048,24 +4744,8 @@ where
                 if let Ok(discovered_writer_handle) =
                     InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
                 {
-                    self.domain_participant
-                        .remove_discovered_writer(&discovered_writer_handle);
-
-                    let mut handle_list = Vec::new();
-                    for subscriber in &self.domain_participant.user_defined_subscriber_list {
-                        for data_reader in subscriber.data_reader_list.iter() {
-                            handle_list
-                                .push((subscriber.instance_handle, data_reader.instance_handle));
-                        }
-                    }
-                    for (subscriber_handle, data_reader_handle) in handle_list {
-                        self.remove_discovered_writer(
-                            discovered_writer_handle,
-                            subscriber_handle,
-                            data_reader_handle,
-                        )
+                    self.unmatch_discovered_writer(discovered_writer_handle)
                         .await;
-                    }
                 }
             }
             ChangeKind::AliveFiltered | ChangeKind::NotAliveUnregistered => (),
@@ -4096,6 +4776,12 @@ where
                 if let Ok(discovered_reader_data) =
                     DiscoveredReaderData::deserialize_data(cache_change.data_value.as_ref())
                 {
+                    if self
+                        .domain_participant
+                        .is_subscription_ignored(&discovered_reader_data.dds_subscription_data)
+                    {
+                        return;
+                    }
                     if self
                         .domain_participant
                         .find_topic(&discovered_reader_data.dds_subscription_data.topic_name)
@@ -4180,25 +4866,8 @@ where
                 if let Ok(discovered_reader_handle) =
                     InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
                 {
-                    self.domain_participant
-                        .remove_discovered_reader(&discovered_reader_handle);
-
-                    let mut handle_list = Vec::new();
-                    for publisher in &self.domain_participant.user_defined_publisher_list {
-                        for data_writer in publisher.data_writer_list.iter() {
-                            handle_list
-                                .push((publisher.instance_handle, data_writer.instance_handle));
-                        }
-                    }
-
-                    for (publisher_handle, data_writer_handle) in handle_list {
-                        self.remove_discovered_reader(
-                            discovered_reader_handle,
-                            publisher_handle,
-                            data_writer_handle,
-                        )
+                    self.unmatch_discovered_reader(discovered_reader_handle)
                         .await;
-                    }
                 }
             }
             ChangeKind::AliveFiltered | ChangeKind::NotAliveUnregistered => (),
@@ -4225,6 +4894,13 @@ where
                 if let Ok(topic_builtin_topic_data) =
                     TopicBuiltinTopicData::deserialize_data(cache_change.data_value.as_ref())
                 {
+                    if self
+                        .domain_participant
+                        .ignored_topic_list
+                        .contains(&InstanceHandle::new(topic_builtin_topic_data.key().value))
+                    {
+                        return;
+                    }
                     self.domain_participant
                         .add_discovered_topic(topic_builtin_topic_data.clone());
                     for topic in self.domain_participant.topic_list.iter_mut() {
@@ -4274,6 +4950,47 @@ where
         cache_change: CacheChange,
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
+    ) {
+        let Some(subscriber) = self
+            .domain_participant
+            .user_defined_subscriber_list
+            .iter_mut()
+            .find(|x| x.instance_handle == subscriber_handle)
+        else {
+            return;
+        };
+        let coherent_access = subscriber.qos.presentation.coherent_access;
+        let Some(data_reader) = subscriber
+            .data_reader_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_reader_handle)
+        else {
+            return;
+        };
+
+        // The changes of a coherent set are only made available once the whole set is received
+        let cache_change_list = if coherent_access {
+            complete_coherent_set(&mut data_reader.coherent_set_changes, cache_change)
+        } else {
+            vec![cache_change]
+        };
+        for cache_change in cache_change_list {
+            self.add_cache_change_to_data_reader(
+                participant_address.clone(),
+                cache_change,
+                subscriber_handle,
+                data_reader_handle,
+            )
+            .await;
+        }
+    }
+
+    async fn add_cache_change_to_data_reader(
+        &mut self,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+        cache_change: CacheChange,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
     ) {
         let reception_timestamp = self.get_current_time();
         let Some(subscriber) = self
@@ -4292,41 +5009,63 @@ where
         else {
             return;
         };
-        let writer_instance_handle = InstanceHandle::new(cache_change.writer_guid.into());
+        let writer_instance_handle = InstanceHandle::from(cache_change.writer_guid);
 
-        if data_reader
+        if let Some(matched_publication) = data_reader
             .matched_publication_list
             .iter()
-            .any(|x| &x.key().value == writer_instance_handle.as_ref())
+            .find(|x| &x.key().value == writer_instance_handle.as_ref())
         {
+            // The samples expire once the lifespan of their writer has elapsed since their source timestamp
+            let remaining_lifespan = match (
+                matched_publication.lifespan().duration,
+                cache_change.source_timestamp,
+            ) {
+                (DurationKind::Finite(lifespan_duration), Some(source_timestamp)) => {
+                    let source_timestamp: Time = source_timestamp.into();
+                    Some(source_timestamp - reception_timestamp + lifespan_duration)
+                }
+                _ => None,
+            };
+            if remaining_lifespan.is_some_and(|x| x <= Duration::new(0, 0)) {
+                return;
+            }
+            let sequence_number = cache_change.sequence_number;
+
             match data_reader.add_reader_change(cache_change, reception_timestamp) {
                 Ok(AddChangeResult::Added(change_instance_handle)) => {
-                    if let DurationKind::Finite(deadline_missed_period) =
-                        data_reader.qos.deadline.period
-                    {
+                    let deadline_missed_period =
+                        data_reader.start_requested_deadline_check(change_instance_handle);
+                    let deta_reader_on_data_available_active = data_reader
+                        .listener_mask
+                        .contains(&StatusKind::DataAvailable);
+                    if let Some(deadline_missed_period) = deadline_missed_period {
+                        self.spawn_requested_deadline_check(
+                            deadline_missed_period,
+                            participant_address.clone(),
+                            subscriber_handle,
+                            data_reader_handle,
+                            change_instance_handle,
+                        );
+                    }
+                    if let Some(remaining_lifespan) = remaining_lifespan {
                         let mut timer_handle = self.timer_handle.clone();
                         let participant_address = participant_address.clone();
-
                         self.spawner_handle.spawn(async move {
-                            loop {
-                                timer_handle.delay(deadline_missed_period.into()).await;
-                                participant_address
-                                    .send(DcpsDomainParticipantMail::Event(
-                                        EventServiceMail::RequestedDeadlineMissed {
-                                            subscriber_handle,
-                                            data_reader_handle,
-                                            change_instance_handle,
-                                            participant_address: participant_address.clone(),
-                                        },
-                                    ))
-                                    .await
-                                    .ok();
-                            }
+                            timer_handle.delay(remaining_lifespan.into()).await;
+                            participant_address
+                                .send(DcpsDomainParticipantMail::Message(
+                                    MessageServiceMail::RemoveReaderChange {
+                                        subscriber_handle,
+                                        data_reader_handle,
+                                        writer_handle: writer_instance_handle,
+                                        sequence_number,
+                                    },
+                                ))
+                                .await
+                                .ok();
                         });
                     }
-                    let deta_reader_on_data_available_active = data_reader
-                        .listener_mask
-                        .contains(&StatusKind::DataAvailable);
 
                     let Some(subscriber) = self
                         .domain_participant
@@ -4422,6 +5161,7 @@ where
                 }
                 Ok(AddChangeResult::NotAdded) => (), // Do nothing
                 Ok(AddChangeResult::Rejected(instance_handle, sample_rejected_status_kind)) => {
+                    self.cache_full_drops += 1;
                     data_reader.increment_sample_rejected_status(
                         instance_handle,
                         sample_rejected_status_kind,
@@ -4573,12 +5313,33 @@ where
             if let Some(dw) = p
                 .data_writer_list
                 .iter_mut()
-                .find(|x| x.instance_handle == data_writer_handle)
+                .find(|x| x.instance_handle == data_writer_handle)
+            {
+                dw.remove_change_from_history(sequence_number).await;
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_reader_change(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        writer_handle: InstanceHandle,
+        sequence_number: i64,
+    ) {
+        if let Some(s) = self
+            .domain_participant
+            .user_defined_subscriber_list
+            .iter_mut()
+            .find(|x| x.instance_handle == subscriber_handle)
+        {
+            if let Some(dr) = s
+                .data_reader_list
+                .iter_mut()
+                .find(|x| x.instance_handle == data_reader_handle)
             {
-                dw.transport_writer
-                    .history_cache()
-                    .remove_change(sequence_number)
-                    .await;
+                dr.remove_change(writer_handle, sequence_number).await;
             }
         }
     }
@@ -4754,6 +5515,195 @@ where
             .await;
     }
 
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn liveliness_lost(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+    ) {
+        let current_time = self.get_current_time();
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter_mut()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return;
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return;
+        };
+
+        let DurationKind::Finite(lease_duration) = data_writer.qos.liveliness.lease_duration else {
+            return;
+        };
+        // A writer which is already not alive is not counted again
+        match data_writer.last_liveliness_assertion_time {
+            Some(t) if current_time - t >= lease_duration => (),
+            _ => return,
+        }
+
+        data_writer.last_liveliness_assertion_time = None;
+        data_writer.liveliness_lost_status.total_count += 1;
+        data_writer.liveliness_lost_status.total_count_change += 1;
+
+        if data_writer
+            .listener_mask
+            .contains(&StatusKind::LivelinessLost)
+        {
+            let status = data_writer.get_liveliness_lost_status().await;
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+
+            let Some(publisher) = self
+                .domain_participant
+                .user_defined_publisher_list
+                .iter_mut()
+                .find(|x| x.instance_handle == publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher
+                .data_writer_list
+                .iter_mut()
+                .find(|x| x.instance_handle == data_writer_handle)
+            else {
+                return;
+            };
+
+            if let Some(l) = &data_writer.listener_sender {
+                l.send(ListenerMail::LivelinessLost { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if publisher
+            .listener_mask
+            .contains(&StatusKind::LivelinessLost)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self
+                .domain_participant
+                .user_defined_publisher_list
+                .iter_mut()
+                .find(|x| x.instance_handle == publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher
+                .data_writer_list
+                .iter_mut()
+                .find(|x| x.instance_handle == data_writer_handle)
+            else {
+                return;
+            };
+            let status = data_writer.get_liveliness_lost_status().await;
+            if let Some(l) = &publisher.listener_sender {
+                l.send(ListenerMail::LivelinessLost { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if self
+            .domain_participant
+            .listener_mask
+            .contains(&StatusKind::LivelinessLost)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+
+            let Some(publisher) = self
+                .domain_participant
+                .user_defined_publisher_list
+                .iter_mut()
+                .find(|x| x.instance_handle == publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher
+                .data_writer_list
+                .iter_mut()
+                .find(|x| x.instance_handle == data_writer_handle)
+            else {
+                return;
+            };
+            let status = data_writer.get_liveliness_lost_status().await;
+            if let Some(l) = &self.domain_participant.listener_sender {
+                l.send(ListenerMail::LivelinessLost { the_writer, status })
+                    .await
+                    .ok();
+            }
+        }
+
+        let Some(publisher) = self
+            .domain_participant
+            .user_defined_publisher_list
+            .iter_mut()
+            .find(|x| x.instance_handle == publisher_handle)
+        else {
+            return;
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list
+            .iter_mut()
+            .find(|x| x.instance_handle == data_writer_handle)
+        else {
+            return;
+        };
+        data_writer
+            .status_condition
+            .send_actor_mail(DcpsStatusConditionMail::AddCommunicationState {
+                state: StatusKind::LivelinessLost,
+            })
+            .await;
+    }
+
+    fn spawn_requested_deadline_check(
+        &self,
+        deadline_missed_period: Duration,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        instance_handle: InstanceHandle,
+    ) {
+        let mut timer_handle = self.timer_handle.clone();
+        self.spawner_handle.spawn(async move {
+            loop {
+                timer_handle.delay(deadline_missed_period.into()).await;
+                participant_address
+                    .send(DcpsDomainParticipantMail::Event(
+                        EventServiceMail::RequestedDeadlineMissed {
+                            subscriber_handle,
+                            data_reader_handle,
+                            change_instance_handle: instance_handle,
+                            participant_address: participant_address.clone(),
+                        },
+                    ))
+                    .await
+                    .ok();
+            }
+        });
+    }
+
     #[tracing::instrument(skip(self, participant_address))]
     pub async fn requested_deadline_missed(
         &mut self,
@@ -4959,19 +5909,201 @@ where
                 .await;
             self.add_matched_topics_announcer(&discovered_participant_data)
                 .await;
+            self.add_participant_announcement_locators(&discovered_participant_data);
 
             self.announce_participant().await;
         }
 
+        let participant_handle =
+            InstanceHandle::new(discovered_participant_data.dds_participant_data.key().value);
+        let reception_timestamp = self.get_current_time();
         self.domain_participant
             .add_discovered_participant(discovered_participant_data);
+        self.domain_participant
+            .add_participant_received_time(participant_handle, reception_timestamp);
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_stale_participants(&mut self) {
+        let current_time = self.get_current_time();
+        let stale_participant_list: Vec<_> = self
+            .domain_participant
+            .discovered_participant_list
+            .iter()
+            .map(|p| InstanceHandle::new(p.dds_participant_data.key().value))
+            .filter(|&h| {
+                self.domain_participant
+                    .participant_lease_expired(h, current_time)
+            })
+            .collect();
+        for participant_handle in stale_participant_list {
+            tracing::debug!(
+                ?participant_handle,
+                "Removing participant with expired lease"
+            );
+            self.remove_discovered_participant(participant_handle).await;
+        }
     }
 
     #[tracing::instrument(skip(self))]
-    fn remove_discovered_participant(&mut self, discovered_participant: InstanceHandle) {
+    async fn remove_discovered_participant(&mut self, discovered_participant: InstanceHandle) {
+        if let Some(discovered_participant_data) = self
+            .domain_participant
+            .discovered_participant_list
+            .iter()
+            .find(|p| &p.dds_participant_data.key().value == discovered_participant.as_ref())
+            .cloned()
+        {
+            self.remove_participant_announcement_locators(&discovered_participant_data);
+        }
         self.domain_participant
             .discovered_participant_list
             .retain(|p| &p.dds_participant_data.key().value != discovered_participant.as_ref());
+        self.domain_participant
+            .participant_received_time_list
+            .retain(|x| x.participant_handle != discovered_participant);
+
+        // The endpoints of a participant that announced its deletion are unmatched right away
+        // instead of waiting for their own dispose or for the participant lease to expire
+        let participant_prefix = &discovered_participant.as_ref()[..12];
+        let discovered_writer_handle_list: Vec<_> = self
+            .domain_participant
+            .discovered_writer_list
+            .iter()
+            .map(|x| InstanceHandle::new(x.dds_publication_data.key().value))
+            .filter(|x| &x.as_ref()[..12] == participant_prefix)
+            .collect();
+        for discovered_writer_handle in discovered_writer_handle_list {
+            self.unmatch_discovered_writer(discovered_writer_handle)
+                .await;
+        }
+
+        let discovered_reader_handle_list: Vec<_> = self
+            .domain_participant
+            .discovered_reader_list
+            .iter()
+            .map(|x| InstanceHandle::new(x.dds_subscription_data.key().value))
+            .filter(|x| &x.as_ref()[..12] == participant_prefix)
+            .collect();
+        for discovered_reader_handle in discovered_reader_handle_list {
+            self.unmatch_discovered_reader(discovered_reader_handle)
+                .await;
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn unmatch_discovered_writer(&mut self, discovered_writer_handle: InstanceHandle) {
+        self.domain_participant
+            .remove_discovered_writer(&discovered_writer_handle);
+
+        let mut handle_list = Vec::new();
+        for subscriber in &self.domain_participant.user_defined_subscriber_list {
+            for data_reader in subscriber.data_reader_list.iter() {
+                handle_list.push((subscriber.instance_handle, data_reader.instance_handle));
+            }
+        }
+        for (subscriber_handle, data_reader_handle) in handle_list {
+            self.remove_discovered_writer(
+                discovered_writer_handle,
+                subscriber_handle,
+                data_reader_handle,
+            )
+            .await;
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn unmatch_discovered_reader(&mut self, discovered_reader_handle: InstanceHandle) {
+        self.domain_participant
+            .remove_discovered_reader(&discovered_reader_handle);
+
+        let mut handle_list = Vec::new();
+        for publisher in &self.domain_participant.user_defined_publisher_list {
+            for data_writer in publisher.data_writer_list.iter() {
+                handle_list.push((publisher.instance_handle, data_writer.instance_handle));
+            }
+        }
+        for (publisher_handle, data_writer_handle) in handle_list {
+            self.remove_discovered_reader(
+                discovered_reader_handle,
+                publisher_handle,
+                data_writer_handle,
+            )
+            .await;
+        }
+    }
+
+    // Without multicast the participant announcements only reach the initial peers. The discovered
+    // participants are then announced to directly so that they also discover this participant when
+    // it is not in their own initial peers.
+    fn participant_announcement_locators(
+        &self,
+        discovered_participant_data: &SpdpDiscoveredParticipantData,
+    ) -> Vec<Locator> {
+        if !self
+            .transport
+            .metatraffic_multicast_locator_list()
+            .is_empty()
+        {
+            return Vec::new();
+        }
+        discovered_participant_data
+            .participant_proxy
+            .metatraffic_unicast_locator_list
+            .iter()
+            .filter(|l| !self.transport.discovery_locator_list().contains(l))
+            .copied()
+            .collect()
+    }
+
+    fn add_participant_announcement_locators(
+        &mut self,
+        discovered_participant_data: &SpdpDiscoveredParticipantData,
+    ) {
+        let locator_list = self.participant_announcement_locators(discovered_participant_data);
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher
+            .data_writer_list
+            .iter_mut()
+            .find(|dw| {
+                dw.transport_writer.guid().entity_id() == ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER
+            })
+        {
+            match &mut dw.transport_writer {
+                TransportWriterKind::Stateless(w) => {
+                    for locator in locator_list {
+                        w.add_reader_locator(locator);
+                    }
+                }
+                TransportWriterKind::Stateful(_) => panic!("Invalid built-in writer type"),
+            }
+        }
+    }
+
+    fn remove_participant_announcement_locators(
+        &mut self,
+        discovered_participant_data: &SpdpDiscoveredParticipantData,
+    ) {
+        let locator_list = self.participant_announcement_locators(discovered_participant_data);
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher
+            .data_writer_list
+            .iter_mut()
+            .find(|dw| {
+                dw.transport_writer.guid().entity_id() == ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER
+            })
+        {
+            match &mut dw.transport_writer {
+                TransportWriterKind::Stateless(w) => {
+                    for locator in &locator_list {
+                        w.remove_reader_locator(locator);
+                    }
+                }
+                TransportWriterKind::Stateful(_) => panic!("Invalid built-in writer type"),
+            }
+        }
     }
 
     #[tracing::instrument(skip(self))]
@@ -5358,6 +6490,27 @@ fn get_discovered_writer_incompatible_qos_policy_list<
     if &data_reader.qos.durability > publication_builtin_topic_data.durability() {
         incompatible_qos_policy_list.push(DURABILITY_QOS_POLICY_ID);
     }
+    // Late-joining data of TRANSIENT and PERSISTENT readers comes from the durability service so
+    // its history must be able to hold as many samples as the reader history requests
+    if data_reader.qos.durability.kind >= DurabilityQosPolicyKind::Transient {
+        let durability_service_history_kind = publication_builtin_topic_data
+            .durability_service()
+            .history_kind;
+        let is_durability_service_history_too_short = match (
+            data_reader.qos.history.kind,
+            durability_service_history_kind,
+        ) {
+            (_, HistoryQosPolicyKind::KeepAll) => false,
+            (HistoryQosPolicyKind::KeepAll, HistoryQosPolicyKind::KeepLast(_)) => true,
+            (
+                HistoryQosPolicyKind::KeepLast(reader_depth),
+                HistoryQosPolicyKind::KeepLast(durability_service_depth),
+            ) => reader_depth > durability_service_depth,
+        };
+        if is_durability_service_history_too_short {
+            incompatible_qos_policy_list.push(DURABILITYSERVICE_QOS_POLICY_ID);
+        }
+    }
     if &data_reader.qos.deadline < publication_builtin_topic_data.deadline() {
         incompatible_qos_policy_list.push(DEADLINE_QOS_POLICY_ID);
     }
@@ -5399,6 +6552,86 @@ fn get_discovered_writer_incompatible_qos_policy_list<
     incompatible_qos_policy_list
 }
 
+fn is_partition_matched(
+    local_partition: &PartitionQosPolicy,
+    discovered_partition: &PartitionQosPolicy,
+) -> bool {
+    // An empty partition list is equivalent to the default partition named by the empty string
+    let default_partition_name = [String::new()];
+    let local_partition_name = if local_partition.name.is_empty() {
+        &default_partition_name[..]
+    } else {
+        &local_partition.name[..]
+    };
+    let discovered_partition_name = if discovered_partition.name.is_empty() {
+        &default_partition_name[..]
+    } else {
+        &discovered_partition.name[..]
+    };
+
+    let is_any_name_matched = discovered_partition_name
+        .iter()
+        .any(|n| local_partition_name.contains(n));
+
+    let is_any_received_regex_matched_with_partition_qos = discovered_partition_name
+        .iter()
+        .filter_map(|n| Regex::new(&fnmatch_to_regex(n)).ok())
+        .any(|regex| local_partition_name.iter().any(|n| regex.is_match(n)));
+
+    let is_any_local_regex_matched_with_received_partition_qos = local_partition_name
+        .iter()
+        .filter_map(|n| Regex::new(&fnmatch_to_regex(n)).ok())
+        .any(|regex| discovered_partition_name.iter().any(|n| regex.is_match(n)));
+
+    is_any_name_matched
+        || is_any_received_regex_matched_with_partition_qos
+        || is_any_local_regex_matched_with_received_partition_qos
+}
+
+fn get_discovered_reader_match_diagnostic<R: DdsRuntime, T: TransportParticipantFactory>(
+    data_writer: &DataWriterEntity<R, T>,
+    publisher_qos: &PublisherQos,
+    discovered_reader_data: &SubscriptionBuiltinTopicData,
+    is_ignored: bool,
+) -> MatchDiagnostic {
+    MatchDiagnostic {
+        is_topic_name_matched: discovered_reader_data.topic_name == data_writer.topic_name,
+        is_type_name_matched: discovered_reader_data.get_type_name() == data_writer.type_name,
+        is_partition_matched: is_partition_matched(
+            &publisher_qos.partition,
+            &discovered_reader_data.partition,
+        ),
+        is_ignored,
+        incompatible_qos_policy_list: get_discovered_reader_incompatible_qos_policy_list(
+            &data_writer.qos,
+            discovered_reader_data,
+            publisher_qos,
+        ),
+    }
+}
+
+fn get_discovered_writer_match_diagnostic<R: DdsRuntime, T: TransportParticipantFactory>(
+    data_reader: &DataReaderEntity<R, T>,
+    subscriber_qos: &SubscriberQos,
+    discovered_writer_data: &PublicationBuiltinTopicData,
+    is_ignored: bool,
+) -> MatchDiagnostic {
+    MatchDiagnostic {
+        is_topic_name_matched: discovered_writer_data.topic_name == data_reader.topic_name,
+        is_type_name_matched: discovered_writer_data.get_type_name() == data_reader.type_name,
+        is_partition_matched: is_partition_matched(
+            &subscriber_qos.partition,
+            &discovered_writer_data.partition,
+        ),
+        is_ignored,
+        incompatible_qos_policy_list: get_discovered_writer_incompatible_qos_policy_list(
+            data_reader,
+            discovered_writer_data,
+            subscriber_qos,
+        ),
+    }
+}
+
 fn is_discovered_topic_consistent(
     topic_qos: &TopicQos,
     topic_builtin_topic_data: &TopicBuiltinTopicData,
@@ -5516,6 +6749,10 @@ pub const BUILT_IN_TOPIC_NAME_LIST: [&str; 4] = [
 pub struct DomainParticipantEntity<R: DdsRuntime, T: TransportParticipantFactory> {
     domain_id: DomainId,
     domain_tag: String,
+    serialization_buffer_capacity: usize,
+    serialization_buffer_shrink_after_idle: Duration,
+    max_matched_readers_per_writer: Option<usize>,
+    participant_lease_duration: Duration,
     instance_handle: InstanceHandle,
     qos: DomainParticipantQos,
     builtin_subscriber: SubscriberEntity<R, T>,
@@ -5528,6 +6765,7 @@ pub struct DomainParticipantEntity<R: DdsRuntime, T: TransportParticipantFactory
     content_filtered_topic_list: Vec<ContentFilteredTopicEntity>,
     default_topic_qos: TopicQos,
     discovered_participant_list: Vec<SpdpDiscoveredParticipantData>,
+    participant_received_time_list: Vec<ParticipantReceivedTime>,
     discovered_topic_list: Vec<TopicBuiltinTopicData>,
     discovered_reader_list: Vec<DiscoveredReaderData>,
     discovered_writer_list: Vec<DiscoveredWriterData>,
@@ -5535,7 +6773,7 @@ pub struct DomainParticipantEntity<R: DdsRuntime, T: TransportParticipantFactory
     ignored_participants: Vec<InstanceHandle>,
     ignored_publications: Vec<InstanceHandle>,
     ignored_subcriptions: Vec<InstanceHandle>,
-    _ignored_topic_list: Vec<InstanceHandle>,
+    ignored_topic_list: Vec<InstanceHandle>,
     listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
     listener_mask: Vec<StatusKind>,
 }
@@ -5552,6 +6790,10 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantEntity<R, T
         builtin_subscriber: SubscriberEntity<R, T>,
         topic_list: Vec<TopicEntity<R>>,
         domain_tag: String,
+        serialization_buffer_capacity: usize,
+        serialization_buffer_shrink_after_idle: Duration,
+        max_matched_readers_per_writer: Option<usize>,
+        participant_lease_duration: Duration,
     ) -> Self {
         Self {
             domain_id,
@@ -5567,6 +6809,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantEntity<R, T
             content_filtered_topic_list: Vec::new(),
             default_topic_qos: TopicQos::const_default(),
             discovered_participant_list: Vec::new(),
+            participant_received_time_list: Vec::new(),
             discovered_topic_list: Vec::new(),
             discovered_reader_list: Vec::new(),
             discovered_writer_list: Vec::new(),
@@ -5574,13 +6817,59 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantEntity<R, T
             ignored_participants: Vec::new(),
             ignored_publications: Vec::new(),
             ignored_subcriptions: Vec::new(),
-            _ignored_topic_list: Vec::new(),
+            ignored_topic_list: Vec::new(),
             listener_sender,
             listener_mask,
             domain_tag,
+            serialization_buffer_capacity,
+            serialization_buffer_shrink_after_idle,
+            max_matched_readers_per_writer,
+            participant_lease_duration,
         }
     }
 
+    pub fn available_builtin_endpoints(&self) -> BuiltinEndpointSet {
+        let writer_endpoints = self.builtin_publisher.data_writer_list.iter().map(|dw| {
+            match dw.transport_writer.guid().entity_id() {
+                ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_ANNOUNCER
+                }
+                ENTITYID_SEDP_BUILTIN_TOPICS_ANNOUNCER => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_TOPICS_ANNOUNCER
+                }
+                ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_PUBLICATIONS_ANNOUNCER
+                }
+                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_SUBSCRIPTIONS_ANNOUNCER
+                }
+                _ => 0,
+            }
+        });
+        let reader_endpoints = self.builtin_subscriber.data_reader_list.iter().map(|dr| {
+            match dr.transport_reader.guid().entity_id() {
+                ENTITYID_SPDP_BUILTIN_PARTICIPANT_READER => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_DETECTOR
+                }
+                ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_TOPICS_DETECTOR
+                }
+                ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_PUBLICATIONS_DETECTOR
+                }
+                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR => {
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_SUBSCRIPTIONS_DETECTOR
+                }
+                _ => 0,
+            }
+        });
+        BuiltinEndpointSet::new(
+            writer_endpoints
+                .chain(reader_endpoints)
+                .fold(0, |a, b| a | b),
+        )
+    }
+
     pub fn instance_handle(&self) -> InstanceHandle {
         self.instance_handle
     }
@@ -5614,6 +6903,34 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantEntity<R, T
             .find(|x| &x.key().value == topic_handle.as_ref())
     }
 
+    fn is_participant_ignored(&self, participant_key: &[u8; 16]) -> bool {
+        self.ignored_participants
+            .contains(&InstanceHandle::new(*participant_key))
+    }
+
+    fn is_topic_name_ignored(&self, topic_name: &str) -> bool {
+        self.discovered_topic_list.iter().any(|t| {
+            t.name() == topic_name
+                && self
+                    .ignored_topic_list
+                    .contains(&InstanceHandle::new(t.key().value))
+        })
+    }
+
+    fn is_publication_ignored(&self, publication_data: &PublicationBuiltinTopicData) -> bool {
+        self.ignored_publications
+            .contains(&InstanceHandle::new(publication_data.key().value))
+            || self.is_participant_ignored(&publication_data.participant_key().value)
+            || self.is_topic_name_ignored(publication_data.topic_name())
+    }
+
+    fn is_subscription_ignored(&self, subscription_data: &SubscriptionBuiltinTopicData) -> bool {
+        self.ignored_subcriptions
+            .contains(&InstanceHandle::new(subscription_data.key().value))
+            || self.is_participant_ignored(&subscription_data.participant_key().value)
+            || self.is_topic_name_ignored(subscription_data.topic_name())
+    }
+
     pub fn find_topic(&self, topic_name: &str) -> Option<&TopicBuiltinTopicData> {
         self.discovered_topic_list
             .iter()
@@ -5634,6 +6951,46 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantEntity<R, T
         }
     }
 
+    pub fn add_participant_received_time(
+        &mut self,
+        participant_handle: InstanceHandle,
+        reception_timestamp: Time,
+    ) {
+        match self
+            .participant_received_time_list
+            .iter_mut()
+            .find(|x| x.participant_handle == participant_handle)
+        {
+            Some(x) => x.last_received_time = reception_timestamp,
+            None => self
+                .participant_received_time_list
+                .push(ParticipantReceivedTime {
+                    participant_handle,
+                    last_received_time: reception_timestamp,
+                }),
+        }
+    }
+
+    pub fn participant_lease_expired(
+        &self,
+        participant_handle: InstanceHandle,
+        current_time: Time,
+    ) -> bool {
+        let Some(discovered_participant) = self
+            .discovered_participant_list
+            .iter()
+            .find(|p| &p.dds_participant_data.key().value == participant_handle.as_ref())
+        else {
+            return false;
+        };
+        self.participant_received_time_list
+            .iter()
+            .find(|x| x.participant_handle == participant_handle)
+            .is_some_and(|x| {
+                current_time - x.last_received_time > discovered_participant.lease_duration
+            })
+    }
+
     pub fn add_discovered_reader(&mut self, discovered_reader_data: DiscoveredReaderData) {
         match self.discovered_reader_list.iter_mut().find(|x| {
             x.dds_subscription_data.key() == discovered_reader_data.dds_subscription_data.key()
@@ -5797,6 +7154,8 @@ pub struct PublisherEntity<R: DdsRuntime, T: TransportParticipantFactory> {
     default_datawriter_qos: DataWriterQos,
     listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
     listener_mask: Vec<StatusKind>,
+    // Number of nested begin_coherent_changes calls not yet terminated
+    coherent_set_depth: u32,
 }
 
 impl<R: DdsRuntime, T: TransportParticipantFactory> PublisherEntity<R, T> {
@@ -5815,8 +7174,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> PublisherEntity<R, T> {
             default_datawriter_qos: DataWriterQos::const_default(),
             listener_sender,
             listener_mask,
+            coherent_set_depth: 0,
         }
     }
+
+    // Changes are only grouped when the publisher offers coherent access
+    fn is_coherent_set_open(&self) -> bool {
+        self.coherent_set_depth > 0 && self.qos.presentation.coherent_access
+    }
 }
 
 pub enum TransportWriterKind<T: TransportParticipantFactory> {
@@ -5838,6 +7203,13 @@ impl<T: TransportParticipantFactory> TransportWriterKind<T> {
             TransportWriterKind::Stateless(w) => w.history_cache(),
         }
     }
+
+    pub fn unicast_locator_list(&self) -> &[Locator] {
+        match self {
+            TransportWriterKind::Stateful(w) => w.unicast_locator_list(),
+            TransportWriterKind::Stateless(_) => &[],
+        }
+    }
 }
 
 pub struct InstancePublicationTime {
@@ -5850,6 +7222,11 @@ pub struct InstanceSamples {
     samples: VecDeque<i64>,
 }
 
+pub struct InstanceNotAliveChange {
+    instance: InstanceHandle,
+    sequence_number: i64,
+}
+
 pub struct DataWriterEntity<R: DdsRuntime, T: TransportParticipantFactory> {
     instance_handle: InstanceHandle,
     transport_writer: TransportWriterKind<T>,
@@ -5869,8 +7246,19 @@ pub struct DataWriterEntity<R: DdsRuntime, T: TransportParticipantFactory> {
     qos: DataWriterQos,
     registered_instance_list: Vec<InstanceHandle>,
     offered_deadline_missed_status: OfferedDeadlineMissedStatus,
+    liveliness_lost_status: LivelinessLostStatus,
+    // Time at which the liveliness was last asserted or None if the writer is not alive
+    last_liveliness_assertion_time: Option<Time>,
     instance_publication_time: Vec<InstancePublicationTime>,
+    // Instances for which a check of the offered deadline is running
+    deadline_checked_instance_list: Vec<InstanceHandle>,
     instance_samples: Vec<InstanceSamples>,
+    // Last dispose or unregister change of the instances of a KEEP_LAST writer
+    instance_not_alive_change: Vec<InstanceNotAliveChange>,
+    serialization_buffer: SerializationBuffer,
+    rejected_matched_reader_count: u32,
+    // Changes written during a coherent set, held back until the set is terminated
+    coherent_set_changes: Option<Vec<CacheChange>>,
 }
 
 impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
@@ -5885,6 +7273,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         listener_mask: Vec<StatusKind>,
         qos: DataWriterQos,
+        serialization_buffer: SerializationBuffer,
     ) -> Self {
         Self {
             instance_handle,
@@ -5905,43 +7294,110 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
             qos,
             registered_instance_list: Vec::new(),
             offered_deadline_missed_status: OfferedDeadlineMissedStatus::const_default(),
+            liveliness_lost_status: LivelinessLostStatus::const_default(),
+            last_liveliness_assertion_time: None,
             instance_publication_time: Vec::new(),
+            deadline_checked_instance_list: Vec::new(),
             instance_samples: Vec::new(),
+            instance_not_alive_change: Vec::new(),
+            serialization_buffer,
+            rejected_matched_reader_count: 0,
+            coherent_set_changes: None,
         }
     }
 
-    pub async fn write_w_timestamp(
-        &mut self,
-        dynamic_data: DynamicData,
-        timestamp: Time,
-        clock: &impl Clock,
-    ) -> DdsResult<i64> {
-        if !self.enabled {
-            return Err(DdsError::NotEnabled);
+    pub fn begin_coherent_set(&mut self) {
+        if self.coherent_set_changes.is_none() {
+            self.coherent_set_changes = Some(Vec::new());
         }
+    }
 
-        self.last_change_sequence_number += 1;
-
-        let instance_handle = get_instance_handle_from_dynamic_data(dynamic_data.clone())?;
+    // All the changes of the coherent set are added to the history cache together
+    pub async fn end_coherent_set(&mut self) {
+        if let Some(mut coherent_set_changes) = self.coherent_set_changes.take() {
+            if let Some(last_change) = coherent_set_changes.last_mut() {
+                last_change.coherent_set_end = true;
+            }
+            for change in coherent_set_changes {
+                self.transport_writer
+                    .history_cache()
+                    .add_change(change)
+                    .await;
+            }
+        }
+    }
 
-        if !self.registered_instance_list.contains(&instance_handle) {
-            if self.registered_instance_list.len() < self.qos.resource_limits.max_instances {
-                self.registered_instance_list.push(instance_handle);
-            } else {
-                return Err(DdsError::OutOfResources);
+    async fn add_change_to_history(&mut self, mut change: CacheChange) {
+        match &mut self.coherent_set_changes {
+            Some(coherent_set_changes) => {
+                // Every change of a coherent set carries the sequence number of its first change
+                change.coherent_set = coherent_set_changes
+                    .first()
+                    .and_then(|x| x.coherent_set)
+                    .or(Some(change.sequence_number));
+                coherent_set_changes.push(change)
+            }
+            None => {
+                self.transport_writer
+                    .history_cache()
+                    .add_change(change)
+                    .await
             }
         }
+    }
 
-        if let Length::Limited(max_instances) = self.qos.resource_limits.max_instances {
-            if !self
-                .instance_samples
-                .iter()
-                .any(|x| x.instance == instance_handle)
-                && self.instance_samples.len() == max_instances as usize
-            {
+    async fn remove_change_from_history(&mut self, sequence_number: i64) {
+        if let Some(coherent_set_changes) = &mut self.coherent_set_changes {
+            coherent_set_changes.retain(|x| x.sequence_number != sequence_number);
+        }
+        self.transport_writer
+            .history_cache()
+            .remove_change(sequence_number)
+            .await;
+    }
+
+    // A RELIABLE writer at its resource limits blocks up to max_blocking_time until the sample
+    // in the way is acknowledged by all the matched readers, and then removes it
+    async fn make_room_for_sample(
+        &mut self,
+        instance_handle: InstanceHandle,
+        clock: &impl Clock,
+    ) -> DdsResult<()> {
+        let start_time = clock.now();
+        while let Some(blocking_sequence_number) =
+            self.sample_exceeding_resource_limits(instance_handle)?
+        {
+            let TransportWriterKind::Stateful(w) = &self.transport_writer else {
                 return Err(DdsError::OutOfResources);
+            };
+            if self.qos.reliability.kind != ReliabilityQosPolicyKind::Reliable {
+                return Err(DdsError::OutOfResources);
+            }
+            if w.is_change_acknowledged(blocking_sequence_number).await {
+                for s in self.instance_samples.iter_mut() {
+                    s.samples.retain(|&x| x != blocking_sequence_number);
+                }
+                self.remove_change_from_history(blocking_sequence_number)
+                    .await;
+            } else if let DurationKind::Finite(t) = self.qos.reliability.max_blocking_time {
+                if (clock.now() - start_time) > t {
+                    return Err(DdsError::Timeout);
+                }
             }
         }
+        Ok(())
+    }
+
+    // Sequence number of the sample to remove so that a new sample of the instance stays within
+    // the resource limits, if any
+    fn sample_exceeding_resource_limits(
+        &self,
+        instance_handle: InstanceHandle,
+    ) -> DdsResult<Option<i64>> {
+        let instance_samples = self
+            .instance_samples
+            .iter()
+            .find(|x| x.instance == instance_handle);
 
         if let Length::Limited(max_samples_per_instance) =
             self.qos.resource_limits.max_samples_per_instance
@@ -5951,14 +7407,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
             match self.qos.history.kind {
                 HistoryQosPolicyKind::KeepLast(depth) if depth <= max_samples_per_instance => {}
                 _ => {
-                    if let Some(s) = self
-                        .instance_samples
-                        .iter()
-                        .find(|x| x.instance == instance_handle)
-                    {
+                    if let Some(s) = instance_samples {
                         // Only Alive changes count towards the resource limits
                         if s.samples.len() >= max_samples_per_instance as usize {
-                            return Err(DdsError::OutOfResources);
+                            return s
+                                .samples
+                                .front()
+                                .map(|&x| Some(x))
+                                .ok_or(DdsError::OutOfResources);
                         }
                     }
                 }
@@ -5970,20 +7426,76 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
                 .instance_samples
                 .iter()
                 .fold(0, |acc, x| acc + x.samples.len());
+            // A KEEP_LAST instance holding depth samples replaces its oldest sample
+            let is_sample_replaced = match self.qos.history.kind {
+                HistoryQosPolicyKind::KeepLast(depth) => {
+                    instance_samples.is_some_and(|x| x.samples.len() == depth as usize)
+                }
+                HistoryQosPolicyKind::KeepAll => false,
+            };
+
+            if total_samples >= max_samples as usize && !is_sample_replaced {
+                return self
+                    .instance_samples
+                    .iter()
+                    .filter_map(|x| x.samples.front())
+                    .min()
+                    .map(|&x| Some(x))
+                    .ok_or(DdsError::OutOfResources);
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn write_w_timestamp(
+        &mut self,
+        dynamic_data: DynamicData,
+        timestamp: Time,
+        clock: &impl Clock,
+    ) -> DdsResult<i64> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        self.last_change_sequence_number += 1;
+
+        let instance_handle = get_instance_handle_from_dynamic_data(dynamic_data.clone())?;
+
+        if !self.registered_instance_list.contains(&instance_handle) {
+            if self.registered_instance_list.len() < self.qos.resource_limits.max_instances {
+                self.registered_instance_list.push(instance_handle);
+            } else {
+                return Err(DdsError::OutOfResources);
+            }
+        }
 
-            if total_samples >= max_samples as usize {
+        if let Length::Limited(max_instances) = self.qos.resource_limits.max_instances {
+            if !self
+                .instance_samples
+                .iter()
+                .any(|x| x.instance == instance_handle)
+                && self.instance_samples.len() == max_instances as usize
+            {
                 return Err(DdsError::OutOfResources);
             }
         }
 
-        let serialized_data = if self.qos.representation.value.is_empty()
+        self.make_room_for_sample(instance_handle, clock).await?;
+
+        // The sample is serialized into the buffer kept by the writer and then copied into the
+        // history cache so that its grown capacity can be reused by the following writes
+        let buffer = self.serialization_buffer.get(clock.now());
+        if self.qos.representation.value.is_empty()
             || self.qos.representation.value[0] == XCDR_DATA_REPRESENTATION
         {
-            let mut buffer = match dynamic_data.type_ref().get_descriptor().extensibility_kind {
-                ExtensibilityKind::Final | ExtensibilityKind::Appendable => vec![0x00, 0x01, 0, 0],
-                ExtensibilityKind::Mutable => vec![0x00, 0x03, 0, 0],
+            match dynamic_data.type_ref().get_descriptor().extensibility_kind {
+                ExtensibilityKind::Final | ExtensibilityKind::Appendable => {
+                    buffer.extend_from_slice(&[0x00, 0x01, 0, 0])
+                }
+                ExtensibilityKind::Mutable => buffer.extend_from_slice(&[0x00, 0x03, 0, 0]),
             };
-            let mut serializer = Xcdr1LeSerializer::new(&mut buffer);
+            let mut serializer = Xcdr1LeSerializer::new(&mut *buffer);
             dynamic_data.serialize(&mut serializer)?;
 
             let padding = match buffer.len() % 4 {
@@ -5994,25 +7506,22 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
             };
             buffer.extend_from_slice(padding);
             buffer[3] = padding.len() as u8;
-
-            buffer
         } else if self.qos.representation.value[0] == XCDR2_DATA_REPRESENTATION {
-            let mut buffer = match dynamic_data.type_ref().get_descriptor().extensibility_kind {
-                ExtensibilityKind::Final => vec![0x00, 0x07, 0, 0],
-                ExtensibilityKind::Appendable => vec![0x00, 0x09, 0, 0],
+            match dynamic_data.type_ref().get_descriptor().extensibility_kind {
+                ExtensibilityKind::Final => buffer.extend_from_slice(&[0x00, 0x07, 0, 0]),
+                ExtensibilityKind::Appendable => buffer.extend_from_slice(&[0x00, 0x09, 0, 0]),
                 ExtensibilityKind::Mutable => todo!(),
             };
-            let mut serializer = Xcdr2LeSerializer::new(&mut buffer);
+            let mut serializer = Xcdr2LeSerializer::new(&mut *buffer);
             dynamic_data.serialize(&mut serializer)?;
-            buffer
         } else if self.qos.representation.value[0] == BUILT_IN_DATA_REPRESENTATION {
-            let mut buffer = vec![0x00, 0x03, 0, 0];
-            let mut serializer = PlCdrLeSerializer::new(&mut buffer);
+            buffer.extend_from_slice(&[0x00, 0x03, 0, 0]);
+            let mut serializer = PlCdrLeSerializer::new(&mut *buffer);
             dynamic_data.serialize(&mut serializer)?;
-            buffer
         } else {
             panic!("Invalid data representation")
         };
+        let serialized_data = Arc::from(buffer.as_slice());
 
         let change = CacheChange {
             kind: ChangeKind::Alive,
@@ -6020,7 +7529,9 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
             sequence_number: self.last_change_sequence_number,
             source_timestamp: Some(timestamp.into()),
             instance_handle: Some(instance_handle.into()),
-            data_value: serialized_data.into(),
+            data_value: serialized_data,
+            coherent_set: None,
+            coherent_set_end: false,
         };
         if let HistoryQosPolicyKind::KeepLast(depth) = self.qos.history.kind {
             if let Some(s) = self
@@ -6048,9 +7559,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
                         }
                     }
                     if let Some(smallest_seq_num_instance) = s.samples.pop_front() {
-                        self.transport_writer
-                            .history_cache()
-                            .remove_change(smallest_seq_num_instance)
+                        self.remove_change_from_history(smallest_seq_num_instance)
                             .await;
                     }
                 }
@@ -6095,10 +7604,8 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
                 self.instance_samples.push(s);
             }
         }
-        self.transport_writer
-            .history_cache()
-            .add_change(change)
-            .await;
+        self.add_change_to_history(change).await;
+        self.assert_liveliness(clock.now());
         Ok(self.last_change_sequence_number)
     }
 
@@ -6152,10 +7659,10 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
             source_timestamp: Some(timestamp.into()),
             instance_handle: Some(instance_handle.into()),
             data_value: serialized_key.into(),
+            coherent_set: None,
+            coherent_set_end: false,
         };
-        self.transport_writer
-            .history_cache()
-            .add_change(cache_change)
+        self.add_not_alive_change(instance_handle, cache_change)
             .await;
 
         Ok(())
@@ -6205,21 +7712,75 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
 
         self.last_change_sequence_number += 1;
 
+        let kind = if self
+            .qos
+            .writer_data_lifecycle
+            .autodispose_unregistered_instances
+        {
+            ChangeKind::NotAliveDisposedUnregistered
+        } else {
+            ChangeKind::NotAliveUnregistered
+        };
         let cache_change = CacheChange {
-            kind: ChangeKind::NotAliveDisposed,
+            kind,
             writer_guid: self.transport_writer.guid(),
             sequence_number: self.last_change_sequence_number,
             source_timestamp: Some(timestamp.into()),
             instance_handle: Some(instance_handle.into()),
             data_value: serialized_key.into(),
+            coherent_set: None,
+            coherent_set_end: false,
         };
-        self.transport_writer
-            .history_cache()
-            .add_change(cache_change)
+        self.add_not_alive_change(instance_handle, cache_change)
             .await;
         Ok(())
     }
 
+    // With KEEP_LAST history only the last dispose or unregister change of an instance is kept
+    async fn add_not_alive_change(
+        &mut self,
+        instance_handle: InstanceHandle,
+        cache_change: CacheChange,
+    ) {
+        if let HistoryQosPolicyKind::KeepLast(_) = self.qos.history.kind {
+            let sequence_number = cache_change.sequence_number;
+            match self
+                .instance_not_alive_change
+                .iter_mut()
+                .find(|x| x.instance == instance_handle)
+            {
+                Some(x) => {
+                    let previous_sequence_number = x.sequence_number;
+                    x.sequence_number = sequence_number;
+                    self.remove_change_from_history(previous_sequence_number)
+                        .await;
+                }
+                None => self.instance_not_alive_change.push(InstanceNotAliveChange {
+                    instance: instance_handle,
+                    sequence_number,
+                }),
+            }
+        }
+        self.add_change_to_history(cache_change).await;
+    }
+
+    pub fn has_room_for_matched_subscription(
+        &self,
+        subscription_builtin_topic_data: &SubscriptionBuiltinTopicData,
+        max_matched_readers: Option<usize>,
+    ) -> bool {
+        match max_matched_readers {
+            Some(max_matched_readers) => {
+                self.matched_subscription_list.len() < max_matched_readers
+                    || self
+                        .matched_subscription_list
+                        .iter()
+                        .any(|x| x.key() == subscription_builtin_topic_data.key())
+            }
+            None => true,
+        }
+    }
+
     pub fn add_matched_subscription(
         &mut self,
         subscription_builtin_topic_data: SubscriptionBuiltinTopicData,
@@ -6298,29 +7859,102 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
         status
     }
 
-    pub fn get_instance_write_time(&self, instance_handle: InstanceHandle) -> Option<Time> {
-        self.instance_publication_time
+    pub fn get_instance_write_time(&self, instance_handle: InstanceHandle) -> Option<Time> {
+        self.instance_publication_time
+            .iter()
+            .find(|x| x.instance == instance_handle)
+            .map(|x| x.last_write_time)
+    }
+
+    pub async fn are_all_changes_acknowledged(&self) -> bool {
+        // Changes held back in an open coherent set have not been sent yet
+        let last_sent_sequence_number =
+            match self.coherent_set_changes.as_ref().and_then(|x| x.first()) {
+                Some(first_coherent_set_change) => first_coherent_set_change.sequence_number - 1,
+                None => self.last_change_sequence_number,
+            };
+        match &self.transport_writer {
+            TransportWriterKind::Stateful(w) => {
+                w.is_change_acknowledged(last_sent_sequence_number).await
+            }
+            TransportWriterKind::Stateless(_) => true,
+        }
+    }
+
+    pub async fn get_offered_deadline_missed_status(&mut self) -> OfferedDeadlineMissedStatus {
+        let status = self.offered_deadline_missed_status.clone();
+        self.offered_deadline_missed_status.total_count_change = 0;
+        self.status_condition
+            .send_actor_mail(DcpsStatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::OfferedDeadlineMissed,
+            })
+            .await;
+
+        status
+    }
+
+    pub fn register_instance_w_timestamp(
+        &mut self,
+        instance_handle: InstanceHandle,
+        timestamp: Time,
+    ) -> DdsResult<()> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        if !self.registered_instance_list.contains(&instance_handle) {
+            if self.registered_instance_list.len() < self.qos.resource_limits.max_instances {
+                self.registered_instance_list.push(instance_handle);
+            } else {
+                return Err(DdsError::OutOfResources);
+            }
+        }
+
+        // The offered deadline of a registered instance is counted from its registration
+        // until it is written for the first time
+        if !self
+            .instance_publication_time
             .iter()
-            .find(|x| x.instance == instance_handle)
-            .map(|x| x.last_write_time)
+            .any(|x| x.instance == instance_handle)
+        {
+            self.instance_publication_time
+                .push(InstancePublicationTime {
+                    instance: instance_handle,
+                    last_write_time: timestamp,
+                });
+        }
+
+        Ok(())
     }
 
-    pub async fn are_all_changes_acknowledged(&self) -> bool {
-        match &self.transport_writer {
-            TransportWriterKind::Stateful(w) => {
-                w.is_change_acknowledged(self.last_change_sequence_number)
-                    .await
-            }
-            TransportWriterKind::Stateless(_) => true,
+    // Returns the period of the offered deadline if its check is not yet running for the instance
+    pub fn start_offered_deadline_check(
+        &mut self,
+        instance_handle: InstanceHandle,
+    ) -> Option<Duration> {
+        let DurationKind::Finite(deadline_missed_period) = self.qos.deadline.period else {
+            return None;
+        };
+        if self
+            .deadline_checked_instance_list
+            .contains(&instance_handle)
+        {
+            return None;
         }
+        self.deadline_checked_instance_list.push(instance_handle);
+        Some(deadline_missed_period)
     }
 
-    pub async fn get_offered_deadline_missed_status(&mut self) -> OfferedDeadlineMissedStatus {
-        let status = self.offered_deadline_missed_status.clone();
-        self.offered_deadline_missed_status.total_count_change = 0;
+    pub fn assert_liveliness(&mut self, current_time: Time) {
+        self.last_liveliness_assertion_time = Some(current_time);
+    }
+
+    pub async fn get_liveliness_lost_status(&mut self) -> LivelinessLostStatus {
+        let status = self.liveliness_lost_status.clone();
+        self.liveliness_lost_status.total_count_change = 0;
         self.status_condition
             .send_actor_mail(DcpsStatusConditionMail::RemoveCommunicationState {
-                state: StatusKind::OfferedDeadlineMissed,
+                state: StatusKind::LivelinessLost,
             })
             .await;
 
@@ -6330,6 +7964,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataWriterEntity<R, T> {
 
 type SampleList = Vec<(Option<Arc<[u8]>>, SampleInfo)>;
 
+pub type DeserializedData = Arc<dyn Any + Send + Sync>;
+
+pub type DeserializeDataFn = fn(&[u8]) -> DdsResult<DeserializedData>;
+
+pub type SampleFilterFn = Box<dyn Fn(&[u8]) -> bool + Send>;
+
+pub type DeserializedSampleList = Vec<(Option<Arc<[u8]>>, Option<DeserializedData>, SampleInfo)>;
+
 pub enum AddChangeResult {
     Added(InstanceHandle),
     NotAdded,
@@ -6342,6 +7984,10 @@ struct InstanceState {
     instance_state: InstanceStateKind,
     most_recent_disposed_generation_count: i32,
     most_recent_no_writers_generation_count: i32,
+    // Most recent source timestamp of the samples added to the history, kept after they are taken
+    last_added_source_timestamp: Option<Time>,
+    // Most recent serialized data of the instance, kept after the samples are taken to retrieve the key
+    last_alive_serialized_data: Option<Arc<[u8]>>,
 }
 
 impl InstanceState {
@@ -6352,6 +7998,8 @@ impl InstanceState {
             instance_state: InstanceStateKind::Alive,
             most_recent_disposed_generation_count: 0,
             most_recent_no_writers_generation_count: 0,
+            last_added_source_timestamp: None,
+            last_alive_serialized_data: None,
         }
     }
 
@@ -6385,6 +8033,7 @@ impl InstanceState {
             ViewStateKind::NotNew => {
                 if change_kind == ChangeKind::NotAliveDisposed
                     || change_kind == ChangeKind::NotAliveUnregistered
+                    || change_kind == ChangeKind::NotAliveDisposedUnregistered
                 {
                     self.view_state = ViewStateKind::New;
                 }
@@ -6404,10 +8053,12 @@ impl InstanceState {
 #[derive(Debug)]
 pub struct ReaderSample {
     pub kind: ChangeKind,
-    pub writer_guid: [u8; 16],
+    pub writer_handle: InstanceHandle,
+    pub sequence_number: i64,
     pub instance_handle: InstanceHandle,
     pub source_timestamp: Option<Time>,
     pub data_value: Arc<[u8]>,
+    pub deserialized_data: Option<DeserializedData>,
     pub sample_state: SampleStateKind,
     pub disposed_generation_count: i32,
     pub no_writers_generation_count: i32,
@@ -6431,11 +8082,27 @@ impl<T: TransportParticipantFactory> TransportReaderKind<T> {
             TransportReaderKind::Stateless(r) => r.guid(),
         }
     }
+
+    pub fn unicast_locator_list(&self) -> &[Locator] {
+        match self {
+            TransportReaderKind::Stateful(r) => r.unicast_locator_list(),
+            TransportReaderKind::Stateless(_) => &[],
+        }
+    }
 }
 
 struct InstanceOwnership {
     instance_handle: InstanceHandle,
-    owner_handle: [u8; 16],
+    owner_handle: InstanceHandle,
+}
+
+struct ParticipantReceivedTime {
+    participant_handle: InstanceHandle,
+    last_received_time: Time,
+}
+
+struct InstanceReceivedTime {
+    instance_handle: InstanceHandle,
     last_received_time: Time,
 }
 
@@ -6459,6 +8126,11 @@ pub struct DataReaderEntity<R: DdsRuntime, T: TransportParticipantFactory> {
     listener_mask: Vec<StatusKind>,
     instances: Vec<InstanceState>,
     instance_ownership: Vec<InstanceOwnership>,
+    instance_received_time: Vec<InstanceReceivedTime>,
+    // Instances for which a check of the requested deadline is running
+    deadline_checked_instance_list: Vec<InstanceHandle>,
+    // Changes of the coherent sets which have not been completely received yet
+    coherent_set_changes: Vec<CacheChange>,
     transport_reader: TransportReaderKind<T>,
 }
 
@@ -6495,6 +8167,9 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
             listener_mask,
             instances: Vec::new(),
             instance_ownership: Vec::new(),
+            instance_received_time: Vec::new(),
+            deadline_checked_instance_list: Vec::new(),
+            coherent_set_changes: Vec::new(),
             transport_reader,
         }
     }
@@ -6506,6 +8181,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
         view_states: &[ViewStateKind],
         instance_states: &[InstanceStateKind],
         specific_instance_handle: Option<InstanceHandle>,
+        sample_filter: Option<&SampleFilterFn>,
     ) -> DdsResult<Vec<IndexedSample>> {
         if let Some(h) = specific_instance_handle {
             if !self.instances.iter().any(|x| x.handle() == h) {
@@ -6538,6 +8214,17 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                 continue;
             }
 
+            // Samples without data carry instance state changes and are never filtered out
+            if let Some(sample_filter) = sample_filter {
+                let has_data = matches!(
+                    cache_change.kind,
+                    ChangeKind::Alive | ChangeKind::AliveFiltered
+                );
+                if has_data && !sample_filter(cache_change.data_value.as_ref()) {
+                    continue;
+                }
+            }
+
             if !instances_in_collection
                 .iter()
                 .any(|x| x.handle() == cache_change.instance_handle)
@@ -6579,7 +8266,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                 absolute_generation_rank,
                 source_timestamp: cache_change.source_timestamp,
                 instance_handle: cache_change.instance_handle,
-                publication_handle: InstanceHandle::new(cache_change.writer_guid),
+                publication_handle: cache_change.writer_handle,
                 valid_data,
             };
 
@@ -6636,12 +8323,6 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                 total_instance_samples_in_collection -= 1;
                 sample_info.sample_rank = total_instance_samples_in_collection as i32;
             }
-
-            self.instances
-                .iter_mut()
-                .find(|x| x.handle() == handle)
-                .expect("Sample must exist")
-                .mark_viewed()
         }
 
         if indexed_samples.is_empty() {
@@ -6651,6 +8332,27 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
         }
     }
 
+    fn mark_samples_read(&mut self, indexed_samples: &[IndexedSample]) {
+        self.mark_instances_viewed(indexed_samples);
+        for IndexedSample { index, .. } in indexed_samples {
+            self.sample_list[*index].sample_state = SampleStateKind::Read;
+        }
+    }
+
+    fn mark_instances_viewed(&mut self, indexed_samples: &[IndexedSample]) {
+        for IndexedSample {
+            sample: (_, sample_info),
+            ..
+        } in indexed_samples
+        {
+            self.instances
+                .iter_mut()
+                .find(|x| x.handle() == sample_info.instance_handle)
+                .expect("Sample must exist")
+                .mark_viewed()
+        }
+    }
+
     fn next_instance(&mut self, previous_handle: Option<InstanceHandle>) -> Option<InstanceHandle> {
         match previous_handle {
             Some(p) => self
@@ -6697,10 +8399,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                     .iter_mut()
                     .find(|x| x.handle() == instance_handle)
                 {
-                    Some(x) => x.update_state(cache_change.kind),
+                    Some(x) => {
+                        x.update_state(cache_change.kind);
+                        x.last_alive_serialized_data = Some(cache_change.data_value.clone());
+                    }
                     None => {
                         let mut s = InstanceState::new(instance_handle);
                         s.update_state(cache_change.kind);
+                        s.last_alive_serialized_data = Some(cache_change.data_value.clone());
                         self.instances.push(s);
                     }
                 }
@@ -6731,10 +8437,12 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
             .expect("Sample with handle must exist");
         Ok(ReaderSample {
             kind: cache_change.kind,
-            writer_guid: cache_change.writer_guid.into(),
+            writer_handle: cache_change.writer_guid.into(),
+            sequence_number: cache_change.sequence_number,
             instance_handle,
             source_timestamp: cache_change.source_timestamp.map(Into::into),
             data_value: cache_change.data_value.clone(),
+            deserialized_data: None,
             sample_state: SampleStateKind::NotRead,
             disposed_generation_count: instance.most_recent_disposed_generation_count,
             no_writers_generation_count: instance.most_recent_no_writers_generation_count,
@@ -6749,6 +8457,19 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
     ) -> DdsResult<AddChangeResult> {
         let sample = self.convert_cache_change_to_sample(cache_change, reception_timestamp)?;
         let change_instance_handle = sample.instance_handle;
+        // When ordering by source timestamp a sample older than the last one accepted for its
+        // instance is discarded so that all readers end up with the same final instance value
+        if self.qos.destination_order.kind == DestinationOrderQosPolicyKind::BySourceTimestamp {
+            let is_older_than_last_added_sample = self
+                .instances
+                .iter()
+                .find(|x| x.handle == sample.instance_handle)
+                .and_then(|x| x.last_added_source_timestamp)
+                .is_some_and(|t| Some(t) > sample.source_timestamp);
+            if is_older_than_last_added_sample {
+                return Ok(AddChangeResult::NotAdded);
+            }
+        }
         // data_reader exclusive access if the writer is not the allowed to write the sample do an early return
         if self.qos.ownership.kind == OwnershipQosPolicyKind::Exclusive {
             // Get the InstanceHandle of the data writer owning this instance
@@ -6757,22 +8478,21 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                 .iter()
                 .find(|x| x.instance_handle == sample.instance_handle)
             {
-                let instance_writer = InstanceHandle::new(sample.writer_guid);
                 let Some(sample_owner) = self
                     .matched_publication_list
                     .iter()
-                    .find(|x| x.key().value == instance_owner.owner_handle.as_ref())
+                    .find(|x| &x.key().value == instance_owner.owner_handle.as_ref())
                 else {
                     return Ok(AddChangeResult::NotAdded);
                 };
                 let Some(sample_writer) = self
                     .matched_publication_list
                     .iter()
-                    .find(|x| &x.key().value == instance_writer.as_ref())
+                    .find(|x| &x.key().value == sample.writer_handle.as_ref())
                 else {
                     return Ok(AddChangeResult::NotAdded);
                 };
-                if instance_owner.owner_handle != sample.writer_guid
+                if instance_owner.owner_handle != sample.writer_handle
                     && sample_writer.ownership_strength().value
                         <= sample_owner.ownership_strength().value
                 {
@@ -6786,12 +8506,11 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                 .find(|x| x.instance_handle == sample.instance_handle)
             {
                 Some(x) => {
-                    x.owner_handle = sample.writer_guid;
+                    x.owner_handle = sample.writer_handle;
                 }
                 None => self.instance_ownership.push(InstanceOwnership {
                     instance_handle: sample.instance_handle,
-                    owner_handle: sample.writer_guid,
-                    last_received_time: reception_timestamp,
+                    owner_handle: sample.writer_handle,
                 }),
             }
         }
@@ -6812,12 +8531,19 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
         }
 
         let is_sample_of_interest_based_on_time = {
+            let last_added_source_timestamp = self
+                .instances
+                .iter()
+                .find(|x| x.handle == sample.instance_handle)
+                .and_then(|x| x.last_added_source_timestamp)
+                .filter(|&t| Some(t) <= sample.source_timestamp);
             let closest_timestamp_before_received_sample = self
                 .sample_list
                 .iter()
                 .filter(|cc| cc.instance_handle == sample.instance_handle)
                 .filter(|cc| cc.source_timestamp <= sample.source_timestamp)
                 .map(|cc| cc.source_timestamp)
+                .chain(last_added_source_timestamp.map(Some))
                 .max();
 
             if let Some(Some(t)) = closest_timestamp_before_received_sample {
@@ -6941,7 +8667,17 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
             }
         }?;
 
-        let sample_writer_guid = sample.writer_guid;
+        if let Some(instance) = self
+            .instances
+            .iter_mut()
+            .find(|x| x.handle == sample.instance_handle)
+        {
+            if instance.last_added_source_timestamp < sample.source_timestamp {
+                instance.last_added_source_timestamp = sample.source_timestamp;
+            }
+        }
+
+        let sample_writer_handle = sample.writer_handle;
         tracing::debug!(cache_change = ?sample, "Adding change to data reader history cache");
         self.sample_list.push(sample);
         self.data_available_status_changed_flag = true;
@@ -6959,13 +8695,23 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                         )
                 });
             }
-            DestinationOrderQosPolicyKind::ByReceptionTimestamp => self
-                .sample_list
-                .sort_by(|a, b| a.reception_timestamp.cmp(&b.reception_timestamp)),
+            DestinationOrderQosPolicyKind::ByReceptionTimestamp => {
+                self.sample_list.sort_by_key(|a| a.reception_timestamp)
+            }
         }
 
-        match self
+        if !self
             .instance_ownership
+            .iter()
+            .any(|x| x.instance_handle == change_instance_handle)
+        {
+            self.instance_ownership.push(InstanceOwnership {
+                instance_handle: change_instance_handle,
+                owner_handle: sample_writer_handle,
+            });
+        }
+        match self
+            .instance_received_time
             .iter_mut()
             .find(|x| x.instance_handle == change_instance_handle)
         {
@@ -6974,15 +8720,42 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                     x.last_received_time = reception_timestamp;
                 }
             }
-            None => self.instance_ownership.push(InstanceOwnership {
+            None => self.instance_received_time.push(InstanceReceivedTime {
                 instance_handle: change_instance_handle,
                 last_received_time: reception_timestamp,
-                owner_handle: sample_writer_guid,
             }),
         }
         Ok(AddChangeResult::Added(change_instance_handle))
     }
 
+    // A sample whose lifespan expired leaves the reader the same way as a taken sample
+    pub async fn remove_change(&mut self, writer_handle: InstanceHandle, sequence_number: i64) {
+        self.sample_list.retain(|x| {
+            !(x.writer_handle == writer_handle && x.sequence_number == sequence_number)
+        });
+        self.forget_not_alive_instances_without_samples();
+
+        if !self.has_unread_samples() {
+            self.status_condition
+                .send_actor_mail(DcpsStatusConditionMail::RemoveCommunicationState {
+                    state: StatusKind::DataAvailable,
+                })
+                .await;
+        }
+    }
+
+    // The instances which are no longer alive are forgotten once all their samples are gone
+    // so that an instance written again starts as a new instance
+    fn forget_not_alive_instances_without_samples(&mut self) {
+        self.instances.retain(|x| {
+            x.instance_state == InstanceStateKind::Alive
+                || self
+                    .sample_list
+                    .iter()
+                    .any(|s| s.instance_handle == x.handle)
+        });
+    }
+
     pub fn add_matched_publication(
         &mut self,
         publication_builtin_topic_data: PublicationBuiltinTopicData,
@@ -6997,8 +8770,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
                 .matched_publication_list
                 .push(publication_builtin_topic_data),
         }
-        self.subscription_matched_status.current_count +=
-            self.matched_publication_list.len() as i32;
+        self.subscription_matched_status.current_count = self.matched_publication_list.len() as i32;
         self.subscription_matched_status.current_count_change += 1;
         self.subscription_matched_status.total_count += 1;
         self.subscription_matched_status.total_count_change += 1;
@@ -7089,6 +8861,28 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
         status
     }
 
+    pub fn has_unread_samples(&self) -> bool {
+        self.sample_list
+            .iter()
+            .any(|x| x.sample_state == SampleStateKind::NotRead)
+    }
+
+    pub fn get_instance_serialized_data(
+        &self,
+        instance_handle: InstanceHandle,
+    ) -> DdsResult<Arc<[u8]>> {
+        let instance = self
+            .instances
+            .iter()
+            .find(|x| x.handle() == instance_handle)
+            .ok_or(DdsError::BadParameter)?;
+        instance.last_alive_serialized_data.clone().ok_or_else(|| {
+            DdsError::PreconditionNotMet(String::from(
+                "No sample with the key of the instance has been received",
+            ))
+        })
+    }
+
     pub fn get_matched_publications(&self) -> Vec<InstanceHandle> {
         self.matched_publication_list
             .iter()
@@ -7097,12 +8891,30 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
     }
 
     pub fn get_instance_received_time(&self, instance_handle: &InstanceHandle) -> Option<Time> {
-        self.instance_ownership
+        self.instance_received_time
             .iter()
             .find(|x| &x.instance_handle == instance_handle)
             .map(|x| x.last_received_time)
     }
 
+    // Returns the period of the requested deadline if its check is not yet running for the instance
+    pub fn start_requested_deadline_check(
+        &mut self,
+        instance_handle: InstanceHandle,
+    ) -> Option<Duration> {
+        let DurationKind::Finite(deadline_missed_period) = self.qos.deadline.period else {
+            return None;
+        };
+        if self
+            .deadline_checked_instance_list
+            .contains(&instance_handle)
+        {
+            return None;
+        }
+        self.deadline_checked_instance_list.push(instance_handle);
+        Some(deadline_missed_period)
+    }
+
     pub async fn remove_matched_publication(&mut self, publication_handle: &InstanceHandle) {
         let Some(i) = self
             .matched_publication_list
@@ -7112,6 +8924,9 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
             return;
         };
         self.matched_publication_list.remove(i);
+        // The instances owned by the removed writer can be taken over by the remaining writers
+        self.instance_ownership
+            .retain(|x| &x.owner_handle != publication_handle);
         self.subscription_matched_status.current_count = self.matched_publication_list.len() as i32;
         self.subscription_matched_status.current_count_change -= 1;
         self.status_condition
@@ -7128,7 +8943,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
         view_states: &[ViewStateKind],
         instance_states: &[InstanceStateKind],
         specific_instance_handle: Option<InstanceHandle>,
-    ) -> DdsResult<SampleList> {
+    ) -> DdsResult<DeserializedSampleList> {
         if !self.enabled {
             return Err(DdsError::NotEnabled);
         }
@@ -7145,21 +8960,188 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
             view_states,
             instance_states,
             specific_instance_handle,
+            None,
         )?;
+        self.mark_samples_read(&indexed_sample_list);
 
-        let change_index_list: Vec<usize>;
-        let samples;
+        // The values cached by previous deserialized reads are returned along with the data
+        Ok(indexed_sample_list
+            .into_iter()
+            .map(
+                |IndexedSample {
+                     index,
+                     sample: (data, sample_info),
+                 }| {
+                    let deserialized_data = data
+                        .as_ref()
+                        .and(self.sample_list[index].deserialized_data.clone());
+                    (data, deserialized_data, sample_info)
+                },
+            )
+            .collect())
+    }
 
-        (change_index_list, samples) = indexed_sample_list
+    pub async fn read_with_filter(
+        &mut self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        sample_filter: SampleFilterFn,
+    ) -> DdsResult<SampleList> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        self.status_condition
+            .send_actor_mail(DcpsStatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataAvailable,
+            })
+            .await;
+
+        // Samples rejected by the filter do not count towards max_samples and are left unread
+        let indexed_sample_list = self.create_indexed_sample_collection(
+            max_samples,
+            sample_states,
+            view_states,
+            instance_states,
+            None,
+            Some(&sample_filter),
+        )?;
+        self.mark_samples_read(&indexed_sample_list);
+
+        Ok(indexed_sample_list
             .into_iter()
-            .map(|IndexedSample { index, sample }| (index, sample))
-            .unzip();
+            .map(|IndexedSample { sample, .. }| sample)
+            .collect())
+    }
 
-        for index in change_index_list {
-            self.sample_list[index].sample_state = SampleStateKind::Read;
+    pub async fn read_deserialized(
+        &mut self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        deserialized_type: TypeId,
+        deserialize_data: DeserializeDataFn,
+    ) -> DdsResult<DeserializedSampleList> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
         }
 
-        Ok(samples)
+        self.status_condition
+            .send_actor_mail(DcpsStatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataAvailable,
+            })
+            .await;
+
+        let indexed_sample_list = self.create_indexed_sample_collection(
+            max_samples,
+            sample_states,
+            view_states,
+            instance_states,
+            None,
+            None,
+        )?;
+
+        let mut deserialized_data_list = Vec::with_capacity(indexed_sample_list.len());
+        for IndexedSample {
+            index,
+            sample: (data, _),
+        } in &indexed_sample_list
+        {
+            // The deserialized value is kept with the sample so that it gets discarded
+            // together with it when the sample is taken or removed from the history. A value
+            // cached by a reader handle of another type is replaced.
+            let reader_sample = &mut self.sample_list[*index];
+            let deserialized_data = match (data, &reader_sample.deserialized_data) {
+                (Some(_), Some(cached)) if (**cached).type_id() == deserialized_type => {
+                    Some(cached.clone())
+                }
+                (Some(data), _) => {
+                    let deserialized = deserialize_data(data.as_ref())?;
+                    reader_sample.deserialized_data = Some(deserialized.clone());
+                    Some(deserialized)
+                }
+                (None, _) => None,
+            };
+            deserialized_data_list.push(deserialized_data);
+        }
+
+        // The samples are only marked as read once all of them could be deserialized
+        self.mark_samples_read(&indexed_sample_list);
+
+        Ok(indexed_sample_list
+            .into_iter()
+            .zip(deserialized_data_list)
+            .map(
+                |(
+                    IndexedSample {
+                        sample: (data, sample_info),
+                        ..
+                    },
+                    deserialized_data,
+                )| (data, deserialized_data, sample_info),
+            )
+            .collect())
+    }
+
+    pub fn peek_next_info(&mut self) -> DdsResult<SampleInfo> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        // The sample and instance states are left untouched so that the sample
+        // is still returned as not read by the following read or take
+        let mut indexed_sample_list = self.create_indexed_sample_collection(
+            1,
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+            None,
+            None,
+        )?;
+        let IndexedSample {
+            sample: (_, sample_info),
+            ..
+        } = indexed_sample_list
+            .pop()
+            .expect("Would return NoData if empty");
+        Ok(sample_info)
+    }
+
+    pub fn get_received_sequence_numbers(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<Vec<(i64, InstanceStateKind)>> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        if !self
+            .matched_publication_list
+            .iter()
+            .any(|x| &x.key().value == publication_handle.as_ref())
+        {
+            return Err(DdsError::BadParameter);
+        }
+
+        let mut received_sequence_numbers: Vec<_> = self
+            .sample_list
+            .iter()
+            .filter(|sample| sample.writer_handle == publication_handle)
+            .map(|sample| {
+                let instance_state = self
+                    .instances
+                    .iter()
+                    .find(|x| x.handle() == sample.instance_handle)
+                    .expect("Sample with handle must exist")
+                    .instance_state;
+                (sample.sequence_number, instance_state)
+            })
+            .collect();
+        received_sequence_numbers.sort_by_key(|(sequence_number, _)| *sequence_number);
+        Ok(received_sequence_numbers)
     }
 
     pub async fn take(
@@ -7180,7 +9162,9 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
             &view_states,
             &instance_states,
             specific_instance_handle,
+            None,
         )?;
+        self.mark_instances_viewed(&indexed_sample_list);
 
         self.status_condition
             .send_actor_mail(DcpsStatusConditionMail::RemoveCommunicationState {
@@ -7199,6 +9183,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
         while let Some(index) = change_index_list.pop() {
             self.sample_list.remove(index);
         }
+        self.forget_not_alive_instances_without_samples();
 
         Ok(samples)
     }
@@ -7243,20 +9228,138 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DataReaderEntity<R, T> {
         }
 
         match self.next_instance(previous_handle) {
-            Some(next_handle) => {
-                self.read(
+            Some(next_handle) => Ok(self
+                .read(
                     max_samples,
                     sample_states,
                     view_states,
                     instance_states,
                     Some(next_handle),
                 )
-                .await
-            }
+                .await?
+                .into_iter()
+                .map(|(data, _, sample_info)| (data, sample_info))
+                .collect()),
             None => Err(DdsError::NoData),
         }
     }
 }
 
+// Holds back the received changes of a coherent set until its last change is received and
+// then returns all of them. A set that is not complete is discarded once its writer sends a
+// change which does not belong to it.
+fn complete_coherent_set(
+    coherent_set_changes: &mut Vec<CacheChange>,
+    cache_change: CacheChange,
+) -> Vec<CacheChange> {
+    let writer_guid = cache_change.writer_guid;
+    let pending_coherent_set = coherent_set_changes
+        .iter()
+        .find(|x| x.writer_guid == writer_guid)
+        .and_then(|x| x.coherent_set);
+    if pending_coherent_set.is_some() && pending_coherent_set != cache_change.coherent_set {
+        coherent_set_changes.retain(|x| x.writer_guid != writer_guid);
+    }
+
+    match cache_change.coherent_set {
+        None => vec![cache_change],
+        Some(_) if cache_change.coherent_set_end => {
+            let (mut complete_set, other_changes): (Vec<_>, Vec<_>) =
+                core::mem::take(coherent_set_changes)
+                    .into_iter()
+                    .partition(|x| x.writer_guid == writer_guid);
+            *coherent_set_changes = other_changes;
+            complete_set.push(cache_change);
+            complete_set
+        }
+        Some(_) => {
+            coherent_set_changes.push(cache_change);
+            Vec::new()
+        }
+    }
+}
+
 // #[cfg(test)]
 // mod tests;
+
+#[cfg(test)]
+mod poll_timeout_tests {
+    use super::*;
+    use crate::std_runtime::{executor::block_on, timer::TimerDriver};
+
+    #[test]
+    fn poll_timeout_expires_while_the_future_keeps_waking_up() {
+        let timer_driver = TimerDriver::new();
+        let always_waking_future = poll_fn(|cx| {
+            cx.waker().wake_by_ref();
+            Poll::<()>::Pending
+        });
+
+        let result = block_on(poll_timeout(
+            timer_driver.handle(),
+            core::time::Duration::from_millis(50),
+            Box::pin(always_waking_future),
+        ));
+
+        assert_eq!(result, Err(DdsError::Timeout));
+    }
+}
+
+#[cfg(test)]
+mod coherent_set_tests {
+    use super::*;
+    use crate::transport::types::{EntityId, USER_DEFINED_WRITER_WITH_KEY};
+
+    fn writer_guid(id: u8) -> Guid {
+        Guid::new(
+            [id; 12],
+            EntityId::new([1, 2, 3], USER_DEFINED_WRITER_WITH_KEY),
+        )
+    }
+
+    #[test]
+    fn coherent_set_changes_are_held_until_the_end_of_the_set() {
+        let mut coherent_set_changes = Vec::new();
+        let first = CacheChange::builder(ChangeKind::Alive, writer_guid(1), 1)
+            .coherent_set(1)
+            .build();
+        let other_writer = CacheChange::builder(ChangeKind::Alive, writer_guid(2), 1).build();
+        let last = CacheChange::builder(ChangeKind::Alive, writer_guid(1), 2)
+            .coherent_set(1)
+            .coherent_set_end()
+            .build();
+
+        assert_eq!(
+            complete_coherent_set(&mut coherent_set_changes, first.clone()),
+            vec![]
+        );
+        assert_eq!(
+            complete_coherent_set(&mut coherent_set_changes, other_writer.clone()),
+            vec![other_writer]
+        );
+        assert_eq!(
+            complete_coherent_set(&mut coherent_set_changes, last.clone()),
+            vec![first, last]
+        );
+        assert!(coherent_set_changes.is_empty());
+    }
+
+    #[test]
+    fn incomplete_coherent_set_is_discarded_by_a_change_of_another_set() {
+        let mut coherent_set_changes = Vec::new();
+        let incomplete = CacheChange::builder(ChangeKind::Alive, writer_guid(1), 1)
+            .coherent_set(1)
+            .build();
+        let next_set = CacheChange::builder(ChangeKind::Alive, writer_guid(1), 3)
+            .coherent_set(3)
+            .coherent_set_end()
+            .build();
+
+        complete_coherent_set(&mut coherent_set_changes, incomplete);
+
+        assert_eq!(
+            complete_coherent_set(&mut coherent_set_changes, next_set.clone()),
+            vec![next_set]
+        );
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
             discovered_reader_data::DiscoveredReaderData,
             discovered_topic_data::DiscoveredTopicData,
             discovered_writer_data::DiscoveredWriterData,
-            spdp_discovered_participant_data::SpdpDiscoveredParticipantData,
+            spdp_discovered_participant_data::{BuiltinEndpointSet, SpdpDiscoveredParticipantData},
         },
         domain_participant::{
             DataReaderEntity, DataWriterEntity, DcpsDomainParticipant, DomainParticipantEntity,
@@ -20,6 +20,7 @@ use crate::{
             ParticipantServiceMail,
         },
         listeners::domain_participant_listener::ListenerMail,
+        serialization_buffer::SerializationBuffer,
         status_condition::DcpsStatusCondition,
     },
     infrastructure::{
@@ -42,14 +43,14 @@ use crate::{
     runtime::{ChannelReceive, ChannelSend, DdsRuntime, Spawner, Timer},
     transport::{
         interface::{
-            HistoryCache, TransportParticipant, TransportParticipantFactory,
-            TransportStatefulReader, TransportStatefulWriter, TransportStatelessReader,
-            TransportStatelessWriter,
+            HistoryCache, TransportParticipant, TransportParticipantError,
+            TransportParticipantFactory, TransportStatefulReader, TransportStatefulWriter,
+            TransportStatelessReader, TransportStatelessWriter,
         },
         types::{
-            CacheChange, EntityId, GuidPrefix, ReliabilityKind, BUILT_IN_READER_GROUP,
+            CacheChange, EntityId, Guid, GuidPrefix, ReliabilityKind, BUILT_IN_READER_GROUP,
             BUILT_IN_READER_WITH_KEY, BUILT_IN_TOPIC, BUILT_IN_WRITER_GROUP,
-            BUILT_IN_WRITER_WITH_KEY,
+            BUILT_IN_WRITER_WITH_KEY, ENTITYID_PARTICIPANT,
         },
     },
 };
@@ -86,11 +87,15 @@ pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER: EntityId =
 pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR: EntityId =
     EntityId::new([0, 0, 0x04], BUILT_IN_READER_WITH_KEY);
 
+struct ParticipantListEntry<R: DdsRuntime> {
+    handle: InstanceHandle,
+    domain_id: DomainId,
+    participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+    builtin_subscriber_status_condition_address: ActorAddress<R, DcpsStatusCondition<R>>,
+}
+
 pub struct DcpsParticipantFactory<R: DdsRuntime, T> {
-    domain_participant_list: Vec<(
-        InstanceHandle,
-        R::ChannelSender<DcpsDomainParticipantMail<R>>,
-    )>,
+    domain_participant_list: Vec<ParticipantListEntry<R>>,
     qos: DomainParticipantFactoryQos,
     default_participant_qos: DomainParticipantQos,
     configuration: DustDdsConfiguration,
@@ -121,6 +126,9 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
     }
 
     fn create_new_guid_prefix(&mut self) -> GuidPrefix {
+        if let Some(guid_prefix) = self.configuration.guid_prefix() {
+            return guid_prefix;
+        }
         let instance_id = self.get_unique_participant_id().to_ne_bytes();
 
         [
@@ -160,13 +168,27 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
         };
 
         let guid_prefix = self.create_new_guid_prefix();
+        let participant_handle = InstanceHandle::from(Guid::new(guid_prefix, ENTITYID_PARTICIPANT));
+        if self
+            .domain_participant_list
+            .iter()
+            .any(|p| p.handle == participant_handle)
+        {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "A participant with the configured GUID prefix already exists",
+            )));
+        }
         let (participant_sender, mut participant_receiver) = R::channel();
 
         let mut transport = self
             .transport
             .create_participant(guid_prefix, domain_id)
-            .await;
-        let participant_instance_handle = InstanceHandle::new(transport.guid().into());
+            .await
+            .map_err(|e| match e {
+                TransportParticipantError::OutOfResources(_) => DdsError::OutOfResources,
+                TransportParticipantError::Error(e) => DdsError::PreconditionNotMet(e),
+            })?;
+        let participant_instance_handle = InstanceHandle::from(transport.guid());
 
         fn sedp_data_reader_qos() -> DataReaderQos {
             DataReaderQos {
@@ -367,7 +389,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
             )
             .await;
         let dcps_participant_reader = DataReaderEntity::new(
-            InstanceHandle::new(dcps_participant_transport_reader.guid().into()),
+            InstanceHandle::from(dcps_participant_transport_reader.guid()),
             spdp_reader_qos,
             String::from(DCPS_PARTICIPANT),
             "SpdpDiscoveredParticipantData".to_string(),
@@ -377,73 +399,81 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
             Vec::new(),
             TransportReaderKind::Stateless(dcps_participant_transport_reader),
         );
-        let dcps_topic_transport_reader = transport
-            .create_stateful_reader(
-                ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR,
-                ReliabilityKind::Reliable,
-                Box::new(DcpsTopicsReaderHistoryCache::<R> {
-                    participant_address: participant_sender.clone(),
-                }),
-            )
-            .await;
-        let dcps_topic_reader = DataReaderEntity::new(
-            InstanceHandle::new(dcps_topic_transport_reader.guid().into()),
-            sedp_data_reader_qos(),
-            String::from(DCPS_TOPIC),
-            "DiscoveredTopicData".to_string(),
-            Arc::new(DiscoveredTopicData::get_type()),
-            Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
-            None,
-            Vec::new(),
-            TransportReaderKind::Stateful(dcps_topic_transport_reader),
-        );
-        let dcps_publication_transport_reader = transport
-            .create_stateful_reader(
-                ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
-                ReliabilityKind::Reliable,
-                Box::new(DcpsPublicationsReaderHistoryCache::<R> {
-                    participant_address: participant_sender.clone(),
-                }),
-            )
-            .await;
-        let dcps_publication_reader = DataReaderEntity::new(
-            InstanceHandle::new(dcps_publication_transport_reader.guid().into()),
-            sedp_data_reader_qos(),
-            String::from(DCPS_PUBLICATION),
-            "DiscoveredWriterData".to_string(),
-            Arc::new(DiscoveredWriterData::get_type()),
-            Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
-            None,
-            Vec::new(),
-            TransportReaderKind::Stateful(dcps_publication_transport_reader),
-        );
-        let dcps_subscription_transport_reader = transport
-            .create_stateful_reader(
-                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
-                ReliabilityKind::Reliable,
-                Box::new(DcpsSubscriptionsReaderHistoryCache::<R> {
-                    participant_address: participant_sender.clone(),
-                }),
-            )
-            .await;
-        let dcps_subscription_reader = DataReaderEntity::new(
-            InstanceHandle::new(dcps_subscription_transport_reader.guid().into()),
-            sedp_data_reader_qos(),
-            String::from(DCPS_SUBSCRIPTION),
-            "DiscoveredReaderData".to_string(),
-            Arc::new(DiscoveredReaderData::get_type()),
-            Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
-            None,
-            Vec::new(),
-            TransportReaderKind::Stateful(dcps_subscription_transport_reader),
-        );
+        let builtin_endpoints = self.configuration.builtin_endpoints();
+        let mut data_reader_list = vec![dcps_participant_reader];
+        if builtin_endpoints.has(BuiltinEndpointSet::BUILTIN_ENDPOINT_TOPICS_DETECTOR) {
+            let dcps_topic_transport_reader = transport
+                .create_stateful_reader(
+                    ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR,
+                    DCPS_TOPIC,
+                    ReliabilityKind::Reliable,
+                    Box::new(DcpsTopicsReaderHistoryCache::<R> {
+                        participant_address: participant_sender.clone(),
+                    }),
+                )
+                .await;
+            let dcps_topic_reader = DataReaderEntity::new(
+                InstanceHandle::from(dcps_topic_transport_reader.guid()),
+                sedp_data_reader_qos(),
+                String::from(DCPS_TOPIC),
+                "DiscoveredTopicData".to_string(),
+                Arc::new(DiscoveredTopicData::get_type()),
+                Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
+                None,
+                Vec::new(),
+                TransportReaderKind::Stateful(dcps_topic_transport_reader),
+            );
+            data_reader_list.push(dcps_topic_reader);
+        }
+        if builtin_endpoints.has(BuiltinEndpointSet::BUILTIN_ENDPOINT_PUBLICATIONS_DETECTOR) {
+            let dcps_publication_transport_reader = transport
+                .create_stateful_reader(
+                    ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
+                    DCPS_PUBLICATION,
+                    ReliabilityKind::Reliable,
+                    Box::new(DcpsPublicationsReaderHistoryCache::<R> {
+                        participant_address: participant_sender.clone(),
+                    }),
+                )
+                .await;
+            let dcps_publication_reader = DataReaderEntity::new(
+                InstanceHandle::from(dcps_publication_transport_reader.guid()),
+                sedp_data_reader_qos(),
+                String::from(DCPS_PUBLICATION),
+                "DiscoveredWriterData".to_string(),
+                Arc::new(DiscoveredWriterData::get_type()),
+                Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
+                None,
+                Vec::new(),
+                TransportReaderKind::Stateful(dcps_publication_transport_reader),
+            );
+            data_reader_list.push(dcps_publication_reader);
+        }
+        if builtin_endpoints.has(BuiltinEndpointSet::BUILTIN_ENDPOINT_SUBSCRIPTIONS_DETECTOR) {
+            let dcps_subscription_transport_reader = transport
+                .create_stateful_reader(
+                    ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+                    DCPS_SUBSCRIPTION,
+                    ReliabilityKind::Reliable,
+                    Box::new(DcpsSubscriptionsReaderHistoryCache::<R> {
+                        participant_address: participant_sender.clone(),
+                    }),
+                )
+                .await;
+            let dcps_subscription_reader = DataReaderEntity::new(
+                InstanceHandle::from(dcps_subscription_transport_reader.guid()),
+                sedp_data_reader_qos(),
+                String::from(DCPS_SUBSCRIPTION),
+                "DiscoveredReaderData".to_string(),
+                Arc::new(DiscoveredReaderData::get_type()),
+                Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
+                None,
+                Vec::new(),
+                TransportReaderKind::Stateful(dcps_subscription_transport_reader),
+            );
+            data_reader_list.push(dcps_subscription_reader);
+        }
 
-        let data_reader_list = vec![
-            dcps_participant_reader,
-            dcps_topic_reader,
-            dcps_publication_reader,
-            dcps_subscription_reader,
-        ];
         let builtin_subscriber_handle = [
             participant_instance_handle[0],
             participant_instance_handle[1],
@@ -471,14 +501,22 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
             vec![],
         );
 
+        let serialization_buffer_capacity = self.configuration.serialization_buffer_capacity();
+        let serialization_buffer_shrink_after_idle: Duration = self
+            .configuration
+            .serialization_buffer_shrink_after_idle()
+            .into();
+        let participant_lease_duration: Duration =
+            self.configuration.participant_lease_duration().into();
+
         let mut dcps_participant_transport_writer = transport
             .create_stateless_writer(ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER)
             .await;
-        for &discovery_locator in transport.metatraffic_multicast_locator_list() {
+        for &discovery_locator in transport.discovery_locator_list() {
             dcps_participant_transport_writer.add_reader_locator(discovery_locator);
         }
         let dcps_participant_writer = DataWriterEntity::new(
-            InstanceHandle::new(dcps_participant_transport_writer.guid().into()),
+            InstanceHandle::from(dcps_participant_transport_writer.guid()),
             TransportWriterKind::Stateless(dcps_participant_transport_writer),
             String::from(DCPS_PARTICIPANT),
             "SpdpDiscoveredParticipantData".to_string(),
@@ -487,66 +525,89 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
             None,
             vec![],
             spdp_writer_qos,
+            SerializationBuffer::new(
+                serialization_buffer_capacity,
+                serialization_buffer_shrink_after_idle,
+            ),
         );
 
-        let dcps_topics_transport_writer = transport
-            .create_stateful_writer(
-                ENTITYID_SEDP_BUILTIN_TOPICS_ANNOUNCER,
-                ReliabilityKind::Reliable,
-            )
-            .await;
-        let dcps_topics_writer = DataWriterEntity::new(
-            InstanceHandle::new(dcps_topics_transport_writer.guid().into()),
-            TransportWriterKind::Stateful(dcps_topics_transport_writer),
-            String::from(DCPS_TOPIC),
-            "DiscoveredTopicData".to_string(),
-            Arc::new(DiscoveredTopicData::get_type()),
-            Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
-            None,
-            vec![],
-            sedp_data_writer_qos(),
-        );
-        let dcps_publications_transport_writer = transport
-            .create_stateful_writer(
-                ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
-                ReliabilityKind::Reliable,
-            )
-            .await;
-        let dcps_publications_writer = DataWriterEntity::new(
-            InstanceHandle::new(dcps_publications_transport_writer.guid().into()),
-            TransportWriterKind::Stateful(dcps_publications_transport_writer),
-            String::from(DCPS_PUBLICATION),
-            "DiscoveredWriterData".to_string(),
-            Arc::new(DiscoveredWriterData::get_type()),
-            Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
-            None,
-            vec![],
-            sedp_data_writer_qos(),
-        );
+        let mut builtin_data_writer_list = vec![dcps_participant_writer];
+        if builtin_endpoints.has(BuiltinEndpointSet::BUILTIN_ENDPOINT_TOPICS_ANNOUNCER) {
+            let dcps_topics_transport_writer = transport
+                .create_stateful_writer(
+                    ENTITYID_SEDP_BUILTIN_TOPICS_ANNOUNCER,
+                    DCPS_TOPIC,
+                    ReliabilityKind::Reliable,
+                )
+                .await;
+            let dcps_topics_writer = DataWriterEntity::new(
+                InstanceHandle::from(dcps_topics_transport_writer.guid()),
+                TransportWriterKind::Stateful(dcps_topics_transport_writer),
+                String::from(DCPS_TOPIC),
+                "DiscoveredTopicData".to_string(),
+                Arc::new(DiscoveredTopicData::get_type()),
+                Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
+                None,
+                vec![],
+                sedp_data_writer_qos(),
+                SerializationBuffer::new(
+                    serialization_buffer_capacity,
+                    serialization_buffer_shrink_after_idle,
+                ),
+            );
+            builtin_data_writer_list.push(dcps_topics_writer);
+        }
+        if builtin_endpoints.has(BuiltinEndpointSet::BUILTIN_ENDPOINT_PUBLICATIONS_ANNOUNCER) {
+            let dcps_publications_transport_writer = transport
+                .create_stateful_writer(
+                    ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+                    DCPS_PUBLICATION,
+                    ReliabilityKind::Reliable,
+                )
+                .await;
+            let dcps_publications_writer = DataWriterEntity::new(
+                InstanceHandle::from(dcps_publications_transport_writer.guid()),
+                TransportWriterKind::Stateful(dcps_publications_transport_writer),
+                String::from(DCPS_PUBLICATION),
+                "DiscoveredWriterData".to_string(),
+                Arc::new(DiscoveredWriterData::get_type()),
+                Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
+                None,
+                vec![],
+                sedp_data_writer_qos(),
+                SerializationBuffer::new(
+                    serialization_buffer_capacity,
+                    serialization_buffer_shrink_after_idle,
+                ),
+            );
+            builtin_data_writer_list.push(dcps_publications_writer);
+        }
 
-        let dcps_subscriptions_transport_writer = transport
-            .create_stateful_writer(
-                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
-                ReliabilityKind::Reliable,
-            )
-            .await;
-        let dcps_subscriptions_writer = DataWriterEntity::new(
-            InstanceHandle::new(dcps_subscriptions_transport_writer.guid().into()),
-            TransportWriterKind::Stateful(dcps_subscriptions_transport_writer),
-            String::from(DCPS_SUBSCRIPTION),
-            "DiscoveredReaderData".to_string(),
-            Arc::new(DiscoveredReaderData::get_type()),
-            Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
-            None,
-            vec![],
-            sedp_data_writer_qos(),
-        );
-        let builtin_data_writer_list = vec![
-            dcps_participant_writer,
-            dcps_topics_writer,
-            dcps_publications_writer,
-            dcps_subscriptions_writer,
-        ];
+        if builtin_endpoints.has(BuiltinEndpointSet::BUILTIN_ENDPOINT_SUBSCRIPTIONS_ANNOUNCER) {
+            let dcps_subscriptions_transport_writer = transport
+                .create_stateful_writer(
+                    ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+                    DCPS_SUBSCRIPTION,
+                    ReliabilityKind::Reliable,
+                )
+                .await;
+            let dcps_subscriptions_writer = DataWriterEntity::new(
+                InstanceHandle::from(dcps_subscriptions_transport_writer.guid()),
+                TransportWriterKind::Stateful(dcps_subscriptions_transport_writer),
+                String::from(DCPS_SUBSCRIPTION),
+                "DiscoveredReaderData".to_string(),
+                Arc::new(DiscoveredReaderData::get_type()),
+                Actor::spawn(DcpsStatusCondition::default(), &spawner_handle),
+                None,
+                vec![],
+                sedp_data_writer_qos(),
+                SerializationBuffer::new(
+                    serialization_buffer_capacity,
+                    serialization_buffer_shrink_after_idle,
+                ),
+            );
+            builtin_data_writer_list.push(dcps_subscriptions_writer);
+        }
         let builtin_publisher_handle = [
             participant_instance_handle[0],
             participant_instance_handle[1],
@@ -583,6 +644,10 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
             builtin_subscriber,
             topic_list,
             String::from(self.configuration.domain_tag()),
+            serialization_buffer_capacity,
+            serialization_buffer_shrink_after_idle,
+            self.configuration.max_matched_readers_per_writer(),
+            participant_lease_duration,
         );
 
         let mut dcps_participant: DcpsDomainParticipant<R, T> = DcpsDomainParticipant::new(
@@ -600,12 +665,17 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
         spawner_handle.spawn(async move {
             while let Some(m) = participant_receiver.receive().await {
                 dcps_participant.handle(m).await;
+                // Dropping the receiver once the participant is deleted stops the tasks sending to it
+                if dcps_participant.is_deleted() {
+                    break;
+                }
             }
         });
 
         //****** Spawn the participant actor and tasks **********//
 
-        // Start the regular participant announcement task
+        // Start the regular participant announcement task which also removes the discovered
+        // participants whose lease has expired
         let participant_address = participant_sender.clone();
         let participant_announcement_interval =
             self.configuration.participant_announcement_interval();
@@ -617,6 +687,12 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
                 ))
                 .await
                 .is_ok()
+                && participant_address
+                    .send(DcpsDomainParticipantMail::Discovery(
+                        DiscoveryServiceMail::RemoveStaleParticipants,
+                    ))
+                    .await
+                    .is_ok()
             {
                 timer_handle.delay(participant_announcement_interval).await;
             }
@@ -633,8 +709,13 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
         }
 
         let participant_address = participant_sender.clone();
-        self.domain_participant_list
-            .push((participant_instance_handle, participant_sender));
+        self.domain_participant_list.push(ParticipantListEntry {
+            handle: participant_instance_handle,
+            domain_id,
+            participant_address: participant_sender,
+            builtin_subscriber_status_condition_address:
+                builtin_subscriber_status_condition_address.clone(),
+        });
 
         Ok((
             participant_address,
@@ -650,14 +731,35 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsParticipantFactory<R, T>
         let index = self
             .domain_participant_list
             .iter()
-            .position(|(h, _)| h == &handle)
+            .position(|x| x.handle == handle)
             .ok_or(DdsError::PreconditionNotMet(
                 "Participant can only be deleted from its parent domain participant factory"
                     .to_string(),
             ))?;
 
-        let (_, participant) = self.domain_participant_list.remove(index);
-        Ok(participant)
+        let participant = self.domain_participant_list.remove(index);
+        Ok(participant.participant_address)
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn lookup_participant(
+        &self,
+        domain_id: DomainId,
+    ) -> Option<(
+        R::ChannelSender<DcpsDomainParticipantMail<R>>,
+        InstanceHandle,
+        ActorAddress<R, DcpsStatusCondition<R>>,
+    )> {
+        self.domain_participant_list
+            .iter()
+            .find(|x| x.domain_id == domain_id)
+            .map(|x| {
+                (
+                    x.participant_address.clone(),
+                    x.handle,
+                    x.builtin_subscriber_status_condition_address.clone(),
+                )
+            })
     }
 
     pub fn set_default_participant_qos(
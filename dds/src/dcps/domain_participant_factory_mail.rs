@@ -41,6 +41,17 @@ pub enum DcpsParticipantFactoryMail<R: DdsRuntime> {
         handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<R::ChannelSender<DcpsDomainParticipantMail<R>>>>,
     },
+    LookupParticipant {
+        domain_id: DomainId,
+        #[allow(clippy::type_complexity)]
+        reply_sender: R::OneshotSender<
+            Option<(
+                R::ChannelSender<DcpsDomainParticipantMail<R>>,
+                InstanceHandle,
+                ActorAddress<R, DcpsStatusCondition<R>>,
+            )>,
+        >,
+    },
     SetDefaultParticipantQos {
         qos: QosKind<DomainParticipantQos>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
@@ -93,6 +104,10 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> MailHandler for DcpsParticip
                 handle,
                 reply_sender,
             } => reply_sender.send(self.delete_participant(handle)),
+            DcpsParticipantFactoryMail::LookupParticipant {
+                domain_id,
+                reply_sender,
+            } => reply_sender.send(self.lookup_participant(domain_id)),
             DcpsParticipantFactoryMail::SetDefaultParticipantQos { qos, reply_sender } => {
                 reply_sender.send(self.set_default_participant_qos(qos))
             }
@@ -1,4 +1,6 @@
-use super::domain_participant::DcpsDomainParticipant;
+use super::domain_participant::{
+    DcpsDomainParticipant, DeserializeDataFn, DeserializedSampleList, SampleFilterFn,
+};
 use crate::{
     builtin_topics::{
         ParticipantBuiltinTopicData, PublicationBuiltinTopicData, SubscriptionBuiltinTopicData,
@@ -18,17 +20,21 @@ use crate::{
         },
         sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
         status::{
-            InconsistentTopicStatus, OfferedDeadlineMissedStatus, PublicationMatchedStatus,
-            StatusKind, SubscriptionMatchedStatus,
+            InconsistentTopicStatus, LivelinessLostStatus, MatchDiagnostic,
+            OfferedDeadlineMissedStatus, ParticipantStatistics, PublicationMatchedStatus,
+            RequestedDeadlineMissedStatus, StatusKind, SubscriptionMatchedStatus,
         },
         time::{Duration, Time},
     },
     runtime::{DdsRuntime, OneshotSend},
-    transport::{interface::TransportParticipantFactory, types::CacheChange},
+    transport::{
+        interface::TransportParticipantFactory,
+        types::{CacheChange, EntityId},
+    },
     xtypes::dynamic_type::{DynamicData, DynamicType},
 };
 use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
-use core::{future::Future, pin::Pin};
+use core::{any::TypeId, future::Future, pin::Pin};
 
 pub enum ParticipantServiceMail<R: DdsRuntime> {
     CreateUserDefinedPublisher {
@@ -108,10 +114,18 @@ pub enum ParticipantServiceMail<R: DdsRuntime> {
             >,
         >,
     },
+    LookupRegisteredType {
+        type_name: String,
+        reply_sender: R::OneshotSender<Option<DynamicType>>,
+    },
     IgnoreParticipant {
         handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    IgnoreTopic {
+        handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     IgnoreSubscription {
         handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<()>>,
@@ -162,6 +176,12 @@ pub enum ParticipantServiceMail<R: DdsRuntime> {
     GetCurrentTime {
         reply_sender: R::OneshotSender<Time>,
     },
+    GetStatistics {
+        reply_sender: R::OneshotSender<ParticipantStatistics>,
+    },
+    Announce {
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     SetQos {
         qos: QosKind<DomainParticipantQos>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
@@ -211,6 +231,7 @@ pub enum PublisherServiceMail<R: DdsRuntime> {
         publisher_handle: InstanceHandle,
         topic_name: String,
         qos: QosKind<DataWriterQos>,
+        entity_id: Option<EntityId>,
         status_condition: Actor<R, DcpsStatusCondition<R>>,
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         mask: Vec<StatusKind>,
@@ -246,6 +267,14 @@ pub enum PublisherServiceMail<R: DdsRuntime> {
         mask: Vec<StatusKind>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    BeginCoherentChanges {
+        publisher_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    EndCoherentChanges {
+        publisher_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
 }
 
 pub enum SubscriberServiceMail<R: DdsRuntime> {
@@ -253,6 +282,7 @@ pub enum SubscriberServiceMail<R: DdsRuntime> {
         subscriber_handle: InstanceHandle,
         topic_name: String,
         qos: QosKind<DataReaderQos>,
+        entity_id: Option<EntityId>,
         status_condition: Actor<R, DcpsStatusCondition<R>>,
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         mask: Vec<StatusKind>,
@@ -296,6 +326,11 @@ pub enum SubscriberServiceMail<R: DdsRuntime> {
         mask: Vec<StatusKind>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    NotifyDataReaders {
+        subscriber_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
 }
 
 pub enum WriterServiceMail<R: DdsRuntime> {
@@ -306,6 +341,17 @@ pub enum WriterServiceMail<R: DdsRuntime> {
         listener_mask: Vec<StatusKind>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    GetListener {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        #[allow(clippy::type_complexity)]
+        reply_sender: R::OneshotSender<DdsResult<Option<R::ChannelSender<ListenerMail<R>>>>>,
+    },
+    GetListenerMask {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Option<Vec<StatusKind>>>>,
+    },
     GetDataWriterQos {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
@@ -322,11 +368,25 @@ pub enum WriterServiceMail<R: DdsRuntime> {
         subscription_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<SubscriptionBuiltinTopicData>>,
     },
+    GetSubscriptionMatchDiagnostic {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        subscription_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<MatchDiagnostic>>,
+    },
     GetPublicationMatchedStatus {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<PublicationMatchedStatus>>,
     },
+    RegisterInstance {
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        dynamic_data: DynamicData,
+        timestamp: Time,
+        reply_sender: R::OneshotSender<DdsResult<Option<InstanceHandle>>>,
+    },
     UnregisterInstance {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
@@ -360,6 +420,16 @@ pub enum WriterServiceMail<R: DdsRuntime> {
         data_writer_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<OfferedDeadlineMissedStatus>>,
     },
+    GetLivelinessLostStatus {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<LivelinessLostStatus>>,
+    },
+    AssertLiveliness {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     EnableDataWriter {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
@@ -389,9 +459,35 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         view_states: Vec<ViewStateKind>,
         instance_states: Vec<InstanceStateKind>,
         specific_instance_handle: Option<InstanceHandle>,
+        reply_sender: R::OneshotSender<DdsResult<DeserializedSampleList>>,
+    },
+    ReadWithFilter {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        sample_filter: SampleFilterFn,
         #[allow(clippy::type_complexity)]
         reply_sender: R::OneshotSender<DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>>>,
     },
+    ReadDeserialized {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        deserialized_type: TypeId,
+        deserialize_data: DeserializeDataFn,
+        reply_sender: R::OneshotSender<DdsResult<DeserializedSampleList>>,
+    },
+    PeekNextInfo {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<SampleInfo>>,
+    },
     Take {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
@@ -430,6 +526,11 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         data_reader_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<SubscriptionMatchedStatus>>,
     },
+    GetRequestedDeadlineMissedStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<RequestedDeadlineMissedStatus>>,
+    },
     WaitForHistoricalData {
         participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
         subscriber_handle: InstanceHandle,
@@ -443,11 +544,29 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         publication_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<PublicationBuiltinTopicData>>,
     },
+    GetPublicationMatchDiagnostic {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<MatchDiagnostic>>,
+    },
+    GetReceivedSequenceNumbers {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Vec<(i64, InstanceStateKind)>>>,
+    },
     GetMatchedPublications {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<Vec<InstanceHandle>>>,
     },
+    GetInstanceSerializedData {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        instance_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Arc<[u8]>>>,
+    },
     SetQos {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
@@ -480,6 +599,12 @@ pub enum MessageServiceMail<R: DdsRuntime> {
         data_writer_handle: InstanceHandle,
         sequence_number: i64,
     },
+    RemoveReaderChange {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        writer_handle: InstanceHandle,
+        sequence_number: i64,
+    },
     AreAllChangesAcknowledged {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
@@ -513,6 +638,11 @@ pub enum EventServiceMail<R: DdsRuntime> {
         change_instance_handle: InstanceHandle,
         participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
     },
+    LivelinessLost {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DcpsDomainParticipantMail<R>>,
+    },
     RequestedDeadlineMissed {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
@@ -521,9 +651,10 @@ pub enum EventServiceMail<R: DdsRuntime> {
     },
 }
 
-pub enum DiscoveryServiceMail {
+pub enum DiscoveryServiceMail<R: DdsRuntime> {
     AnnounceParticipant,
-    AnnounceDeletedParticipant,
+    AnnounceDeletedParticipant { reply_sender: R::OneshotSender<()> },
+    RemoveStaleParticipants,
 }
 
 pub enum DcpsDomainParticipantMail<R: DdsRuntime> {
@@ -535,7 +666,7 @@ pub enum DcpsDomainParticipantMail<R: DdsRuntime> {
     Reader(ReaderServiceMail<R>),
     Message(MessageServiceMail<R>),
     Event(EventServiceMail<R>),
-    Discovery(DiscoveryServiceMail),
+    Discovery(DiscoveryServiceMail<R>),
 }
 
 impl<R: DdsRuntime, T: TransportParticipantFactory> MailHandler for DcpsDomainParticipant<R, T> {
@@ -662,10 +793,18 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 topic_name,
                 reply_sender,
             } => reply_sender.send(self.lookup_topicdescription(topic_name)),
+            ParticipantServiceMail::LookupRegisteredType {
+                type_name,
+                reply_sender,
+            } => reply_sender.send(self.lookup_registered_type(type_name)),
             ParticipantServiceMail::IgnoreParticipant {
                 handle,
                 reply_sender,
             } => reply_sender.send(self.ignore_participant(handle)),
+            ParticipantServiceMail::IgnoreTopic {
+                handle,
+                reply_sender,
+            } => reply_sender.send(self.ignore_topic(handle)),
             ParticipantServiceMail::IgnoreSubscription {
                 handle,
                 reply_sender,
@@ -698,6 +837,12 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
             ParticipantServiceMail::GetCurrentTime { reply_sender } => {
                 reply_sender.send(self.get_current_time())
             }
+            ParticipantServiceMail::GetStatistics { reply_sender } => {
+                reply_sender.send(self.get_statistics())
+            }
+            ParticipantServiceMail::Announce { reply_sender } => {
+                reply_sender.send(self.announce().await)
+            }
             ParticipantServiceMail::GetDiscoveredParticipants { reply_sender } => {
                 reply_sender.send(self.get_discovered_participants())
             }
@@ -765,6 +910,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 publisher_handle,
                 topic_name,
                 qos,
+                entity_id,
                 status_condition,
                 listener_sender,
                 mask,
@@ -775,6 +921,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                     publisher_handle,
                     topic_name,
                     qos,
+                    entity_id,
                     status_condition,
                     listener_sender,
                     mask,
@@ -803,6 +950,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 publisher_handle,
                 reply_sender,
             } => reply_sender.send(self.get_publisher_qos(publisher_handle)),
+            PublisherServiceMail::BeginCoherentChanges {
+                publisher_handle,
+                reply_sender,
+            } => reply_sender.send(self.begin_coherent_changes(publisher_handle)),
+            PublisherServiceMail::EndCoherentChanges {
+                publisher_handle,
+                reply_sender,
+            } => reply_sender.send(self.end_coherent_changes(publisher_handle).await),
             PublisherServiceMail::SetPublisherQos {
                 publisher_handle,
                 qos,
@@ -835,6 +990,18 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 listener_sender,
                 listener_mask,
             )),
+            WriterServiceMail::GetListener {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender
+                .send(self.get_data_writer_listener(publisher_handle, data_writer_handle)),
+            WriterServiceMail::GetListenerMask {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender
+                .send(self.get_data_writer_listener_mask(publisher_handle, data_writer_handle)),
             WriterServiceMail::GetDataWriterQos {
                 publisher_handle,
                 data_writer_handle,
@@ -856,6 +1023,16 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 data_writer_handle,
                 subscription_handle,
             )),
+            WriterServiceMail::GetSubscriptionMatchDiagnostic {
+                publisher_handle,
+                data_writer_handle,
+                subscription_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_subscription_match_diagnostic(
+                publisher_handle,
+                data_writer_handle,
+                subscription_handle,
+            )),
             WriterServiceMail::GetPublicationMatchedStatus {
                 publisher_handle,
                 data_writer_handle,
@@ -864,6 +1041,23 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 self.get_publication_matched_status(publisher_handle, data_writer_handle)
                     .await,
             ),
+            WriterServiceMail::RegisterInstance {
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+                dynamic_data,
+                timestamp,
+                reply_sender,
+            } => reply_sender.send(
+                self.register_instance_w_timestamp(
+                    participant_address,
+                    publisher_handle,
+                    data_writer_handle,
+                    dynamic_data,
+                    timestamp,
+                )
+                .await,
+            ),
             WriterServiceMail::UnregisterInstance {
                 publisher_handle,
                 data_writer_handle,
@@ -929,6 +1123,20 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 self.get_offered_deadline_missed_status(publisher_handle, data_writer_handle)
                     .await,
             ),
+            WriterServiceMail::GetLivelinessLostStatus {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_liveliness_lost_status(publisher_handle, data_writer_handle)
+                    .await,
+            ),
+            WriterServiceMail::AssertLiveliness {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender
+                .send(self.assert_data_writer_liveliness(publisher_handle, data_writer_handle)),
             WriterServiceMail::EnableDataWriter {
                 publisher_handle,
                 data_writer_handle,
@@ -959,6 +1167,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 subscriber_handle,
                 topic_name,
                 qos,
+                entity_id,
                 status_condition,
                 listener_sender,
                 mask,
@@ -969,6 +1178,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                     subscriber_handle,
                     topic_name,
                     qos,
+                    entity_id,
                     status_condition,
                     listener_sender,
                     mask,
@@ -1017,6 +1227,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 listener_sender,
                 mask,
             )),
+            SubscriberServiceMail::NotifyDataReaders {
+                subscriber_handle,
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(
+                self.notify_data_readers(subscriber_handle, participant_address)
+                    .await,
+            ),
         }
     }
 
@@ -1043,6 +1261,55 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 )
                 .await,
             ),
+            ReaderServiceMail::ReadWithFilter {
+                subscriber_handle,
+                data_reader_handle,
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                sample_filter,
+                reply_sender,
+            } => reply_sender.send(
+                self.read_with_filter(
+                    subscriber_handle,
+                    data_reader_handle,
+                    max_samples,
+                    sample_states,
+                    view_states,
+                    instance_states,
+                    sample_filter,
+                )
+                .await,
+            ),
+            ReaderServiceMail::ReadDeserialized {
+                subscriber_handle,
+                data_reader_handle,
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                deserialized_type,
+                deserialize_data,
+                reply_sender,
+            } => reply_sender.send(
+                self.read_deserialized(
+                    subscriber_handle,
+                    data_reader_handle,
+                    max_samples,
+                    sample_states,
+                    view_states,
+                    instance_states,
+                    deserialized_type,
+                    deserialize_data,
+                )
+                .await,
+            ),
+            ReaderServiceMail::PeekNextInfo {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(self.peek_next_info(subscriber_handle, data_reader_handle)),
             ReaderServiceMail::Take {
                 subscriber_handle,
                 data_reader_handle,
@@ -1123,6 +1390,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 self.get_subscription_matched_status(subscriber_handle, data_reader_handle)
                     .await,
             ),
+            ReaderServiceMail::GetRequestedDeadlineMissedStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_requested_deadline_missed_status(subscriber_handle, data_reader_handle)
+                    .await,
+            ),
             ReaderServiceMail::WaitForHistoricalData {
                 participant_address,
                 subscriber_handle,
@@ -1145,12 +1420,42 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 data_reader_handle,
                 publication_handle,
             )),
+            ReaderServiceMail::GetPublicationMatchDiagnostic {
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_publication_match_diagnostic(
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+            )),
+            ReaderServiceMail::GetReceivedSequenceNumbers {
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_received_sequence_numbers(
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+            )),
             ReaderServiceMail::GetMatchedPublications {
                 subscriber_handle,
                 data_reader_handle,
                 reply_sender,
             } => reply_sender
                 .send(self.get_matched_publications(subscriber_handle, data_reader_handle)),
+            ReaderServiceMail::GetInstanceSerializedData {
+                subscriber_handle,
+                data_reader_handle,
+                instance_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_data_reader_instance_serialized_data(
+                subscriber_handle,
+                data_reader_handle,
+                instance_handle,
+            )),
             ReaderServiceMail::GetQos {
                 subscriber_handle,
                 data_reader_handle,
@@ -1204,6 +1509,20 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 self.remove_writer_change(publisher_handle, data_writer_handle, sequence_number)
                     .await
             }
+            MessageServiceMail::RemoveReaderChange {
+                subscriber_handle,
+                data_reader_handle,
+                writer_handle,
+                sequence_number,
+            } => {
+                self.remove_reader_change(
+                    subscriber_handle,
+                    data_reader_handle,
+                    writer_handle,
+                    sequence_number,
+                )
+                .await
+            }
             MessageServiceMail::AreAllChangesAcknowledged {
                 publisher_handle,
                 data_writer_handle,
@@ -1267,6 +1586,14 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
                 )
                 .await
             }
+            EventServiceMail::LivelinessLost {
+                publisher_handle,
+                data_writer_handle,
+                participant_address,
+            } => {
+                self.liveliness_lost(publisher_handle, data_writer_handle, participant_address)
+                    .await
+            }
             EventServiceMail::RequestedDeadlineMissed {
                 subscriber_handle,
                 data_reader_handle,
@@ -1284,13 +1611,17 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DcpsDomainParticipant<R, T>
         }
     }
 
-    async fn handle_discovery_service(&mut self, discovery_service_mail: DiscoveryServiceMail) {
+    async fn handle_discovery_service(&mut self, discovery_service_mail: DiscoveryServiceMail<R>) {
         match discovery_service_mail {
             DiscoveryServiceMail::AnnounceParticipant => {
                 self.announce_participant().await;
             }
-            DiscoveryServiceMail::AnnounceDeletedParticipant => {
+            DiscoveryServiceMail::AnnounceDeletedParticipant { reply_sender } => {
                 self.announce_deleted_participant().await;
+                reply_sender.send(())
+            }
+            DiscoveryServiceMail::RemoveStaleParticipants => {
+                self.remove_stale_participants().await;
             }
         }
     }
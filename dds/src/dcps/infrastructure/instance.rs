@@ -1,6 +1,6 @@
-use core::ops::Index;
+use core::{fmt::Display, ops::Index};
 
-use crate::infrastructure::type_support::DdsDeserialize;
+use crate::{infrastructure::type_support::DdsDeserialize, transport::types::Guid};
 
 use crate::xtypes::deserialize::XTypesDeserialize;
 use dust_dds_derive::TypeSupport;
@@ -40,6 +40,18 @@ impl From<InstanceHandle> for crate::rtps::behavior_types::InstanceHandle {
     }
 }
 
+impl From<Guid> for InstanceHandle {
+    fn from(value: Guid) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<InstanceHandle> for Guid {
+    fn from(value: InstanceHandle) -> Self {
+        Guid::from(value.0)
+    }
+}
+
 impl AsRef<[u8; 16]> for InstanceHandle {
     fn as_ref(&self) -> &[u8; 16] {
         &self.0
@@ -68,3 +80,43 @@ impl From<InstanceHandle> for [u8; 16] {
         x.0
     }
 }
+
+impl Display for InstanceHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, b) in self.0.iter().enumerate() {
+            if i == 12 {
+                write!(f, ".")?;
+            }
+            write!(f, "{b:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::types::{EntityId, USER_DEFINED_WRITER_WITH_KEY};
+    use alloc::string::ToString;
+
+    #[test]
+    fn guid_round_trip() {
+        let guid = Guid::new(
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+            EntityId::new([13, 14, 15], USER_DEFINED_WRITER_WITH_KEY),
+        );
+        let handle = InstanceHandle::from(guid);
+        assert_eq!(Guid::from(handle), guid);
+        assert_eq!(handle.as_ref(), &<[u8; 16]>::from(guid));
+    }
+
+    #[test]
+    fn display_separates_entity_id() {
+        let handle = InstanceHandle::new([
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0xa0, 0xb0,
+            0xc0, 0xd0,
+        ]);
+        assert_eq!(handle.to_string(), "0102030405060708090a0b0c.a0b0c0d0");
+        assert_eq!(HANDLE_NIL.to_string(), "000000000000000000000000.00000000");
+    }
+}
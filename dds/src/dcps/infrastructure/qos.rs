@@ -6,12 +6,13 @@ use crate::{
 use super::{
     qos_policy::{
         DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy,
-        DurabilityQosPolicy, EntityFactoryQosPolicy, GroupDataQosPolicy, HistoryQosPolicy,
-        HistoryQosPolicyKind, LatencyBudgetQosPolicy, LifespanQosPolicy, LivelinessQosPolicy,
-        OwnershipQosPolicy, OwnershipStrengthQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
-        ReaderDataLifecycleQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
-        ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
-        TransportPriorityQosPolicy, UserDataQosPolicy, WriterDataLifecycleQosPolicy,
+        DurabilityQosPolicy, DurabilityServiceQosPolicy, EntityFactoryQosPolicy,
+        GroupDataQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind, LatencyBudgetQosPolicy,
+        LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy,
+        PartitionQosPolicy, PresentationQosPolicy, ReaderDataLifecycleQosPolicy,
+        ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+        TimeBasedFilterQosPolicy, TopicDataQosPolicy, TransportPriorityQosPolicy,
+        UserDataQosPolicy, WriterDataLifecycleQosPolicy,
     },
     time::DurationKind,
 };
@@ -41,6 +42,44 @@ pub struct DomainParticipantQos {
     pub entity_factory: EntityFactoryQosPolicy,
 }
 
+/// Builder for the [`DomainParticipantQos`]
+#[derive(Default)]
+pub struct DomainParticipantQosBuilder {
+    qos: DomainParticipantQos,
+}
+
+impl DomainParticipantQosBuilder {
+    /// Construct a builder starting from the default [`DomainParticipantQos`].
+    pub fn new() -> Self {
+        Self {
+            qos: DomainParticipantQos::default(),
+        }
+    }
+
+    /// Build the [`DomainParticipantQos`]
+    pub fn build(self) -> DomainParticipantQos {
+        self.qos
+    }
+
+    /// Set the user data QoS policy
+    pub fn user_data(mut self, user_data: UserDataQosPolicy) -> Self {
+        self.qos.user_data = user_data;
+        self
+    }
+
+    /// Set the entity factory QoS policy
+    pub fn entity_factory(mut self, entity_factory: EntityFactoryQosPolicy) -> Self {
+        self.qos.entity_factory = entity_factory;
+        self
+    }
+
+    /// Set whether the entities created by the participant are enabled automatically
+    pub fn autoenable(mut self, autoenable_created_entities: bool) -> Self {
+        self.qos.entity_factory.autoenable_created_entities = autoenable_created_entities;
+        self
+    }
+}
+
 /// QoS policies applicable to the [`Publisher`](crate::publication::publisher::Publisher)
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct PublisherQos {
@@ -76,6 +115,8 @@ impl Default for PublisherQos {
 pub struct DataWriterQos {
     /// Value of the durability QoS policy.
     pub durability: DurabilityQosPolicy,
+    /// Value of the durability service QoS policy.
+    pub durability_service: DurabilityServiceQosPolicy,
     /// Value of the deadline QoS policy.
     pub deadline: DeadlineQosPolicy,
     /// Value of the latency budget QoS policy.
@@ -117,6 +158,7 @@ impl DataWriterQos {
                 )),
             },
             durability: DurabilityQosPolicy::const_default(),
+            durability_service: DurabilityServiceQosPolicy::const_default(),
             deadline: DeadlineQosPolicy::const_default(),
             latency_budget: LatencyBudgetQosPolicy::const_default(),
             liveliness: LivelinessQosPolicy::const_default(),
@@ -140,6 +182,125 @@ impl Default for DataWriterQos {
     }
 }
 
+/// Builder for the [`DataWriterQos`]
+#[derive(Default)]
+pub struct DataWriterQosBuilder {
+    qos: DataWriterQos,
+}
+
+impl DataWriterQosBuilder {
+    /// Construct a builder starting from the default [`DataWriterQos`].
+    pub fn new() -> Self {
+        Self {
+            qos: DataWriterQos::default(),
+        }
+    }
+
+    /// Build the [`DataWriterQos`]
+    pub fn build(self) -> DataWriterQos {
+        self.qos
+    }
+
+    /// Set the durability QoS policy
+    pub fn durability(mut self, durability: DurabilityQosPolicy) -> Self {
+        self.qos.durability = durability;
+        self
+    }
+
+    /// Set the durability service QoS policy
+    pub fn durability_service(mut self, durability_service: DurabilityServiceQosPolicy) -> Self {
+        self.qos.durability_service = durability_service;
+        self
+    }
+
+    /// Set the deadline QoS policy
+    pub fn deadline(mut self, deadline: DeadlineQosPolicy) -> Self {
+        self.qos.deadline = deadline;
+        self
+    }
+
+    /// Set the latency budget QoS policy
+    pub fn latency_budget(mut self, latency_budget: LatencyBudgetQosPolicy) -> Self {
+        self.qos.latency_budget = latency_budget;
+        self
+    }
+
+    /// Set the liveliness QoS policy
+    pub fn liveliness(mut self, liveliness: LivelinessQosPolicy) -> Self {
+        self.qos.liveliness = liveliness;
+        self
+    }
+
+    /// Set the reliability QoS policy
+    pub fn reliability(mut self, reliability: ReliabilityQosPolicy) -> Self {
+        self.qos.reliability = reliability;
+        self
+    }
+
+    /// Set the destination order QoS policy
+    pub fn destination_order(mut self, destination_order: DestinationOrderQosPolicy) -> Self {
+        self.qos.destination_order = destination_order;
+        self
+    }
+
+    /// Set the history QoS policy
+    pub fn history(mut self, history: HistoryQosPolicy) -> Self {
+        self.qos.history = history;
+        self
+    }
+
+    /// Set the resource limits QoS policy
+    pub fn resource_limits(mut self, resource_limits: ResourceLimitsQosPolicy) -> Self {
+        self.qos.resource_limits = resource_limits;
+        self
+    }
+
+    /// Set the transport priority QoS policy
+    pub fn transport_priority(mut self, transport_priority: TransportPriorityQosPolicy) -> Self {
+        self.qos.transport_priority = transport_priority;
+        self
+    }
+
+    /// Set the lifespan QoS policy
+    pub fn lifespan(mut self, lifespan: LifespanQosPolicy) -> Self {
+        self.qos.lifespan = lifespan;
+        self
+    }
+
+    /// Set the user data QoS policy
+    pub fn user_data(mut self, user_data: UserDataQosPolicy) -> Self {
+        self.qos.user_data = user_data;
+        self
+    }
+
+    /// Set the ownership QoS policy
+    pub fn ownership(mut self, ownership: OwnershipQosPolicy) -> Self {
+        self.qos.ownership = ownership;
+        self
+    }
+
+    /// Set the ownership strength QoS policy
+    pub fn ownership_strength(mut self, ownership_strength: OwnershipStrengthQosPolicy) -> Self {
+        self.qos.ownership_strength = ownership_strength;
+        self
+    }
+
+    /// Set the writer data lifecycle QoS policy
+    pub fn writer_data_lifecycle(
+        mut self,
+        writer_data_lifecycle: WriterDataLifecycleQosPolicy,
+    ) -> Self {
+        self.qos.writer_data_lifecycle = writer_data_lifecycle;
+        self
+    }
+
+    /// Set the data representation QoS policy
+    pub fn representation(mut self, representation: DataRepresentationQosPolicy) -> Self {
+        self.qos.representation = representation;
+        self
+    }
+}
+
 impl DataWriterQos {
     pub(crate) fn is_consistent(&self) -> DdsResult<()> {
         // On the writer there can be no more than one value on the representation
@@ -153,6 +314,16 @@ impl DataWriterQos {
             return Err(DdsError::InconsistentPolicy);
         }
 
+        // The same consistency rules apply to the history and resource limits of the durability service
+        if self.durability_service.max_samples < self.durability_service.max_samples_per_instance {
+            return Err(DdsError::InconsistentPolicy);
+        }
+        if let HistoryQosPolicyKind::KeepLast(depth) = self.durability_service.history_kind {
+            if depth as usize > self.durability_service.max_samples_per_instance {
+                return Err(DdsError::InconsistentPolicy);
+            }
+        }
+
         // The setting of RESOURCE_LIMITS max_samples_per_instance must be consistent with the HISTORY depth. For these two
         // QoS to be consistent, they must verify that *depth <= max_samples_per_instance.*
         match self.history.kind {
@@ -169,6 +340,7 @@ impl DataWriterQos {
 
     pub(crate) fn check_immutability(&self, other: &Self) -> DdsResult<()> {
         if self.durability != other.durability
+            || self.durability_service != other.durability_service
             || self.liveliness != other.liveliness
             || self.reliability != other.reliability
             || self.destination_order != other.destination_order
@@ -283,6 +455,107 @@ impl Default for DataReaderQos {
     }
 }
 
+/// Builder for the [`DataReaderQos`]
+#[derive(Default)]
+pub struct DataReaderQosBuilder {
+    qos: DataReaderQos,
+}
+
+impl DataReaderQosBuilder {
+    /// Construct a builder starting from the default [`DataReaderQos`].
+    pub fn new() -> Self {
+        Self {
+            qos: DataReaderQos::default(),
+        }
+    }
+
+    /// Build the [`DataReaderQos`]
+    pub fn build(self) -> DataReaderQos {
+        self.qos
+    }
+
+    /// Set the durability QoS policy
+    pub fn durability(mut self, durability: DurabilityQosPolicy) -> Self {
+        self.qos.durability = durability;
+        self
+    }
+
+    /// Set the deadline QoS policy
+    pub fn deadline(mut self, deadline: DeadlineQosPolicy) -> Self {
+        self.qos.deadline = deadline;
+        self
+    }
+
+    /// Set the latency budget QoS policy
+    pub fn latency_budget(mut self, latency_budget: LatencyBudgetQosPolicy) -> Self {
+        self.qos.latency_budget = latency_budget;
+        self
+    }
+
+    /// Set the liveliness QoS policy
+    pub fn liveliness(mut self, liveliness: LivelinessQosPolicy) -> Self {
+        self.qos.liveliness = liveliness;
+        self
+    }
+
+    /// Set the reliability QoS policy
+    pub fn reliability(mut self, reliability: ReliabilityQosPolicy) -> Self {
+        self.qos.reliability = reliability;
+        self
+    }
+
+    /// Set the destination order QoS policy
+    pub fn destination_order(mut self, destination_order: DestinationOrderQosPolicy) -> Self {
+        self.qos.destination_order = destination_order;
+        self
+    }
+
+    /// Set the history QoS policy
+    pub fn history(mut self, history: HistoryQosPolicy) -> Self {
+        self.qos.history = history;
+        self
+    }
+
+    /// Set the resource limits QoS policy
+    pub fn resource_limits(mut self, resource_limits: ResourceLimitsQosPolicy) -> Self {
+        self.qos.resource_limits = resource_limits;
+        self
+    }
+
+    /// Set the user data QoS policy
+    pub fn user_data(mut self, user_data: UserDataQosPolicy) -> Self {
+        self.qos.user_data = user_data;
+        self
+    }
+
+    /// Set the ownership QoS policy
+    pub fn ownership(mut self, ownership: OwnershipQosPolicy) -> Self {
+        self.qos.ownership = ownership;
+        self
+    }
+
+    /// Set the time based filter QoS policy
+    pub fn time_based_filter(mut self, time_based_filter: TimeBasedFilterQosPolicy) -> Self {
+        self.qos.time_based_filter = time_based_filter;
+        self
+    }
+
+    /// Set the reader data lifecycle QoS policy
+    pub fn reader_data_lifecycle(
+        mut self,
+        reader_data_lifecycle: ReaderDataLifecycleQosPolicy,
+    ) -> Self {
+        self.qos.reader_data_lifecycle = reader_data_lifecycle;
+        self
+    }
+
+    /// Set the data representation QoS policy
+    pub fn representation(mut self, representation: DataRepresentationQosPolicy) -> Self {
+        self.qos.representation = representation;
+        self
+    }
+}
+
 impl DataReaderQos {
     pub(crate) fn is_consistent(&self) -> DdsResult<()> {
         // The setting of RESOURCE_LIMITS max_samples must be consistent with the max_samples_per_instance. For these two
@@ -419,6 +692,48 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn qos_builders_only_change_the_set_policies() {
+        assert_eq!(
+            DomainParticipantQosBuilder::default()
+                .autoenable(false)
+                .build(),
+            DomainParticipantQos {
+                entity_factory: EntityFactoryQosPolicy {
+                    autoenable_created_entities: false,
+                },
+                ..Default::default()
+            }
+        );
+
+        let history = HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(5),
+        };
+        assert_eq!(
+            DataWriterQosBuilder::new().history(history.clone()).build(),
+            DataWriterQos {
+                history: history.clone(),
+                ..Default::default()
+            }
+        );
+
+        let reliability = ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Infinite,
+        };
+        assert_eq!(
+            DataReaderQosBuilder::new()
+                .reliability(reliability.clone())
+                .history(history.clone())
+                .build(),
+            DataReaderQos {
+                reliability,
+                history,
+                ..Default::default()
+            }
+        );
+    }
+
     #[test]
     fn data_writer_qos_consistency() {
         assert_eq!(DataWriterQos::default().is_consistent(), Ok(()));
@@ -448,6 +763,18 @@ mod tests {
             .is_consistent(),
             Err(DdsError::InconsistentPolicy)
         );
+        assert_eq!(
+            DataWriterQos {
+                durability_service: DurabilityServiceQosPolicy {
+                    history_kind: HistoryQosPolicyKind::KeepLast(3),
+                    max_samples_per_instance: Length::Limited(2),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+            .is_consistent(),
+            Err(DdsError::InconsistentPolicy)
+        );
     }
 
     #[test]
@@ -155,6 +155,7 @@ const TOPICDATA_QOS_POLICY_NAME: &str = "TopicData";
 const TRANSPORTPRIORITY_QOS_POLICY_NAME: &str = "TransportPriority";
 const GROUPDATA_QOS_POLICY_NAME: &str = "GroupData";
 const LIFESPAN_QOS_POLICY_NAME: &str = "Lifespan";
+const DURABILITYSERVICE_QOS_POLICY_NAME: &str = "DurabilityService";
 const DATA_REPRESENTATION_QOS_POLICY_NAME: &str = "DataRepresentation";
 
 /// QosPolicy Id representing an invalid QoS policy
@@ -1348,6 +1349,156 @@ impl Default for ResourceLimitsQosPolicy {
     }
 }
 
+/// This policy is used to configure the [`HistoryQosPolicy`] and [`ResourceLimitsQosPolicy`] of the fictitious
+/// [`DataReader`](crate::subscription::data_reader::DataReader) and [`DataWriter`](crate::publication::data_writer::DataWriter)
+/// used by the durability service.
+///
+/// The durability service is the part of the Service that implements the [`DurabilityQosPolicyKind::Transient`] and
+/// [`DurabilityQosPolicyKind::Persistent`] kinds of durability. It behaves as a fictitious reader that reads the data
+/// written by the [`DataWriter`](crate::publication::data_writer::DataWriter) and a fictitious writer that sends it to
+/// late-joining [`DataReader`](crate::subscription::data_reader::DataReader) entities.
+/// The `service_cleanup_delay` controls when the service is able to remove all information regarding a data-instance after the
+/// instance has been disposed and all the writers that wrote it have been deleted.
+/// The setting of `history_kind` must be consistent with the `max_samples_per_instance`, that is
+/// *depth <= max_samples_per_instance*, and `max_samples` must verify that *max_samples >= max_samples_per_instance*.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesDeserialize)]
+pub struct DurabilityServiceQosPolicy {
+    /// Delay until the service can remove the information of a disposed instance.
+    pub service_cleanup_delay: DurationKind,
+    /// History kind and depth of the durability service.
+    pub history_kind: HistoryQosPolicyKind,
+    /// Maximum number of samples limit of the durability service.
+    pub max_samples: Length,
+    /// Maximum number of instances limit of the durability service.
+    pub max_instances: Length,
+    /// Maximum number of samples per instance limit of the durability service.
+    pub max_samples_per_instance: Length,
+}
+
+impl DurabilityServiceQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            service_cleanup_delay: DurationKind::Finite(Duration::new(
+                DURATION_ZERO_SEC,
+                DURATION_ZERO_NSEC,
+            )),
+            history_kind: HistoryQosPolicyKind::KeepLast(1),
+            max_samples: Length::Unlimited,
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Unlimited,
+        }
+    }
+}
+
+impl dust_dds::infrastructure::type_support::TypeSupport for DurabilityServiceQosPolicy {
+    fn get_type() -> dust_dds::xtypes::dynamic_type::DynamicType {
+        extern crate alloc;
+        let mut builder = dust_dds::xtypes::dynamic_type::DynamicTypeBuilderFactory::create_type(
+            dust_dds::xtypes::dynamic_type::TypeDescriptor {
+                kind: dust_dds::xtypes::dynamic_type::TypeKind::STRUCTURE,
+                name: alloc::string::String::from("DurabilityServiceQosPolicy"),
+                base_type: None,
+                discriminator_type: None,
+                bound: alloc::vec::Vec::new(),
+                element_type: None,
+                key_element_type: None,
+                extensibility_kind: dust_dds::xtypes::dynamic_type::ExtensibilityKind::Appendable,
+                is_nested: true,
+            },
+        );
+        let int32_type =
+            dust_dds::xtypes::dynamic_type::DynamicTypeBuilderFactory::get_primitive_type(
+                TypeKind::INT32,
+            );
+        let members = [
+            ("service_cleanup_delay", DurationKind::get_type()),
+            ("history_kind", int32_type.clone()),
+            ("history_depth", int32_type.clone()),
+            ("max_samples", int32_type.clone()),
+            ("max_instances", int32_type.clone()),
+            ("max_samples_per_instance", int32_type),
+        ];
+        for (index, (name, r#type)) in members.into_iter().enumerate() {
+            builder
+                .add_member(dust_dds::xtypes::dynamic_type::MemberDescriptor {
+                    name: alloc::string::String::from(name),
+                    id: index as u32,
+                    r#type,
+                    default_value: None,
+                    index: index as u32,
+                    try_construct_kind:
+                        dust_dds::xtypes::dynamic_type::TryConstructKind::UseDefault,
+                    label: alloc::vec::Vec::new(),
+                    is_key: false,
+                    is_optional: false,
+                    is_must_understand: true,
+                    is_shared: false,
+                    is_default_label: false,
+                })
+                .unwrap();
+        }
+        builder.build()
+    }
+
+    fn create_sample(src: crate::xtypes::dynamic_type::DynamicData) -> Self {
+        fn length(value: i32) -> Length {
+            match value {
+                0.. => Length::Limited(value as u32),
+                _ => Length::Unlimited,
+            }
+        }
+        let int32_value = |id| src.get_int32_value(id).copied().unwrap_or_default();
+        let service_cleanup_delay = src
+            .get_complex_value(0)
+            .cloned()
+            .map(DurationKind::create_sample)
+            .unwrap_or(Self::const_default().service_cleanup_delay);
+        let history_kind = match int32_value(1) {
+            1 => HistoryQosPolicyKind::KeepAll,
+            _ => HistoryQosPolicyKind::KeepLast(int32_value(2) as u32),
+        };
+        Self {
+            service_cleanup_delay,
+            history_kind,
+            max_samples: length(int32_value(3)),
+            max_instances: length(int32_value(4)),
+            max_samples_per_instance: length(int32_value(5)),
+        }
+    }
+
+    fn create_dynamic_sample(self) -> crate::xtypes::dynamic_type::DynamicData {
+        fn length_value(length: Length) -> i32 {
+            match length {
+                Length::Limited(length) => length as i32,
+                Length::Unlimited => LENGTH_UNLIMITED,
+            }
+        }
+        let (history_kind, history_depth) = match self.history_kind {
+            HistoryQosPolicyKind::KeepLast(depth) => (0, depth as i32),
+            HistoryQosPolicyKind::KeepAll => (1, 0),
+        };
+        DynamicDataFactory::create_data(Self::get_type())
+            .set_value(0, self.service_cleanup_delay)
+            .set_value(1, history_kind)
+            .set_value(2, history_depth)
+            .set_value(3, length_value(self.max_samples))
+            .set_value(4, length_value(self.max_instances))
+            .set_value(5, length_value(self.max_samples_per_instance))
+    }
+}
+
+impl QosPolicy for DurabilityServiceQosPolicy {
+    fn name(&self) -> &str {
+        DURABILITYSERVICE_QOS_POLICY_NAME
+    }
+}
+
+impl Default for DurabilityServiceQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
 /// This policy controls the behavior of the Entity as a factory for other entities.
 ///
 /// This policy concerns only DomainParticipant (as factory for Publisher, Subscriber, and Topic), Publisher (as factory for
@@ -1662,4 +1813,30 @@ mod tests {
         assert!(Length::Limited(10) == 10usize);
         assert!(10usize == Length::Limited(10));
     }
+
+    #[test]
+    fn durability_service_qos_policy_dynamic_sample_round_trip() {
+        let policy_list = [
+            DurabilityServiceQosPolicy {
+                service_cleanup_delay: DurationKind::Finite(Duration::new(3, 500)),
+                history_kind: HistoryQosPolicyKind::KeepLast(4),
+                max_samples: Length::Limited(40),
+                max_instances: Length::Unlimited,
+                max_samples_per_instance: Length::Limited(10),
+            },
+            DurabilityServiceQosPolicy {
+                service_cleanup_delay: DurationKind::Infinite,
+                history_kind: HistoryQosPolicyKind::KeepAll,
+                max_samples: Length::Unlimited,
+                max_instances: Length::Limited(2),
+                max_samples_per_instance: Length::Unlimited,
+            },
+        ];
+        for policy in policy_list {
+            assert_eq!(
+                DurabilityServiceQosPolicy::create_sample(policy.clone().create_dynamic_sample()),
+                policy
+            );
+        }
+    }
 }
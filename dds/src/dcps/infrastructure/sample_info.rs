@@ -2,24 +2,47 @@ use super::{
     error::{DdsError, DdsResult},
     type_support::DdsDeserialize,
 };
-use crate::infrastructure::{instance::InstanceHandle, time::Time};
+use crate::{
+    dcps::domain_participant::DeserializedData,
+    infrastructure::{instance::InstanceHandle, time::Time},
+};
 use alloc::sync::Arc;
 use core::marker::PhantomData;
 
 /// A [`Sample`] contains the data and [`SampleInfo`] read by the [`DataReader`].
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub struct Sample<Foo> {
     /// Data received by the [`DataReader`]. A sample might contain no valid data in which case this field is [`None`].
     data: Option<Arc<[u8]>>,
+    /// Value deserialized from the data and cached by the [`DataReader`].
+    deserialized_data: Option<DeserializedData>,
     /// Information of the sample received by the [`DataReader`].
     sample_info: SampleInfo,
     phantom: PhantomData<Foo>,
 }
 
+// The deserialized value is derived from the data so it is left out of the comparison
+impl<Foo> PartialEq for Sample<Foo> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.sample_info == other.sample_info
+    }
+}
+
+impl<Foo> Eq for Sample<Foo> {}
+
 impl<Foo> Sample<Foo> {
     pub(crate) fn new(data: Option<Arc<[u8]>>, sample_info: SampleInfo) -> Self {
+        Self::new_deserialized(data, None, sample_info)
+    }
+
+    pub(crate) fn new_deserialized(
+        data: Option<Arc<[u8]>>,
+        deserialized_data: Option<DeserializedData>,
+        sample_info: SampleInfo,
+    ) -> Self {
         Self {
             data,
+            deserialized_data,
             sample_info,
             phantom: PhantomData,
         }
@@ -39,6 +62,22 @@ where
     }
 }
 
+impl<Foo> Sample<Foo>
+where
+    Foo: for<'de> DdsDeserialize<'de> + Send + Sync + 'static,
+{
+    /// Get a shared reference to the Foo value associated with this sample. The value cached by
+    /// the [`DataReader`] is returned when available so that the data is not deserialized again.
+    pub fn shared_data(&self) -> DdsResult<Arc<Foo>> {
+        if let Some(deserialized_data) = &self.deserialized_data {
+            if let Ok(data) = deserialized_data.clone().downcast::<Foo>() {
+                return Ok(data);
+            }
+        }
+        Ok(Arc::new(self.data()?))
+    }
+}
+
 impl<Foo> Sample<Foo> {
     /// Get the sample info associated with this sample.
     pub fn sample_info(&self) -> SampleInfo {
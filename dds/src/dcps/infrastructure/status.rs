@@ -142,7 +142,7 @@ impl Default for SampleRejectedStatus {
 }
 
 /// Structure holding the values related to the Liveliness Lost communication status.
-#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LivelinessLostStatus {
     /// Total cumulative number of times that a previously-alive DataWriter
     /// became not alive due to a failure to actively signal its liveliness within
@@ -155,6 +155,21 @@ pub struct LivelinessLostStatus {
     pub total_count_change: i32,
 }
 
+impl LivelinessLostStatus {
+    pub const fn const_default() -> Self {
+        Self {
+            total_count: 0,
+            total_count_change: 0,
+        }
+    }
+}
+
+impl Default for LivelinessLostStatus {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
 /// Structure holding the values related to the Liveliness Changed communication status.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LivelinessChangedStatus {
@@ -415,3 +430,43 @@ impl Default for SubscriptionMatchedStatus {
         Self::const_default()
     }
 }
+
+/// Structure explaining the matching decision between a local entity and a discovered remote one.
+/// It is intended for troubleshooting why a [`DataWriter`](crate::publication::data_writer::DataWriter)
+/// and a [`DataReader`](crate::subscription::data_reader::DataReader) are or are not matched.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MatchDiagnostic {
+    /// The remote entity uses the same topic name as the local one.
+    pub is_topic_name_matched: bool,
+    /// The remote entity uses the same type name as the local one.
+    pub is_type_name_matched: bool,
+    /// The partition of the remote entity matches the one of the local publisher or subscriber.
+    pub is_partition_matched: bool,
+    /// The application has indicated that the remote entity or its participant should be ignored.
+    pub is_ignored: bool,
+    /// List of the QosPolicyId of the policies whose requested and offered values are incompatible.
+    pub incompatible_qos_policy_list: Vec<QosPolicyId>,
+}
+
+impl MatchDiagnostic {
+    /// Whether all the conditions for the local and remote entities to be matched are met.
+    pub fn is_matched(&self) -> bool {
+        self.is_topic_name_matched
+            && self.is_type_name_matched
+            && self.is_partition_matched
+            && !self.is_ignored
+            && self.incompatible_qos_policy_list.is_empty()
+    }
+}
+
+/// Counters of the data received by a [`DomainParticipant`](crate::domain::domain_participant::DomainParticipant)
+/// which had to be discarded. It is intended for monitoring the health of the communication.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ParticipantStatistics {
+    /// Number of received datagrams which are not valid RTPS messages or contain an invalid submessage.
+    pub malformed_packets: u64,
+    /// Number of received submessages whose kind is not known and which were skipped.
+    pub unknown_submessages: u64,
+    /// Number of received samples rejected by a reader because its resource limits were reached.
+    pub cache_full_drops: u64,
+}
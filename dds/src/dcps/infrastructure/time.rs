@@ -22,8 +22,13 @@ impl TypeSupport for DurationKind {
         Duration::get_type()
     }
 
-    fn create_sample(_src: crate::xtypes::dynamic_type::DynamicData) -> Self {
-        todo!()
+    fn create_sample(src: crate::xtypes::dynamic_type::DynamicData) -> Self {
+        let sec = src.get_int32_value(0).copied().unwrap_or_default();
+        let nanosec = src.get_uint32_value(1).copied().unwrap_or_default();
+        match (Duration { sec, nanosec }) {
+            DURATION_INFINITE => DurationKind::Infinite,
+            duration => DurationKind::Finite(duration),
+        }
     }
 
     fn create_dynamic_sample(self) -> crate::xtypes::dynamic_type::DynamicData {
@@ -62,6 +62,12 @@ impl DcpsDataReaderListener {
                     } => {
                         panic!("Not valid for reader")
                     }
+                    ListenerMail::LivelinessLost {
+                        the_writer: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for reader")
+                    }
                 }
             }
         });
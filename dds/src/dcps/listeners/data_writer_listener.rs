@@ -31,6 +31,11 @@ impl DcpsDataWriterListener {
                             .on_offered_deadline_missed(the_writer.change_foo_type(), status)
                             .await;
                     }
+                    ListenerMail::LivelinessLost { the_writer, status } => {
+                        listener
+                            .on_liveliness_lost(the_writer.change_foo_type(), status)
+                            .await;
+                    }
                     ListenerMail::DataAvailable { the_reader: _ } => {
                         panic!("Not valid for writer")
                     }
@@ -4,9 +4,9 @@ use crate::{
     },
     domain::domain_participant_listener::DomainParticipantListener,
     infrastructure::status::{
-        OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
-        RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus, SampleRejectedStatus,
-        SubscriptionMatchedStatus,
+        LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
+        PublicationMatchedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
+        SampleRejectedStatus, SubscriptionMatchedStatus,
     },
     runtime::{ChannelReceive, DdsRuntime, Spawner},
 };
@@ -54,6 +54,9 @@ impl DcpsDomainParticipantListener {
                             .on_offered_deadline_missed(the_writer, status)
                             .await;
                     }
+                    ListenerMail::LivelinessLost { the_writer, status } => {
+                        listener.on_liveliness_lost(the_writer, status).await;
+                    }
                     ListenerMail::DataOnReaders { the_subscriber: _ } => {
                         panic!("Not valid for domain participant")
                     }
@@ -99,4 +102,8 @@ pub enum ListenerMail<R: DdsRuntime> {
         the_writer: DataWriterAsync<R, ()>,
         status: OfferedDeadlineMissedStatus,
     },
+    LivelinessLost {
+        the_writer: DataWriterAsync<R, ()>,
+        status: LivelinessLostStatus,
+    },
 }
@@ -29,6 +29,9 @@ impl DcpsPublisherListener {
                             .on_offered_deadline_missed(the_writer, status)
                             .await;
                     }
+                    ListenerMail::LivelinessLost { the_writer, status } => {
+                        listener.on_liveliness_lost(the_writer, status).await;
+                    }
                     ListenerMail::DataOnReaders { the_subscriber: _ } => {
                         panic!("Not valid for publisher")
                     }
@@ -56,6 +56,12 @@ impl DcpsSubscriberListener {
                     } => {
                         panic!("Not valid for subscriber")
                     }
+                    ListenerMail::LivelinessLost {
+                        the_writer: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for subscriber")
+                    }
                 }
             }
         });
@@ -5,6 +5,7 @@ pub mod domain_participant_factory;
 pub mod domain_participant_factory_mail;
 pub mod domain_participant_mail;
 pub mod listeners;
+pub mod serialization_buffer;
 pub mod status_condition;
 pub mod status_condition_mail;
 pub mod xtypes_glue;
@@ -0,0 +1,80 @@
+use crate::infrastructure::time::{Duration, Time};
+use alloc::vec::Vec;
+
+/// Buffer kept by a data writer to serialize its samples. Reusing it avoids growing a new
+/// allocation for every sample written by publishers of large samples.
+pub struct SerializationBuffer {
+    buffer: Vec<u8>,
+    initial_capacity: usize,
+    shrink_after_idle: Duration,
+    last_use: Option<Time>,
+}
+
+impl SerializationBuffer {
+    pub const fn new(initial_capacity: usize, shrink_after_idle: Duration) -> Self {
+        Self {
+            buffer: Vec::new(),
+            initial_capacity,
+            shrink_after_idle,
+            last_use: None,
+        }
+    }
+
+    /// Get the empty buffer to serialize a sample at time `now`. The capacity grown by the
+    /// previous samples is kept unless the buffer has not been used for longer than the idle
+    /// period, in which case it goes back to the initial capacity.
+    pub fn get(&mut self, now: Time) -> &mut Vec<u8> {
+        self.buffer.clear();
+        if let Some(last_use) = self.last_use {
+            if now - last_use > self.shrink_after_idle {
+                self.buffer.shrink_to(self.initial_capacity);
+            }
+        }
+        self.buffer.reserve(self.initial_capacity);
+        self.last_use = Some(now);
+        &mut self.buffer
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_is_reused_across_writes() {
+        let mut serialization_buffer = SerializationBuffer::new(16, Duration::new(10, 0));
+
+        let buffer = serialization_buffer.get(Time::new(1, 0));
+        buffer.extend_from_slice(&[1; 4096]);
+        let grown_capacity = serialization_buffer.capacity();
+        let grown_address = serialization_buffer.buffer.as_ptr();
+        assert!(grown_capacity >= 4096);
+
+        for sec in 2..10 {
+            let buffer = serialization_buffer.get(Time::new(sec, 0));
+            assert!(buffer.is_empty());
+            buffer.extend_from_slice(&[2; 4096]);
+            assert_eq!(buffer.as_ptr(), grown_address);
+        }
+        assert_eq!(serialization_buffer.capacity(), grown_capacity);
+    }
+
+    #[test]
+    fn buffer_shrinks_after_idle_period() {
+        let mut serialization_buffer = SerializationBuffer::new(16, Duration::new(10, 0));
+
+        serialization_buffer
+            .get(Time::new(1, 0))
+            .extend_from_slice(&[1; 4096]);
+        serialization_buffer.get(Time::new(11, 0));
+        assert!(serialization_buffer.capacity() >= 4096);
+
+        serialization_buffer.get(Time::new(22, 0));
+        assert!(serialization_buffer.capacity() >= 16);
+        assert!(serialization_buffer.capacity() < 4096);
+    }
+}
@@ -1,13 +1,13 @@
-use core::marker::PhantomData;
-
-use crate::{infrastructure::status::StatusKind, runtime::DdsRuntime};
+use crate::{
+    infrastructure::status::StatusKind,
+    runtime::{DdsRuntime, OneshotSend},
+};
 use alloc::{vec, vec::Vec};
 
-#[derive(Debug)]
 pub struct DcpsStatusCondition<R: DdsRuntime> {
     enabled_statuses: Vec<StatusKind>,
     status_changes: Vec<StatusKind>,
-    phantom: PhantomData<R>,
+    status_waiters: Vec<(StatusKind, R::OneshotSender<()>)>,
 }
 
 impl<R: DdsRuntime> Default for DcpsStatusCondition<R> {
@@ -29,14 +29,38 @@ impl<R: DdsRuntime> Default for DcpsStatusCondition<R> {
                 StatusKind::SubscriptionMatched,
             ],
             status_changes: Vec::new(),
-            phantom: PhantomData,
+            status_waiters: Vec::new(),
         }
     }
 }
 
 impl<R: DdsRuntime> DcpsStatusCondition<R> {
     pub fn add_communication_state(&mut self, state: StatusKind) {
-        self.status_changes.push(state);
+        if !self.status_changes.contains(&state) {
+            self.status_changes.push(state);
+        }
+        let mut index = 0;
+        while index < self.status_waiters.len() {
+            if self.status_waiters[index].0 == state {
+                let (_, reply_sender) = self.status_waiters.remove(index);
+                reply_sender.send(());
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    // The reply is sent once the status is among the status changes
+    pub fn wait_for_communication_state(
+        &mut self,
+        state: StatusKind,
+        reply_sender: R::OneshotSender<()>,
+    ) {
+        if self.status_changes.contains(&state) {
+            reply_sender.send(());
+        } else {
+            self.status_waiters.push((state, reply_sender));
+        }
     }
 
     pub fn remove_communication_state(&mut self, state: StatusKind) {
@@ -51,6 +75,10 @@ impl<R: DdsRuntime> DcpsStatusCondition<R> {
         self.enabled_statuses = mask;
     }
 
+    pub fn get_status_changes(&self) -> Vec<StatusKind> {
+        self.status_changes.clone()
+    }
+
     pub fn get_trigger_value(&self) -> bool {
         for status in &self.status_changes {
             if self.enabled_statuses.contains(status) {
@@ -15,12 +15,19 @@ pub enum DcpsStatusConditionMail<R: DdsRuntime> {
     GetStatusConditionTriggerValue {
         reply_sender: R::OneshotSender<bool>,
     },
+    GetStatusChanges {
+        reply_sender: R::OneshotSender<Vec<StatusKind>>,
+    },
     AddCommunicationState {
         state: StatusKind,
     },
     RemoveCommunicationState {
         state: StatusKind,
     },
+    WaitForCommunicationState {
+        state: StatusKind,
+        reply_sender: R::OneshotSender<()>,
+    },
 }
 
 impl<R: DdsRuntime> MailHandler for DcpsStatusCondition<R> {
@@ -36,12 +43,19 @@ impl<R: DdsRuntime> MailHandler for DcpsStatusCondition<R> {
             DcpsStatusConditionMail::GetStatusConditionTriggerValue { reply_sender } => {
                 reply_sender.send(self.get_trigger_value())
             }
+            DcpsStatusConditionMail::GetStatusChanges { reply_sender } => {
+                reply_sender.send(self.get_status_changes())
+            }
             DcpsStatusConditionMail::AddCommunicationState { state } => {
                 self.add_communication_state(state)
             }
             DcpsStatusConditionMail::RemoveCommunicationState { state } => {
                 self.remove_communication_state(state)
             }
+            DcpsStatusConditionMail::WaitForCommunicationState {
+                state,
+                reply_sender,
+            } => self.wait_for_communication_state(state, reply_sender),
         }
     }
 }
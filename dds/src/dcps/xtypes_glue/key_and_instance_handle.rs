@@ -21,6 +21,14 @@ struct Md5 {
 }
 
 impl Md5 {
+    fn new() -> Self {
+        Self {
+            key: [0; 16],
+            context: md5::Context::new(),
+            length: 0,
+        }
+    }
+
     fn into_key(mut self) -> [u8; 16] {
         const ZEROS: [u8; 16] = [0; 16];
         if self.length < ZEROS.len() {
@@ -447,15 +455,20 @@ const D_CDR2_LE: RepresentationIdentifier = [0x00, 0x09];
 const PL_CDR_BE: RepresentationIdentifier = [0x00, 0x02];
 const PL_CDR_LE: RepresentationIdentifier = [0x00, 0x03];
 
+/// Compute the key hash of a key serialized as XCDR2 big-endian final struct. Keys of up to 16 bytes
+/// are zero padded and longer keys are hashed with MD5 (as of 9.6.4.8). This is the hash used for
+/// the instance handles computed from the samples, the serialized keys and the dynamic data.
+pub fn key_hash_from_serialized_key(serialized_key: &[u8]) -> [u8; 16] {
+    let mut md5_collection = Md5::new();
+    md5_collection.write(serialized_key);
+    md5_collection.into_key()
+}
+
 pub fn get_instance_handle_from_serialized_key(
     mut data: &[u8],
     dynamic_type: &DynamicType,
 ) -> Result<InstanceHandle, XTypesError> {
-    let mut md5_collection = Md5 {
-        key: [0; 16],
-        context: md5::Context::new(),
-        length: 0,
-    };
+    let mut md5_collection = Md5::new();
     {
         let representation_identifier = [data[0], data[1]];
         data = &data[4..];
@@ -484,11 +497,7 @@ pub fn get_instance_handle_from_serialized_foo(
     mut data: &[u8],
     dynamic_type: &DynamicType,
 ) -> Result<InstanceHandle, XTypesError> {
-    let mut md5_collection = Md5 {
-        key: [0; 16],
-        context: md5::Context::new(),
-        length: 0,
-    };
+    let mut md5_collection = Md5::new();
     {
         let representation_identifier = [data[0], data[1]];
         data = &data[4..];
@@ -514,11 +523,7 @@ pub fn get_instance_handle_from_serialized_foo(
 pub fn get_instance_handle_from_dynamic_data(
     mut dynamic_data: DynamicData,
 ) -> Result<InstanceHandle, XTypesError> {
-    let mut md5_collection = Md5 {
-        key: [0; 16],
-        context: md5::Context::new(),
-        length: 0,
-    };
+    let mut md5_collection = Md5::new();
     dynamic_data.clear_nonkey_values()?;
     dynamic_data.make_descriptor_extensibility_kind_final();
 
@@ -1057,3 +1062,47 @@ pub fn get_serialized_key_from_serialized_foo(
 //         )
 //     }
 // }
+
+#[cfg(test)]
+mod key_tests {
+    use super::*;
+    use crate::infrastructure::type_support::TypeSupport;
+
+    #[derive(TypeSupport)]
+    struct TwoKeyFieldStruct {
+        #[dust_dds(key)]
+        key_field1: u8,
+        field_inbetween: u32,
+        #[dust_dds(key)]
+        key_field2: u16,
+    }
+
+    #[test]
+    fn two_field_key_round_trip() {
+        let sample = TwoKeyFieldStruct {
+            key_field1: 1,
+            field_inbetween: 7,
+            key_field2: 2,
+        };
+        let expected_instance_handle =
+            InstanceHandle::new([1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let serialized_key =
+            get_serialized_key_from_serialized_foo(sample.create_dynamic_sample()).unwrap();
+        assert_eq!(
+            serialized_key,
+            vec![
+                0, 1, 0, 0, // RTPS header
+                1, 0, 2, 0, // key_field1 (u8) | padding (1 byte) | key_field2 (u16)
+            ]
+        );
+        assert_eq!(
+            get_instance_handle_from_serialized_key(
+                &serialized_key,
+                &TwoKeyFieldStruct::get_type()
+            )
+            .unwrap(),
+            expected_instance_handle
+        );
+    }
+}
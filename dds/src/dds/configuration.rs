@@ -1,4 +1,8 @@
-use crate::infrastructure::error::DdsResult;
+use crate::{
+    dcps::data_representation_builtin_endpoints::spdp_discovered_participant_data::BuiltinEndpointSet,
+    infrastructure::error::{DdsError, DdsResult},
+    transport::types::GuidPrefix,
+};
 use alloc::string::{String, ToString};
 use core::time::Duration;
 
@@ -8,6 +12,12 @@ use core::time::Duration;
 pub struct DustDdsConfiguration {
     domain_tag: String,
     participant_announcement_interval: Duration,
+    participant_lease_duration: Duration,
+    serialization_buffer_capacity: usize,
+    serialization_buffer_shrink_after_idle: Duration,
+    max_matched_readers_per_writer: Option<usize>,
+    guid_prefix: Option<GuidPrefix>,
+    builtin_endpoints: BuiltinEndpointSet,
 }
 
 impl DustDdsConfiguration {
@@ -20,6 +30,38 @@ impl DustDdsConfiguration {
     pub fn participant_announcement_interval(&self) -> Duration {
         self.participant_announcement_interval
     }
+
+    /// Lease duration announced by the participants. Remote participants which receive no announcement
+    /// within this time remove the participant and its endpoints.
+    pub fn participant_lease_duration(&self) -> Duration {
+        self.participant_lease_duration
+    }
+
+    /// Initial capacity in bytes of the buffer each data writer reuses to serialize its samples.
+    pub fn serialization_buffer_capacity(&self) -> usize {
+        self.serialization_buffer_capacity
+    }
+
+    /// Time without writes after which a data writer releases the serialization buffer capacity
+    /// grown beyond the initial one.
+    pub fn serialization_buffer_shrink_after_idle(&self) -> Duration {
+        self.serialization_buffer_shrink_after_idle
+    }
+
+    /// Maximum number of remote readers each data writer is matched with, or [`None`] if unlimited.
+    pub fn max_matched_readers_per_writer(&self) -> Option<usize> {
+        self.max_matched_readers_per_writer
+    }
+
+    /// Explicit GUID prefix of the participants, or [`None`] if it is generated by the factory.
+    pub fn guid_prefix(&self) -> Option<GuidPrefix> {
+        self.guid_prefix
+    }
+
+    /// Set of SEDP built-in endpoints created by the participants. The SPDP endpoints are always created.
+    pub fn builtin_endpoints(&self) -> BuiltinEndpointSet {
+        self.builtin_endpoints
+    }
 }
 
 impl Default for DustDdsConfiguration {
@@ -27,6 +69,12 @@ impl Default for DustDdsConfiguration {
         Self {
             domain_tag: "".to_string(),
             participant_announcement_interval: Duration::from_secs(5),
+            participant_lease_duration: Duration::from_secs(100),
+            serialization_buffer_capacity: 1024,
+            serialization_buffer_shrink_after_idle: Duration::from_secs(10),
+            max_matched_readers_per_writer: None,
+            guid_prefix: None,
+            builtin_endpoints: BuiltinEndpointSet::default(),
         }
     }
 }
@@ -45,8 +93,14 @@ impl DustDdsConfigurationBuilder {
         }
     }
 
-    /// Build a new configuration
+    /// Build a new configuration. Returns [`DdsError::BadParameter`] if the participant lease duration
+    /// is not larger than the participant announcement interval.
     pub fn build(self) -> DdsResult<DustDdsConfiguration> {
+        if self.configuration.participant_lease_duration
+            <= self.configuration.participant_announcement_interval
+        {
+            return Err(DdsError::BadParameter);
+        }
         Ok(self.configuration)
     }
 
@@ -57,7 +111,8 @@ impl DustDdsConfigurationBuilder {
     }
 
     /// Set the maximum interval at which the participant is announced on the network. This corresponds to the time
-    /// between SPDP messages.
+    /// between SPDP messages. The heartbeat period of the reliable writers is set on the transport instead, e.g. with
+    /// `RtpsUdpTransportParticipantFactoryBuilder::heartbeat_period`.
     pub fn participant_announcement_interval(
         mut self,
         participant_announcement_interval: Duration,
@@ -65,4 +120,51 @@ impl DustDdsConfigurationBuilder {
         self.configuration.participant_announcement_interval = participant_announcement_interval;
         self
     }
+
+    /// Set the lease duration announced by the participants. This should be larger than the participant
+    /// announcement interval, otherwise remote participants remove the participant between two announcements.
+    pub fn participant_lease_duration(mut self, participant_lease_duration: Duration) -> Self {
+        self.configuration.participant_lease_duration = participant_lease_duration;
+        self
+    }
+
+    /// Set the initial capacity in bytes of the buffer each data writer reuses to serialize its samples.
+    /// Setting it close to the usual serialized sample size avoids growing the buffer on the first writes.
+    pub fn serialization_buffer_capacity(mut self, serialization_buffer_capacity: usize) -> Self {
+        self.configuration.serialization_buffer_capacity = serialization_buffer_capacity;
+        self
+    }
+
+    /// Set the time without writes after which a data writer shrinks its serialization buffer back to
+    /// the initial capacity. The buffer keeps its grown capacity as long as samples keep being written.
+    pub fn serialization_buffer_shrink_after_idle(
+        mut self,
+        serialization_buffer_shrink_after_idle: Duration,
+    ) -> Self {
+        self.configuration.serialization_buffer_shrink_after_idle =
+            serialization_buffer_shrink_after_idle;
+        self
+    }
+
+    /// Set the maximum number of readers each data writer is matched with. Compatible readers discovered
+    /// once a writer reaches the limit are not matched until one of its matched readers goes away.
+    pub fn max_matched_readers_per_writer(mut self, max_matched_readers_per_writer: usize) -> Self {
+        self.configuration.max_matched_readers_per_writer = Some(max_matched_readers_per_writer);
+        self
+    }
+
+    /// Set the GUID prefix of the participants instead of generating it from the host, the application and
+    /// a participant counter. Since the prefix must be unique, the factory creates only one participant at a time with it.
+    pub fn guid_prefix(mut self, guid_prefix: GuidPrefix) -> Self {
+        self.configuration.guid_prefix = Some(guid_prefix);
+        self
+    }
+
+    /// Set the SEDP built-in endpoints created by the participants and advertised in their announcement.
+    /// A participant which only writes data can leave out the publications detector and the subscriptions
+    /// announcer, and the topics endpoints, to reduce the discovery traffic. The SPDP endpoints are always created.
+    pub fn builtin_endpoints(mut self, builtin_endpoints: BuiltinEndpointSet) -> Self {
+        self.configuration.builtin_endpoints = builtin_endpoints;
+        self
+    }
 }
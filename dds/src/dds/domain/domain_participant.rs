@@ -7,7 +7,7 @@ use crate::{
         error::DdsResult,
         instance::InstanceHandle,
         qos::{DomainParticipantQos, PublisherQos, QosKind, SubscriberQos, TopicQos},
-        status::StatusKind,
+        status::{ParticipantStatistics, StatusKind},
         time::{Duration, Time},
         type_support::TypeSupport,
     },
@@ -273,6 +273,16 @@ impl<R: DdsRuntime> DomainParticipant<R> {
         )
     }
 
+    /// This operation retrieves the description of a type registered with the [`DomainParticipant`] under the given `type_name`.
+    /// A type is registered when a [`Topic`] of that type is created on the [`DomainParticipant`] and it remains registered while
+    /// such a [`Topic`] exists. The returned [`DynamicType`] describes the members of the type, including which of them form the key,
+    /// which allows generic tools to handle the data of topics whose type is not known at compile time.
+    /// If no type is registered with the given name, the operation succeeds and a [`None`] value is returned.
+    #[tracing::instrument(skip(self))]
+    pub fn lookup_registered_type(&self, type_name: &str) -> DdsResult<Option<DynamicType>> {
+        R::block_on(self.participant_async.lookup_registered_type(type_name))
+    }
+
     /// This operation allows access to the built-in [`Subscriber`]. Each [`DomainParticipant`] contains several built-in [`Topic`] objects as
     /// well as corresponding [`DataReader`](crate::subscription::data_reader::DataReader) objects to access them. All these [`DataReader`](crate::subscription::data_reader::DataReader) objects belong to a single built-in [`Subscriber`].
     /// The built-in topics are used to communicate information about other [`DomainParticipant`], [`Topic`], [`DataReader`](crate::subscription::data_reader::DataReader), and [`DataWriter`](crate::publication::data_writer::DataWriter)
@@ -490,6 +500,22 @@ impl<R: DdsRuntime> DomainParticipant<R> {
     pub fn get_current_time(&self) -> DdsResult<Time> {
         R::block_on(self.participant_async.get_current_time())
     }
+
+    /// This operation returns the counters of the received data which had to be discarded by this participant:
+    /// malformed datagrams, unknown submessages and samples rejected because the resource limits of a reader were reached.
+    #[tracing::instrument(skip(self))]
+    pub fn get_statistics(&self) -> DdsResult<ParticipantStatistics> {
+        R::block_on(self.participant_async.get_statistics())
+    }
+
+    /// This operation sends the participant announcement (SPDP) right away instead of waiting for the next
+    /// periodic announcement. It can be used to speed up the discovery, for example after new peers have
+    /// become reachable. The announcement has been handed to the transport when this operation returns.
+    /// If the [`DomainParticipant`] is not enabled this operation returns [`DdsError::NotEnabled`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn announce(&self) -> DdsResult<()> {
+        R::block_on(self.participant_async.announce())
+    }
 }
 
 /// This implementation block contains the Entity operations for the [`DomainParticipant`].
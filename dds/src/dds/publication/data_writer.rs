@@ -7,13 +7,16 @@ use crate::{
         instance::InstanceHandle,
         qos::{DataWriterQos, QosKind},
         status::{
-            LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
-            PublicationMatchedStatus, StatusKind,
+            LivelinessLostStatus, MatchDiagnostic, OfferedDeadlineMissedStatus,
+            OfferedIncompatibleQosStatus, PublicationMatchedStatus, StatusKind,
         },
         time::{Duration, Time},
         type_support::TypeSupport,
     },
-    publication::{data_writer_listener::DataWriterListener, publisher::Publisher},
+    publication::{
+        data_writer_listener::{DataWriterListener, DataWriterListenerHandle},
+        publisher::Publisher,
+    },
     runtime::DdsRuntime,
     topic_definition::topic_description::TopicDescription,
 };
@@ -66,7 +69,7 @@ where
     /// The explicit use of this operation is optional as the application may call directly [`DataWriter::write`]
     /// and specify no [`InstanceHandle`] to indicate that the *key* should be examined to identify the instance.
     #[tracing::instrument(skip(self, instance))]
-    pub fn register_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
+    pub fn register_instance(&self, instance: Foo) -> DdsResult<Option<InstanceHandle>> {
         R::block_on(self.writer_async.register_instance(instance))
     }
 
@@ -77,7 +80,7 @@ where
     #[tracing::instrument(skip(self, instance))]
     pub fn register_instance_w_timestamp(
         &self,
-        instance: &Foo,
+        instance: Foo,
         timestamp: Time,
     ) -> DdsResult<Option<InstanceHandle>> {
         R::block_on(
@@ -328,6 +331,22 @@ impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
         )
     }
 
+    /// This operation explains why the [`DataWriter`] is or is not matched with a discovered subscription. The returned
+    /// [`MatchDiagnostic`] tells whether the topic name, type name and partition match, whether the subscription has been
+    /// ignored and which QoS policies are incompatible. Contrary to [`DataWriter::get_matched_subscription_data`], the
+    /// subscription does not need to be matched with the [`DataWriter`], but it must have been discovered by the participant,
+    /// otherwise the operation will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn get_subscription_match_diagnostic(
+        &self,
+        subscription_handle: InstanceHandle,
+    ) -> DdsResult<MatchDiagnostic> {
+        R::block_on(
+            self.writer_async
+                .get_subscription_match_diagnostic(subscription_handle),
+        )
+    }
+
     /// This operation retrieves the list of subscriptions currently *associated* with the [`DataWriter`]]; that is, subscriptions that have a
     /// matching [`Topic`] and compatible QoS that the application has not indicated should be *ignored* by means of the
     ///  [`DomainParticipant::ignore_subscription`](crate::domain::domain_participant::DomainParticipant) operation.
@@ -431,4 +450,18 @@ impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
     ) -> DdsResult<()> {
         R::block_on(self.writer_async.set_listener(a_listener, mask))
     }
+
+    /// This operation returns a handle to the Listener currently installed on the Entity, or [`None`] if no listener is installed.
+    /// The listener passed to [`Self::set_listener()`] is owned by the task that invokes its callbacks for as long as it is installed,
+    /// so a [`DataWriterListenerHandle`] to that task is returned instead of the listener itself.
+    #[tracing::instrument(skip(self))]
+    pub fn get_listener(&self) -> DdsResult<Option<DataWriterListenerHandle<R>>> {
+        R::block_on(self.writer_async.get_listener())
+    }
+
+    /// This operation returns the status mask with which the Listener currently installed on the Entity was set, or [`None`] if no listener is installed.
+    #[tracing::instrument(skip(self))]
+    pub fn get_listener_mask(&self) -> DdsResult<Option<Vec<StatusKind>>> {
+        R::block_on(self.writer_async.get_listener_mask())
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    dcps::listeners::domain_participant_listener::ListenerMail,
     runtime::DdsRuntime,
     dds_async::data_writer::DataWriterAsync,
     infrastructure::status::{
@@ -8,6 +9,28 @@ use crate::{
 };
 use core::future::Future;
 
+/// Handle to the listener installed on a [`DataWriter`](crate::publication::data_writer::DataWriter).
+/// The listener passed to `set_listener` is owned by the task that invokes its callbacks, so the handle
+/// refers to that task instead of the listener object. The task is kept alive for as long as a handle to it exists,
+/// even after the listener was replaced on the writer.
+pub struct DataWriterListenerHandle<R: DdsRuntime> {
+    listener_sender: R::ChannelSender<ListenerMail<R>>,
+}
+
+impl<R: DdsRuntime> DataWriterListenerHandle<R> {
+    pub(crate) fn new(listener_sender: R::ChannelSender<ListenerMail<R>>) -> Self {
+        Self { listener_sender }
+    }
+}
+
+impl<R: DdsRuntime> Clone for DataWriterListenerHandle<R> {
+    fn clone(&self) -> Self {
+        Self {
+            listener_sender: self.listener_sender.clone(),
+        }
+    }
+}
+
 /// This trait represents a listener object which can be associated with the [`DataWriter`] entity.
 pub trait DataWriterListener<R: DdsRuntime, Foo>: 'static {
     /// Method that is called when this writer reports a liveliness lost status.
@@ -12,6 +12,7 @@ use crate::{
     publication::data_writer::DataWriter,
     runtime::DdsRuntime,
     topic_definition::topic_description::TopicDescription,
+    transport::types::EntityId,
 };
 use alloc::vec::Vec;
 
@@ -71,6 +72,37 @@ impl<R: DdsRuntime> Publisher<R> {
         .map(DataWriter::from)
     }
 
+    /// This operation creates a [`DataWriter`] in the same way as [`Publisher::create_datawriter`] but uses the given
+    /// `entity_id` for the RTPS GUID of the writer instead of allocating one. This allows applications that need
+    /// deterministic GUIDs, such as interoperability tests or bridges, to choose them explicitly.
+    /// The kind of the `entity_id` must match the keyedness of the [`Topic`], i.e. it must be
+    /// [`USER_DEFINED_WRITER_WITH_KEY`](crate::transport::types::USER_DEFINED_WRITER_WITH_KEY) for keyed types and
+    /// [`USER_DEFINED_WRITER_NO_KEY`](crate::transport::types::USER_DEFINED_WRITER_NO_KEY) otherwise, or the operation returns
+    /// [`DdsError::BadParameter`](crate::infrastructure::error::DdsError). If another writer or reader of the
+    /// [`DomainParticipant`] already uses the `entity_id` the operation returns
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, a_topic, a_listener))]
+    pub fn create_datawriter_with_entity_id<Foo>(
+        &self,
+        a_topic: &TopicDescription<R>,
+        entity_id: EntityId,
+        qos: QosKind<DataWriterQos>,
+        a_listener: Option<impl DataWriterListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+    ) -> DdsResult<DataWriter<R, Foo>> {
+        R::block_on(
+            self.publisher_async
+                .create_datawriter_with_entity_id::<Foo>(
+                    &a_topic.clone().into(),
+                    entity_id,
+                    qos,
+                    a_listener,
+                    mask,
+                ),
+        )
+        .map(DataWriter::from)
+    }
+
     /// This operation deletes a [`DataWriter`] that belongs to the [`Publisher`]. This operation must be called on the
     /// same [`Publisher`] object used to create the [`DataWriter`]. If [`Publisher::delete_datawriter`] is called on a
     /// different [`Publisher`], the operation will have no effect and it will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
@@ -137,6 +169,8 @@ impl<R: DdsRuntime> Publisher<R> {
     /// the values are inter-related (for example, if there are two data-instances representing the 'altitude' and 'velocity vector' of the
     /// same aircraft and both are changed, it may be useful to communicate those values in a way the reader can see both together;
     /// otherwise, it may e.g., erroneously interpret that the aircraft is on a collision course).
+    /// The modifications are only grouped if the [`PresentationQosPolicy`](crate::infrastructure::qos_policy::PresentationQosPolicy)
+    /// of the [`Publisher`] has `coherent_access` enabled, otherwise they are sent as they are written.
     #[tracing::instrument(skip(self))]
     pub fn begin_coherent_changes(&self) -> DdsResult<()> {
         R::block_on(self.publisher_async.begin_coherent_changes())
@@ -7,18 +7,20 @@ use crate::{
         error::DdsResult,
         instance::InstanceHandle,
         qos::{DataReaderQos, QosKind},
-        sample_info::{InstanceStateKind, Sample, SampleStateKind, ViewStateKind},
+        sample_info::{InstanceStateKind, Sample, SampleInfo, SampleStateKind, ViewStateKind},
         status::{
-            LivelinessChangedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
-            SampleLostStatus, SampleRejectedStatus, StatusKind, SubscriptionMatchedStatus,
+            LivelinessChangedStatus, MatchDiagnostic, RequestedDeadlineMissedStatus,
+            RequestedIncompatibleQosStatus, SampleLostStatus, SampleRejectedStatus, StatusKind,
+            SubscriptionMatchedStatus,
         },
         time::Duration,
+        type_support::DdsDeserialize,
     },
     runtime::DdsRuntime,
     subscription::data_reader_listener::DataReaderListener,
     topic_definition::topic_description::TopicDescription,
 };
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 
 /// A [`DataReader`] allows the application (1) to declare the data it wishes to receive (i.e., make a subscription) and (2) to access the
 /// data received by the attached [`Subscriber`].
@@ -139,6 +141,14 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         R::block_on(self.reader_async.read_next_sample())
     }
 
+    /// This operation reads the next, non-previously accessed [`Sample`] value from the [`DataReader`] like [`DataReader::read_next_sample`]
+    /// but, if no such sample is available, it blocks the calling thread until one is received or the `timeout` elapses.
+    /// If the `timeout` elapses before a sample is received the operation returns [`DdsError::Timeout`](crate::infrastructure::error::DdsError::Timeout).
+    #[tracing::instrument(skip(self))]
+    pub fn read_next_sample_with_timeout(&self, timeout: Duration) -> DdsResult<Sample<Foo>> {
+        R::block_on(self.reader_async.read_next_sample_with_timeout(timeout))
+    }
+
     /// This operation takes the next, non-previously accessed [`Sample`] value from the [`DataReader`].
     /// The implied order among the samples stored in the [`DataReader`] is the same as for the [`DataReader::read`]
     /// operation. This operation is semantically equivalent to the read operation where the input Data sequence has
@@ -151,6 +161,16 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         R::block_on(self.reader_async.take_next_sample())
     }
 
+    /// This operation returns the [`SampleInfo`] of the sample that would be returned by [`DataReader::read_next_sample`]
+    /// without accessing it. The data is not deserialized and the sample and view states are left unchanged, so a
+    /// following [`DataReader::read_next_sample`] or [`DataReader::take_next_sample`] still returns this same sample.
+    /// This allows dispatching a sample based on its instance or publication handle before deciding to access it.
+    /// If there is no unread sample the operation returns [`DdsError::NoData`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn peek_next_info(&self) -> DdsResult<SampleInfo> {
+        R::block_on(self.reader_async.peek_next_info())
+    }
+
     /// This operation accesses a collection of [`Sample`] from the [`DataReader`]. The
     /// behavior is identical to [`DataReader::read`] except that all samples returned
     /// belong to the single specified instance whose handle is `a_handle`.
@@ -265,15 +285,6 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         ))
     }
 
-    /// This operation can be used to retrieve the instance key that corresponds to an `handle`.
-    /// The operation will only fill the fields that form the key inside the `key_holder` instance.
-    /// This operation may return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError)
-    /// if the [`InstanceHandle`] `handle` does not correspond to an existing data object known to the [`DataReader`].
-    #[tracing::instrument(skip(self, key_holder))]
-    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
-        R::block_on(self.reader_async.get_key_value(key_holder, handle))
-    }
-
     /// This operation takes as a parameter an instance and returns an [`InstanceHandle`] handle
     /// that can be used in subsequent operations that accept an instance handle as an argument.
     /// The instance parameter is only used for the purpose of examining the fields that define the
@@ -286,6 +297,64 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
     }
 }
 
+impl<R: DdsRuntime, Foo> DataReader<R, Foo>
+where
+    Foo: for<'de> DdsDeserialize<'de> + Send + Sync + 'static,
+{
+    /// This operation accesses a collection of deserialized samples from the [`DataReader`]. It selects
+    /// the samples and updates their state in the same way as [`DataReader::read`], but it returns the
+    /// data already deserialized.
+    /// The deserialized value is kept by the [`DataReader`] together with the received sample, so reading
+    /// the same sample again does not deserialize it a second time. The stored value is discarded
+    /// when the sample is taken or removed from the [`DataReader`], hence the number of stored values
+    /// is bounded by the [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy).
+    #[tracing::instrument(skip(self))]
+    pub fn read_deserialized(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<(Option<Arc<Foo>>, SampleInfo)>> {
+        R::block_on(self.reader_async.read_deserialized(
+            max_samples,
+            sample_states,
+            view_states,
+            instance_states,
+        ))
+    }
+
+    /// This operation accesses the samples of the [`DataReader`] whose data satisfies the `filter`
+    /// predicate. Each candidate sample is deserialized and passed to `filter`, and only the samples
+    /// for which it returns `true` are returned, up to `max_samples`. The returned samples are marked
+    /// as read in the same way as [`DataReader::read`], while the samples rejected by the filter keep
+    /// their state. Samples without valid data, such as disposed or unregistered notifications, are
+    /// always returned since they have no data to filter on.
+    #[tracing::instrument(skip(self, filter))]
+    pub fn read_with_filter(
+        &self,
+        max_samples: i32,
+        filter: impl Fn(&Foo) -> bool + Send + 'static,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        R::block_on(self.reader_async.read_with_filter(max_samples, filter))
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReader<R, Foo>
+where
+    Foo: for<'de> DdsDeserialize<'de>,
+{
+    /// This operation can be used to retrieve the instance key that corresponds to an `handle`.
+    /// The `key_holder` is filled with the most recent sample with data received for the instance, so the fields
+    /// that form the key are those of the instance. The key remains available after the instance is disposed.
+    /// This operation may return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError)
+    /// if the [`InstanceHandle`] `handle` does not correspond to an existing data object known to the [`DataReader`].
+    #[tracing::instrument(skip(self, key_holder))]
+    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
+        R::block_on(self.reader_async.get_key_value(key_holder, handle))
+    }
+}
+
 impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
     /// This operation allows access to the [`LivelinessChangedStatus`].
     #[tracing::instrument(skip(self))]
@@ -373,6 +442,39 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         )
     }
 
+    /// This operation explains why the [`DataReader`] is or is not matched with a discovered publication. The returned
+    /// [`MatchDiagnostic`] tells whether the topic name, type name and partition match, whether the publication has been
+    /// ignored and which QoS policies are incompatible. Contrary to [`DataReader::get_matched_publication_data`], the
+    /// publication does not need to be matched with the [`DataReader`], but it must have been discovered by the participant,
+    /// otherwise the operation will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn get_publication_match_diagnostic(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<MatchDiagnostic> {
+        R::block_on(
+            self.reader_async
+                .get_publication_match_diagnostic(publication_handle),
+        )
+    }
+
+    /// This operation retrieves the sequence numbers of the samples received from the publication identified by
+    /// `publication_handle` that are still held by the [`DataReader`], in increasing order, together with the current
+    /// [`InstanceStateKind`] of the instance each sample belongs to. Samples that have been taken or removed from the
+    /// history are not reported, which makes gaps in the sequence numbers useful to inspect reliability issues.
+    /// The `publication_handle` must correspond to a publication currently associated with the [`DataReader`] otherwise the operation
+    /// will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn get_received_sequence_numbers(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<Vec<(i64, InstanceStateKind)>> {
+        R::block_on(
+            self.reader_async
+                .get_received_sequence_numbers(publication_handle),
+        )
+    }
+
     /// This operation retrieves the list of publications currently *associated* with the [`DataReader`]; that is, publications that have a
     /// matching [`Topic`] and compatible qos that the application has not indicated should be ignored by means of the
     /// [`DomainParticipant::ignore_publication`](crate::domain::domain_participant::DomainParticipant) operation.
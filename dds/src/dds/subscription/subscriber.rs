@@ -14,6 +14,7 @@ use crate::{
     },
     runtime::DdsRuntime,
     topic_definition::topic_description::TopicDescription,
+    transport::types::EntityId,
 };
 use alloc::vec::Vec;
 
@@ -74,6 +75,37 @@ impl<R: DdsRuntime> Subscriber<R> {
         .map(DataReader::from)
     }
 
+    /// This operation creates a [`DataReader`] in the same way as [`Subscriber::create_datareader`] but uses the given
+    /// `entity_id` for the RTPS GUID of the reader instead of allocating one. This allows applications that need
+    /// deterministic GUIDs, such as interoperability tests or bridges, to choose them explicitly.
+    /// The kind of the `entity_id` must match the keyedness of the [`Topic`], i.e. it must be
+    /// [`USER_DEFINED_READER_WITH_KEY`](crate::transport::types::USER_DEFINED_READER_WITH_KEY) for keyed types and
+    /// [`USER_DEFINED_READER_NO_KEY`](crate::transport::types::USER_DEFINED_READER_NO_KEY) otherwise, or the operation returns
+    /// [`DdsError::BadParameter`](crate::infrastructure::error::DdsError). If another writer or reader of the
+    /// [`DomainParticipant`] already uses the `entity_id` the operation returns
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, a_topic, a_listener))]
+    pub fn create_datareader_with_entity_id<Foo>(
+        &self,
+        a_topic: &TopicDescription<R>,
+        entity_id: EntityId,
+        qos: QosKind<DataReaderQos>,
+        a_listener: Option<impl DataReaderListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+    ) -> DdsResult<DataReader<R, Foo>> {
+        R::block_on(
+            self.subscriber_async
+                .create_datareader_with_entity_id::<Foo>(
+                    &a_topic.clone().into(),
+                    entity_id,
+                    qos,
+                    a_listener,
+                    mask,
+                ),
+        )
+        .map(DataReader::from)
+    }
+
     /// This operation deletes a [`DataReader`] that belongs to the [`Subscriber`]. This operation must be called on the
     /// same [`Subscriber`] object used to create the [`DataReader`]. If [`Subscriber::delete_datareader`] is called on a
     /// different [`Subscriber`], the operation will have no effect and it will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
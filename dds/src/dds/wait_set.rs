@@ -80,9 +80,13 @@ impl<R: DdsRuntime> WaitSet<R> {
 
     /// Detaches a [`Condition`] from the [`WaitSet`].
     /// If the [`Condition`] was not attached to the [`WaitSet`], the operation will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet).
-    #[tracing::instrument(skip(self, _cond))]
-    pub fn detach_condition(&self, _cond: Condition<R>) -> DdsResult<()> {
-        todo!()
+    #[tracing::instrument(skip(self, cond))]
+    pub fn detach_condition(&mut self, cond: Condition<R>) -> DdsResult<()> {
+        match cond {
+            Condition::StatusCondition(sc) => R::block_on(self.waitset_async.detach_condition(
+                ConditionAsync::StatusCondition(sc.condition_async().clone()),
+            )),
+        }
     }
 
     /// This operation retrieves the list of attached conditions.
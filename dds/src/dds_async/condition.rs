@@ -3,7 +3,7 @@ use crate::{
         actor::ActorAddress, status_condition::DcpsStatusCondition,
         status_condition_mail::DcpsStatusConditionMail,
     },
-    infrastructure::{error::DdsResult, status::StatusKind},
+    infrastructure::{error::DdsResult, instance::InstanceHandle, status::StatusKind},
     runtime::{DdsRuntime, OneshotReceive},
 };
 use alloc::vec::Vec;
@@ -11,6 +11,7 @@ use alloc::vec::Vec;
 /// Async version of [`StatusCondition`](crate::infrastructure::condition::StatusCondition).
 pub struct StatusConditionAsync<R: DdsRuntime> {
     address: ActorAddress<R, DcpsStatusCondition<R>>,
+    entity_handle: InstanceHandle,
     clock_handle: R::ClockHandle,
 }
 
@@ -18,6 +19,7 @@ impl<R: DdsRuntime> Clone for StatusConditionAsync<R> {
     fn clone(&self) -> Self {
         Self {
             address: self.address.clone(),
+            entity_handle: self.entity_handle,
             clock_handle: self.clock_handle.clone(),
         }
     }
@@ -26,17 +28,32 @@ impl<R: DdsRuntime> Clone for StatusConditionAsync<R> {
 impl<R: DdsRuntime> StatusConditionAsync<R> {
     pub(crate) fn new(
         address: ActorAddress<R, DcpsStatusCondition<R>>,
+        entity_handle: InstanceHandle,
         clock_handle: R::ClockHandle,
     ) -> Self {
         Self {
             address,
+            entity_handle,
             clock_handle,
         }
     }
 
+    // Each entity has a single status condition so its handle identifies the condition
+    pub(crate) fn entity_handle(&self) -> InstanceHandle {
+        self.entity_handle
+    }
+
     pub(crate) fn clock_handle(&self) -> &R::ClockHandle {
         &self.clock_handle
     }
+
+    pub(crate) async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.address
+            .send_actor_mail(DcpsStatusConditionMail::GetStatusChanges { reply_sender })
+            .await?;
+        reply_receiver.receive().await
+    }
 }
 
 impl<R: DdsRuntime> StatusConditionAsync<R> {
@@ -76,7 +93,9 @@ impl<R: DdsRuntime> StatusConditionAsync<R> {
     pub async fn get_trigger_value(&self) -> DdsResult<bool> {
         let (reply_sender, reply_receiver) = R::oneshot();
         self.address
-            .send_actor_mail(DcpsStatusConditionMail::GetStatusConditionTriggerValue { reply_sender })
+            .send_actor_mail(DcpsStatusConditionMail::GetStatusConditionTriggerValue {
+                reply_sender,
+            })
             .await?;
         reply_receiver.receive().await
     }
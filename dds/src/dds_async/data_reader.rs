@@ -5,30 +5,34 @@ use crate::{
     builtin_topics::PublicationBuiltinTopicData,
     dcps::{
         actor::ActorAddress,
+        domain_participant::{poll_timeout, DeserializedData, DeserializedSampleList},
         domain_participant_mail::{DcpsDomainParticipantMail, ReaderServiceMail},
         listeners::data_reader_listener::DcpsDataReaderListener,
         status_condition::DcpsStatusCondition,
+        status_condition_mail::DcpsStatusConditionMail,
     },
     dds_async::topic_description::TopicDescriptionAsync,
     infrastructure::{
-        error::DdsResult,
+        error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataReaderQos, QosKind},
         sample_info::{
-            InstanceStateKind, Sample, SampleStateKind, ViewStateKind, ANY_INSTANCE_STATE,
-            ANY_VIEW_STATE,
+            InstanceStateKind, Sample, SampleInfo, SampleStateKind, ViewStateKind,
+            ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE,
         },
         status::{
-            LivelinessChangedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
-            SampleLostStatus, SampleRejectedStatus, StatusKind, SubscriptionMatchedStatus,
+            LivelinessChangedStatus, MatchDiagnostic, RequestedDeadlineMissedStatus,
+            RequestedIncompatibleQosStatus, SampleLostStatus, SampleRejectedStatus, StatusKind,
+            SubscriptionMatchedStatus,
         },
         time::Duration,
+        type_support::DdsDeserialize,
     },
     runtime::{ChannelSend, DdsRuntime, OneshotReceive},
     subscription::data_reader_listener::DataReaderListener,
 };
-use alloc::{vec, vec::Vec};
-use core::marker::PhantomData;
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+use core::{any::TypeId, marker::PhantomData};
 
 /// Async version of [`DataReader`](crate::subscription::data_reader::DataReader).
 pub struct DataReaderAsync<R: DdsRuntime, Foo> {
@@ -109,7 +113,9 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
 
         Ok(samples
             .into_iter()
-            .map(|(data, sample_info)| Sample::new(data, sample_info))
+            .map(|(data, deserialized_data, sample_info)| {
+                Sample::new_deserialized(data, deserialized_data, sample_info)
+            })
             .collect())
     }
 
@@ -160,8 +166,70 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
             }))
             .await?;
         let mut samples = reply_receiver.receive().await??;
-        let (data, sample_info) = samples.pop().expect("Would return NoData if empty");
-        Ok(Sample::new(data, sample_info))
+        let (data, deserialized_data, sample_info) =
+            samples.pop().expect("Would return NoData if empty");
+        Ok(Sample::new_deserialized(
+            data,
+            deserialized_data,
+            sample_info,
+        ))
+    }
+
+    /// Async version of [`read_next_sample_with_timeout`](crate::subscription::data_reader::DataReader::read_next_sample_with_timeout).
+    #[tracing::instrument(skip(self))]
+    pub async fn read_next_sample_with_timeout(&self, timeout: Duration) -> DdsResult<Sample<Foo>> {
+        let subscriber_handle = self.subscriber.get_instance_handle().await;
+        let data_reader_handle = self.handle;
+        let participant_address = self.participant_address().clone();
+        let status_condition_address = self.status_condition_address.clone();
+        let timer_handle = self.subscriber.get_participant().timer_handle().clone();
+
+        let mut samples = poll_timeout(
+            timer_handle,
+            timeout.into(),
+            Box::pin(async move {
+                loop {
+                    let (reply_sender, reply_receiver) = R::oneshot();
+                    participant_address
+                        .send(DcpsDomainParticipantMail::Reader(ReaderServiceMail::Read {
+                            subscriber_handle,
+                            data_reader_handle,
+                            max_samples: 1,
+                            sample_states: vec![SampleStateKind::NotRead],
+                            view_states: ANY_VIEW_STATE.to_vec(),
+                            instance_states: ANY_INSTANCE_STATE.to_vec(),
+                            specific_instance_handle: None,
+                            reply_sender,
+                        }))
+                        .await?;
+                    match reply_receiver.receive().await? {
+                        // Reading cleared the data available status so it is set again only
+                        // once a new sample is received
+                        Err(DdsError::NoData) => {
+                            let (reply_sender, reply_receiver) = R::oneshot();
+                            status_condition_address
+                                .send_actor_mail(
+                                    DcpsStatusConditionMail::WaitForCommunicationState {
+                                        state: StatusKind::DataAvailable,
+                                        reply_sender,
+                                    },
+                                )
+                                .await?;
+                            reply_receiver.receive().await?;
+                        }
+                        r => return r,
+                    }
+                }
+            }),
+        )
+        .await??;
+        let (data, deserialized_data, sample_info) =
+            samples.pop().expect("Would return NoData if empty");
+        Ok(Sample::new_deserialized(
+            data,
+            deserialized_data,
+            sample_info,
+        ))
     }
 
     /// Async version of [`take_next_sample`](crate::subscription::data_reader::DataReader::take_next_sample).
@@ -185,6 +253,22 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         Ok(Sample::new(data, sample_info))
     }
 
+    /// Async version of [`peek_next_info`](crate::subscription::data_reader::DataReader::peek_next_info).
+    #[tracing::instrument(skip(self))]
+    pub async fn peek_next_info(&self) -> DdsResult<SampleInfo> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::PeekNextInfo {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
     /// Async version of [`read_instance`](crate::subscription::data_reader::DataReader::read_instance).
     #[tracing::instrument(skip(self))]
     pub async fn read_instance(
@@ -211,7 +295,9 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         let samples = reply_receiver.receive().await??;
         Ok(samples
             .into_iter()
-            .map(|(data, sample_info)| Sample::new(data, sample_info))
+            .map(|(data, deserialized_data, sample_info)| {
+                Sample::new_deserialized(data, deserialized_data, sample_info)
+            })
             .collect())
     }
 
@@ -310,16 +396,6 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
             .collect())
     }
 
-    /// Async version of [`get_key_value`](crate::subscription::data_reader::DataReader::get_key_value).
-    #[tracing::instrument(skip(self, _key_holder))]
-    pub async fn get_key_value(
-        &self,
-        _key_holder: &mut Foo,
-        _handle: InstanceHandle,
-    ) -> DdsResult<()> {
-        todo!()
-    }
-
     /// Async version of [`lookup_instance`](crate::subscription::data_reader::DataReader::lookup_instance).
     #[tracing::instrument(skip(self, _instance))]
     pub async fn lookup_instance(&self, _instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
@@ -327,6 +403,126 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     }
 }
 
+impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo>
+where
+    Foo: for<'de> DdsDeserialize<'de> + Send + Sync + 'static,
+{
+    /// Async version of [`read_deserialized`](crate::subscription::data_reader::DataReader::read_deserialized).
+    #[tracing::instrument(skip(self))]
+    pub async fn read_deserialized(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<(Option<Arc<Foo>>, SampleInfo)>> {
+        let samples = self
+            .read_deserialized_samples(max_samples, sample_states, view_states, instance_states)
+            .await?;
+
+        Ok(samples
+            .into_iter()
+            .map(|(_, deserialized_data, sample_info)| {
+                let data = deserialized_data.map(|d| {
+                    d.downcast::<Foo>()
+                        .expect("Deserialized with the type of the reader")
+                });
+                (data, sample_info)
+            })
+            .collect())
+    }
+
+    async fn read_deserialized_samples(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<DeserializedSampleList> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::ReadDeserialized {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    max_samples,
+                    sample_states: sample_states.to_vec(),
+                    view_states: view_states.to_vec(),
+                    instance_states: instance_states.to_vec(),
+                    deserialized_type: TypeId::of::<Foo>(),
+                    deserialize_data: deserialize_data::<Foo>,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`read_with_filter`](crate::subscription::data_reader::DataReader::read_with_filter).
+    #[tracing::instrument(skip(self, filter))]
+    pub async fn read_with_filter(
+        &self,
+        max_samples: i32,
+        filter: impl Fn(&Foo) -> bool + Send + 'static,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        let sample_filter = Box::new(move |serialized_data: &[u8]| {
+            Foo::deserialize_data(serialized_data).is_ok_and(|data| filter(&data))
+        });
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::ReadWithFilter {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    max_samples,
+                    sample_states: ANY_SAMPLE_STATE.to_vec(),
+                    view_states: ANY_VIEW_STATE.to_vec(),
+                    instance_states: ANY_INSTANCE_STATE.to_vec(),
+                    sample_filter,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let samples = reply_receiver.receive().await??;
+
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info)| Sample::new(data, sample_info))
+            .collect())
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo>
+where
+    Foo: for<'de> DdsDeserialize<'de>,
+{
+    /// Async version of [`get_key_value`](crate::subscription::data_reader::DataReader::get_key_value).
+    #[tracing::instrument(skip(self, key_holder))]
+    pub async fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::GetInstanceSerializedData {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    instance_handle: handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let serialized_data = reply_receiver.receive().await??;
+        *key_holder = Foo::deserialize_data(&serialized_data)?;
+        Ok(())
+    }
+}
+
+fn deserialize_data<Foo>(serialized_data: &[u8]) -> DdsResult<DeserializedData>
+where
+    Foo: for<'de> DdsDeserialize<'de> + Send + Sync + 'static,
+{
+    Ok(Arc::new(Foo::deserialize_data(serialized_data)?))
+}
+
 impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     /// Async version of [`get_liveliness_changed_status`](crate::subscription::data_reader::DataReader::get_liveliness_changed_status).
     #[tracing::instrument(skip(self))]
@@ -339,7 +535,17 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub async fn get_requested_deadline_missed_status(
         &self,
     ) -> DdsResult<RequestedDeadlineMissedStatus> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::GetRequestedDeadlineMissedStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_requested_incompatible_qos_status`](crate::subscription::data_reader::DataReader::get_requested_incompatible_qos_status).
@@ -429,6 +635,48 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         reply_receiver.receive().await?
     }
 
+    /// Async version of [`get_publication_match_diagnostic`](crate::subscription::data_reader::DataReader::get_publication_match_diagnostic).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_publication_match_diagnostic(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<MatchDiagnostic> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::GetPublicationMatchDiagnostic {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    publication_handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_received_sequence_numbers`](crate::subscription::data_reader::DataReader::get_received_sequence_numbers).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_received_sequence_numbers(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<Vec<(i64, InstanceStateKind)>> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::GetReceivedSequenceNumbers {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    publication_handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+
+        reply_receiver.receive().await?
+    }
+
     /// Async version of [`get_matched_publications`](crate::subscription::data_reader::DataReader::get_matched_publications).
     #[tracing::instrument(skip(self))]
     pub async fn get_matched_publications(&self) -> DdsResult<Vec<InstanceHandle>> {
@@ -451,12 +699,14 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub async fn set_qos(&self, qos: QosKind<DataReaderQos>) -> DdsResult<()> {
         let (reply_sender, reply_receiver) = R::oneshot();
         self.participant_address()
-            .send(DcpsDomainParticipantMail::Reader(ReaderServiceMail::SetQos {
-                subscriber_handle: self.subscriber.get_instance_handle().await,
-                data_reader_handle: self.handle,
-                qos,
-                reply_sender,
-            }))
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::SetQos {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    qos,
+                    reply_sender,
+                },
+            ))
             .await?;
         reply_receiver.receive().await?
     }
@@ -466,11 +716,13 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub async fn get_qos(&self) -> DdsResult<DataReaderQos> {
         let (reply_sender, reply_receiver) = R::oneshot();
         self.participant_address()
-            .send(DcpsDomainParticipantMail::Reader(ReaderServiceMail::GetQos {
-                subscriber_handle: self.subscriber.get_instance_handle().await,
-                data_reader_handle: self.handle,
-                reply_sender,
-            }))
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::GetQos {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
             .await?;
         reply_receiver.receive().await?
     }
@@ -480,6 +732,7 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            self.handle,
             self.subscriber.get_participant().clock_handle().clone(),
         )
     }
@@ -487,7 +740,7 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     /// Async version of [`get_status_changes`](crate::subscription::data_reader::DataReader::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        self.get_statuscondition().get_status_changes().await
     }
 
     /// Async version of [`enable`](crate::subscription::data_reader::DataReader::enable).
@@ -495,12 +748,14 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub async fn enable(&self) -> DdsResult<()> {
         let (reply_sender, reply_receiver) = R::oneshot();
         self.participant_address()
-            .send(DcpsDomainParticipantMail::Reader(ReaderServiceMail::Enable {
-                subscriber_handle: self.subscriber.get_instance_handle().await,
-                data_reader_handle: self.handle,
-                participant_address: self.participant_address().clone(),
-                reply_sender,
-            }))
+            .send(DcpsDomainParticipantMail::Reader(
+                ReaderServiceMail::Enable {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    participant_address: self.participant_address().clone(),
+                    reply_sender,
+                },
+            ))
             .await?;
         reply_receiver.receive().await?
     }
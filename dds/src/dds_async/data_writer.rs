@@ -5,7 +5,7 @@ use crate::{
     builtin_topics::SubscriptionBuiltinTopicData,
     dcps::{
         actor::ActorAddress,
-        domain_participant::poll_timeout,
+        domain_participant::{poll_timeout, ACKNOWLEDGMENT_POLL_INTERVAL},
         domain_participant_mail::{
             DcpsDomainParticipantMail, MessageServiceMail, WriterServiceMail,
         },
@@ -18,14 +18,14 @@ use crate::{
         instance::InstanceHandle,
         qos::{DataWriterQos, QosKind},
         status::{
-            LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
-            PublicationMatchedStatus, StatusKind,
+            LivelinessLostStatus, MatchDiagnostic, OfferedDeadlineMissedStatus,
+            OfferedIncompatibleQosStatus, PublicationMatchedStatus, StatusKind,
         },
         time::{Duration, Time},
         type_support::TypeSupport,
     },
-    publication::data_writer_listener::DataWriterListener,
-    runtime::{ChannelSend, DdsRuntime, OneshotReceive},
+    publication::data_writer_listener::{DataWriterListener, DataWriterListenerHandle},
+    runtime::{ChannelSend, DdsRuntime, OneshotReceive, Timer},
 };
 use alloc::{boxed::Box, string::String, vec::Vec};
 use core::marker::PhantomData;
@@ -88,7 +88,7 @@ where
 {
     /// Async version of [`register_instance`](crate::publication::data_writer::DataWriter::register_instance).
     #[tracing::instrument(skip(self, instance))]
-    pub async fn register_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
+    pub async fn register_instance(&self, instance: Foo) -> DdsResult<Option<InstanceHandle>> {
         let timestamp = self
             .get_publisher()
             .get_participant()
@@ -99,13 +99,27 @@ where
     }
 
     /// Async version of [`register_instance_w_timestamp`](crate::publication::data_writer::DataWriter::register_instance_w_timestamp).
-    #[tracing::instrument(skip(self, _instance))]
+    #[tracing::instrument(skip(self, instance))]
     pub async fn register_instance_w_timestamp(
         &self,
-        _instance: &Foo,
+        instance: Foo,
         timestamp: Time,
     ) -> DdsResult<Option<InstanceHandle>> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        let dynamic_data = instance.create_dynamic_sample();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Writer(
+                WriterServiceMail::RegisterInstance {
+                    participant_address: self.participant_address().clone(),
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    dynamic_data,
+                    timestamp,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`unregister_instance`](crate::publication::data_writer::DataWriter::unregister_instance).
@@ -258,6 +272,7 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
             .get_participant()
             .timer_handle()
             .clone();
+        let mut poll_timer_handle = timer_handle.clone();
         let participant_address = self.participant_address().clone();
         let data_writer_handle = self.handle;
 
@@ -281,7 +296,9 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
                     match reply {
                         Ok(are_changes_acknowledged) => match are_changes_acknowledged {
                             Ok(true) => return Ok(()),
-                            Ok(false) => (),
+                            Ok(false) => {
+                                poll_timer_handle.delay(ACKNOWLEDGMENT_POLL_INTERVAL).await
+                            }
                             Err(e) => return Err(e),
                         },
                         Err(_) => return Err(DdsError::Error(String::from("Channel error"))),
@@ -295,7 +312,17 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     /// Async version of [`get_liveliness_lost_status`](crate::publication::data_writer::DataWriter::get_liveliness_lost_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_liveliness_lost_status(&self) -> DdsResult<LivelinessLostStatus> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Writer(
+                WriterServiceMail::GetLivelinessLostStatus {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_offered_deadline_missed_status`](crate::publication::data_writer::DataWriter::get_offered_deadline_missed_status).
@@ -355,7 +382,17 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     /// Async version of [`assert_liveliness`](crate::publication::data_writer::DataWriter::assert_liveliness).
     #[tracing::instrument(skip(self))]
     pub async fn assert_liveliness(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Writer(
+                WriterServiceMail::AssertLiveliness {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_matched_subscription_data`](crate::publication::data_writer::DataWriter::get_matched_subscription_data).
@@ -378,6 +415,26 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
         reply_receiver.receive().await?
     }
 
+    /// Async version of [`get_subscription_match_diagnostic`](crate::publication::data_writer::DataWriter::get_subscription_match_diagnostic).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_subscription_match_diagnostic(
+        &self,
+        subscription_handle: InstanceHandle,
+    ) -> DdsResult<MatchDiagnostic> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Writer(
+                WriterServiceMail::GetSubscriptionMatchDiagnostic {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    subscription_handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
     /// Async version of [`get_matched_subscriptions`](crate::publication::data_writer::DataWriter::get_matched_subscriptions).
     #[tracing::instrument(skip(self))]
     pub async fn get_matched_subscriptions(&self) -> DdsResult<Vec<InstanceHandle>> {
@@ -434,6 +491,7 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            self.handle,
             self.publisher.get_participant().clock_handle().clone(),
         )
     }
@@ -441,7 +499,7 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     /// Async version of [`get_status_changes`](crate::publication::data_writer::DataWriter::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        self.get_statuscondition().get_status_changes().await
     }
 
     /// Async version of [`enable`](crate::publication::data_writer::DataWriter::enable).
@@ -495,4 +553,39 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
             .await?;
         reply_receiver.receive().await?
     }
+
+    /// Async version of [`get_listener`](crate::publication::data_writer::DataWriter::get_listener).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_listener(&self) -> DdsResult<Option<DataWriterListenerHandle<R>>> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Writer(
+                WriterServiceMail::GetListener {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        Ok(reply_receiver
+            .receive()
+            .await??
+            .map(DataWriterListenerHandle::new))
+    }
+
+    /// Async version of [`get_listener_mask`](crate::publication::data_writer::DataWriter::get_listener_mask).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_listener_mask(&self) -> DdsResult<Option<Vec<StatusKind>>> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Writer(
+                WriterServiceMail::GetListenerMask {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
 }
@@ -19,7 +19,7 @@ use crate::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DomainParticipantQos, PublisherQos, QosKind, SubscriberQos, TopicQos},
-        status::StatusKind,
+        status::{ParticipantStatistics, StatusKind},
         time::{Duration, Time},
         type_support::TypeSupport,
     },
@@ -402,6 +402,21 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
         }
     }
 
+    /// Async version of [`lookup_registered_type`](crate::domain::domain_participant::DomainParticipant::lookup_registered_type).
+    #[tracing::instrument(skip(self))]
+    pub async fn lookup_registered_type(&self, type_name: &str) -> DdsResult<Option<DynamicType>> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DcpsDomainParticipantMail::Participant(
+                ParticipantServiceMail::LookupRegisteredType {
+                    type_name: String::from(type_name),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await
+    }
+
     /// Async version of [`get_builtin_subscriber`](crate::domain::domain_participant::DomainParticipant::get_builtin_subscriber).
     #[tracing::instrument(skip(self))]
     pub fn get_builtin_subscriber(&self) -> SubscriberAsync<R> {
@@ -430,7 +445,16 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
     /// Async version of [`ignore_topic`](crate::domain::domain_participant::DomainParticipant::ignore_topic).
     #[tracing::instrument(skip(self))]
     pub async fn ignore_topic(&self, handle: InstanceHandle) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DcpsDomainParticipantMail::Participant(
+                ParticipantServiceMail::IgnoreTopic {
+                    handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`ignore_publication`](crate::domain::domain_participant::DomainParticipant::ignore_publication).
@@ -636,6 +660,30 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
             .await?;
         reply_receiver.receive().await
     }
+
+    /// Async version of [`get_statistics`](crate::domain::domain_participant::DomainParticipant::get_statistics).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_statistics(&self) -> DdsResult<ParticipantStatistics> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DcpsDomainParticipantMail::Participant(
+                ParticipantServiceMail::GetStatistics { reply_sender },
+            ))
+            .await?;
+        reply_receiver.receive().await
+    }
+
+    /// Async version of [`announce`](crate::domain::domain_participant::DomainParticipant::announce).
+    #[tracing::instrument(skip(self))]
+    pub async fn announce(&self) -> DdsResult<()> {
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DcpsDomainParticipantMail::Participant(
+                ParticipantServiceMail::Announce { reply_sender },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
 }
 
 impl<R: DdsRuntime> DomainParticipantAsync<R> {
@@ -688,7 +736,8 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
     /// Async version of [`get_status_changes`](crate::domain::domain_participant::DomainParticipant::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        // This entity has no communication statuses of its own
+        Ok(Vec::new())
     }
 
     /// Async version of [`enable`](crate::domain::domain_participant::DomainParticipant::enable).
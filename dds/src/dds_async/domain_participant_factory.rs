@@ -6,7 +6,7 @@ use crate::{
         domain_participant_factory::DcpsParticipantFactory,
         domain_participant_factory_mail::DcpsParticipantFactoryMail,
         domain_participant_mail::{
-            DiscoveryServiceMail, DcpsDomainParticipantMail, ParticipantServiceMail,
+            DcpsDomainParticipantMail, DiscoveryServiceMail, ParticipantServiceMail,
         },
         listeners::domain_participant_listener::DcpsDomainParticipantListener,
     },
@@ -100,12 +100,13 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantFactoryAsyn
                 })
                 .await;
             let deleted_participant = reply_receiver.receive().await??;
+            let (reply_sender, reply_receiver) = R::oneshot();
             deleted_participant
                 .send(DcpsDomainParticipantMail::Discovery(
-                    DiscoveryServiceMail::AnnounceDeletedParticipant,
+                    DiscoveryServiceMail::AnnounceDeletedParticipant { reply_sender },
                 ))
-                .await
-                .ok();
+                .await?;
+            reply_receiver.receive().await?;
             Ok(())
         } else {
             Err(DdsError::PreconditionNotMet(String::from(
@@ -117,9 +118,33 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantFactoryAsyn
     /// Async version of [`lookup_participant`](crate::domain::domain_participant_factory::DomainParticipantFactory::lookup_participant).
     pub async fn lookup_participant(
         &self,
-        _domain_id: DomainId,
+        domain_id: DomainId,
     ) -> DdsResult<Option<DomainParticipantAsync<R>>> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.domain_participant_factory_actor
+            .send_actor_mail(DcpsParticipantFactoryMail::LookupParticipant {
+                domain_id,
+                reply_sender,
+            })
+            .await;
+
+        Ok(reply_receiver.receive().await?.map(
+            |(
+                participant_address,
+                participant_handle,
+                builtin_subscriber_status_condition_address,
+            )| {
+                DomainParticipantAsync::new(
+                    participant_address,
+                    builtin_subscriber_status_condition_address,
+                    domain_id,
+                    participant_handle,
+                    self.runtime.spawner(),
+                    self.runtime.clock(),
+                    self.runtime.timer(),
+                )
+            },
+        ))
     }
 
     /// Async version of [`set_default_participant_qos`](crate::domain::domain_participant_factory::DomainParticipantFactory::set_default_participant_qos).
@@ -141,9 +166,7 @@ impl<R: DdsRuntime, T: TransportParticipantFactory> DomainParticipantFactoryAsyn
     pub async fn get_default_participant_qos(&self) -> DdsResult<DomainParticipantQos> {
         let (reply_sender, reply_receiver) = R::oneshot();
         self.domain_participant_factory_actor
-            .send_actor_mail(DcpsParticipantFactoryMail::GetDefaultParticipantQos {
-                reply_sender,
-            })
+            .send_actor_mail(DcpsParticipantFactoryMail::GetDefaultParticipantQos { reply_sender })
             .await;
         reply_receiver.receive().await
     }
@@ -220,7 +243,6 @@ impl
         use core::net::IpAddr;
         use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
         use std::sync::OnceLock;
-        use tracing::warn;
 
         static PARTICIPANT_FACTORY_ASYNC: OnceLock<
             DomainParticipantFactoryAsync<
@@ -247,9 +269,9 @@ impl
                     IpAddr::V6(_) => unimplemented!("IPv6 not yet implemented"),
                 }
             } else {
-                warn!("Failed to get Host ID from IP address, use 0 instead");
                 [0; 4]
             };
+            let host_id = host_id_or_fallback(host_id);
 
             let app_id = std::process::id().to_ne_bytes();
             let transport = crate::rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactory::default();
@@ -262,3 +284,63 @@ impl
         })
     }
 }
+
+// Hosts without a usable IPv4 address, e.g. some containers, would otherwise all share the
+// same Host ID. Derive it from the MD5 digest of the host name so that it stays the same on
+// every run and every platform, or pick a random one if the host name is not available.
+#[cfg(feature = "std")]
+fn host_id_or_fallback(host_id: [u8; 4]) -> [u8; 4] {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    };
+    use tracing::warn;
+
+    if host_id != [0; 4] {
+        return host_id;
+    }
+
+    let host_name = std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty());
+    if let Some(host_name) = host_name {
+        warn!("Failed to get Host ID from IP address, derive it from the host name instead");
+        host_id_from_host_name(&host_name)
+    } else {
+        warn!("Failed to get Host ID from IP address or host name, use a random one instead");
+        (RandomState::new().build_hasher().finish() as u32).to_ne_bytes()
+    }
+}
+
+#[cfg(feature = "std")]
+fn host_id_from_host_name(host_name: &str) -> [u8; 4] {
+    let digest = md5::compute(host_name.as_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_id_from_ip_address_is_kept() {
+        assert_eq!(host_id_or_fallback([192, 168, 1, 2]), [192, 168, 1, 2]);
+    }
+
+    #[test]
+    fn unspecified_host_id_falls_back_to_generated_one() {
+        assert_ne!(host_id_or_fallback([0; 4]), [0; 4]);
+    }
+
+    #[test]
+    fn host_id_from_host_name_is_the_start_of_its_md5_digest() {
+        // MD5("dust-dds-host") = 84f68069 4cfa249f 06bb20a5 ea56b895
+        assert_eq!(
+            host_id_from_host_name("dust-dds-host"),
+            [0x84, 0xf6, 0x80, 0x69]
+        );
+    }
+}
@@ -4,8 +4,7 @@ use crate::{
         actor::Actor,
         domain_participant_mail::{DcpsDomainParticipantMail, PublisherServiceMail},
         listeners::{
-            data_writer_listener::DcpsDataWriterListener,
-            publisher_listener::DcpsPublisherListener,
+            data_writer_listener::DcpsDataWriterListener, publisher_listener::DcpsPublisherListener,
         },
         status_condition::DcpsStatusCondition,
     },
@@ -21,6 +20,7 @@ use crate::{
         data_writer_listener::DataWriterListener, publisher_listener::PublisherListener,
     },
     runtime::{ChannelSend, DdsRuntime, OneshotReceive},
+    transport::types::EntityId,
 };
 use alloc::vec::Vec;
 
@@ -61,6 +61,38 @@ impl<R: DdsRuntime> PublisherAsync<R> {
         qos: QosKind<DataWriterQos>,
         a_listener: Option<impl DataWriterListener<R, Foo> + Send + 'static>,
         mask: &[StatusKind],
+    ) -> DdsResult<DataWriterAsync<R, Foo>> {
+        self.create_datawriter_with_optional_entity_id(a_topic, None, qos, a_listener, mask)
+            .await
+    }
+
+    /// Async version of [`create_datawriter_with_entity_id`](crate::publication::publisher::Publisher::create_datawriter_with_entity_id).
+    #[tracing::instrument(skip(self, a_topic, a_listener))]
+    pub async fn create_datawriter_with_entity_id<Foo>(
+        &self,
+        a_topic: &TopicDescriptionAsync<R>,
+        entity_id: EntityId,
+        qos: QosKind<DataWriterQos>,
+        a_listener: Option<impl DataWriterListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+    ) -> DdsResult<DataWriterAsync<R, Foo>> {
+        self.create_datawriter_with_optional_entity_id(
+            a_topic,
+            Some(entity_id),
+            qos,
+            a_listener,
+            mask,
+        )
+        .await
+    }
+
+    async fn create_datawriter_with_optional_entity_id<Foo>(
+        &self,
+        a_topic: &TopicDescriptionAsync<R>,
+        entity_id: Option<EntityId>,
+        qos: QosKind<DataWriterQos>,
+        a_listener: Option<impl DataWriterListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
     ) -> DdsResult<DataWriterAsync<R, Foo>> {
         let topic_name = a_topic.get_name();
         let status_condition = Actor::spawn(
@@ -68,8 +100,8 @@ impl<R: DdsRuntime> PublisherAsync<R> {
             self.participant.spawner_handle(),
         );
         let writer_status_condition_address = status_condition.address();
-        let listener_sender = a_listener
-            .map(|l| DcpsDataWriterListener::spawn(l, self.participant.spawner_handle()));
+        let listener_sender =
+            a_listener.map(|l| DcpsDataWriterListener::spawn(l, self.participant.spawner_handle()));
         let (reply_sender, reply_receiver) = R::oneshot();
         self.participant_address()
             .send(DcpsDomainParticipantMail::Publisher(
@@ -77,6 +109,7 @@ impl<R: DdsRuntime> PublisherAsync<R> {
                     publisher_handle: self.handle,
                     topic_name,
                     qos,
+                    entity_id,
                     status_condition,
                     listener_sender,
                     mask: mask.to_vec(),
@@ -138,13 +171,31 @@ impl<R: DdsRuntime> PublisherAsync<R> {
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::begin_coherent_changes).
     #[tracing::instrument(skip(self))]
     pub async fn begin_coherent_changes(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Publisher(
+                PublisherServiceMail::BeginCoherentChanges {
+                    publisher_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::end_coherent_changes).
     #[tracing::instrument(skip(self))]
     pub async fn end_coherent_changes(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Publisher(
+                PublisherServiceMail::EndCoherentChanges {
+                    publisher_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::wait_for_acknowledgments).
@@ -265,7 +316,8 @@ impl<R: DdsRuntime> PublisherAsync<R> {
     /// Async version of [`get_status_changes`](crate::publication::publisher::Publisher::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        // This entity has no communication statuses of its own
+        Ok(Vec::new())
     }
 
     /// Async version of [`enable`](crate::publication::publisher::Publisher::enable).
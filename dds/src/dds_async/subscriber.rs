@@ -23,6 +23,7 @@ use crate::{
     subscription::{
         data_reader_listener::DataReaderListener, subscriber_listener::SubscriberListener,
     },
+    transport::types::EntityId,
 };
 use alloc::{string::String, vec::Vec};
 
@@ -70,14 +71,46 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
         qos: QosKind<DataReaderQos>,
         a_listener: Option<impl DataReaderListener<R, Foo> + Send + 'static>,
         mask: &[StatusKind],
+    ) -> DdsResult<DataReaderAsync<R, Foo>> {
+        self.create_datareader_with_optional_entity_id(a_topic, None, qos, a_listener, mask)
+            .await
+    }
+
+    /// Async version of [`create_datareader_with_entity_id`](crate::subscription::subscriber::Subscriber::create_datareader_with_entity_id).
+    #[tracing::instrument(skip(self, a_topic, a_listener))]
+    pub async fn create_datareader_with_entity_id<Foo>(
+        &self,
+        a_topic: &TopicDescriptionAsync<R>,
+        entity_id: EntityId,
+        qos: QosKind<DataReaderQos>,
+        a_listener: Option<impl DataReaderListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+    ) -> DdsResult<DataReaderAsync<R, Foo>> {
+        self.create_datareader_with_optional_entity_id(
+            a_topic,
+            Some(entity_id),
+            qos,
+            a_listener,
+            mask,
+        )
+        .await
+    }
+
+    async fn create_datareader_with_optional_entity_id<Foo>(
+        &self,
+        a_topic: &TopicDescriptionAsync<R>,
+        entity_id: Option<EntityId>,
+        qos: QosKind<DataReaderQos>,
+        a_listener: Option<impl DataReaderListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
     ) -> DdsResult<DataReaderAsync<R, Foo>> {
         let status_condition = Actor::spawn(
             DcpsStatusCondition::default(),
             self.participant.spawner_handle(),
         );
         let reader_status_condition_address = status_condition.address();
-        let listener_sender = a_listener
-            .map(|l| DcpsDataReaderListener::spawn(l, self.participant.spawner_handle()));
+        let listener_sender =
+            a_listener.map(|l| DcpsDataReaderListener::spawn(l, self.participant.spawner_handle()));
         let (reply_sender, reply_receiver) = R::oneshot();
         self.participant_address()
             .send(DcpsDomainParticipantMail::Subscriber(
@@ -85,6 +118,7 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
                     subscriber_handle: self.handle,
                     topic_name: a_topic.get_name(),
                     qos,
+                    entity_id,
                     status_condition,
                     listener_sender,
                     mask: mask.to_vec(),
@@ -159,7 +193,17 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
     /// Async version of [`notify_datareaders`](crate::subscription::subscriber::Subscriber::notify_datareaders).
     #[tracing::instrument(skip(self))]
     pub async fn notify_datareaders(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DcpsDomainParticipantMail::Subscriber(
+                SubscriberServiceMail::NotifyDataReaders {
+                    subscriber_handle: self.handle,
+                    participant_address: self.participant_address().clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_participant`](crate::subscription::subscriber::Subscriber::get_participant).
@@ -260,8 +304,8 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
         mask: &[StatusKind],
     ) -> DdsResult<()> {
         let (reply_sender, reply_receiver) = R::oneshot();
-        let listener_sender = a_listener
-            .map(|l| DcpsSubscriberListener::spawn(l, self.participant.spawner_handle()));
+        let listener_sender =
+            a_listener.map(|l| DcpsSubscriberListener::spawn(l, self.participant.spawner_handle()));
         self.participant_address()
             .send(DcpsDomainParticipantMail::Subscriber(
                 SubscriberServiceMail::SetListener {
@@ -280,6 +324,7 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            self.handle,
             self.participant.clock_handle().clone(),
         )
     }
@@ -287,7 +332,7 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
     /// Async version of [`get_status_changes`](crate::subscription::subscriber::Subscriber::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        self.get_statuscondition().get_status_changes().await
     }
 
     /// Async version of [`enable`](crate::subscription::subscriber::Subscriber::enable).
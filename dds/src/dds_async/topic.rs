@@ -131,6 +131,7 @@ impl<R: DdsRuntime> TopicAsync<R> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            self.handle,
             self.participant.clock_handle().clone(),
         )
     }
@@ -138,7 +139,7 @@ impl<R: DdsRuntime> TopicAsync<R> {
     /// Async version of [`get_status_changes`](crate::topic_definition::topic::Topic::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        self.get_statuscondition().get_status_changes().await
     }
 
     /// Async version of [`enable`](crate::topic_definition::topic::Topic::enable).
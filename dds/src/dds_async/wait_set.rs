@@ -30,6 +30,14 @@ impl<R: DdsRuntime> ConditionAsync<R> {
             ConditionAsync::StatusCondition(c) => c.get_trigger_value().await,
         }
     }
+
+    fn is_same_condition(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ConditionAsync::StatusCondition(a), ConditionAsync::StatusCondition(b)) => {
+                a.entity_handle() == b.entity_handle()
+            }
+        }
+    }
 }
 
 /// Async version of [`WaitSet`](crate::infrastructure::wait_set::WaitSet).
@@ -89,14 +97,26 @@ impl<R: DdsRuntime> WaitSetAsync<R> {
     /// Async version of [`attach_condition`](crate::infrastructure::wait_set::WaitSet::attach_condition).
     #[tracing::instrument(skip(self, cond))]
     pub async fn attach_condition(&mut self, cond: ConditionAsync<R>) -> DdsResult<()> {
-        self.conditions.push(cond);
+        if !self.conditions.iter().any(|c| c.is_same_condition(&cond)) {
+            self.conditions.push(cond);
+        }
         Ok(())
     }
 
     /// Async version of [`detach_condition`](crate::infrastructure::wait_set::WaitSet::detach_condition).
-    #[tracing::instrument(skip(self, _cond))]
-    pub async fn detach_condition(&self, _cond: ConditionAsync<R>) -> DdsResult<()> {
-        todo!()
+    #[tracing::instrument(skip(self, cond))]
+    pub async fn detach_condition(&mut self, cond: ConditionAsync<R>) -> DdsResult<()> {
+        let Some(index) = self
+            .conditions
+            .iter()
+            .position(|c| c.is_same_condition(&cond))
+        else {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Condition is not attached to the WaitSet",
+            )));
+        };
+        self.conditions.remove(index);
+        Ok(())
     }
 
     /// Async version of [`get_conditions`](crate::infrastructure::wait_set::WaitSet::get_conditions).
@@ -34,6 +34,10 @@ pub mod rtps_messages;
 #[doc(hidden)]
 pub mod rtps_udp_transport;
 
+#[cfg(feature = "rtps_memory_transport")]
+#[doc(hidden)]
+pub mod rtps_memory_transport;
+
 #[cfg(feature = "transport")]
 #[doc(hidden)]
 /// Contains the Dust DDS transport interface definition.
@@ -3,14 +3,18 @@ use crate::xtypes::deserialize::XTypesDeserialize;
 use crate::{
     rtps_messages::{
         self,
+        overall_structure::{TryReadFromBytes, WriteIntoBytes},
         submessage_elements::{Parameter, ParameterList},
         submessages::data::DataSubmessage,
         types::ParameterId,
     },
-    transport::types::{CacheChange, ChangeKind, EntityId, Guid, GuidPrefix},
+    transport::types::{CacheChange, ChangeKind, EntityId, Guid, GuidPrefix, SequenceNumber},
 };
 use alloc::{sync::Arc, vec::Vec};
 
+pub const PID_COHERENT_SET: ParameterId = 0x0056;
+// Vendor specific parameter, without value, sent with the last change of a coherent set
+pub const PID_COHERENT_SET_END: ParameterId = 0x8056_u16 as ParameterId;
 pub const PID_KEY_HASH: ParameterId = 0x0070;
 pub const PID_STATUS_INFO: ParameterId = 0x0071;
 
@@ -50,6 +54,17 @@ impl CacheChange {
         if let Some(i) = self.instance_handle {
             parameters.push(Parameter::new(PID_KEY_HASH, Arc::from(i)));
         }
+        if let Some(coherent_set) = self.coherent_set {
+            let mut coherent_set_value = Vec::with_capacity(8);
+            coherent_set.write_into_bytes(&mut coherent_set_value);
+            parameters.push(Parameter::new(
+                PID_COHERENT_SET,
+                Arc::from(coherent_set_value),
+            ));
+            if self.coherent_set_end {
+                parameters.push(Parameter::new(PID_COHERENT_SET_END, Arc::from([])));
+            }
+        }
         let parameter_list = ParameterList::new(parameters);
 
         DataSubmessage::new(
@@ -65,11 +80,18 @@ impl CacheChange {
         )
     }
 
+    /// A submessage with the NonStandardPayloadFlag set is rejected with [`RtpsError::NonStandardPayload`]
+    /// since its payload can not be deserialized as CDR data or key. The raw bytes remain available
+    /// from the submessage itself.
     pub fn try_from_data_submessage(
         data_submessage: &DataSubmessage,
         source_guid_prefix: GuidPrefix,
         source_timestamp: Option<rtps_messages::types::Time>,
     ) -> Result<Self, RtpsError> {
+        if data_submessage.non_standard_payload_flag() {
+            return Err(RtpsError::NonStandardPayload);
+        }
+
         let kind = match data_submessage
             .inline_qos()
             .parameter()
@@ -107,6 +129,25 @@ impl CacheChange {
             None => None,
         };
 
+        let coherent_set = match data_submessage
+            .inline_qos()
+            .parameter()
+            .iter()
+            .find(|&x| x.parameter_id() == PID_COHERENT_SET)
+        {
+            Some(p) => Some(
+                SequenceNumber::try_read_from_bytes(&mut p.value(), &data_submessage.endianness())
+                    .map_err(|_| RtpsError::InvalidData)?,
+            ),
+            None => None,
+        };
+        let coherent_set_end = coherent_set.is_some()
+            && data_submessage
+                .inline_qos()
+                .parameter()
+                .iter()
+                .any(|x| x.parameter_id() == PID_COHERENT_SET_END);
+
         Ok(CacheChange {
             kind,
             writer_guid: Guid::new(source_guid_prefix, data_submessage.writer_id()),
@@ -114,6 +155,139 @@ impl CacheChange {
             instance_handle,
             sequence_number: data_submessage.writer_sn(),
             data_value: data_submessage.serialized_payload().clone().into(),
+            coherent_set,
+            coherent_set_end,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rtps_messages::{
+            overall_structure::{write_submessage_into_bytes_vec, SubmessageHeaderRead},
+            submessage_elements::Data,
+        },
+        transport::types::{USER_DEFINED_READER_NO_KEY, USER_DEFINED_WRITER_NO_KEY},
+    };
+    use alloc::vec;
+
+    #[test]
+    fn data_submessage_shares_the_serialized_data_of_the_cache_change() {
+        let writer_id = EntityId::new([6, 7, 8], USER_DEFINED_WRITER_NO_KEY);
+        let cache_change =
+            CacheChange::builder(ChangeKind::Alive, Guid::new([1; 12], writer_id), 1)
+                .data_value(vec![0, 1, 0, 0, 1, 2, 3, 4])
+                .build();
+
+        let data_submessage = cache_change.as_data_submessage(
+            EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
+            writer_id,
+        );
+
+        // The sample is serialized once on write and the submessage borrows the same allocation
+        let submessage_data: Arc<[u8]> = data_submessage.serialized_payload().clone().into();
+        assert!(Arc::ptr_eq(&submessage_data, &cache_change.data_value));
+    }
+
+    #[test]
+    fn non_standard_payload_is_not_converted_to_cache_change() {
+        let data_submessage = DataSubmessage::new(
+            false,
+            true,
+            false,
+            true,
+            EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
+            EntityId::new([6, 7, 8], USER_DEFINED_WRITER_NO_KEY),
+            5,
+            ParameterList::empty(),
+            Data::new(vec![0xca, 0xfe, 0xba, 0xbe].into()),
+        );
+
+        assert!(matches!(
+            CacheChange::try_from_data_submessage(&data_submessage, [1; 12], None),
+            Err(RtpsError::NonStandardPayload)
+        ));
+    }
+
+    #[test]
+    fn coherent_set_is_sent_as_inline_qos() {
+        let writer_id = EntityId::new([6, 7, 8], USER_DEFINED_WRITER_NO_KEY);
+        let cache_change =
+            CacheChange::builder(ChangeKind::Alive, Guid::new([1; 12], writer_id), 4)
+                .data_value(vec![0, 1, 0, 0, 1, 2, 3, 4])
+                .coherent_set(3)
+                .build();
+
+        let data_submessage = cache_change.as_data_submessage(
+            EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
+            writer_id,
+        );
+
+        assert_eq!(
+            data_submessage.inline_qos(),
+            &ParameterList::new(vec![Parameter::new(
+                PID_COHERENT_SET,
+                Arc::from([0, 0, 0, 0, 3, 0, 0, 0])
+            )])
+        );
+        assert_eq!(
+            CacheChange::try_from_data_submessage(&data_submessage, [1; 12], None).unwrap(),
+            cache_change
+        );
+    }
+
+    #[test]
+    fn coherent_set_end_is_sent_as_inline_qos() {
+        let writer_id = EntityId::new([6, 7, 8], USER_DEFINED_WRITER_NO_KEY);
+        let cache_change =
+            CacheChange::builder(ChangeKind::Alive, Guid::new([1; 12], writer_id), 4)
+                .data_value(vec![0, 1, 0, 0, 1, 2, 3, 4])
+                .coherent_set(3)
+                .coherent_set_end()
+                .build();
+
+        let data_submessage = cache_change.as_data_submessage(
+            EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
+            writer_id,
+        );
+        let buffer = write_submessage_into_bytes_vec(&data_submessage);
+        let mut data = &buffer[..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let received_data_submessage =
+            DataSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        assert_eq!(
+            CacheChange::try_from_data_submessage(&received_data_submessage, [1; 12], None)
+                .unwrap(),
+            cache_change
+        );
+    }
+
+    #[test]
+    fn coherent_set_is_read_in_the_submessage_endianness() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x15, 0b_0000_0110, 0, 36, // Submessage header (big endian)
+            0, 0, 0, 16, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 2, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            0, 0, 0, 5, // writerSN: low
+            0, 0x56, 0, 8, // inlineQos: parameterId, length
+            0, 0, 0, 0, // inlineQos: PID_COHERENT_SET high
+            0, 0, 0, 4, // inlineQos: PID_COHERENT_SET low
+            0, 1, 0, 0, // inlineQos: Sentinel
+            0, 0, 0, 0, // SerializedPayload
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let data_submessage = DataSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        let cache_change =
+            CacheChange::try_from_data_submessage(&data_submessage, [1; 12], None).unwrap();
+
+        assert_eq!(cache_change.sequence_number, 5);
+        assert_eq!(cache_change.coherent_set, Some(4));
+    }
+}
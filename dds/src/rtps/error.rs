@@ -10,6 +10,7 @@ pub enum RtpsError {
     MessageError,
     XTypesError,
     ParameterNotFound,
+    NonStandardPayload,
 }
 
 impl From<XTypesError> for RtpsError {
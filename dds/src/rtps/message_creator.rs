@@ -5,12 +5,24 @@ use crate::{
 
 use super::types::{PROTOCOLVERSION_2_4, VENDOR_ID_S2E};
 
+// Octets reserved on top of a serialized payload for the message header and the submessages
+// composed along with the DATA or DATA_FRAG submessage carrying it
+pub const PAYLOAD_MESSAGE_OVERHEAD: usize = 128;
+
 impl RtpsMessageWrite {
     pub fn from_submessages(
         submessages: &[&(dyn Submessage + Send)],
         guid_prefix: GuidPrefix,
+    ) -> Self {
+        Self::from_submessages_with_capacity(submessages, guid_prefix, 0)
+    }
+
+    pub fn from_submessages_with_capacity(
+        submessages: &[&(dyn Submessage + Send)],
+        guid_prefix: GuidPrefix,
+        capacity: usize,
     ) -> Self {
         let header = RtpsMessageHeader::new(PROTOCOLVERSION_2_4, VENDOR_ID_S2E, guid_prefix);
-        RtpsMessageWrite::new(&header, submessages)
+        RtpsMessageWrite::new_with_capacity(&header, submessages, capacity)
     }
 }
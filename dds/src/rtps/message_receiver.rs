@@ -10,12 +10,13 @@ use crate::{
 use alloc::vec::Vec;
 
 pub struct MessageReceiver<'a> {
+    own_guid_prefix: GuidPrefix,
     source_version: ProtocolVersion,
     source_vendor_id: VendorId,
     source_guid_prefix: GuidPrefix,
     dest_guid_prefix: GuidPrefix,
-    _unicast_reply_locator_list: Vec<Locator>,
-    _multicast_reply_locator_list: Vec<Locator>,
+    unicast_reply_locator_list: Vec<Locator>,
+    multicast_reply_locator_list: Vec<Locator>,
     have_timestamp: bool,
     timestamp: rtps_messages::types::Time,
     submessage_iter: core::slice::Iter<'a, RtpsSubmessageReadKind>,
@@ -33,12 +34,29 @@ impl<'a> Iterator for MessageReceiver<'a> {
                 | RtpsSubmessageReadKind::Gap(_)
                 | RtpsSubmessageReadKind::Heartbeat(_)
                 | RtpsSubmessageReadKind::HeartbeatFrag(_)
-                | RtpsSubmessageReadKind::NackFrag(_) => return Some(submessage),
+                | RtpsSubmessageReadKind::NackFrag(_) => {
+                    // Entity submessages following an INFO_DST addressed to another
+                    // participant are not meant for this one
+                    if self.dest_guid_prefix == self.own_guid_prefix {
+                        return Some(submessage);
+                    }
+                }
 
                 RtpsSubmessageReadKind::InfoDestination(m) => {
-                    self.dest_guid_prefix = m.guid_prefix();
+                    self.dest_guid_prefix = if m.guid_prefix() == GUIDPREFIX_UNKNOWN {
+                        self.own_guid_prefix
+                    } else {
+                        m.guid_prefix()
+                    };
+                }
+                RtpsSubmessageReadKind::InfoReply(m) => {
+                    self.unicast_reply_locator_list = m.unicast_locator_list().value().to_vec();
+                    self.multicast_reply_locator_list = if m.multicast_flag() {
+                        m.multicast_locator_list().value().to_vec()
+                    } else {
+                        Vec::new()
+                    };
                 }
-                RtpsSubmessageReadKind::InfoReply(_) => todo!(),
                 RtpsSubmessageReadKind::InfoSource(m) => {
                     self.source_vendor_id = m.vendor_id();
                     self.source_version = m.protocol_version();
@@ -61,15 +79,16 @@ impl<'a> Iterator for MessageReceiver<'a> {
 }
 
 impl<'a> MessageReceiver<'a> {
-    pub fn new(message: &'a RtpsMessageRead) -> Self {
+    pub fn new(message: &'a RtpsMessageRead, own_guid_prefix: GuidPrefix) -> Self {
         let header = message.header();
         Self {
+            own_guid_prefix,
             source_version: header.version(),
             source_vendor_id: header.vendor_id(),
             source_guid_prefix: header.guid_prefix(),
-            dest_guid_prefix: GUIDPREFIX_UNKNOWN,
-            _unicast_reply_locator_list: Vec::new(),
-            _multicast_reply_locator_list: Vec::new(),
+            dest_guid_prefix: own_guid_prefix,
+            unicast_reply_locator_list: Vec::new(),
+            multicast_reply_locator_list: Vec::new(),
             have_timestamp: false,
             timestamp: TIME_INVALID,
             submessage_iter: message.submessages().iter(),
@@ -80,6 +99,22 @@ impl<'a> MessageReceiver<'a> {
         self.source_guid_prefix
     }
 
+    pub fn source_vendor_id(&self) -> VendorId {
+        self.source_vendor_id
+    }
+
+    pub fn dest_guid_prefix(&self) -> GuidPrefix {
+        self.dest_guid_prefix
+    }
+
+    pub fn unicast_reply_locator_list(&self) -> &[Locator] {
+        &self.unicast_reply_locator_list
+    }
+
+    pub fn multicast_reply_locator_list(&self) -> &[Locator] {
+        &self.multicast_reply_locator_list
+    }
+
     pub fn source_timestamp(&self) -> Option<rtps_messages::types::Time> {
         if self.have_timestamp {
             Some(self.timestamp)
@@ -88,3 +123,168 @@ impl<'a> MessageReceiver<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rtps::types::{PROTOCOLVERSION, VENDOR_ID_S2E},
+        rtps_messages::{
+            overall_structure::{RtpsMessageHeader, RtpsMessageWrite},
+            submessage_elements::{Data, LocatorList, ParameterList},
+            submessages::{
+                data::DataSubmessage, heartbeat::HeartbeatSubmessage,
+                info_destination::InfoDestinationSubmessage, info_reply::InfoReplySubmessage,
+                info_timestamp::InfoTimestampSubmessage,
+            },
+        },
+        transport::types::ENTITYID_UNKNOWN,
+    };
+
+    const OWN_GUID_PREFIX: GuidPrefix = [1; 12];
+    const OTHER_GUID_PREFIX: GuidPrefix = [2; 12];
+
+    fn heartbeat(count: i32) -> HeartbeatSubmessage {
+        HeartbeatSubmessage::new(
+            false,
+            false,
+            ENTITYID_UNKNOWN,
+            ENTITYID_UNKNOWN,
+            1,
+            1,
+            count,
+        )
+    }
+
+    fn read_message(info_dst_guid_prefix: GuidPrefix) -> RtpsMessageRead {
+        let header = RtpsMessageHeader::new(PROTOCOLVERSION, VENDOR_ID_S2E, [3; 12]);
+        let message = RtpsMessageWrite::new(
+            &header,
+            &[
+                &heartbeat(1),
+                &InfoDestinationSubmessage::new(info_dst_guid_prefix),
+                &heartbeat(2),
+            ],
+        );
+        RtpsMessageRead::try_from(message.buffer()).unwrap()
+    }
+
+    #[test]
+    fn submessages_for_other_participant_are_skipped() {
+        let message = read_message(OTHER_GUID_PREFIX);
+        let mut message_receiver = MessageReceiver::new(&message, OWN_GUID_PREFIX);
+
+        assert_eq!(
+            message_receiver.next(),
+            Some(&RtpsSubmessageReadKind::Heartbeat(heartbeat(1)))
+        );
+        assert_eq!(message_receiver.next(), None);
+        assert_eq!(message_receiver.dest_guid_prefix(), OTHER_GUID_PREFIX);
+    }
+
+    #[test]
+    fn unknown_info_destination_resets_to_own_guid_prefix() {
+        let message = read_message(GUIDPREFIX_UNKNOWN);
+        let mut message_receiver = MessageReceiver::new(&message, OWN_GUID_PREFIX);
+
+        assert_eq!(
+            message_receiver.next(),
+            Some(&RtpsSubmessageReadKind::Heartbeat(heartbeat(1)))
+        );
+        assert_eq!(
+            message_receiver.next(),
+            Some(&RtpsSubmessageReadKind::Heartbeat(heartbeat(2)))
+        );
+        assert_eq!(message_receiver.dest_guid_prefix(), OWN_GUID_PREFIX);
+    }
+
+    #[test]
+    fn info_reply_sets_reply_locator_lists() {
+        let unicast_locator = Locator::new(1, 7410, [1; 16]);
+        let multicast_locator = Locator::new(1, 7400, [2; 16]);
+        let header = RtpsMessageHeader::new(PROTOCOLVERSION, VENDOR_ID_S2E, [3; 12]);
+        let message = RtpsMessageWrite::new(
+            &header,
+            &[
+                &InfoReplySubmessage::new(
+                    true,
+                    LocatorList::new(vec![unicast_locator]),
+                    LocatorList::new(vec![multicast_locator]),
+                ),
+                &heartbeat(1),
+                &InfoReplySubmessage::new(
+                    false,
+                    LocatorList::new(vec![unicast_locator]),
+                    LocatorList::new(vec![]),
+                ),
+                &heartbeat(2),
+            ],
+        );
+        let message = RtpsMessageRead::try_from(message.buffer()).unwrap();
+        let mut message_receiver = MessageReceiver::new(&message, OWN_GUID_PREFIX);
+
+        assert_eq!(message_receiver.unicast_reply_locator_list(), &[]);
+        assert_eq!(message_receiver.multicast_reply_locator_list(), &[]);
+
+        message_receiver.next();
+        assert_eq!(
+            message_receiver.unicast_reply_locator_list(),
+            &[unicast_locator]
+        );
+        assert_eq!(
+            message_receiver.multicast_reply_locator_list(),
+            &[multicast_locator]
+        );
+
+        message_receiver.next();
+        assert_eq!(
+            message_receiver.unicast_reply_locator_list(),
+            &[unicast_locator]
+        );
+        assert_eq!(message_receiver.multicast_reply_locator_list(), &[]);
+    }
+
+    #[test]
+    fn info_timestamp_sets_and_invalidates_source_timestamp() {
+        let data = |writer_sn| {
+            DataSubmessage::new(
+                false,
+                true,
+                false,
+                false,
+                ENTITYID_UNKNOWN,
+                ENTITYID_UNKNOWN,
+                writer_sn,
+                ParameterList::empty(),
+                Data::new(vec![1, 2, 3, 4].into()),
+            )
+        };
+        let timestamp = rtps_messages::types::Time::new(10, 20);
+        let header = RtpsMessageHeader::new(PROTOCOLVERSION, VENDOR_ID_S2E, [3; 12]);
+        let message = RtpsMessageWrite::new(
+            &header,
+            &[
+                &InfoTimestampSubmessage::new(false, timestamp),
+                &data(1),
+                &InfoTimestampSubmessage::new(true, TIME_INVALID),
+                &data(2),
+            ],
+        );
+        let message = RtpsMessageRead::try_from(message.buffer()).unwrap();
+        let mut message_receiver = MessageReceiver::new(&message, OWN_GUID_PREFIX);
+
+        assert_eq!(message_receiver.source_timestamp(), None);
+
+        assert_eq!(
+            message_receiver.next(),
+            Some(&RtpsSubmessageReadKind::Data(data(1)))
+        );
+        assert_eq!(message_receiver.source_timestamp(), Some(timestamp));
+
+        assert_eq!(
+            message_receiver.next(),
+            Some(&RtpsSubmessageReadKind::Data(data(2)))
+        );
+        assert_eq!(message_receiver.source_timestamp(), None);
+    }
+}
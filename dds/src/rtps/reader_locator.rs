@@ -1,9 +1,11 @@
 use crate::transport::types::{CacheChange, Locator, SequenceNumber};
+use alloc::vec::Vec;
 
 pub struct RtpsReaderLocator {
     locator: Locator,
     _expects_inline_qos: bool,
     highest_sent_change_sn: SequenceNumber,
+    requested_changes: Vec<SequenceNumber>,
 }
 
 impl RtpsReaderLocator {
@@ -12,6 +14,7 @@ impl RtpsReaderLocator {
             locator,
             _expects_inline_qos: expects_inline_qos,
             highest_sent_change_sn: 0,
+            requested_changes: Vec::new(),
         }
     }
 
@@ -36,4 +39,27 @@ impl RtpsReaderLocator {
     pub fn set_highest_sent_change_sn(&mut self, highest_sent_change_sn: SequenceNumber) {
         self.highest_sent_change_sn = highest_sent_change_sn;
     }
+
+    pub fn next_requested_change(&mut self) -> Option<SequenceNumber> {
+        // next_seq_num := MIN { change.sequenceNumber SUCH-THAT change IN this.requested_changes() }
+        // return change IN this.requested_changes() SUCH-THAT (change.sequenceNumber == next_seq_num);
+        let next_requested_change = self.requested_changes.iter().min().cloned();
+
+        if let Some(next_sn) = &next_requested_change {
+            self.requested_changes.retain(|sn| sn != next_sn);
+        }
+
+        next_requested_change
+    }
+
+    pub fn requested_changes_set(&mut self, req_seq_num_set: impl Iterator<Item = SequenceNumber>) {
+        // FOR_EACH seq_num IN req_seq_num_set DO
+        //     this.requested_changes := this.requested_changes() + seq_num
+        // END
+        for seq_num in req_seq_num_set {
+            if !self.requested_changes.contains(&seq_num) {
+                self.requested_changes.push(seq_num);
+            }
+        }
+    }
 }
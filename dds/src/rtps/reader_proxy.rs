@@ -92,6 +92,8 @@ pub struct RtpsReaderProxy {
     highest_sent_seq_num: SequenceNumber,
     highest_acked_seq_num: SequenceNumber,
     requested_changes: Vec<SequenceNumber>,
+    // Time at which each not yet acknowledged change was last repaired
+    repaired_changes: Vec<(SequenceNumber, core::time::Duration)>,
     expects_inline_qos: bool,
     is_active: bool,
     last_received_acknack_count: Count,
@@ -126,6 +128,7 @@ impl RtpsReaderProxy {
             highest_sent_seq_num: 0,
             highest_acked_seq_num: 0,
             requested_changes: Vec::new(),
+            repaired_changes: Vec::new(),
             expects_inline_qos,
             is_active,
             last_received_acknack_count: 0,
@@ -146,6 +149,16 @@ impl RtpsReaderProxy {
         self.unicast_locator_list.as_slice()
     }
 
+    /// Locators the messages for the reader are sent to. These are all its unicast locators or,
+    /// if the reader has none, its multicast locators.
+    pub fn locator_list(&self) -> &[Locator] {
+        if self.unicast_locator_list.is_empty() {
+            self.multicast_locator_list.as_slice()
+        } else {
+            self.unicast_locator_list.as_slice()
+        }
+    }
+
     pub fn reliability(&self) -> ReliabilityKind {
         self.reliability
     }
@@ -168,6 +181,8 @@ impl RtpsReaderProxy {
         if committed_seq_num > self.highest_acked_seq_num {
             self.highest_acked_seq_num = committed_seq_num
         }
+        self.repaired_changes
+            .retain(|(sn, _)| *sn > self.highest_acked_seq_num);
     }
 
     pub fn next_requested_change(&mut self) -> Option<SequenceNumber> {
@@ -251,6 +266,30 @@ impl RtpsReaderProxy {
         self.first_relevant_sample_seq_num = seq_num;
     }
 
+    pub fn set_change_repaired(&mut self, seq_num: SequenceNumber, now: core::time::Duration) {
+        match self
+            .repaired_changes
+            .iter_mut()
+            .find(|(sn, _)| *sn == seq_num)
+        {
+            Some((_, repair_time)) => *repair_time = now,
+            None => self.repaired_changes.push((seq_num, now)),
+        }
+    }
+
+    /// A change repaired less than `nack_suppression_duration` ago is not sent again, so that
+    /// NACKs sent before the repair was received do not trigger repeated retransmissions
+    pub fn is_repair_suppressed(
+        &self,
+        seq_num: SequenceNumber,
+        now: core::time::Duration,
+        nack_suppression_duration: core::time::Duration,
+    ) -> bool {
+        self.repaired_changes.iter().any(|&(sn, repair_time)| {
+            sn == seq_num && now - repair_time < nack_suppression_duration
+        })
+    }
+
     pub fn last_received_acknack_count(&self) -> Count {
         self.last_received_acknack_count
     }
@@ -150,11 +150,14 @@ impl RtpsStatefulReader {
             .iter_mut()
             .find(|w| w.remote_writer_guid() == writer_guid)
         {
-            for seq_num in gap_submessage.gap_start()..gap_submessage.gap_list().base() {
-                writer_proxy.irrelevant_change_set(seq_num)
+            if gap_submessage.gap_start() < gap_submessage.gap_list().base() {
+                writer_proxy.irrelevant_change_range_set(
+                    gap_submessage.gap_start(),
+                    gap_submessage.gap_list().base() - 1,
+                );
             }
 
-            for seq_num in gap_submessage.gap_list().set() {
+            for seq_num in gap_submessage.gap_list().iter() {
                 writer_proxy.irrelevant_change_set(seq_num)
             }
         }
@@ -211,7 +214,7 @@ impl RtpsStatefulReader {
         message_writer: &impl WriteMessage,
     ) -> RtpsResult<()> {
         let rtps_message = RtpsMessageRead::try_from(datagram)?;
-        let mut message_receiver = MessageReceiver::new(&rtps_message);
+        let mut message_receiver = MessageReceiver::new(&rtps_message, self.guid.prefix());
 
         while let Some(submessage) = message_receiver.next() {
             match submessage {
@@ -267,3 +270,150 @@ impl RtpsStatefulReader {
             .any(|p| !p.is_historical_data_received())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rtps_messages::{
+            submessage_elements::{Data, ParameterList, SequenceNumberSet},
+            types::Count,
+        },
+        std_runtime::executor::block_on,
+        transport::types::{
+            DurabilityKind, EntityId, Locator, SequenceNumber, ENTITYID_UNKNOWN,
+            LOCATOR_KIND_UDP_V4,
+        },
+    };
+    use alloc::sync::Arc;
+    use core::{future::Future, pin::Pin};
+    use std::sync::Mutex;
+
+    struct MockMessageWriter {
+        sent_messages: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl WriteMessage for MockMessageWriter {
+        async fn write_message(&self, datagram: &[u8], _locator_list: &[Locator]) {
+            self.sent_messages.lock().unwrap().push(datagram.to_vec());
+        }
+
+        fn guid_prefix(&self) -> GuidPrefix {
+            [2; 12]
+        }
+    }
+
+    struct MockHistoryCache;
+
+    impl HistoryCache for MockHistoryCache {
+        fn add_change(
+            &mut self,
+            _cache_change: CacheChange,
+        ) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+            Box::pin(async {})
+        }
+
+        fn remove_change(
+            &mut self,
+            _sequence_number: i64,
+        ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            Box::pin(async {})
+        }
+    }
+
+    fn heartbeat(writer_guid: Guid, last_sn: SequenceNumber, count: Count) -> HeartbeatSubmessage {
+        HeartbeatSubmessage::new(
+            false,
+            false,
+            ENTITYID_UNKNOWN,
+            writer_guid.entity_id(),
+            1,
+            last_sn,
+            count,
+        )
+    }
+
+    fn last_requested_changes(
+        message_writer: &MockMessageWriter,
+    ) -> (SequenceNumber, Vec<SequenceNumber>) {
+        let sent_messages = message_writer.sent_messages.lock().unwrap();
+        let message = RtpsMessageRead::try_from(sent_messages.last().unwrap().as_slice()).unwrap();
+        let acknack = message
+            .submessages()
+            .iter()
+            .find_map(|s| match s {
+                RtpsSubmessageReadKind::AckNack(a) => Some(a),
+                _ => None,
+            })
+            .expect("AckNack must be sent");
+        (
+            acknack.reader_sn_state().base(),
+            acknack.reader_sn_state().iter().collect(),
+        )
+    }
+
+    #[test]
+    fn gapped_changes_are_not_requested_again() {
+        let reader_guid = Guid::new([2; 12], EntityId::new([1, 2, 3], 0x07));
+        let writer_guid = Guid::new([1; 12], EntityId::new([1, 2, 3], 0x02));
+        let mut reader = RtpsStatefulReader::new(
+            reader_guid,
+            Box::new(MockHistoryCache),
+            ReliabilityKind::Reliable,
+        );
+        reader.add_matched_writer(&WriterProxy {
+            remote_writer_guid: writer_guid,
+            remote_group_entity_id: ENTITYID_UNKNOWN,
+            reliability_kind: ReliabilityKind::Reliable,
+            durability_kind: DurabilityKind::Volatile,
+            unicast_locator_list: vec![Locator::new(LOCATOR_KIND_UDP_V4, 7400, [0; 16])],
+            multicast_locator_list: vec![],
+        });
+        let message_writer = MockMessageWriter {
+            sent_messages: Mutex::new(Vec::new()),
+        };
+
+        block_on(reader.on_heartbeat_submessage_received(
+            &heartbeat(writer_guid, 4, 1),
+            writer_guid.prefix(),
+            &message_writer,
+        ));
+        assert_eq!(
+            last_requested_changes(&message_writer),
+            (1, vec![1, 2, 3, 4])
+        );
+
+        let gap = GapSubmessage::new(
+            reader_guid.entity_id(),
+            writer_guid.entity_id(),
+            2,
+            SequenceNumberSet::new(4, []),
+        );
+        reader.on_gap_submessage_received(&gap, writer_guid.prefix());
+        block_on(reader.on_heartbeat_submessage_received(
+            &heartbeat(writer_guid, 4, 2),
+            writer_guid.prefix(),
+            &message_writer,
+        ));
+        assert_eq!(last_requested_changes(&message_writer), (1, vec![1, 4]));
+
+        let data = DataSubmessage::new(
+            false,
+            true,
+            false,
+            false,
+            reader_guid.entity_id(),
+            writer_guid.entity_id(),
+            1,
+            ParameterList::empty(),
+            Data::new(Arc::from([0, 1, 0, 0, 1, 2, 3, 4])),
+        );
+        block_on(reader.on_data_submessage_received(&data, writer_guid.prefix(), None));
+        block_on(reader.on_heartbeat_submessage_received(
+            &heartbeat(writer_guid, 4, 3),
+            writer_guid.prefix(),
+            &message_writer,
+        ));
+        assert_eq!(last_requested_changes(&message_writer), (4, vec![4]));
+    }
+}
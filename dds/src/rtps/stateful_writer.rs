@@ -3,7 +3,7 @@ use super::{
     message_sender::Clock, reader_proxy::RtpsReaderProxy,
 };
 use crate::{
-    rtps::message_sender::WriteMessage,
+    rtps::{message_creator::PAYLOAD_MESSAGE_OVERHEAD, message_sender::WriteMessage},
     rtps_messages::{
         overall_structure::{RtpsMessageRead, RtpsMessageWrite, RtpsSubmessageReadKind},
         submessage_elements::{ParameterList, SequenceNumberSet, SerializedDataFragment},
@@ -24,8 +24,11 @@ use alloc::vec::Vec;
 pub struct RtpsStatefulWriter {
     guid: Guid,
     changes: Vec<CacheChange>,
+    // Kept in matching order so that messages are sent to the readers in a reproducible order
     matched_readers: Vec<RtpsReaderProxy>,
     heartbeat_period: Duration,
+    nack_suppression_duration: Duration,
+    heartbeat_on_reader_match: bool,
     data_max_size_serialized: usize,
 }
 
@@ -36,6 +39,8 @@ impl RtpsStatefulWriter {
             changes: Vec::new(),
             matched_readers: Vec::new(),
             heartbeat_period: Duration::from_millis(200),
+            nack_suppression_duration: Duration::from_millis(0),
+            heartbeat_on_reader_match: true,
             data_max_size_serialized,
         }
     }
@@ -44,6 +49,18 @@ impl RtpsStatefulWriter {
         self.guid
     }
 
+    pub fn set_heartbeat_period(&mut self, heartbeat_period: Duration) {
+        self.heartbeat_period = heartbeat_period;
+    }
+
+    pub fn set_nack_suppression_duration(&mut self, nack_suppression_duration: Duration) {
+        self.nack_suppression_duration = nack_suppression_duration;
+    }
+
+    pub fn set_heartbeat_on_reader_match(&mut self, heartbeat_on_reader_match: bool) {
+        self.heartbeat_on_reader_match = heartbeat_on_reader_match;
+    }
+
     pub fn data_max_size_serialized(&self) -> usize {
         self.data_max_size_serialized
     }
@@ -65,7 +82,12 @@ impl RtpsStatefulWriter {
             .any(|rp| rp.unacked_changes(Some(sequence_number)))
     }
 
-    pub fn add_matched_reader(&mut self, reader_proxy: &ReaderProxy) {
+    pub async fn add_matched_reader(
+        &mut self,
+        reader_proxy: &ReaderProxy,
+        message_writer: &impl WriteMessage,
+        clock: &impl Clock,
+    ) {
         let first_relevant_sample_seq_num = match reader_proxy.durability_kind {
             DurabilityKind::Volatile => self
                 .changes
@@ -77,7 +99,7 @@ impl RtpsStatefulWriter {
             | DurabilityKind::Transient
             | DurabilityKind::Persistent => 0,
         };
-        let rtps_reader_proxy = RtpsReaderProxy::new(
+        let mut rtps_reader_proxy = RtpsReaderProxy::new(
             reader_proxy.remote_reader_guid,
             reader_proxy.remote_group_entity_id,
             &reader_proxy.unicast_locator_list,
@@ -88,14 +110,66 @@ impl RtpsStatefulWriter {
             first_relevant_sample_seq_num,
             reader_proxy.durability_kind,
         );
-        if let Some(rp) = self
+        // A best-effort reader is never told about the changes it missed so the changes written
+        // before a volatile reader matched are considered as already sent
+        if reader_proxy.reliability_kind == ReliabilityKind::BestEffort {
+            rtps_reader_proxy.set_highest_sent_seq_num(first_relevant_sample_seq_num);
+        }
+        let rp = match self
             .matched_readers
             .iter_mut()
-            .find(|rp| rp.remote_reader_guid() == reader_proxy.remote_reader_guid)
+            .position(|rp| rp.remote_reader_guid() == reader_proxy.remote_reader_guid)
         {
-            *rp = rtps_reader_proxy;
-        } else {
-            self.matched_readers.push(rtps_reader_proxy);
+            Some(i) => {
+                // Keep the heartbeat count of the known reader so that the heartbeats sent after
+                // the update are not discarded by the reader as already received
+                core::mem::swap(
+                    rtps_reader_proxy.heartbeat_machine(),
+                    self.matched_readers[i].heartbeat_machine(),
+                );
+                self.matched_readers[i] = rtps_reader_proxy;
+                &mut self.matched_readers[i]
+            }
+            None => {
+                self.matched_readers.push(rtps_reader_proxy);
+                self.matched_readers
+                    .last_mut()
+                    .expect("Reader proxy was just added")
+            }
+        };
+
+        // Announce the available changes to a new reliable reader right away so that it can
+        // request the history without having to wait for the next periodic heartbeat
+        if self.heartbeat_on_reader_match && rp.reliability() == ReliabilityKind::Reliable {
+            let last_sn = self
+                .changes
+                .iter()
+                .map(|cc| cc.sequence_number)
+                .max()
+                .unwrap_or(0);
+            // The changes written before a volatile reader matched are not relevant to it
+            let first_sn = self
+                .changes
+                .iter()
+                .map(|cc| cc.sequence_number)
+                .filter(|&sn| sn > first_relevant_sample_seq_num)
+                .min()
+                .unwrap_or(last_sn + 1);
+            let heartbeat_submessage = rp.heartbeat_machine().generate_new_heartbeat(
+                self.guid.entity_id(),
+                first_sn,
+                last_sn,
+                clock.now(),
+                false,
+            );
+            let info_dst = InfoDestinationSubmessage::new(rp.remote_reader_guid().prefix());
+            let rtps_message = RtpsMessageWrite::from_submessages(
+                &[&info_dst, &heartbeat_submessage],
+                message_writer.guid_prefix(),
+            );
+            message_writer
+                .write_message(rtps_message.buffer(), rp.locator_list())
+                .await;
         }
     }
 
@@ -154,7 +228,19 @@ impl RtpsStatefulWriter {
                     && acknack_submessage.count() > reader_proxy.last_received_acknack_count()
                 {
                     reader_proxy.acked_changes_set(acknack_submessage.reader_sn_state().base() - 1);
-                    reader_proxy.requested_changes_set(acknack_submessage.reader_sn_state().set());
+                    let now = clock.now();
+                    let requested_changes: Vec<SequenceNumber> = acknack_submessage
+                        .reader_sn_state()
+                        .iter()
+                        .filter(|&sn| {
+                            !reader_proxy.is_repair_suppressed(
+                                sn,
+                                now,
+                                self.nack_suppression_duration.into(),
+                            )
+                        })
+                        .collect();
+                    reader_proxy.requested_changes_set(requested_changes.into_iter());
 
                     reader_proxy.set_last_received_acknack_count(acknack_submessage.count());
 
@@ -219,7 +305,7 @@ impl RtpsStatefulWriter {
         clock: &impl Clock,
     ) -> RtpsResult<()> {
         let rtps_message = RtpsMessageRead::try_from(datagram)?;
-        let mut message_receiver = MessageReceiver::new(&rtps_message);
+        let mut message_receiver = MessageReceiver::new(&rtps_message, self.guid.prefix());
 
         while let Some(submessage) = message_receiver.next() {
             match &submessage {
@@ -294,7 +380,7 @@ async fn write_message_to_reader_proxy_best_effort(
                 message_writer.guid_prefix(),
             );
             message_writer
-                .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                 .await;
 
             reader_proxy.set_highest_sent_seq_num(next_unsent_change_seq_num);
@@ -358,12 +444,13 @@ async fn write_message_to_reader_proxy_best_effort(
                         ParameterList::new(Vec::new()),
                         serialized_payload,
                     );
-                    let rtps_message = RtpsMessageWrite::from_submessages(
+                    let rtps_message = RtpsMessageWrite::from_submessages_with_capacity(
                         &[&info_dst, &info_timestamp, &data_frag],
                         message_writer.guid_prefix(),
+                        end - start + PAYLOAD_MESSAGE_OVERHEAD,
                     );
                     message_writer
-                        .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                        .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                         .await;
                 }
             } else {
@@ -379,12 +466,13 @@ async fn write_message_to_reader_proxy_best_effort(
                 let data_submessage = cache_change
                     .as_data_submessage(reader_proxy.remote_reader_guid().entity_id(), writer_id);
 
-                let rtps_message = RtpsMessageWrite::from_submessages(
+                let rtps_message = RtpsMessageWrite::from_submessages_with_capacity(
                     &[&info_dst, &info_timestamp, &data_submessage],
                     message_writer.guid_prefix(),
+                    cache_change.data_value.len() + PAYLOAD_MESSAGE_OVERHEAD,
                 );
                 message_writer
-                    .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                    .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                     .await;
             }
         } else {
@@ -399,7 +487,7 @@ async fn write_message_to_reader_proxy_best_effort(
                 message_writer.guid_prefix(),
             );
             message_writer
-                .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                 .await;
         }
 
@@ -445,7 +533,7 @@ async fn write_message_to_reader_proxy_reliable(
                     message_writer.guid_prefix(),
                 );
                 message_writer
-                    .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                    .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                     .await;
             } else {
                 write_change_message_reader_proxy_reliable(
@@ -484,7 +572,7 @@ async fn write_message_to_reader_proxy_reliable(
                 message_writer.guid_prefix(),
             );
             message_writer
-                .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                 .await;
         }
     } else if reader_proxy
@@ -504,18 +592,50 @@ async fn write_message_to_reader_proxy_reliable(
             message_writer.guid_prefix(),
         );
         message_writer
-            .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+            .write_message(rtps_message.buffer(), reader_proxy.locator_list())
             .await;
     }
 
     // Middle-part of the state-machine - Figure 8.19 RTPS standard
     if !reader_proxy.requested_changes().is_empty() {
+        // Changes requested below the first available change have already been removed from the
+        // history cache so they are all announced as irrelevant with a single Gap
+        let first_available_seq_num =
+            seq_num_min.unwrap_or(reader_proxy.highest_sent_seq_num() + 1);
+        let unavailable_requested_changes: Vec<SequenceNumber> = reader_proxy
+            .requested_changes()
+            .into_iter()
+            .filter(|&sn| sn < first_available_seq_num)
+            .collect();
+        if let Some(&gap_start_sequence_number) = unavailable_requested_changes.iter().min() {
+            // The unavailable changes are the lowest requested ones
+            for _ in &unavailable_requested_changes {
+                reader_proxy.next_requested_change();
+            }
+            let info_dst =
+                InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
+            let gap_submessage = GapSubmessage::new(
+                reader_proxy.remote_reader_guid().entity_id(),
+                writer_id,
+                gap_start_sequence_number,
+                SequenceNumberSet::new(first_available_seq_num, []),
+            );
+            let rtps_message = RtpsMessageWrite::from_submessages(
+                &[&info_dst, &gap_submessage],
+                message_writer.guid_prefix(),
+            );
+            message_writer
+                .write_message(rtps_message.buffer(), reader_proxy.locator_list())
+                .await;
+        }
+
         while let Some(next_requested_change_seq_num) = reader_proxy.next_requested_change() {
             // "a_change.status := UNDERWAY;" should be done by next_requested_change() as
             // it's not done here to avoid the change being a mutable reference
             // Also the post-condition:
             // a_change BELONGS-TO the_reader_proxy.requested_changes() ) == FALSE
             // should be full-filled by next_requested_change()
+            reader_proxy.set_change_repaired(next_requested_change_seq_num, now);
             write_change_message_reader_proxy_reliable(
                 reader_proxy,
                 writer_id,
@@ -607,12 +727,28 @@ async fn write_change_message_reader_proxy_reliable(
                         serialized_payload,
                     );
 
-                    let rtps_message = RtpsMessageWrite::from_submessages(
-                        &[&info_dst, &info_timestamp, &data_frag],
-                        message_writer.guid_prefix(),
-                    );
+                    // The heartbeat soliciting the acknowledgment of the change is piggybacked
+                    // on the last fragment
+                    let rtps_message = if frag_index + 1 == number_of_fragments {
+                        let first_sn = seq_num_min.unwrap_or(1);
+                        let last_sn = seq_num_max.unwrap_or(0);
+                        let heartbeat = reader_proxy
+                            .heartbeat_machine()
+                            .generate_new_heartbeat(writer_id, first_sn, last_sn, now, false);
+                        RtpsMessageWrite::from_submessages_with_capacity(
+                            &[&info_dst, &info_timestamp, &data_frag, &heartbeat],
+                            message_writer.guid_prefix(),
+                            end - start + PAYLOAD_MESSAGE_OVERHEAD,
+                        )
+                    } else {
+                        RtpsMessageWrite::from_submessages_with_capacity(
+                            &[&info_dst, &info_timestamp, &data_frag],
+                            message_writer.guid_prefix(),
+                            end - start + PAYLOAD_MESSAGE_OVERHEAD,
+                        )
+                    };
                     message_writer
-                        .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                        .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                         .await;
                 }
             } else {
@@ -634,21 +770,24 @@ async fn write_change_message_reader_proxy_reliable(
                     .heartbeat_machine()
                     .generate_new_heartbeat(writer_id, first_sn, last_sn, now, false);
 
-                let rtps_message = RtpsMessageWrite::from_submessages(
+                let rtps_message = RtpsMessageWrite::from_submessages_with_capacity(
                     &[&info_dst, &info_timestamp, &data_submessage, &heartbeat],
                     message_writer.guid_prefix(),
+                    cache_change.data_value.len() + PAYLOAD_MESSAGE_OVERHEAD,
                 );
                 message_writer
-                    .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                    .write_message(rtps_message.buffer(), reader_proxy.locator_list())
                     .await;
             }
         }
         _ => {
+            // The change is either no longer available or irrelevant to this reader only, so the
+            // Gap must not be addressed to the other readers of the participant
             let info_dst =
                 InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
 
             let gap_submessage = GapSubmessage::new(
-                ENTITYID_UNKNOWN,
+                reader_proxy.remote_reader_guid().entity_id(),
                 writer_id,
                 change_seq_num,
                 SequenceNumberSet::new(change_seq_num + 1, []),
@@ -659,8 +798,686 @@ async fn write_change_message_reader_proxy_reliable(
                 message_writer.guid_prefix(),
             );
             message_writer
-                .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                .write_message(rtps_message.buffer(), reader_proxy.locator_list())
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        std_runtime::executor::block_on,
+        transport::types::{Locator, LOCATOR_KIND_UDP_V4},
+    };
+    use std::sync::Mutex;
+
+    struct MockMessageWriter {
+        sent_messages: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl WriteMessage for MockMessageWriter {
+        async fn write_message(&self, datagram: &[u8], _locator_list: &[Locator]) {
+            self.sent_messages.lock().unwrap().push(datagram.to_vec());
+        }
+
+        fn guid_prefix(&self) -> GuidPrefix {
+            [1; 12]
+        }
+    }
+
+    struct LocatorRecordingMessageWriter {
+        locator_list: Mutex<Vec<Vec<Locator>>>,
+    }
+
+    impl WriteMessage for LocatorRecordingMessageWriter {
+        async fn write_message(&self, _datagram: &[u8], locator_list: &[Locator]) {
+            self.locator_list
+                .lock()
+                .unwrap()
+                .push(locator_list.to_vec());
+        }
+
+        fn guid_prefix(&self) -> GuidPrefix {
+            [1; 12]
+        }
+    }
+
+    struct MockClock;
+
+    impl Clock for MockClock {
+        fn now(&self) -> core::time::Duration {
+            core::time::Duration::from_secs(10)
+        }
+    }
+
+    // Clock whose time only advances when the test tells it to
+    struct ManualClock(core::cell::Cell<core::time::Duration>);
+
+    impl ManualClock {
+        fn new(now: core::time::Duration) -> Self {
+            Self(core::cell::Cell::new(now))
+        }
+
+        fn advance(&self, duration: core::time::Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> core::time::Duration {
+            self.0.get()
+        }
+    }
+
+    const WRITER_GUID: Guid = Guid::new([1; 12], EntityId::new([1, 2, 3], 0x02));
+    const READER_GUID: Guid = Guid::new([2; 12], EntityId::new([1, 2, 3], 0x07));
+
+    impl MockMessageWriter {
+        fn new() -> Self {
+            Self {
+                sent_messages: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    fn cache_change(sequence_number: SequenceNumber) -> CacheChange {
+        CacheChange {
+            kind: ChangeKind::Alive,
+            writer_guid: WRITER_GUID,
+            sequence_number,
+            source_timestamp: None,
+            instance_handle: None,
+            data_value: vec![0, 1, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
+        }
+    }
+
+    fn stateful_writer(
+        data_max_size_serialized: usize,
+        sequence_number_list: &[SequenceNumber],
+    ) -> RtpsStatefulWriter {
+        let mut writer = RtpsStatefulWriter::new(WRITER_GUID, data_max_size_serialized);
+        for &sequence_number in sequence_number_list {
+            writer.add_change(cache_change(sequence_number));
+        }
+        writer
+    }
+
+    fn reader_proxy(
+        reliability_kind: ReliabilityKind,
+        durability_kind: DurabilityKind,
+    ) -> ReaderProxy {
+        ReaderProxy {
+            remote_reader_guid: READER_GUID,
+            remote_group_entity_id: ENTITYID_UNKNOWN,
+            reliability_kind,
+            durability_kind,
+            unicast_locator_list: vec![Locator::new(LOCATOR_KIND_UDP_V4, 7400, [0; 16])],
+            multicast_locator_list: vec![],
+            expects_inline_qos: false,
+        }
+    }
+
+    #[test]
+    fn matching_reliable_reader_sends_heartbeat() {
+        let mut writer = stateful_writer(1344, &[2, 3, 4]);
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let message_writer = MockMessageWriter::new();
+
+        block_on(writer.add_matched_reader(&reader_proxy, &message_writer, &MockClock));
+
+        let sent_messages = message_writer.sent_messages.into_inner().unwrap();
+        assert_eq!(sent_messages.len(), 1);
+        let message = RtpsMessageRead::try_from(sent_messages[0].as_slice()).unwrap();
+        let heartbeat = message
+            .submessages()
+            .iter()
+            .find_map(|s| match s {
+                RtpsSubmessageReadKind::Heartbeat(h) => Some(h),
+                _ => None,
+            })
+            .expect("Heartbeat must be sent");
+        assert_eq!(heartbeat.first_sn(), 2);
+        assert_eq!(heartbeat.last_sn(), 4);
+        assert_eq!(heartbeat.writer_id(), WRITER_GUID.entity_id());
+    }
+
+    #[test]
+    fn matching_volatile_reader_heartbeat_starts_after_the_existing_changes() {
+        let mut writer = stateful_writer(1344, &[2, 3]);
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::Volatile);
+        let message_writer = MockMessageWriter::new();
+
+        block_on(writer.add_matched_reader(&reader_proxy, &message_writer, &MockClock));
+
+        let sent_messages = message_writer.sent_messages.into_inner().unwrap();
+        assert_eq!(sent_messages.len(), 1);
+        let message = RtpsMessageRead::try_from(sent_messages[0].as_slice()).unwrap();
+        let heartbeat = message
+            .submessages()
+            .iter()
+            .find_map(|s| match s {
+                RtpsSubmessageReadKind::Heartbeat(h) => Some(h),
+                _ => None,
+            })
+            .expect("Heartbeat must be sent");
+        assert_eq!(heartbeat.first_sn(), 4);
+        assert_eq!(heartbeat.last_sn(), 3);
+    }
+
+    #[test]
+    fn matching_reliable_reader_sends_no_heartbeat_when_disabled() {
+        let mut writer = stateful_writer(1344, &[1]);
+        writer.set_heartbeat_on_reader_match(false);
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let message_writer = MockMessageWriter::new();
+
+        block_on(writer.add_matched_reader(&reader_proxy, &message_writer, &MockClock));
+
+        assert!(message_writer
+            .sent_messages
+            .into_inner()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn messages_are_sent_to_all_unicast_locators_of_the_reader() {
+        let mut writer = stateful_writer(1344, &[1]);
+
+        let unicast_locator_list = vec![
+            Locator::new(
+                LOCATOR_KIND_UDP_V4,
+                7410,
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 168, 0, 2],
+            ),
+            Locator::new(
+                LOCATOR_KIND_UDP_V4,
+                7410,
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 2],
+            ),
+        ];
+        let reader_proxy = ReaderProxy {
+            unicast_locator_list: unicast_locator_list.clone(),
+            multicast_locator_list: vec![Locator::new(
+                LOCATOR_KIND_UDP_V4,
+                7401,
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 255, 0, 1],
+            )],
+            ..reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal)
+        };
+        let message_writer = LocatorRecordingMessageWriter {
+            locator_list: Mutex::new(Vec::new()),
+        };
+
+        block_on(writer.add_matched_reader(&reader_proxy, &message_writer, &MockClock));
+
+        let locator_list = message_writer.locator_list.into_inner().unwrap();
+        assert!(!locator_list.is_empty());
+        for message_locator_list in locator_list {
+            assert_eq!(message_locator_list, unicast_locator_list);
+        }
+    }
+
+    #[test]
+    fn messages_are_sent_to_multicast_locators_of_reader_without_unicast_locators() {
+        let mut writer = stateful_writer(1344, &[1]);
+
+        let multicast_locator_list = vec![Locator::new(
+            LOCATOR_KIND_UDP_V4,
+            7401,
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 255, 0, 1],
+        )];
+        let reader_proxy = ReaderProxy {
+            unicast_locator_list: vec![],
+            multicast_locator_list: multicast_locator_list.clone(),
+            ..reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal)
+        };
+        let message_writer = LocatorRecordingMessageWriter {
+            locator_list: Mutex::new(Vec::new()),
+        };
+
+        block_on(writer.add_matched_reader(&reader_proxy, &message_writer, &MockClock));
+
+        let locator_list = message_writer.locator_list.into_inner().unwrap();
+        assert!(!locator_list.is_empty());
+        for message_locator_list in locator_list {
+            assert_eq!(message_locator_list, multicast_locator_list);
+        }
+    }
+
+    #[test]
+    fn heartbeat_count_increases_for_each_heartbeat_to_a_reader() {
+        let mut writer = stateful_writer(1344, &[]);
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let message_writer = MockMessageWriter::new();
+
+        let clock = ManualClock::new(core::time::Duration::from_secs(10));
+
+        block_on(async {
+            writer
+                .add_matched_reader(&reader_proxy, &message_writer, &clock)
                 .await;
+            clock.advance(core::time::Duration::from_secs(1));
+            writer.write_message(&message_writer, &clock).await;
+            // Matching the same reader again only updates its proxy
+            clock.advance(core::time::Duration::from_secs(1));
+            writer
+                .add_matched_reader(&reader_proxy, &message_writer, &clock)
+                .await;
+            clock.advance(core::time::Duration::from_secs(1));
+            writer.write_message(&message_writer, &clock).await;
+        });
+
+        let heartbeat_count_list: Vec<_> = message_writer
+            .sent_messages
+            .into_inner()
+            .unwrap()
+            .iter()
+            .flat_map(|m| {
+                RtpsMessageRead::try_from(m.as_slice())
+                    .unwrap()
+                    .submessages()
+                    .iter()
+                    .filter_map(|s| match s {
+                        RtpsSubmessageReadKind::Heartbeat(h) => Some(h.count()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(heartbeat_count_list.len(), 4);
+        assert!(heartbeat_count_list.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn heartbeat_is_sent_at_the_configured_period() {
+        let mut writer = stateful_writer(1344, &[]);
+        writer.set_heartbeat_period(Duration::from_millis(1000));
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let message_writer = MockMessageWriter::new();
+
+        let clock = ManualClock::new(core::time::Duration::from_secs(10));
+
+        block_on(async {
+            writer
+                .add_matched_reader(&reader_proxy, &message_writer, &clock)
+                .await;
+            assert_eq!(message_writer.sent_messages.lock().unwrap().len(), 1);
+
+            for expected_message_count in [1, 2, 2, 3] {
+                clock.advance(core::time::Duration::from_millis(500));
+                writer.write_message(&message_writer, &clock).await;
+                assert_eq!(
+                    message_writer.sent_messages.lock().unwrap().len(),
+                    expected_message_count
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn acknack_for_removed_changes_is_answered_with_gap() {
+        let mut writer = stateful_writer(1344, &[1, 2, 3]);
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let message_writer = MockMessageWriter::new();
+        block_on(async {
+            writer
+                .add_matched_reader(&reader_proxy, &message_writer, &MockClock)
+                .await;
+            writer.write_message(&message_writer, &MockClock).await;
+        });
+        writer.remove_change(1);
+        writer.remove_change(2);
+        message_writer.sent_messages.lock().unwrap().clear();
+
+        let acknack = AckNackSubmessage::new(
+            false,
+            READER_GUID.entity_id(),
+            WRITER_GUID.entity_id(),
+            SequenceNumberSet::new(1, [1, 2]),
+            1,
+        );
+        block_on(writer.on_acknack_submessage_received(
+            &acknack,
+            READER_GUID.prefix(),
+            &message_writer,
+            &MockClock,
+        ));
+
+        let sent_messages = message_writer.sent_messages.into_inner().unwrap();
+        assert_eq!(sent_messages.len(), 1);
+        let message = RtpsMessageRead::try_from(sent_messages[0].as_slice()).unwrap();
+        let gap = message
+            .submessages()
+            .iter()
+            .find_map(|s| match s {
+                RtpsSubmessageReadKind::Gap(g) => Some(g),
+                _ => None,
+            })
+            .expect("Gap must be sent");
+        assert_eq!(gap.writer_id(), WRITER_GUID.entity_id());
+        assert_eq!(gap.gap_start(), 1);
+        assert_eq!(gap.gap_list(), &SequenceNumberSet::new(3, []));
+    }
+
+    #[test]
+    fn changes_are_sent_to_readers_in_matching_order() {
+        let mut writer = stateful_writer(1344, &[]);
+        let message_writer = MockMessageWriter::new();
+
+        let reader_guid_prefix_list = [[3; 12], [2; 12], [4; 12]];
+        for reader_guid_prefix in reader_guid_prefix_list {
+            let reader_proxy = ReaderProxy {
+                remote_reader_guid: Guid::new(reader_guid_prefix, READER_GUID.entity_id()),
+                ..reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal)
+            };
+            block_on(writer.add_matched_reader(&reader_proxy, &message_writer, &MockClock));
         }
+        message_writer.sent_messages.lock().unwrap().clear();
+
+        writer.add_change(cache_change(1));
+        block_on(writer.write_message(&message_writer, &MockClock));
+
+        let destination_guid_prefix_list: Vec<GuidPrefix> = message_writer
+            .sent_messages
+            .into_inner()
+            .unwrap()
+            .iter()
+            .map(|m| {
+                let message = RtpsMessageRead::try_from(m.as_slice()).unwrap();
+                message
+                    .submessages()
+                    .iter()
+                    .find_map(|s| match s {
+                        RtpsSubmessageReadKind::InfoDestination(i) => Some(i.guid_prefix()),
+                        _ => None,
+                    })
+                    .expect("InfoDestination must be sent")
+            })
+            .collect();
+        assert_eq!(destination_guid_prefix_list, reader_guid_prefix_list);
+    }
+
+    #[test]
+    fn matching_best_effort_reader_sends_no_heartbeat() {
+        let mut writer = stateful_writer(1344, &[1]);
+
+        let reader_proxy = reader_proxy(ReliabilityKind::BestEffort, DurabilityKind::Volatile);
+        let message_writer = MockMessageWriter::new();
+
+        block_on(writer.add_matched_reader(&reader_proxy, &message_writer, &MockClock));
+
+        assert!(message_writer
+            .sent_messages
+            .into_inner()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn repeated_nack_within_suppression_duration_is_not_answered() {
+        let mut writer = stateful_writer(1344, &[1]);
+        writer.set_nack_suppression_duration(Duration::from_millis(100));
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let message_writer = MockMessageWriter::new();
+        let clock = ManualClock::new(core::time::Duration::from_secs(10));
+        let acknack = |count| {
+            AckNackSubmessage::new(
+                false,
+                READER_GUID.entity_id(),
+                WRITER_GUID.entity_id(),
+                SequenceNumberSet::new(1, [1]),
+                count,
+            )
+        };
+        let sent_data_count = |message_writer: &MockMessageWriter| {
+            message_writer
+                .sent_messages
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|m| {
+                    RtpsMessageRead::try_from(m.as_slice())
+                        .unwrap()
+                        .submessages()
+                        .iter()
+                        .filter(|s| matches!(s, RtpsSubmessageReadKind::Data(_)))
+                        .count()
+                })
+                .sum::<usize>()
+        };
+
+        block_on(async {
+            writer
+                .add_matched_reader(&reader_proxy, &message_writer, &clock)
+                .await;
+            writer.write_message(&message_writer, &clock).await;
+            message_writer.sent_messages.lock().unwrap().clear();
+
+            writer
+                .on_acknack_submessage_received(
+                    &acknack(1),
+                    READER_GUID.prefix(),
+                    &message_writer,
+                    &clock,
+                )
+                .await;
+            writer
+                .on_acknack_submessage_received(
+                    &acknack(2),
+                    READER_GUID.prefix(),
+                    &message_writer,
+                    &clock,
+                )
+                .await;
+            assert_eq!(sent_data_count(&message_writer), 1);
+
+            clock.advance(core::time::Duration::from_millis(100));
+            writer
+                .on_acknack_submessage_received(
+                    &acknack(3),
+                    READER_GUID.prefix(),
+                    &message_writer,
+                    &clock,
+                )
+                .await;
+            assert_eq!(sent_data_count(&message_writer), 2);
+        });
+    }
+
+    #[test]
+    fn heartbeat_is_piggybacked_on_last_fragment_and_final_once_acknowledged() {
+        let mut writer = stateful_writer(4, &[1]);
+
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let message_writer = MockMessageWriter::new();
+        let clock = ManualClock::new(core::time::Duration::from_secs(10));
+        block_on(async {
+            writer
+                .add_matched_reader(&reader_proxy, &message_writer, &clock)
+                .await;
+            message_writer.sent_messages.lock().unwrap().clear();
+            writer.write_message(&message_writer, &clock).await;
+        });
+
+        // The 8 byte change is sent as two fragments with the heartbeat in the last datagram
+        let sent_messages = core::mem::take(&mut *message_writer.sent_messages.lock().unwrap());
+        let submessage_kinds: Vec<Vec<_>> = sent_messages
+            .iter()
+            .map(|m| {
+                RtpsMessageRead::try_from(m.as_slice())
+                    .unwrap()
+                    .submessages()
+                    .iter()
+                    .filter_map(|s| match s {
+                        RtpsSubmessageReadKind::DataFrag(_) => Some("DataFrag"),
+                        RtpsSubmessageReadKind::Heartbeat(h) => {
+                            assert!(!h.final_flag());
+                            assert!(!h.liveliness_flag());
+                            Some("Heartbeat")
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            submessage_kinds,
+            vec![vec!["DataFrag"], vec!["DataFrag", "Heartbeat"]]
+        );
+
+        // Once everything is acknowledged the heartbeats don't require a response
+        let acknack = AckNackSubmessage::new(
+            true,
+            READER_GUID.entity_id(),
+            WRITER_GUID.entity_id(),
+            SequenceNumberSet::new(2, []),
+            1,
+        );
+        block_on(async {
+            writer
+                .on_acknack_submessage_received(
+                    &acknack,
+                    READER_GUID.prefix(),
+                    &message_writer,
+                    &clock,
+                )
+                .await;
+            message_writer.sent_messages.lock().unwrap().clear();
+            clock.advance(core::time::Duration::from_secs(1));
+            writer.write_message(&message_writer, &clock).await;
+        });
+        let sent_messages = message_writer.sent_messages.into_inner().unwrap();
+        assert_eq!(sent_messages.len(), 1);
+        let message = RtpsMessageRead::try_from(sent_messages[0].as_slice()).unwrap();
+        let heartbeat = message
+            .submessages()
+            .iter()
+            .find_map(|s| match s {
+                RtpsSubmessageReadKind::Heartbeat(h) => Some(h),
+                _ => None,
+            })
+            .expect("Heartbeat must be sent");
+        assert!(heartbeat.final_flag());
+        assert!(!heartbeat.liveliness_flag());
+    }
+
+    #[test]
+    fn history_is_sent_only_to_late_joining_transient_local_readers() {
+        let mut writer = stateful_writer(1344, &[1, 2]);
+
+        let transient_local_reader_proxy =
+            reader_proxy(ReliabilityKind::Reliable, DurabilityKind::TransientLocal);
+        let volatile_reader_proxy = ReaderProxy {
+            remote_reader_guid: Guid::new([3; 12], READER_GUID.entity_id()),
+            ..reader_proxy(ReliabilityKind::BestEffort, DurabilityKind::Volatile)
+        };
+        let message_writer = MockMessageWriter::new();
+        let sent_data = |message_writer: &MockMessageWriter| {
+            core::mem::take(&mut *message_writer.sent_messages.lock().unwrap())
+                .iter()
+                .flat_map(|m| {
+                    let message = RtpsMessageRead::try_from(m.as_slice()).unwrap();
+                    let destination = message.submessages().iter().find_map(|s| match s {
+                        RtpsSubmessageReadKind::InfoDestination(i) => Some(i.guid_prefix()),
+                        _ => None,
+                    });
+                    message
+                        .submessages()
+                        .iter()
+                        .filter_map(|s| match s {
+                            RtpsSubmessageReadKind::Data(d) => Some((destination, d.writer_sn())),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        block_on(async {
+            writer
+                .add_matched_reader(&transient_local_reader_proxy, &message_writer, &MockClock)
+                .await;
+            writer
+                .add_matched_reader(&volatile_reader_proxy, &message_writer, &MockClock)
+                .await;
+            sent_data(&message_writer);
+            writer.write_message(&message_writer, &MockClock).await;
+        });
+        assert_eq!(
+            sent_data(&message_writer),
+            vec![
+                (Some(READER_GUID.prefix()), 1),
+                (Some(READER_GUID.prefix()), 2)
+            ]
+        );
+
+        writer.add_change(cache_change(3));
+        block_on(writer.write_message(&message_writer, &MockClock));
+        let sent_data = sent_data(&message_writer);
+        assert_eq!(sent_data.len(), 2);
+        assert!(sent_data.iter().all(|(_, sn)| *sn == 3));
+    }
+
+    #[test]
+    fn acknack_for_irrelevant_changes_is_answered_with_gap_to_the_reader() {
+        let mut writer = stateful_writer(1344, &[1, 2]);
+
+        // The changes written before a volatile reader matched are irrelevant to it
+        let reader_proxy = reader_proxy(ReliabilityKind::Reliable, DurabilityKind::Volatile);
+        let message_writer = MockMessageWriter::new();
+        block_on(async {
+            writer
+                .add_matched_reader(&reader_proxy, &message_writer, &MockClock)
+                .await;
+            writer.write_message(&message_writer, &MockClock).await;
+        });
+        message_writer.sent_messages.lock().unwrap().clear();
+
+        let acknack = AckNackSubmessage::new(
+            false,
+            READER_GUID.entity_id(),
+            WRITER_GUID.entity_id(),
+            SequenceNumberSet::new(2, [2]),
+            1,
+        );
+        block_on(writer.on_acknack_submessage_received(
+            &acknack,
+            READER_GUID.prefix(),
+            &message_writer,
+            &MockClock,
+        ));
+
+        let sent_messages = message_writer.sent_messages.into_inner().unwrap();
+        let messages: Vec<_> = sent_messages
+            .iter()
+            .map(|m| RtpsMessageRead::try_from(m.as_slice()).unwrap())
+            .collect();
+        let submessages: Vec<_> = messages.iter().flat_map(|m| m.submessages()).collect();
+        assert!(!submessages
+            .iter()
+            .any(|s| matches!(s, RtpsSubmessageReadKind::Data(_))));
+        let gap = submessages
+            .iter()
+            .find_map(|s| match s {
+                RtpsSubmessageReadKind::Gap(g) => Some(g),
+                _ => None,
+            })
+            .expect("Gap must be sent");
+        assert_eq!(gap.reader_id(), READER_GUID.entity_id());
+        assert_eq!(gap.writer_id(), WRITER_GUID.entity_id());
+        assert_eq!(gap.gap_start(), 2);
+        assert_eq!(gap.gap_list(), &SequenceNumberSet::new(3, []));
     }
 }
@@ -52,7 +52,7 @@ impl RtpsStatelessReader {
 
     pub async fn process_message(&mut self, datagram: &[u8]) -> RtpsResult<()> {
         let rtps_message = RtpsMessageRead::try_from(datagram)?;
-        let mut message_receiver = MessageReceiver::new(&rtps_message);
+        let mut message_receiver = MessageReceiver::new(&rtps_message, self.guid.prefix());
 
         while let Some(submessage) = message_receiver.next() {
             if let RtpsSubmessageReadKind::Data(data_submessage) = &submessage {
@@ -1,13 +1,16 @@
 use super::reader_locator::RtpsReaderLocator;
 use crate::{
-    rtps::message_sender::WriteMessage,
+    rtps::{message_creator::PAYLOAD_MESSAGE_OVERHEAD, message_sender::WriteMessage},
     rtps_messages::{
         overall_structure::RtpsMessageWrite,
         submessage_elements::SequenceNumberSet,
-        submessages::{gap::GapSubmessage, info_timestamp::InfoTimestampSubmessage},
+        submessages::{
+            ack_nack::AckNackSubmessage, gap::GapSubmessage,
+            info_timestamp::InfoTimestampSubmessage,
+        },
         types::TIME_INVALID,
     },
-    transport::types::{CacheChange, Guid, Locator, SequenceNumber, ENTITYID_UNKNOWN},
+    transport::types::{CacheChange, EntityId, Guid, Locator, SequenceNumber, ENTITYID_UNKNOWN},
 };
 
 use alloc::vec::Vec;
@@ -53,53 +56,174 @@ impl RtpsStatelessWriter {
         &mut self.reader_locators
     }
 
+    pub fn on_acknack_submessage_received(
+        &mut self,
+        acknack_submessage: &AckNackSubmessage,
+        reply_locator_list: &[Locator],
+    ) {
+        if &self.guid.entity_id() == acknack_submessage.writer_id() {
+            for reader_locator in self
+                .reader_locators
+                .iter_mut()
+                .filter(|x| reply_locator_list.contains(&x.locator()))
+            {
+                reader_locator.requested_changes_set(acknack_submessage.reader_sn_state().iter());
+            }
+        }
+    }
+
     pub async fn behavior(&mut self, message_writer: &mut impl WriteMessage) {
         for reader_locator in &mut self.reader_locators {
+            while let Some(requested_change_seq_num) = reader_locator.next_requested_change() {
+                write_change(
+                    &self.changes,
+                    self.guid.entity_id(),
+                    requested_change_seq_num,
+                    reader_locator.locator(),
+                    message_writer,
+                )
+                .await;
+            }
+
             while let Some(unsent_change_seq_num) =
                 reader_locator.next_unsent_change(self.changes.iter())
             {
                 // The post-condition:
                 // "( a_change BELONGS-TO the_reader_locator.unsent_changes() ) == FALSE"
                 // should be full-filled by next_unsent_change()
-
-                if let Some(cache_change) = self
-                    .changes
-                    .iter()
-                    .find(|cc| cc.sequence_number == unsent_change_seq_num)
-                {
-                    let info_ts_submessage = cache_change
-                        .source_timestamp
-                        .map_or(InfoTimestampSubmessage::new(true, TIME_INVALID), |t| {
-                            InfoTimestampSubmessage::new(false, t.into())
-                        });
-
-                    let data_submessage =
-                        cache_change.as_data_submessage(ENTITYID_UNKNOWN, self.guid.entity_id());
-
-                    let rtps_message = RtpsMessageWrite::from_submessages(
-                        &[&info_ts_submessage, &data_submessage],
-                        message_writer.guid_prefix(),
-                    );
-                    message_writer
-                        .write_message(rtps_message.buffer(), &[reader_locator.locator()])
-                        .await;
-                } else {
-                    let gap_submessage = GapSubmessage::new(
-                        ENTITYID_UNKNOWN,
-                        self.guid.entity_id(),
-                        unsent_change_seq_num,
-                        SequenceNumberSet::new(unsent_change_seq_num + 1, []),
-                    );
-                    let rtps_message = RtpsMessageWrite::from_submessages(
-                        &[&gap_submessage],
-                        message_writer.guid_prefix(),
-                    );
-                    message_writer
-                        .write_message(rtps_message.buffer(), &[reader_locator.locator()])
-                        .await;
-                }
+                write_change(
+                    &self.changes,
+                    self.guid.entity_id(),
+                    unsent_change_seq_num,
+                    reader_locator.locator(),
+                    message_writer,
+                )
+                .await;
                 reader_locator.set_highest_sent_change_sn(unsent_change_seq_num);
             }
         }
     }
 }
+
+async fn write_change(
+    changes: &[CacheChange],
+    writer_id: EntityId,
+    seq_num: SequenceNumber,
+    locator: Locator,
+    message_writer: &impl WriteMessage,
+) {
+    if let Some(cache_change) = changes.iter().find(|cc| cc.sequence_number == seq_num) {
+        let info_ts_submessage = cache_change
+            .source_timestamp
+            .map_or(InfoTimestampSubmessage::new(true, TIME_INVALID), |t| {
+                InfoTimestampSubmessage::new(false, t.into())
+            });
+
+        let data_submessage = cache_change.as_data_submessage(ENTITYID_UNKNOWN, writer_id);
+
+        let rtps_message = RtpsMessageWrite::from_submessages_with_capacity(
+            &[&info_ts_submessage, &data_submessage],
+            message_writer.guid_prefix(),
+            cache_change.data_value.len() + PAYLOAD_MESSAGE_OVERHEAD,
+        );
+        message_writer
+            .write_message(rtps_message.buffer(), &[locator])
+            .await;
+    } else {
+        let gap_submessage = GapSubmessage::new(
+            ENTITYID_UNKNOWN,
+            writer_id,
+            seq_num,
+            SequenceNumberSet::new(seq_num + 1, []),
+        );
+        let rtps_message =
+            RtpsMessageWrite::from_submessages(&[&gap_submessage], message_writer.guid_prefix());
+        message_writer
+            .write_message(rtps_message.buffer(), &[locator])
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rtps_messages::overall_structure::{RtpsMessageRead, RtpsSubmessageReadKind},
+        std_runtime::executor::block_on,
+        transport::types::{ChangeKind, GuidPrefix, LOCATOR_KIND_UDP_V4},
+    };
+    use std::sync::Mutex;
+
+    struct MockMessageWriter {
+        sent_messages: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl WriteMessage for MockMessageWriter {
+        async fn write_message(&self, datagram: &[u8], _locator_list: &[Locator]) {
+            self.sent_messages.lock().unwrap().push(datagram.to_vec());
+        }
+
+        fn guid_prefix(&self) -> GuidPrefix {
+            [1; 12]
+        }
+    }
+
+    fn cache_change(writer_guid: Guid, sequence_number: SequenceNumber) -> CacheChange {
+        CacheChange {
+            kind: ChangeKind::Alive,
+            writer_guid,
+            sequence_number,
+            source_timestamp: None,
+            instance_handle: None,
+            data_value: vec![0, 1, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
+        }
+    }
+
+    fn sent_data_sequence_numbers(message_writer: &MockMessageWriter) -> Vec<SequenceNumber> {
+        message_writer
+            .sent_messages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|m| {
+                let message = RtpsMessageRead::try_from(m.as_slice()).unwrap();
+                message.submessages().iter().find_map(|s| match s {
+                    RtpsSubmessageReadKind::Data(d) => Some(d.writer_sn()),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn nacked_change_is_resent() {
+        let writer_guid = Guid::new([1; 12], EntityId::new([0, 1, 0], 0xc2));
+        let mut writer = RtpsStatelessWriter::new(writer_guid);
+        let locator = Locator::new(LOCATOR_KIND_UDP_V4, 7400, [0; 16]);
+        let other_locator = Locator::new(LOCATOR_KIND_UDP_V4, 7410, [0; 16]);
+        writer.reader_locator_add(locator);
+        writer.reader_locator_add(other_locator);
+        let mut message_writer = MockMessageWriter {
+            sent_messages: Mutex::new(Vec::new()),
+        };
+
+        writer.add_change(cache_change(writer_guid, 1));
+        writer.add_change(cache_change(writer_guid, 2));
+        block_on(writer.behavior(&mut message_writer));
+        message_writer.sent_messages.lock().unwrap().clear();
+
+        let acknack = AckNackSubmessage::new(
+            false,
+            ENTITYID_UNKNOWN,
+            writer_guid.entity_id(),
+            SequenceNumberSet::new(2, [2]),
+            1,
+        );
+        writer.on_acknack_submessage_received(&acknack, &[locator]);
+        block_on(writer.behavior(&mut message_writer));
+
+        assert_eq!(sent_data_sequence_numbers(&message_writer), vec![2]);
+    }
+}
@@ -11,7 +11,7 @@ use crate::{
     },
     transport::types::{EntityId, Guid, Locator, ReliabilityKind, SequenceNumber},
 };
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
 
 use core::cmp::max;
 
@@ -31,6 +31,7 @@ pub struct RtpsWriterProxy {
     first_available_seq_num: SequenceNumber,
     last_available_seq_num: SequenceNumber,
     highest_received_change_sn: SequenceNumber,
+    irrelevant_changes: BTreeSet<SequenceNumber>,
     must_send_acknacks: bool,
     last_received_heartbeat_count: Count,
     last_received_heartbeat_frag_count: Count,
@@ -56,6 +57,7 @@ impl RtpsWriterProxy {
             first_available_seq_num: 1,
             last_available_seq_num: 0,
             highest_received_change_sn: 0,
+            irrelevant_changes: BTreeSet::new(),
             must_send_acknacks: false,
             last_received_heartbeat_count: 0,
             last_received_heartbeat_frag_count: 0,
@@ -162,11 +164,45 @@ impl RtpsWriterProxy {
         // FIND change FROM this.changes_from_writer SUCH-THAT
         // (change.sequenceNumber == a_seq_num);
         // change.status := RECEIVED; change.is_relevant := FALSE;
-        if a_seq_num > self.highest_received_change_sn {
-            self.highest_received_change_sn = a_seq_num;
+        if a_seq_num > self.available_changes_max() {
+            self.irrelevant_changes.insert(a_seq_num);
+            self.advance_over_irrelevant_changes();
+        }
+    }
+
+    pub fn irrelevant_change_range_set(
+        &mut self,
+        first_seq_num: SequenceNumber,
+        last_seq_num: SequenceNumber,
+    ) {
+        // A range adjacent to the available changes is applied at once, since it can't leave
+        // any change behind as MISSING.
+        if first_seq_num <= self.available_changes_max() + 1 {
+            if last_seq_num > self.highest_received_change_sn {
+                self.highest_received_change_sn = last_seq_num;
+            }
+            self.advance_over_irrelevant_changes();
+        } else {
+            for seq_num in first_seq_num..=last_seq_num {
+                self.irrelevant_change_set(seq_num);
+            }
         }
     }
 
+    fn advance_over_irrelevant_changes(&mut self) {
+        // Irrelevant changes received ahead of a MISSING one are kept aside until every change
+        // before them has been received, otherwise the MISSING ones would be skipped.
+        while self
+            .irrelevant_changes
+            .remove(&(self.available_changes_max() + 1))
+        {
+            self.highest_received_change_sn = self.available_changes_max() + 1;
+        }
+        let available_changes_max = self.available_changes_max();
+        self.irrelevant_changes
+            .retain(|&seq_num| seq_num > available_changes_max);
+    }
+
     pub fn lost_changes_update(&mut self, first_available_seq_num: SequenceNumber) {
         // FOREACH change IN this.changes_from_writer
         // SUCH-THAT ( change.status == UNKNOWN OR change.status == MISSING
@@ -174,9 +210,10 @@ impl RtpsWriterProxy {
         // change.status := LOST;
         // }
         self.first_available_seq_num = first_available_seq_num;
+        self.advance_over_irrelevant_changes();
     }
 
-    pub fn missing_changes(&self) -> impl Iterator<Item = SequenceNumber> {
+    pub fn missing_changes(&self) -> impl Iterator<Item = SequenceNumber> + '_ {
         // The changes with status 'MISSING' represent the set of changes available in the HistoryCache of the RTPS Writer
         // represented by the RTPS WriterProxy that have not been received by the RTPS Reader.
         // return { change IN this.changes_from_writer SUCH-THAT change.status == MISSING};
@@ -190,7 +227,8 @@ impl RtpsWriterProxy {
             self.first_available_seq_num,
             self.highest_received_change_sn + 1,
         );
-        first_missing_change..=highest_number
+        (first_missing_change..=highest_number)
+            .filter(|seq_num| !self.irrelevant_changes.contains(seq_num))
     }
 
     pub fn missing_changes_update(&mut self, last_available_seq_num: SequenceNumber) {
@@ -208,6 +246,7 @@ impl RtpsWriterProxy {
         // change.status := RECEIVED
         if a_seq_num > self.highest_received_change_sn {
             self.highest_received_change_sn = a_seq_num;
+            self.advance_over_irrelevant_changes();
         }
     }
 
@@ -293,6 +332,11 @@ impl RtpsWriterProxy {
                         missing_fragment_number.push(fragment_number)
                     }
                 }
+                // A fragment number set can only span 256 fragments from its base. The remaining
+                // ones are requested once these have been received.
+                if let Some(&base) = missing_fragment_number.first() {
+                    missing_fragment_number.retain(|&f| f - base < 256);
+                }
 
                 if !missing_fragment_number.is_empty() {
                     self.nack_frag_count = self.nack_frag_count.wrapping_add(1);
@@ -300,10 +344,7 @@ impl RtpsWriterProxy {
                         reader_guid.entity_id(),
                         self.remote_writer_guid().entity_id(),
                         missing_seq_num,
-                        FragmentNumberSet::new(
-                            missing_fragment_number[0],
-                            missing_fragment_number.into_iter(),
-                        ),
+                        FragmentNumberSet::new(missing_fragment_number[0], missing_fragment_number),
                         self.nack_frag_count,
                     );
 
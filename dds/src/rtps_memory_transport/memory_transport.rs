@@ -0,0 +1,739 @@
+use crate::{
+    rtps::{
+        message_sender::{Clock, WriteMessage},
+        stateful_reader::RtpsStatefulReader,
+        stateful_writer::RtpsStatefulWriter,
+        stateless_reader::RtpsStatelessReader,
+        stateless_writer::RtpsStatelessWriter,
+        types::{PROTOCOLVERSION, VENDOR_ID_S2E},
+    },
+    std_runtime::executor::block_on,
+    transport::{
+        interface::{
+            HistoryCache, TransportParticipant, TransportParticipantError,
+            TransportParticipantFactory, TransportStatefulReader, TransportStatefulWriter,
+            TransportStatelessReader, TransportStatelessWriter,
+        },
+        types::{
+            CacheChange, EntityId, Guid, GuidPrefix, Locator, Long, ProtocolVersion, ReaderProxy,
+            ReliabilityKind, VendorId, WriterProxy, ENTITYID_PARTICIPANT,
+        },
+    },
+};
+use async_lock::Mutex;
+use core::{future::Future, pin::Pin};
+use std::sync::{
+    mpsc::{channel, Sender},
+    Arc,
+};
+
+/// Locator kind used by the memory transport. It is not defined by the standard so the locators
+/// of the memory transport are ignored by the other transports.
+pub const LOCATOR_KIND_MEMORY: Long = 0x4d45_4d00;
+
+// Address of the locator on which the participants of a domain are discovered. The port of the
+// locator is the domain id.
+const DISCOVERY_LOCATOR_ADDRESS: [u8; 16] = [0xff; 16];
+
+const FRAGMENT_SIZE: usize = 1344;
+
+enum ChannelMessageKind {
+    AddStatelessReader(RtpsStatelessReader),
+    AddStatefulReader(Arc<Mutex<RtpsStatefulReader>>),
+    AddStatefulWriter(Arc<Mutex<RtpsStatefulWriter>>),
+    Datagram(Arc<[u8]>),
+    Poke,
+    Stop,
+}
+
+// Network shared by all the participants created by the factories connected to it. Every
+// entry delivers the datagrams sent to its locator to the receiver of a participant.
+#[derive(Default)]
+struct MemoryNetwork {
+    last_port: u32,
+    last_participant_id: u32,
+    locator_list: Vec<NetworkEntry>,
+}
+
+struct NetworkEntry {
+    locator: Locator,
+    participant_id: u32,
+    sender: Sender<ChannelMessageKind>,
+}
+
+/// Transport factory which connects the participants it creates through memory instead of sockets.
+///
+/// All the factories cloned from the same factory share the same in-memory network, so that for
+/// example two [`DomainParticipantFactory`](crate::domain::domain_participant_factory::DomainParticipantFactory)
+/// instances of the same process can discover each other and exchange data without using UDP.
+#[derive(Clone, Default)]
+pub struct RtpsMemoryTransportParticipantFactory {
+    network: Arc<std::sync::Mutex<MemoryNetwork>>,
+}
+
+impl RtpsMemoryTransportParticipantFactory {
+    /// Construct a factory connected to a new in-memory network.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct two factories connected to the same new in-memory network.
+    pub fn pair() -> (Self, Self) {
+        let factory = Self::new();
+        (factory.clone(), factory)
+    }
+}
+
+impl TransportParticipantFactory for RtpsMemoryTransportParticipantFactory {
+    type TransportParticipant = RtpsMemoryTransportParticipant;
+
+    async fn create_participant(
+        &self,
+        guid_prefix: GuidPrefix,
+        domain_id: i32,
+    ) -> Result<Self::TransportParticipant, TransportParticipantError> {
+        let (channel_message_sender, channel_message_receiver) = channel();
+
+        let discovery_locator = Locator::new(
+            LOCATOR_KIND_MEMORY,
+            domain_id as u32,
+            DISCOVERY_LOCATOR_ADDRESS,
+        );
+        let (participant_id, metatraffic_unicast_locator, default_unicast_locator) = {
+            let mut network = self.network.lock().expect("Network lock not poisoned");
+            network.last_participant_id += 1;
+            let participant_id = network.last_participant_id;
+            network.last_port += 1;
+            let metatraffic_unicast_locator =
+                Locator::new(LOCATOR_KIND_MEMORY, network.last_port, [0; 16]);
+            network.last_port += 1;
+            let default_unicast_locator =
+                Locator::new(LOCATOR_KIND_MEMORY, network.last_port, [0; 16]);
+            for locator in [
+                discovery_locator,
+                metatraffic_unicast_locator,
+                default_unicast_locator,
+            ] {
+                network.locator_list.push(NetworkEntry {
+                    locator,
+                    participant_id,
+                    sender: channel_message_sender.clone(),
+                });
+            }
+            (
+                participant_id,
+                metatraffic_unicast_locator,
+                default_unicast_locator,
+            )
+        };
+
+        let mut message_writer = MessageWriter {
+            guid_prefix,
+            network: self.network.clone(),
+        };
+
+        // The thread stops once the receiver thread has stopped
+        let channel_message_sender_clone = channel_message_sender.clone();
+        std::thread::Builder::new()
+            .name("Regular poke".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                if channel_message_sender_clone
+                    .send(ChannelMessageKind::Poke)
+                    .is_err()
+                {
+                    break;
+                }
+            })
+            .expect("failed to spawn thread");
+
+        let receiver_message_writer = message_writer.clone();
+        std::thread::Builder::new()
+            .name("Memory receiver".to_string())
+            .spawn(move || {
+                let mut stateless_reader_list = Vec::new();
+                let mut stateful_reader_list = Vec::new();
+                let mut stateful_writer_list = Vec::new();
+                while let Ok(channel_message) = channel_message_receiver.recv() {
+                    match channel_message {
+                        ChannelMessageKind::AddStatelessReader(stateless_reader) => {
+                            stateless_reader_list.push(stateless_reader)
+                        }
+                        ChannelMessageKind::AddStatefulReader(stateful_reader) => {
+                            stateful_reader_list.push(stateful_reader)
+                        }
+                        ChannelMessageKind::AddStatefulWriter(stateful_writer) => {
+                            stateful_writer_list.push(stateful_writer)
+                        }
+                        ChannelMessageKind::Datagram(datagram) => block_on(async {
+                            process_message(
+                                &datagram,
+                                &mut message_writer,
+                                &RtpsMemoryTransportClock,
+                                &mut stateless_reader_list,
+                                &stateful_reader_list,
+                                &stateful_writer_list,
+                            )
+                            .await
+                        }),
+                        ChannelMessageKind::Poke => block_on(async {
+                            for rtps_stateful_writer in &stateful_writer_list {
+                                rtps_stateful_writer
+                                    .lock()
+                                    .await
+                                    .write_message(&message_writer, &RtpsMemoryTransportClock)
+                                    .await;
+                            }
+                        }),
+                        ChannelMessageKind::Stop => break,
+                    }
+                }
+            })
+            .expect("failed to spawn thread");
+
+        Ok(RtpsMemoryTransportParticipant {
+            guid: Guid::new(guid_prefix, ENTITYID_PARTICIPANT),
+            participant_id,
+            message_writer: receiver_message_writer,
+            default_unicast_locator_list: vec![default_unicast_locator],
+            metatraffic_unicast_locator_list: vec![metatraffic_unicast_locator],
+            metatraffic_multicast_locator_list: vec![discovery_locator],
+            channel_message_sender,
+        })
+    }
+}
+
+async fn process_message(
+    datagram: &[u8],
+    message_writer: &mut MessageWriter,
+    clock: &impl Clock,
+    stateless_reader_list: &mut [RtpsStatelessReader],
+    stateful_reader_list: &[Arc<Mutex<RtpsStatefulReader>>],
+    stateful_writer_list: &[Arc<Mutex<RtpsStatefulWriter>>],
+) {
+    for stateless_reader in stateless_reader_list {
+        stateless_reader.process_message(datagram).await.ok();
+    }
+    for stateful_reader in stateful_reader_list {
+        stateful_reader
+            .lock()
+            .await
+            .process_message(datagram, message_writer)
+            .await
+            .ok();
+    }
+    for stateful_writer in stateful_writer_list {
+        stateful_writer
+            .lock()
+            .await
+            .process_message(datagram, message_writer, clock)
+            .await
+            .ok();
+    }
+}
+
+#[derive(Clone)]
+struct MessageWriter {
+    guid_prefix: GuidPrefix,
+    network: Arc<std::sync::Mutex<MemoryNetwork>>,
+}
+
+impl WriteMessage for MessageWriter {
+    async fn write_message(&self, datagram: &[u8], locator_list: &[Locator]) {
+        let datagram: Arc<[u8]> = datagram.into();
+        let network = self.network.lock().expect("Network lock not poisoned");
+        for destination_locator in locator_list {
+            for entry in network
+                .locator_list
+                .iter()
+                .filter(|x| &x.locator == destination_locator)
+            {
+                entry
+                    .sender
+                    .send(ChannelMessageKind::Datagram(datagram.clone()))
+                    .ok();
+            }
+        }
+    }
+
+    fn guid_prefix(&self) -> GuidPrefix {
+        self.guid_prefix
+    }
+}
+
+struct RtpsMemoryTransportClock;
+
+impl Clock for RtpsMemoryTransportClock {
+    fn now(&self) -> core::time::Duration {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Clock should always give valid Unix time")
+    }
+}
+
+/// Participant of the memory transport. Dropping it removes it from the network and stops the
+/// threads which deliver its datagrams.
+pub struct RtpsMemoryTransportParticipant {
+    guid: Guid,
+    participant_id: u32,
+    message_writer: MessageWriter,
+    default_unicast_locator_list: Vec<Locator>,
+    metatraffic_unicast_locator_list: Vec<Locator>,
+    metatraffic_multicast_locator_list: Vec<Locator>,
+    channel_message_sender: Sender<ChannelMessageKind>,
+}
+
+impl Drop for RtpsMemoryTransportParticipant {
+    fn drop(&mut self) {
+        self.message_writer
+            .network
+            .lock()
+            .expect("Network lock not poisoned")
+            .locator_list
+            .retain(|x| x.participant_id != self.participant_id);
+        self.channel_message_sender
+            .send(ChannelMessageKind::Stop)
+            .ok();
+    }
+}
+
+pub struct StatelessReader {
+    guid: Guid,
+}
+impl TransportStatelessReader for StatelessReader {
+    fn guid(&self) -> Guid {
+        self.guid
+    }
+}
+
+pub struct StatelessWriter {
+    rtps_writer: RtpsStatelessWriter,
+    message_writer: MessageWriter,
+}
+impl TransportStatelessWriter for StatelessWriter {
+    fn guid(&self) -> Guid {
+        self.rtps_writer.guid()
+    }
+    fn history_cache(&mut self) -> &mut dyn HistoryCache {
+        self
+    }
+
+    fn add_reader_locator(&mut self, locator: Locator) {
+        self.rtps_writer.reader_locator_add(locator);
+    }
+
+    fn remove_reader_locator(&mut self, locator: &Locator) {
+        self.rtps_writer.reader_locator_remove(*locator);
+    }
+}
+impl HistoryCache for StatelessWriter {
+    fn add_change(
+        &mut self,
+        cache_change: CacheChange,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.rtps_writer.add_change(cache_change);
+        Box::pin(async {
+            self.rtps_writer.behavior(&mut self.message_writer).await;
+        })
+    }
+
+    fn remove_change(&mut self, sequence_number: i64) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        self.rtps_writer.remove_change(sequence_number);
+        Box::pin(async {})
+    }
+}
+
+pub struct StatefulWriter {
+    guid: Guid,
+    rtps_stateful_writer: Arc<Mutex<RtpsStatefulWriter>>,
+    message_writer: MessageWriter,
+    default_unicast_locator_list: Vec<Locator>,
+}
+impl TransportStatefulWriter for StatefulWriter {
+    fn guid(&self) -> Guid {
+        self.guid
+    }
+    fn history_cache(&mut self) -> &mut dyn HistoryCache {
+        self
+    }
+    async fn is_change_acknowledged(&self, sequence_number: i64) -> bool {
+        self.rtps_stateful_writer
+            .lock()
+            .await
+            .is_change_acknowledged(sequence_number)
+    }
+    async fn add_matched_reader(&mut self, mut reader_proxy: ReaderProxy) {
+        if reader_proxy.unicast_locator_list.is_empty() {
+            reader_proxy
+                .unicast_locator_list
+                .clone_from(&self.default_unicast_locator_list);
+        }
+
+        self.rtps_stateful_writer
+            .lock()
+            .await
+            .add_matched_reader(
+                &reader_proxy,
+                &self.message_writer,
+                &RtpsMemoryTransportClock,
+            )
+            .await;
+    }
+    async fn remove_matched_reader(&mut self, remote_reader_guid: Guid) {
+        self.rtps_stateful_writer
+            .lock()
+            .await
+            .delete_matched_reader(remote_reader_guid);
+    }
+}
+impl HistoryCache for StatefulWriter {
+    fn add_change(
+        &mut self,
+        cache_change: CacheChange,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            let mut rtps_stateful_writer = self.rtps_stateful_writer.lock().await;
+            rtps_stateful_writer.add_change(cache_change);
+            rtps_stateful_writer
+                .write_message(&self.message_writer, &RtpsMemoryTransportClock)
+                .await;
+        })
+    }
+
+    fn remove_change(&mut self, sequence_number: i64) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        let rtps_stateful_writer = self.rtps_stateful_writer.clone();
+        Box::pin(async move {
+            rtps_stateful_writer
+                .lock()
+                .await
+                .remove_change(sequence_number);
+        })
+    }
+}
+
+pub struct StatefulReader {
+    guid: Guid,
+    rtps_stateful_reader: Arc<Mutex<RtpsStatefulReader>>,
+}
+impl TransportStatefulReader for StatefulReader {
+    fn guid(&self) -> Guid {
+        self.guid
+    }
+    async fn is_historical_data_received(&self) -> bool {
+        self.rtps_stateful_reader
+            .lock()
+            .await
+            .is_historical_data_received()
+    }
+    async fn add_matched_writer(&mut self, writer_proxy: WriterProxy) {
+        self.rtps_stateful_reader
+            .lock()
+            .await
+            .add_matched_writer(&writer_proxy)
+    }
+    async fn remove_matched_writer(&mut self, remote_writer_guid: Guid) {
+        self.rtps_stateful_reader
+            .lock()
+            .await
+            .delete_matched_writer(remote_writer_guid)
+    }
+}
+
+impl TransportParticipant for RtpsMemoryTransportParticipant {
+    type StatelessReader = StatelessReader;
+    type StatelessWriter = StatelessWriter;
+    type StatefulReader = StatefulReader;
+    type StatefulWriter = StatefulWriter;
+
+    fn guid(&self) -> Guid {
+        self.guid
+    }
+    fn protocol_version(&self) -> ProtocolVersion {
+        PROTOCOLVERSION
+    }
+    fn vendor_id(&self) -> VendorId {
+        VENDOR_ID_S2E
+    }
+    fn metatraffic_unicast_locator_list(&self) -> &[Locator] {
+        &self.metatraffic_unicast_locator_list
+    }
+    fn metatraffic_multicast_locator_list(&self) -> &[Locator] {
+        &self.metatraffic_multicast_locator_list
+    }
+    fn discovery_locator_list(&self) -> &[Locator] {
+        &self.metatraffic_multicast_locator_list
+    }
+    fn default_unicast_locator_list(&self) -> &[Locator] {
+        &self.default_unicast_locator_list
+    }
+    fn default_multicast_locator_list(&self) -> &[Locator] {
+        &[]
+    }
+    async fn create_stateless_reader(
+        &mut self,
+        entity_id: EntityId,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatelessReader {
+        let guid = Guid::new(self.guid.prefix(), entity_id);
+        self.channel_message_sender
+            .send(ChannelMessageKind::AddStatelessReader(
+                RtpsStatelessReader::new(guid, reader_history_cache),
+            ))
+            .expect("channel_message receiver alive");
+        StatelessReader { guid }
+    }
+    async fn create_stateless_writer(&mut self, entity_id: EntityId) -> Self::StatelessWriter {
+        let guid = Guid::new(self.guid.prefix(), entity_id);
+        StatelessWriter {
+            rtps_writer: RtpsStatelessWriter::new(guid),
+            message_writer: self.message_writer.clone(),
+        }
+    }
+
+    async fn create_stateful_reader(
+        &mut self,
+        entity_id: EntityId,
+        _topic_name: &str,
+        reliability_kind: ReliabilityKind,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatefulReader {
+        let guid = Guid::new(self.guid.prefix(), entity_id);
+        let rtps_stateful_reader = Arc::new(Mutex::new(RtpsStatefulReader::new(
+            guid,
+            reader_history_cache,
+            reliability_kind,
+        )));
+        self.channel_message_sender
+            .send(ChannelMessageKind::AddStatefulReader(
+                rtps_stateful_reader.clone(),
+            ))
+            .expect("channel_message receiver alive");
+        StatefulReader {
+            guid,
+            rtps_stateful_reader,
+        }
+    }
+
+    async fn create_stateful_writer(
+        &mut self,
+        entity_id: EntityId,
+        _topic_name: &str,
+        _reliability_kind: ReliabilityKind,
+    ) -> Self::StatefulWriter {
+        let guid = Guid::new(self.guid.prefix(), entity_id);
+        let rtps_stateful_writer =
+            Arc::new(Mutex::new(RtpsStatefulWriter::new(guid, FRAGMENT_SIZE)));
+        self.channel_message_sender
+            .send(ChannelMessageKind::AddStatefulWriter(
+                rtps_stateful_writer.clone(),
+            ))
+            .expect("channel_message receiver alive");
+        StatefulWriter {
+            guid,
+            rtps_stateful_writer,
+            message_writer: self.message_writer.clone(),
+            default_unicast_locator_list: self.default_unicast_locator_list.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::types::{ChangeKind, DurabilityKind};
+    use std::sync::mpsc::{sync_channel, SyncSender};
+
+    #[test]
+    fn stateless_writer_of_one_factory_of_a_pair_delivers_to_the_other() {
+        let (transport1, transport2) = RtpsMemoryTransportParticipantFactory::pair();
+        let mut participant1 =
+            block_on(async { transport1.create_participant([1; 12], 0).await }).unwrap();
+        let mut participant2 =
+            block_on(async { transport2.create_participant([2; 12], 0).await }).unwrap();
+
+        struct MockHistoryCache(SyncSender<CacheChange>);
+
+        impl HistoryCache for MockHistoryCache {
+            fn add_change(
+                &mut self,
+                cache_change: CacheChange,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                self.0.send(cache_change).unwrap();
+                Box::pin(async {})
+            }
+            fn remove_change(
+                &mut self,
+                _sequence_number: i64,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                Box::pin(async {
+                    unimplemented!();
+                })
+            }
+        }
+
+        let (sender, receiver) = sync_channel(1);
+        let _reader = block_on(async {
+            participant2
+                .create_stateless_reader(
+                    EntityId::new([1, 2, 3], 4),
+                    Box::new(MockHistoryCache(sender)),
+                )
+                .await
+        });
+        let mut writer = block_on(async {
+            participant1
+                .create_stateless_writer(EntityId::new([5, 6, 7], 8))
+                .await
+        });
+        for locator in participant2.default_unicast_locator_list() {
+            writer.add_reader_locator(*locator);
+        }
+
+        let cache_change = CacheChange {
+            kind: ChangeKind::Alive,
+            writer_guid: writer.guid(),
+            sequence_number: 1,
+            source_timestamp: None,
+            instance_handle: None,
+            data_value: vec![0, 0, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
+        };
+        block_on(async {
+            writer
+                .history_cache()
+                .add_change(cache_change.clone())
+                .await
+        });
+
+        let received_cache_change = receiver
+            .recv_timeout(std::time::Duration::from_secs(3))
+            .unwrap();
+        assert_eq!(cache_change, received_cache_change);
+    }
+
+    #[test]
+    fn stateful_writer_delivers_added_change_to_matched_reader_immediately() {
+        let (transport1, transport2) = RtpsMemoryTransportParticipantFactory::pair();
+        let mut participant1 =
+            block_on(async { transport1.create_participant([1; 12], 0).await }).unwrap();
+        let mut participant2 =
+            block_on(async { transport2.create_participant([2; 12], 0).await }).unwrap();
+
+        struct MockHistoryCache(SyncSender<CacheChange>);
+
+        impl HistoryCache for MockHistoryCache {
+            fn add_change(
+                &mut self,
+                cache_change: CacheChange,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                self.0.send(cache_change).unwrap();
+                Box::pin(async {})
+            }
+            fn remove_change(
+                &mut self,
+                _sequence_number: i64,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                Box::pin(async {
+                    unimplemented!();
+                })
+            }
+        }
+
+        let (sender, receiver) = sync_channel(1);
+        let mut reader = block_on(async {
+            participant2
+                .create_stateful_reader(
+                    EntityId::new([1, 2, 3], 4),
+                    "topic",
+                    ReliabilityKind::BestEffort,
+                    Box::new(MockHistoryCache(sender)),
+                )
+                .await
+        });
+        let mut writer = block_on(async {
+            participant1
+                .create_stateful_writer(
+                    EntityId::new([5, 6, 7], 8),
+                    "topic",
+                    ReliabilityKind::BestEffort,
+                )
+                .await
+        });
+        block_on(async {
+            reader
+                .add_matched_writer(WriterProxy {
+                    remote_writer_guid: writer.guid(),
+                    remote_group_entity_id: EntityId::new([0, 0, 0], 0),
+                    reliability_kind: ReliabilityKind::BestEffort,
+                    durability_kind: DurabilityKind::Volatile,
+                    unicast_locator_list: participant1.default_unicast_locator_list().to_vec(),
+                    multicast_locator_list: vec![],
+                })
+                .await;
+            writer
+                .add_matched_reader(ReaderProxy {
+                    remote_reader_guid: reader.guid(),
+                    remote_group_entity_id: EntityId::new([0, 0, 0], 0),
+                    reliability_kind: ReliabilityKind::BestEffort,
+                    durability_kind: DurabilityKind::Volatile,
+                    unicast_locator_list: participant2.default_unicast_locator_list().to_vec(),
+                    multicast_locator_list: vec![],
+                    expects_inline_qos: false,
+                })
+                .await;
+        });
+
+        let cache_change = CacheChange {
+            kind: ChangeKind::Alive,
+            writer_guid: writer.guid(),
+            sequence_number: 1,
+            source_timestamp: None,
+            instance_handle: None,
+            data_value: vec![0, 0, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
+        };
+        block_on(async {
+            writer
+                .history_cache()
+                .add_change(cache_change.clone())
+                .await
+        });
+
+        // The change is sent as part of add_change without waiting for any periodic task
+        let received_cache_change = receiver
+            .recv_timeout(std::time::Duration::from_millis(100))
+            .unwrap();
+        assert_eq!(cache_change, received_cache_change);
+    }
+
+    #[test]
+    fn dropped_participant_leaves_the_network_and_stops_receiving() {
+        let (transport1, transport2) = RtpsMemoryTransportParticipantFactory::pair();
+        let participant1 =
+            block_on(async { transport1.create_participant([1; 12], 0).await }).unwrap();
+        let participant2 =
+            block_on(async { transport2.create_participant([2; 12], 0).await }).unwrap();
+        assert_eq!(transport1.network.lock().unwrap().locator_list.len(), 6);
+
+        let participant2_sender = participant2.channel_message_sender.clone();
+        drop(participant2);
+
+        let network = transport1.network.lock().unwrap();
+        assert_eq!(network.locator_list.len(), 3);
+        assert!(network
+            .locator_list
+            .iter()
+            .all(|x| x.participant_id == participant1.participant_id));
+        drop(network);
+
+        // The receiver thread of the dropped participant finishes
+        let start_time = std::time::Instant::now();
+        while participant2_sender.send(ChannelMessageKind::Poke).is_ok() {
+            if start_time.elapsed() > std::time::Duration::from_secs(3) {
+                panic!("Receiver thread still running after the participant was dropped")
+            }
+        }
+    }
+}
@@ -8,6 +8,7 @@ pub enum RtpsMessageError {
     InvalidData,
     NotEnoughData,
     UnknownMessage,
+    UnsupportedVersion,
 }
 
 impl From<XTypesError> for RtpsMessageError {
@@ -1,6 +1,9 @@
-use crate::transport::types::{
-    EntityId, GuidPrefix, Locator, Long, Octet, ProtocolVersion, SequenceNumber, UnsignedLong,
-    VendorId,
+use crate::{
+    rtps::types::PROTOCOLVERSION,
+    transport::types::{
+        EntityId, GuidPrefix, Locator, Long, Octet, ProtocolVersion, SequenceNumber, UnsignedLong,
+        VendorId,
+    },
 };
 
 use super::{
@@ -17,8 +20,9 @@ use super::{
         HEARTBEAT_FRAG, INFO_DST, INFO_REPLY, INFO_SRC, INFO_TS, NACK_FRAG, PAD,
     },
 };
-use alloc::{sync::Arc, vec::Vec};
+use alloc::vec::Vec;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Endianness {
     BigEndian,
     LittleEndian,
@@ -75,17 +79,31 @@ impl BufRead for &[u8] {
 
 pub trait Write {
     fn write_all(&mut self, buf: &[u8]) -> RtpsMessageResult<()>;
+
+    /// Byte order in which the written elements are encoded.
+    fn endianness(&self) -> Endianness {
+        Endianness::LittleEndian
+    }
 }
 
-#[derive(Debug, Default, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq)]
 pub struct Cursor<T> {
     inner: T,
     pos: u64,
+    endianness: Endianness,
 }
 
 impl<T> Cursor<T> {
     pub fn new(inner: T) -> Self {
-        Self { inner, pos: 0 }
+        Self::with_endianness(inner, Endianness::LittleEndian)
+    }
+
+    pub fn with_endianness(inner: T, endianness: Endianness) -> Self {
+        Self {
+            inner,
+            pos: 0,
+            endianness,
+        }
     }
 
     pub const fn position(&self) -> u64 {
@@ -121,6 +139,10 @@ impl Write for Cursor<Vec<u8>> {
 
         Ok(())
     }
+
+    fn endianness(&self) -> Endianness {
+        self.endianness
+    }
 }
 
 pub trait WriteIntoBytes {
@@ -134,29 +156,41 @@ impl WriteIntoBytes for Octet {
 
 impl WriteIntoBytes for Long {
     fn write_into_bytes(&self, buf: &mut dyn Write) {
-        buf.write_all(self.to_le_bytes().as_slice())
-            .expect("buffer big enough");
+        let bytes = match buf.endianness() {
+            Endianness::BigEndian => self.to_be_bytes(),
+            Endianness::LittleEndian => self.to_le_bytes(),
+        };
+        buf.write_all(bytes.as_slice()).expect("buffer big enough");
     }
 }
 
 impl WriteIntoBytes for UnsignedLong {
     fn write_into_bytes(&self, buf: &mut dyn Write) {
-        buf.write_all(self.to_le_bytes().as_slice())
-            .expect("buffer big enough");
+        let bytes = match buf.endianness() {
+            Endianness::BigEndian => self.to_be_bytes(),
+            Endianness::LittleEndian => self.to_le_bytes(),
+        };
+        buf.write_all(bytes.as_slice()).expect("buffer big enough");
     }
 }
 
 impl WriteIntoBytes for u16 {
     fn write_into_bytes(&self, buf: &mut dyn Write) {
-        buf.write_all(self.to_le_bytes().as_slice())
-            .expect("buffer big enough");
+        let bytes = match buf.endianness() {
+            Endianness::BigEndian => self.to_be_bytes(),
+            Endianness::LittleEndian => self.to_le_bytes(),
+        };
+        buf.write_all(bytes.as_slice()).expect("buffer big enough");
     }
 }
 
 impl WriteIntoBytes for i16 {
     fn write_into_bytes(&self, buf: &mut dyn Write) {
-        buf.write_all(self.to_le_bytes().as_slice())
-            .expect("buffer big enough");
+        let bytes = match buf.endianness() {
+            Endianness::BigEndian => self.to_be_bytes(),
+            Endianness::LittleEndian => self.to_le_bytes(),
+        };
+        buf.write_all(bytes.as_slice()).expect("buffer big enough");
     }
 }
 
@@ -243,8 +277,8 @@ impl TryReadFromBytes for ProtocolVersion {
 
 impl WriteIntoBytes for ProtocolVersion {
     fn write_into_bytes(&self, buf: &mut dyn Write) {
-        self._major().write_into_bytes(buf);
-        self._minor().write_into_bytes(buf);
+        self.major().write_into_bytes(buf);
+        self.minor().write_into_bytes(buf);
     }
 }
 
@@ -270,11 +304,31 @@ impl dyn Submessage + Send + '_ {
         let pos = buf.position();
         buf.set_position(header_position);
         let len = pos - elements_position;
+        // The receiver advances to the next submessage using this length so it must
+        // represent exactly the elements written
+        debug_assert!(
+            len <= u16::MAX as u64,
+            "Submessage elements length {len} does not fit the submessage header"
+        );
         self.write_submessage_header_into_bytes(len as u16, buf);
+        debug_assert_eq!(
+            written_submessage_length(&buf.inner[header_position as usize..]) as u64,
+            len,
+            "Submessage header length does not match the written elements"
+        );
         buf.set_position(pos);
     }
 }
 
+// Length in the header of the submessage written at the start of the bytes
+fn written_submessage_length(submessage_bytes: &[u8]) -> u16 {
+    let length_bytes = [submessage_bytes[2], submessage_bytes[3]];
+    match Endianness::from_flags(submessage_bytes[1]) {
+        Endianness::BigEndian => u16::from_be_bytes(length_bytes),
+        Endianness::LittleEndian => u16::from_le_bytes(length_bytes),
+    }
+}
+
 pub struct SubmessageHeaderRead {
     submessage_id: u8,
     flags: [SubmessageFlag; 8],
@@ -322,6 +376,41 @@ impl SubmessageHeaderRead {
         self.flags
     }
 
+    /// EndiannessFlag (E), present in every Submessage.
+    pub fn endianness_flag(&self) -> SubmessageFlag {
+        self.flags[0]
+    }
+
+    /// Flags of the ACKNACK Submessage.
+    pub fn ack_nack_flags(&self) -> AckNackFlags {
+        AckNackFlags(self.flags)
+    }
+
+    /// Flags of the HEARTBEAT Submessage.
+    pub fn heartbeat_flags(&self) -> HeartbeatFlags {
+        HeartbeatFlags(self.flags)
+    }
+
+    /// Flags of the DATA Submessage.
+    pub fn data_flags(&self) -> DataFlags {
+        DataFlags(self.flags)
+    }
+
+    /// Flags of the DATA_FRAG Submessage.
+    pub fn data_frag_flags(&self) -> DataFragFlags {
+        DataFragFlags(self.flags)
+    }
+
+    /// Flags of the INFO_TS Submessage.
+    pub fn info_timestamp_flags(&self) -> InfoTimestampFlags {
+        InfoTimestampFlags(self.flags)
+    }
+
+    /// Flags of the INFO_REPLY Submessage.
+    pub fn info_reply_flags(&self) -> InfoReplyFlags {
+        InfoReplyFlags(self.flags)
+    }
+
     pub fn submessage_length(&self) -> u16 {
         self.submessage_length
     }
@@ -331,6 +420,96 @@ impl SubmessageHeaderRead {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AckNackFlags([SubmessageFlag; 8]);
+
+impl AckNackFlags {
+    /// FinalFlag (F)
+    pub fn final_flag(&self) -> SubmessageFlag {
+        self.0[1]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeartbeatFlags([SubmessageFlag; 8]);
+
+impl HeartbeatFlags {
+    /// FinalFlag (F)
+    pub fn final_flag(&self) -> SubmessageFlag {
+        self.0[1]
+    }
+
+    /// LivelinessFlag (L)
+    pub fn liveliness_flag(&self) -> SubmessageFlag {
+        self.0[2]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataFlags([SubmessageFlag; 8]);
+
+impl DataFlags {
+    /// InlineQosFlag (Q)
+    pub fn inline_qos_flag(&self) -> SubmessageFlag {
+        self.0[1]
+    }
+
+    /// DataFlag (D)
+    pub fn data_flag(&self) -> SubmessageFlag {
+        self.0[2]
+    }
+
+    /// KeyFlag (K)
+    pub fn key_flag(&self) -> SubmessageFlag {
+        self.0[3]
+    }
+
+    /// NonStandardPayloadFlag (N)
+    pub fn non_standard_payload_flag(&self) -> SubmessageFlag {
+        self.0[4]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataFragFlags([SubmessageFlag; 8]);
+
+impl DataFragFlags {
+    /// InlineQosFlag (Q)
+    pub fn inline_qos_flag(&self) -> SubmessageFlag {
+        self.0[1]
+    }
+
+    /// KeyFlag (K)
+    pub fn key_flag(&self) -> SubmessageFlag {
+        self.0[2]
+    }
+
+    /// NonStandardPayloadFlag (N)
+    pub fn non_standard_payload_flag(&self) -> SubmessageFlag {
+        self.0[3]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InfoTimestampFlags([SubmessageFlag; 8]);
+
+impl InfoTimestampFlags {
+    /// InvalidateFlag (I)
+    pub fn invalidate_flag(&self) -> SubmessageFlag {
+        self.0[1]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InfoReplyFlags([SubmessageFlag; 8]);
+
+impl InfoReplyFlags {
+    /// MulticastFlag (M)
+    pub fn multicast_flag(&self) -> SubmessageFlag {
+        self.0[1]
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct RtpsMessageRead {
     header: RtpsMessageHeader,
@@ -347,103 +526,141 @@ impl RtpsMessageRead {
     }
 }
 
+impl RtpsMessageRead {
+    /// Parse the header of the message in `v` and get an iterator over its submessages. The
+    /// submessages are parsed as the iterator advances and one that is malformed or of an unknown
+    /// kind is yielded as an error without stopping the iteration.
+    pub fn iter_submessages(mut v: &[u8]) -> RtpsMessageResult<RtpsSubmessageIter<'_>> {
+        if v.len() < 20 {
+            return Err(RtpsMessageError::NotEnoughData);
+        }
+        if b"RTPS" != &[v[0], v[1], v[2], v[3]] {
+            return Err(RtpsMessageError::InvalidData);
+        }
+        let major = v[4];
+        let minor = v[5];
+        // Messages with a higher major version can not be interpreted by this implementation
+        if major > PROTOCOLVERSION.major() {
+            return Err(RtpsMessageError::UnsupportedVersion);
+        }
+        let version = ProtocolVersion::new(major, minor);
+        let vendor_id = [v[6], v[7]];
+        let guid_prefix = [
+            v[8], v[9], v[10], v[11], v[12], v[13], v[14], v[15], v[16], v[17], v[18], v[19],
+        ];
+        let header = RtpsMessageHeader {
+            version,
+            vendor_id,
+            guid_prefix,
+        };
+        v.consume(20);
+
+        const MAX_SUBMESSAGES: usize = 2_usize.pow(16);
+        Ok(RtpsSubmessageIter {
+            header,
+            data: v,
+            remaining_submessages: MAX_SUBMESSAGES,
+        })
+    }
+}
+
 impl TryFrom<&[u8]> for RtpsMessageRead {
     type Error = RtpsMessageError;
 
-    fn try_from(mut v: &[u8]) -> RtpsMessageResult<Self> {
-        if v.len() >= 20 {
-            if b"RTPS" == &[v[0], v[1], v[2], v[3]] {
-                let major = v[4];
-                let minor = v[5];
-                let version = ProtocolVersion::new(major, minor);
-                let vendor_id = [v[6], v[7]];
-                let guid_prefix = [
-                    v[8], v[9], v[10], v[11], v[12], v[13], v[14], v[15], v[16], v[17], v[18],
-                    v[19],
-                ];
-                let header = RtpsMessageHeader {
-                    version,
-                    vendor_id,
-                    guid_prefix,
-                };
-                v.consume(20);
-
-                const MAX_SUBMESSAGES: usize = 2_usize.pow(16);
-                let mut submessages = Vec::new();
-                for _ in 0..MAX_SUBMESSAGES {
-                    if v.len() < 4 {
-                        break;
-                    }
-                    if let Ok(submessage_header) = SubmessageHeaderRead::try_read_from_bytes(&mut v)
-                    {
-                        let mut submessage_length = submessage_header.submessage_length() as usize;
-                        if v.len() < submessage_length {
-                            break;
-                        }
-                        let submessage = match submessage_header.submessage_id() {
-                            ACKNACK => AckNackSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::AckNack),
-                            DATA => DataSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::Data),
-                            DATA_FRAG => DataFragSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::DataFrag),
-                            GAP => GapSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::Gap),
-                            HEARTBEAT => HeartbeatSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::Heartbeat),
-                            HEARTBEAT_FRAG => {
-                                HeartbeatFragSubmessage::try_from_bytes(&submessage_header, v)
-                                    .map(RtpsSubmessageReadKind::HeartbeatFrag)
-                            }
-                            INFO_DST => {
-                                InfoDestinationSubmessage::try_from_bytes(&submessage_header, v)
-                                    .map(RtpsSubmessageReadKind::InfoDestination)
-                            }
-                            INFO_REPLY => {
-                                InfoReplySubmessage::try_from_bytes(&submessage_header, v)
-                                    .map(RtpsSubmessageReadKind::InfoReply)
-                            }
-                            INFO_SRC => InfoSourceSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::InfoSource),
-                            INFO_TS => {
-                                InfoTimestampSubmessage::try_from_bytes(&submessage_header, v)
-                                    .map(RtpsSubmessageReadKind::InfoTimestamp)
-                            }
-                            NACK_FRAG => NackFragSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::NackFrag),
-                            PAD => PadSubmessage::try_from_bytes(&submessage_header, v)
-                                .map(RtpsSubmessageReadKind::Pad),
-                            _ => Err(RtpsMessageError::UnknownMessage),
-                        };
-                        if let Ok(submessage) = submessage {
-
-                            // DATA and DATA_FRAG submessages can have a length of 0 meaning use everything until the end
-                            // of the buffer
-                            if submessage_length == 0
-                                && (matches!(
-                                    submessage,
-                                    RtpsSubmessageReadKind::Data(_)
-                                        | RtpsSubmessageReadKind::DataFrag(_)
-                                ))
-                            {
-                                submessage_length = v.len();
-                            }
-                            submessages.push(submessage);
-                        }
-
-                        v.consume(submessage_length);
-                    }
-                }
-                Ok(Self {
-                    header,
-                    submessages,
-                })
-            } else {
-                Err(RtpsMessageError::InvalidData)
+    fn try_from(v: &[u8]) -> RtpsMessageResult<Self> {
+        let submessage_iter = Self::iter_submessages(v)?;
+        let header = submessage_iter.header();
+        // Submessages which can not be parsed are skipped and the rest of the message is kept
+        let submessages = submessage_iter.filter_map(Result::ok).collect();
+        Ok(Self {
+            header,
+            submessages,
+        })
+    }
+}
+
+pub struct RtpsSubmessageIter<'a> {
+    header: RtpsMessageHeader,
+    data: &'a [u8],
+    remaining_submessages: usize,
+}
+
+impl RtpsSubmessageIter<'_> {
+    pub fn header(&self) -> RtpsMessageHeader {
+        self.header
+    }
+}
+
+impl Iterator for RtpsSubmessageIter<'_> {
+    type Item = RtpsMessageResult<RtpsSubmessageReadKind>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() < 4 || self.remaining_submessages == 0 {
+            return None;
+        }
+        self.remaining_submessages -= 1;
+
+        let submessage_header = match SubmessageHeaderRead::try_read_from_bytes(&mut self.data) {
+            Ok(submessage_header) => submessage_header,
+            Err(e) => {
+                self.data = &[];
+                return Some(Err(e));
             }
+        };
+        let mut submessage_length = submessage_header.submessage_length() as usize;
+        // A submessage going past the end of the message leaves nothing that can be parsed after it
+        if self.data.len() < submessage_length {
+            self.data = &[];
+            return Some(Err(RtpsMessageError::NotEnoughData));
+        }
+        // The elements are parsed only from the octets announced in the header so that a
+        // submessage shorter than its elements is rejected instead of being read into the next one
+        let v = if submessage_length == 0 {
+            self.data
         } else {
-            Err(RtpsMessageError::NotEnoughData)
+            &self.data[..submessage_length]
+        };
+        let submessage = match submessage_header.submessage_id() {
+            ACKNACK => AckNackSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::AckNack),
+            DATA => DataSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::Data),
+            DATA_FRAG => DataFragSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::DataFrag),
+            GAP => GapSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::Gap),
+            HEARTBEAT => HeartbeatSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::Heartbeat),
+            HEARTBEAT_FRAG => HeartbeatFragSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::HeartbeatFrag),
+            INFO_DST => InfoDestinationSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::InfoDestination),
+            INFO_REPLY => InfoReplySubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::InfoReply),
+            INFO_SRC => InfoSourceSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::InfoSource),
+            INFO_TS => InfoTimestampSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::InfoTimestamp),
+            NACK_FRAG => NackFragSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::NackFrag),
+            PAD => PadSubmessage::try_from_bytes(&submessage_header, v)
+                .map(RtpsSubmessageReadKind::Pad),
+            _ => Err(RtpsMessageError::UnknownMessage),
+        };
+
+        // DATA and DATA_FRAG submessages can have a length of 0 meaning use everything until the end
+        // of the buffer
+        if submessage_length == 0
+            && matches!(
+                submessage,
+                Ok(RtpsSubmessageReadKind::Data(_) | RtpsSubmessageReadKind::DataFrag(_))
+            )
+        {
+            submessage_length = v.len();
         }
+        self.data.consume(submessage_length);
+
+        Some(submessage)
     }
 }
 
@@ -463,25 +680,67 @@ pub fn write_submessage_into_bytes_vec(value: &(dyn Submessage + Send)) -> Vec<u
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct RtpsMessageWrite {
-    data: Arc<[u8]>,
+    data: Vec<u8>,
 }
 
 impl RtpsMessageWrite {
     pub fn new(header: &RtpsMessageHeader, submessages: &[&(dyn Submessage + Send)]) -> Self {
-        let buffer = Vec::new();
-        let mut cursor = Cursor::new(buffer);
+        Self::new_with_capacity(header, submessages, 0)
+    }
+
+    /// Compose the message into a buffer allocated up front for `capacity` octets. Senders knowing
+    /// the approximate message size avoid growing the buffer while the submessages are written.
+    pub fn new_with_capacity(
+        header: &RtpsMessageHeader,
+        submessages: &[&(dyn Submessage + Send)],
+        capacity: usize,
+    ) -> Self {
+        Self::compose(header, submessages, capacity, Endianness::LittleEndian)
+    }
+
+    /// Compose the message with every submessage encoded in the given byte order, as indicated
+    /// by the EndiannessFlag of its header.
+    pub fn new_with_endianness(
+        header: &RtpsMessageHeader,
+        submessages: &[&(dyn Submessage + Send)],
+        endianness: Endianness,
+    ) -> Self {
+        Self::compose(header, submessages, 0, endianness)
+    }
+
+    fn compose(
+        header: &RtpsMessageHeader,
+        submessages: &[&(dyn Submessage + Send)],
+        capacity: usize,
+        endianness: Endianness,
+    ) -> Self {
+        let buffer = Vec::with_capacity(capacity);
+        let mut cursor = Cursor::with_endianness(buffer, endianness);
         header.write_into_bytes(&mut cursor);
         for submessage in submessages {
             submessage.write_submessage_into_bytes(&mut cursor);
         }
         Self {
-            data: Arc::from(cursor.into_inner().into_boxed_slice()),
+            data: cursor.into_inner(),
         }
     }
 
     pub fn buffer(&self) -> &[u8] {
         &self.data
     }
+
+    /// Take the composed message without copying it.
+    pub fn to_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Take the composed message with room for at least `capacity` octets, for callers which
+    /// keep appending to the returned buffer.
+    pub fn to_bytes_with_capacity(self, capacity: usize) -> Vec<u8> {
+        let mut data = self.data;
+        data.reserve(capacity.saturating_sub(data.len()));
+        data
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -551,7 +810,7 @@ impl SubmessageHeaderWrite {
         flags: &[SubmessageFlag],
         submessage_length: u16,
     ) -> Self {
-        let mut flags_octet = 0b_0000_0001_u8;
+        let mut flags_octet = 0b_0000_0000_u8;
         for (i, &item) in flags.iter().enumerate() {
             if item {
                 flags_octet |= 0b_0000_0010 << i
@@ -568,8 +827,12 @@ impl SubmessageHeaderWrite {
 
 impl WriteIntoBytes for SubmessageHeaderWrite {
     fn write_into_bytes(&self, buf: &mut dyn Write) {
+        let endianness_flag = match buf.endianness() {
+            Endianness::BigEndian => 0b_0000_0000,
+            Endianness::LittleEndian => 0b_0000_0001,
+        };
         self.submessage_id.write_into_bytes(buf);
-        self.flags_octet.write_into_bytes(buf);
+        (self.flags_octet | endianness_flag).write_into_bytes(buf);
         self.submessage_length.write_into_bytes(buf);
     }
 }
@@ -579,13 +842,37 @@ mod tests {
     use super::*;
     use crate::{
         rtps_messages::{
-            submessage_elements::{Data, Parameter, ParameterList},
+            submessage_elements::{
+                Data, FragmentNumberSet, LocatorList, Parameter, ParameterList, SequenceNumberSet,
+                SerializedDataFragment,
+            },
             submessages::{data::DataSubmessage, info_timestamp::InfoTimestampSubmessage},
             types::Time,
         },
         transport::types::{EntityId, USER_DEFINED_READER_GROUP, USER_DEFINED_READER_NO_KEY},
     };
 
+    // Writes the submessage followed by a sentinel submessage, checks that the length in the
+    // submessage header matches the elements written and that the parser reaches the sentinel
+    fn write_and_read_back(submessage: &(dyn Submessage + Send)) -> RtpsSubmessageReadKind {
+        let submessage_bytes = write_submessage_into_bytes_vec(submessage);
+        let submessage_length = u16::from_le_bytes([submessage_bytes[2], submessage_bytes[3]]);
+        assert_eq!(submessage_length as usize, submessage_bytes.len() - 4);
+
+        let header = RtpsMessageHeader::new(ProtocolVersion::new(2, 3), [9, 8], [3; 12]);
+        let sentinel = InfoDestinationSubmessage::new([7; 12]);
+        let message = RtpsMessageWrite::new(&header, &[submessage, &sentinel]);
+        let mut submessages = RtpsMessageRead::try_from(message.buffer())
+            .unwrap()
+            .submessages;
+        assert_eq!(submessages.len(), 2);
+        assert_eq!(
+            submessages.pop(),
+            Some(RtpsSubmessageReadKind::InfoDestination(sentinel))
+        );
+        submessages.pop().unwrap()
+    }
+
     #[test]
     fn deserialize_u16() {
         let mut data = &[7, 0, 123][..];
@@ -745,6 +1032,33 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn to_bytes_matches_composed_message() {
+        let header = RtpsMessageHeader::new(ProtocolVersion::new(2, 3), [9, 8], [3; 12]);
+        let info_destination_submessage = InfoDestinationSubmessage::new([7; 12]);
+        let info_timestamp_submessage = InfoTimestampSubmessage::new(false, Time::new(4, 0));
+        let submessages: [&(dyn Submessage + Send); 2] =
+            [&info_destination_submessage, &info_timestamp_submessage];
+
+        let mut composed_message = write_into_bytes_vec(header);
+        for submessage in submessages {
+            composed_message.extend(write_submessage_into_bytes_vec(submessage));
+        }
+
+        assert_eq!(
+            RtpsMessageWrite::new(&header, &submessages).to_bytes(),
+            composed_message
+        );
+        assert_eq!(
+            RtpsMessageWrite::new_with_capacity(&header, &submessages, 256).to_bytes(),
+            composed_message
+        );
+        let bytes_with_capacity =
+            RtpsMessageWrite::new(&header, &submessages).to_bytes_with_capacity(1024);
+        assert_eq!(bytes_with_capacity, composed_message);
+        assert!(bytes_with_capacity.capacity() >= 1024);
+    }
+
     #[test]
     fn deserialize_rtps_message_no_submessage() {
         let header = RtpsMessageHeader {
@@ -764,6 +1078,345 @@ mod tests {
         let rtps_message = RtpsMessageRead::try_from(&data[..]).unwrap();
         assert_eq!(rtps_message.header(), header);
         assert_eq!(rtps_message.submessages(), vec![]);
+        assert!(RtpsMessageRead::iter_submessages(&data[..])
+            .unwrap()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn deserialize_rtps_message_shorter_than_header() {
+        #[rustfmt::skip]
+        let data = [
+            b'R', b'T', b'P', b'S', // Protocol
+            2, 3, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, // GuidPrefix (incomplete)
+        ];
+        for length in [0, 4, 19] {
+            assert!(matches!(
+                RtpsMessageRead::try_from(&data[..length]),
+                Err(RtpsMessageError::NotEnoughData)
+            ));
+            assert!(RtpsMessageRead::iter_submessages(&data[..length]).is_err());
+        }
+    }
+
+    #[test]
+    fn deserialize_rtps_message_higher_major_version() {
+        #[rustfmt::skip]
+        let data = [
+            b'R', b'T', b'P', b'S', // Protocol
+            3, 0, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+        ];
+        assert!(matches!(
+            RtpsMessageRead::try_from(&data[..]),
+            Err(RtpsMessageError::UnsupportedVersion)
+        ));
+    }
+
+    #[test]
+    fn deserialize_rtps_message_higher_minor_version() {
+        #[rustfmt::skip]
+        let data = [
+            b'R', b'T', b'P', b'S', // Protocol
+            2, 5, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+        ];
+        let rtps_message = RtpsMessageRead::try_from(&data[..]).unwrap();
+        assert_eq!(rtps_message.header().version(), ProtocolVersion::new(2, 5));
+        assert_eq!(rtps_message.header().vendor_id(), [9, 8]);
+    }
+
+    #[test]
+    fn deserialize_non_rtps_message() {
+        #[rustfmt::skip]
+        let data = [
+            b'X', b'T', b'P', b'S', // Protocol
+            2, 3, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+        ];
+        assert!(matches!(
+            RtpsMessageRead::try_from(&data[..]),
+            Err(RtpsMessageError::InvalidData)
+        ));
+    }
+
+    #[test]
+    fn submessage_header_named_flags_map_to_bit_positions() {
+        let header_with_flags = |flags_byte: u8| {
+            SubmessageHeaderRead::try_read_from_bytes(&mut &[0x15, flags_byte, 0, 0][..]).unwrap()
+        };
+
+        assert!(header_with_flags(0b_0000_0001).endianness_flag());
+        assert!(!header_with_flags(0b_1111_1110).endianness_flag());
+
+        let header = header_with_flags(0b_0000_0011);
+        assert!(header.ack_nack_flags().final_flag());
+        assert!(header.heartbeat_flags().final_flag());
+        assert!(header.data_flags().inline_qos_flag());
+        assert!(header.data_frag_flags().inline_qos_flag());
+        assert!(header.info_timestamp_flags().invalidate_flag());
+        assert!(header.info_reply_flags().multicast_flag());
+        assert!(!header.heartbeat_flags().liveliness_flag());
+
+        let header = header_with_flags(0b_0000_0101);
+        assert!(header.heartbeat_flags().liveliness_flag());
+        assert!(header.data_flags().data_flag());
+        assert!(header.data_frag_flags().key_flag());
+        assert!(!header.heartbeat_flags().final_flag());
+
+        let header = header_with_flags(0b_0000_1001);
+        assert!(header.data_flags().key_flag());
+        assert!(header.data_frag_flags().non_standard_payload_flag());
+        assert!(!header.data_flags().data_flag());
+
+        let header = header_with_flags(0b_0001_0001);
+        assert!(header.data_flags().non_standard_payload_flag());
+        assert!(!header.data_flags().key_flag());
+    }
+
+    #[test]
+    fn submessage_header_named_flags_match_written_flags() {
+        let mut buf = Vec::new();
+        SubmessageHeaderWrite::new(SubmessageKind::DATA, &[false, true, false, true], 0)
+            .write_into_bytes(&mut buf);
+        let header = SubmessageHeaderRead::try_read_from_bytes(&mut buf.as_slice()).unwrap();
+        assert!(header.endianness_flag());
+        assert!(!header.data_flags().inline_qos_flag());
+        assert!(header.data_flags().data_flag());
+        assert!(!header.data_flags().key_flag());
+        assert!(header.data_flags().non_standard_payload_flag());
+        assert_eq!(header.flags()[..5], [true, false, true, false, true]);
+    }
+
+    #[test]
+    fn every_submessage_length_matches_its_written_elements() {
+        let reader_id = EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY);
+        let writer_id = EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP);
+
+        let ack_nack = AckNackSubmessage::new(
+            true,
+            reader_id,
+            writer_id,
+            SequenceNumberSet::new(3, [3, 5]),
+            2,
+        );
+        assert_eq!(
+            write_and_read_back(&ack_nack),
+            RtpsSubmessageReadKind::AckNack(ack_nack)
+        );
+
+        let data = DataSubmessage::new(
+            true,
+            true,
+            false,
+            false,
+            reader_id,
+            writer_id,
+            5,
+            ParameterList::new(vec![Parameter::new(8, vec![71, 72, 73, 74].into())]),
+            Data::new(vec![1, 2, 3, 4, 5, 6, 7, 8].into()),
+        );
+        assert_eq!(
+            write_and_read_back(&data),
+            RtpsSubmessageReadKind::Data(data)
+        );
+
+        let data_frag = DataFragSubmessage::new(
+            false,
+            false,
+            false,
+            reader_id,
+            writer_id,
+            5,
+            2,
+            1,
+            4,
+            8,
+            ParameterList::empty(),
+            SerializedDataFragment::new(Data::new(vec![5, 6, 7, 8].into()), 0..4),
+        );
+        assert_eq!(
+            write_and_read_back(&data_frag),
+            RtpsSubmessageReadKind::DataFrag(data_frag)
+        );
+
+        let gap = GapSubmessage::new(reader_id, writer_id, 5, SequenceNumberSet::new(10, [11]));
+        assert_eq!(write_and_read_back(&gap), RtpsSubmessageReadKind::Gap(gap));
+
+        let heartbeat = HeartbeatSubmessage::new(true, false, reader_id, writer_id, 1, 7, 3);
+        assert_eq!(
+            write_and_read_back(&heartbeat),
+            RtpsSubmessageReadKind::Heartbeat(heartbeat)
+        );
+
+        let heartbeat_frag = HeartbeatFragSubmessage::_new(reader_id, writer_id, 5, 3, 2);
+        assert_eq!(
+            write_and_read_back(&heartbeat_frag),
+            RtpsSubmessageReadKind::HeartbeatFrag(heartbeat_frag)
+        );
+
+        let info_destination = InfoDestinationSubmessage::new([4; 12]);
+        assert_eq!(
+            write_and_read_back(&info_destination),
+            RtpsSubmessageReadKind::InfoDestination(info_destination)
+        );
+
+        let info_reply = InfoReplySubmessage::new(
+            true,
+            LocatorList::new(vec![Locator::new(1, 7400, [1; 16])]),
+            LocatorList::new(vec![Locator::new(1, 7401, [2; 16])]),
+        );
+        assert_eq!(
+            write_and_read_back(&info_reply),
+            RtpsSubmessageReadKind::InfoReply(info_reply)
+        );
+
+        let info_source = InfoSourceSubmessage::_new(ProtocolVersion::new(2, 4), [1, 2], [5; 12]);
+        assert_eq!(
+            write_and_read_back(&info_source),
+            RtpsSubmessageReadKind::InfoSource(info_source)
+        );
+
+        let info_timestamp = InfoTimestampSubmessage::new(false, Time::new(4, 0));
+        assert_eq!(
+            write_and_read_back(&info_timestamp),
+            RtpsSubmessageReadKind::InfoTimestamp(info_timestamp)
+        );
+
+        let nack_frag = NackFragSubmessage::new(
+            reader_id,
+            writer_id,
+            5,
+            FragmentNumberSet::new(2, [2, 4]),
+            1,
+        );
+        assert_eq!(
+            write_and_read_back(&nack_frag),
+            RtpsSubmessageReadKind::NackFrag(nack_frag)
+        );
+
+        let pad = PadSubmessage::new();
+        assert_eq!(write_and_read_back(&pad), RtpsSubmessageReadKind::Pad(pad));
+    }
+
+    #[test]
+    fn big_endian_message_reads_back_as_little_endian_message() {
+        let reader_id = EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY);
+        let writer_id = EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP);
+        let ack_nack = AckNackSubmessage::new(
+            true,
+            reader_id,
+            writer_id,
+            SequenceNumberSet::new(3, [3, 5]),
+            2,
+        );
+        let data = DataSubmessage::new(
+            true,
+            true,
+            false,
+            false,
+            reader_id,
+            writer_id,
+            (1 << 32) + 5,
+            ParameterList::new(vec![Parameter::new(8, vec![71, 72, 73, 74].into())]),
+            Data::new(vec![1, 2, 3, 4, 5, 6, 7, 8].into()),
+        );
+        let gap = GapSubmessage::new(reader_id, writer_id, 5, SequenceNumberSet::new(10, [11]));
+        let heartbeat = HeartbeatSubmessage::new(true, false, reader_id, writer_id, 1, 7, 3);
+        let info_reply = InfoReplySubmessage::new(
+            false,
+            LocatorList::new(vec![Locator::new(1, 7400, [1; 16])]),
+            LocatorList::new(vec![]),
+        );
+        let info_timestamp = InfoTimestampSubmessage::new(false, Time::new(4, 0));
+        let nack_frag = NackFragSubmessage::new(
+            reader_id,
+            writer_id,
+            5,
+            FragmentNumberSet::new(2, [2, 4]),
+            1,
+        );
+        let submessages: [&(dyn Submessage + Send); 7] = [
+            &ack_nack,
+            &data,
+            &gap,
+            &heartbeat,
+            &info_reply,
+            &info_timestamp,
+            &nack_frag,
+        ];
+        let header = RtpsMessageHeader::new(ProtocolVersion::new(2, 3), [9, 8], [3; 12]);
+
+        let big_endian_message =
+            RtpsMessageWrite::new_with_endianness(&header, &submessages, Endianness::BigEndian);
+        let little_endian_message = RtpsMessageWrite::new(&header, &submessages);
+
+        let mut submessages_data = &big_endian_message.buffer()[20..];
+        let mut number_of_submessages = 0;
+        while !submessages_data.is_empty() {
+            assert_eq!(submessages_data[1] & 0b_0000_0001, 0);
+            let submessage_length =
+                u16::from_be_bytes([submessages_data[2], submessages_data[3]]) as usize;
+            submessages_data = &submessages_data[4 + submessage_length..];
+            number_of_submessages += 1;
+        }
+        assert_eq!(number_of_submessages, submessages.len());
+        let big_endian_message_read =
+            RtpsMessageRead::try_from(big_endian_message.buffer()).unwrap();
+        let little_endian_message_read =
+            RtpsMessageRead::try_from(little_endian_message.buffer()).unwrap();
+        assert_eq!(
+            big_endian_message_read.header(),
+            little_endian_message_read.header()
+        );
+        assert_eq!(
+            big_endian_message_read.submessages().len(),
+            little_endian_message_read.submessages().len()
+        );
+        for (big_endian_submessage, little_endian_submessage) in big_endian_message_read
+            .submessages()
+            .iter()
+            .zip(little_endian_message_read.submessages())
+        {
+            match (big_endian_submessage, little_endian_submessage) {
+                (RtpsSubmessageReadKind::Data(b), RtpsSubmessageReadKind::Data(l)) => {
+                    assert_eq!(&b.clone().with_endianness(Endianness::LittleEndian), l)
+                }
+                (b, l) => assert_eq!(b, l),
+            }
+        }
+    }
+
+    #[test]
+    fn write_heartbeat_big_endian() {
+        let reader_id = EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY);
+        let writer_id = EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP);
+        let heartbeat =
+            HeartbeatSubmessage::new(false, false, reader_id, writer_id, 1, (1 << 32) + 7, 3);
+        let header = RtpsMessageHeader::new(ProtocolVersion::new(2, 3), [9, 8], [3; 12]);
+        let message =
+            RtpsMessageWrite::new_with_endianness(&header, &[&heartbeat], Endianness::BigEndian);
+        #[rustfmt::skip]
+        assert_eq!(&message.buffer()[20..], &[
+            0x07, 0b_0000_0000, 0, 28, // Submessage header
+            1, 2, 3, USER_DEFINED_READER_NO_KEY, // readerId
+            6, 7, 8, USER_DEFINED_READER_GROUP, // writerId
+            0, 0, 0, 0, // firstSN: SequenceNumber: high
+            0, 0, 0, 1, // firstSN: SequenceNumber: low
+            0, 0, 0, 1, // lastSN: SequenceNumber: high
+            0, 0, 0, 7, // lastSN: SequenceNumber: low
+            0, 0, 0, 3, // count: Count
+        ]);
     }
 
     #[test]
@@ -829,6 +1482,94 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn iter_submessages_yields_error_for_unknown_submessage_and_continues() {
+        #[rustfmt::skip]
+        let data = [
+            b'R', b'T', b'P', b'S', // Protocol
+            2, 3, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            0x99, 0b_0101_0011, 4, 0, // Submessage header
+            9, 9, 9, 9, // Unkown data
+            0x0e, 0b_0000_0001, 12, 0, // Submessage header
+            7, 7, 7, 7, // GuidPrefix
+            7, 7, 7, 7, // GuidPrefix
+            7, 7, 7, 7, // GuidPrefix
+        ];
+
+        let mut submessage_iter = RtpsMessageRead::iter_submessages(&data[..]).unwrap();
+        assert_eq!(
+            submessage_iter.header(),
+            RtpsMessageHeader::new(ProtocolVersion::new(2, 3), [9, 8], [3; 12])
+        );
+        assert!(matches!(
+            submessage_iter.next(),
+            Some(Err(RtpsMessageError::UnknownMessage))
+        ));
+        assert!(matches!(
+            submessage_iter.next(),
+            Some(Ok(RtpsSubmessageReadKind::InfoDestination(m))) if m.guid_prefix() == [7; 12]
+        ));
+        assert!(submessage_iter.next().is_none());
+    }
+
+    #[test]
+    fn submessage_is_not_parsed_past_its_submessage_length() {
+        #[rustfmt::skip]
+        let data = [
+            b'R', b'T', b'P', b'S', // Protocol
+            2, 3, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            0x07, 0b_0000_0001, 24, 0, // Submessage header (HEARTBEAT, 4 octets too short)
+            1, 2, 3, 4, // readerId
+            5, 6, 7, 8, // writerId
+            0, 0, 0, 0, // firstSN: SequenceNumber: high
+            1, 0, 0, 0, // firstSN: SequenceNumber: low
+            0, 0, 0, 0, // lastSN: SequenceNumber: high
+            7, 0, 0, 0, // lastSN: SequenceNumber: low
+            0x0e, 0b_0000_0001, 12, 0, // Submessage header (INFO_DST)
+            7, 7, 7, 7, // guidPrefix
+            7, 7, 7, 7, // guidPrefix
+            7, 7, 7, 7, // guidPrefix
+        ];
+
+        let mut submessage_iter = RtpsMessageRead::iter_submessages(&data[..]).unwrap();
+        assert!(matches!(
+            submessage_iter.next(),
+            Some(Err(RtpsMessageError::Io))
+        ));
+        assert!(matches!(
+            submessage_iter.next(),
+            Some(Ok(RtpsSubmessageReadKind::InfoDestination(m))) if m.guid_prefix() == [7; 12]
+        ));
+        assert!(submessage_iter.next().is_none());
+    }
+
+    #[test]
+    fn iter_submessages_stops_after_too_high_submessage_length() {
+        #[rustfmt::skip]
+        let data = [
+            b'R', b'T', b'P', b'S', // Protocol
+            2, 3, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            0x09_u8, 0b_0000_0001, 8, 0, // Submessage header
+            4, 0, 0, 0, // Time (half only)
+        ];
+
+        let mut submessage_iter = RtpsMessageRead::iter_submessages(&data[..]).unwrap();
+        assert!(matches!(
+            submessage_iter.next(),
+            Some(Err(RtpsMessageError::NotEnoughData))
+        ));
+        assert!(submessage_iter.next().is_none());
+    }
+
     #[test]
     fn deserialize_rtps_message_unknown_submessage() {
         let expected_data_submessage = RtpsSubmessageReadKind::Data(DataSubmessage::new(
@@ -874,6 +1615,95 @@ mod tests {
         assert_eq!(expected_submessages, rtps_message.submessages());
     }
 
+    #[test]
+    fn pad_between_data_submessages_round_trip() {
+        let header = RtpsMessageHeader::new(ProtocolVersion::new(2, 3), [9, 8], [3; 12]);
+        let data_submessage = |writer_sn| {
+            DataSubmessage::new(
+                false,
+                true,
+                false,
+                false,
+                EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
+                EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP),
+                writer_sn,
+                ParameterList::empty(),
+                Data::new(vec![1, 2, 3, 4].into()),
+            )
+        };
+        let message = RtpsMessageWrite::new(
+            &header,
+            &[
+                &data_submessage(1),
+                &PadSubmessage::new(),
+                &data_submessage(2),
+            ],
+        );
+
+        let rtps_message = RtpsMessageRead::try_from(message.buffer()).unwrap();
+        assert_eq!(
+            rtps_message.submessages(),
+            vec![
+                RtpsSubmessageReadKind::Data(data_submessage(1)),
+                RtpsSubmessageReadKind::Pad(PadSubmessage::new()),
+                RtpsSubmessageReadKind::Data(data_submessage(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_rtps_message_with_padding_between_data_submessages() {
+        let data_submessage = |writer_sn| {
+            RtpsSubmessageReadKind::Data(DataSubmessage::new(
+                false,
+                false,
+                false,
+                false,
+                EntityId::new([1, 2, 3], 4),
+                EntityId::new([6, 7, 8], 9),
+                writer_sn,
+                ParameterList::empty(),
+                Data::default(),
+            ))
+        };
+
+        #[rustfmt::skip]
+        let data = [
+            b'R', b'T', b'P', b'S', // Protocol
+            2, 3, 9, 8, // ProtocolVersion | VendorId
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            3, 3, 3, 3, // GuidPrefix
+            0x15, 0b_0000_0001, 20, 0, // Submessage header
+            0, 0, 16, 0, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            1, 0, 0, 0, // writerSN: low
+            0x01, 0b_0000_0001, 8, 0, // Submessage header
+            0xaa, 0xaa, 0xaa, 0xaa, // Padding
+            0xaa, 0xaa, 0xaa, 0xaa, // Padding
+            0x01, 0b_0000_0001, 0, 0, // Submessage header
+            0x15, 0b_0000_0001, 20, 0, // Submessage header
+            0, 0, 16, 0, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            2, 0, 0, 0, // writerSN: low
+        ];
+
+        let rtps_message = RtpsMessageRead::try_from(&data[..]).unwrap();
+        assert_eq!(
+            rtps_message.submessages(),
+            vec![
+                data_submessage(1),
+                RtpsSubmessageReadKind::Pad(PadSubmessage::new()),
+                RtpsSubmessageReadKind::Pad(PadSubmessage::new()),
+                data_submessage(2),
+            ]
+        );
+    }
+
     #[test]
     fn deserialize_opendds_style_rtps_message() {
         let expected_header = RtpsMessageHeader {
@@ -21,30 +21,65 @@ pub struct SequenceNumberSet {
 }
 
 impl SequenceNumberSet {
+    /// Create a set starting at `base`. Sequence numbers that the set cannot hold are left out,
+    /// see [`SequenceNumberSet::insert`].
     pub fn new(base: SequenceNumber, set: impl IntoIterator<Item = SequenceNumber>) -> Self {
-        let mut bitmap = [0; 8];
-        let mut num_bits = 0;
-        for sequence_number in set {
-            let delta_n = (sequence_number - base) as u32;
-            let bitmap_num = delta_n / 32;
-            bitmap[bitmap_num as usize] |= 1 << (31 - delta_n % 32);
-            if delta_n + 1 > num_bits {
-                num_bits = delta_n + 1;
-            }
-        }
-
-        Self {
+        let mut sequence_number_set = Self {
             base,
-            num_bits,
-            bitmap,
+            num_bits: 0,
+            bitmap: [0; 8],
+        };
+        for sequence_number in set {
+            sequence_number_set.insert(sequence_number);
         }
+        sequence_number_set
     }
 
     pub fn base(&self) -> SequenceNumber {
         self.base
     }
 
-    pub fn set(&self) -> impl Iterator<Item = SequenceNumber> + '_ {
+    /// Add a sequence number to the set and return whether it was not yet present. Only the 256
+    /// sequence numbers starting at the base can be held, any other is not inserted.
+    pub fn insert(&mut self, sequence_number: SequenceNumber) -> bool {
+        let Some((bitmap_num, mask)) = self.bitmap_position(sequence_number) else {
+            return false;
+        };
+        if self.bitmap[bitmap_num] & mask == mask {
+            return false;
+        }
+        self.bitmap[bitmap_num] |= mask;
+        self.num_bits = self.num_bits.max((sequence_number - self.base) as u32 + 1);
+        true
+    }
+
+    pub fn contains(&self, sequence_number: SequenceNumber) -> bool {
+        match self.bitmap_position(sequence_number) {
+            Some((bitmap_num, mask)) => {
+                (sequence_number - self.base) < self.num_bits as i64
+                    && self.bitmap[bitmap_num] & mask == mask
+            }
+            None => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    fn bitmap_position(&self, sequence_number: SequenceNumber) -> Option<(usize, i32)> {
+        let delta_n = sequence_number.checked_sub(self.base)?;
+        if !(0..256).contains(&delta_n) {
+            return None;
+        }
+        Some(((delta_n / 32) as usize, 1 << (31 - delta_n % 32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = SequenceNumber> + '_ {
         struct SequenceNumberSetIterator<'a> {
             set: &'a SequenceNumberSet,
             index: usize,
@@ -126,6 +161,10 @@ impl FragmentNumberSet {
     ) -> RtpsMessageResult<Self> {
         let base = FragmentNumber::try_read_from_bytes(data, endianness)?;
         let num_bits = u32::try_read_from_bytes(data, endianness)?;
+        // Fragment numbers start at 1 and the bitmap can hold at most 256 bits
+        if base < 1 || num_bits > 256 {
+            return Err(RtpsMessageError::InvalidData);
+        }
         let number_of_bitmap_elements = num_bits.div_ceil(32) as usize; //In standard referred to as "M"
         let mut bitmap = [0; 8];
 
@@ -289,12 +328,13 @@ impl ParameterList {
         for _ in 0..MAX_PARAMETERS {
             let parameter_i = Parameter::try_read_from_bytes(data, endianness)?;
             if parameter_i.parameter_id() == PID_SENTINEL {
-                break;
+                return Ok(Self { parameter });
             } else {
                 parameter.push(parameter_i);
             }
         }
-        Ok(Self { parameter })
+        // A list without sentinel within the maximum number of parameters is malformed
+        Err(RtpsMessageError::InvalidData)
     }
 }
 
@@ -437,11 +477,42 @@ mod tests {
 
         assert_eq!(seq_num_set.base(), base);
         assert!(
-            seq_num_set.set().eq(set),
+            seq_num_set.iter().eq(set),
             "{:?} not equal to {:?}",
-            seq_num_set.set().collect::<Vec<_>>(),
+            seq_num_set.iter().collect::<Vec<_>>(),
             set
         );
+        assert_eq!(seq_num_set.len(), 3);
+        assert!(!seq_num_set.is_empty());
+        assert!(seq_num_set.contains(200));
+        assert!(!seq_num_set.contains(201));
+    }
+
+    #[test]
+    fn sequence_number_set_insert() {
+        let mut seq_num_set = SequenceNumberSet::new(100, []);
+        assert!(seq_num_set.is_empty());
+        assert_eq!(seq_num_set.len(), 0);
+
+        assert!(seq_num_set.insert(130));
+        assert!(seq_num_set.insert(101));
+        assert!(!seq_num_set.insert(130));
+        assert!(seq_num_set.contains(101));
+        assert!(seq_num_set.contains(130));
+        assert!(!seq_num_set.contains(100));
+        assert!(seq_num_set.iter().eq([101, 130]));
+        assert_eq!(seq_num_set, SequenceNumberSet::new(100, [130, 101]));
+    }
+
+    #[test]
+    fn sequence_number_set_leaves_out_numbers_outside_its_range() {
+        let mut seq_num_set = SequenceNumberSet::new(100, [99, 100, 355, 356, i64::MIN]);
+        assert!(seq_num_set.iter().eq([100, 355]));
+        assert!(!seq_num_set.insert(356));
+        assert!(!seq_num_set.insert(99));
+        assert!(!seq_num_set.contains(356));
+        assert!(!seq_num_set.contains(i64::MIN));
+        assert_eq!(seq_num_set.len(), 2);
     }
 
     #[test]
@@ -566,6 +637,26 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn deserialize_fragment_number_set_too_many_bits() {
+        #[rustfmt::skip]
+        let result = FragmentNumberSet::try_read_from_bytes(&mut &[
+            2, 0, 0, 0, // bitmapBase: (unsigned long)
+            1, 1, 0, 0, // numBits (unsigned long)
+        ][..], &Endianness::LittleEndian);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_fragment_number_set_zero_base() {
+        #[rustfmt::skip]
+        let result = FragmentNumberSet::try_read_from_bytes(&mut &[
+            0, 0, 0, 0, // bitmapBase: (unsigned long)
+            0, 0, 0, 0, // numBits (unsigned long)
+        ][..], &Endianness::LittleEndian);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn deserialize_guid_prefix() {
         let expected = [1; 12];
@@ -788,6 +879,21 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn parameter_lists_differing_in_one_value_are_not_equal() {
+        let parameter_list_1 = ParameterList::new(vec![
+            Parameter::new(2, vec![51, 61, 71, 81].into()),
+            Parameter::new(3, vec![52, 62, 0, 0].into()),
+        ]);
+        let parameter_list_2 = ParameterList::new(vec![
+            Parameter::new(2, vec![51, 61, 71, 81].into()),
+            Parameter::new(3, vec![52, 63, 0, 0].into()),
+        ]);
+
+        assert_ne!(parameter_list_1, parameter_list_2);
+        assert_eq!(parameter_list_1, parameter_list_1.clone());
+    }
+
     #[test]
     fn serialize_parameter_list_empty() {
         let parameter = ParameterList::empty();
@@ -893,6 +999,31 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn deserialize_parameter_list_with_non_multiple_of_4_length_is_rejected() {
+        let result = ParameterList::try_read_from_bytes(
+            &mut [
+                0x02, 0x00, 4, 0, // Parameter ID | length
+                15, 16, 17, 18, // value
+                0x03, 0x00, 3, 0, // Parameter ID | length
+                25, 26, 27, 0, // value
+                0x01, 0x00, 0, 0, // Sentinel: Parameter ID | length
+            ]
+            .as_slice(),
+            &Endianness::LittleEndian,
+        );
+        assert!(matches!(result, Err(RtpsMessageError::InvalidData)));
+    }
+
+    #[test]
+    fn deserialize_parameter_list_without_sentinel_within_maximum_parameters_is_rejected() {
+        // Empty parameters repeated beyond the maximum number of parameters read
+        let data = [0x02, 0x00, 0, 0].repeat(2_usize.pow(16) + 1);
+        let result =
+            ParameterList::try_read_from_bytes(&mut data.as_slice(), &Endianness::LittleEndian);
+        assert!(matches!(result, Err(RtpsMessageError::InvalidData)));
+    }
+
     #[test]
     fn deserialize_parameter_list_with_long_parameter_including_sentinel() {
         let parameter_value_expected = vec![
@@ -26,7 +26,7 @@ impl AckNackSubmessage {
     ) -> RtpsMessageResult<Self> {
         let endianness = submessage_header.endianness();
         Ok(Self {
-            final_flag: submessage_header.flags()[1],
+            final_flag: submessage_header.ack_nack_flags().final_flag(),
             reader_id: EntityId::try_read_from_bytes(&mut data, endianness)?,
             writer_id: EntityId::try_read_from_bytes(&mut data, endianness)?,
             reader_sn_state: SequenceNumberSet::try_read_from_bytes(&mut data, endianness)?,
@@ -1,14 +1,17 @@
 use super::super::{
     error::{RtpsMessageError, RtpsMessageResult},
     overall_structure::{
-        Submessage, SubmessageHeaderRead, SubmessageHeaderWrite, TryReadFromBytes, Write,
-        WriteIntoBytes,
+        Endianness, Submessage, SubmessageHeaderRead, SubmessageHeaderWrite, TryReadFromBytes,
+        Write, WriteIntoBytes,
     },
     submessage_elements::{Data, ParameterList},
     types::{SubmessageFlag, SubmessageKind},
 };
 use crate::transport::types::{EntityId, SequenceNumber};
 
+// extraFlags, octetsToInlineQos, readerId, writerId and writerSN
+const DATA_FIXED_FIELDS_LENGTH: usize = 20;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct DataSubmessage {
     inline_qos_flag: bool,
@@ -20,6 +23,8 @@ pub struct DataSubmessage {
     writer_sn: SequenceNumber,
     inline_qos: ParameterList,
     serialized_payload: Data,
+    // Byte order of the values in the inline QoS
+    endianness: Endianness,
 }
 
 impl DataSubmessage {
@@ -32,13 +37,19 @@ impl DataSubmessage {
         }
         let mut slice = data;
         let endianness = submessage_header.endianness();
-        let inline_qos_flag = submessage_header.flags()[1];
-        let data_flag = submessage_header.flags()[2];
-        let key_flag = submessage_header.flags()[3];
-        let non_standard_payload_flag = submessage_header.flags()[4];
+        let flags = submessage_header.data_flags();
+        let inline_qos_flag = flags.inline_qos_flag();
+        let data_flag = flags.data_flag();
+        let key_flag = flags.key_flag();
+        let non_standard_payload_flag = flags.non_standard_payload_flag();
 
         let _extra_flags = u16::try_read_from_bytes(&mut slice, endianness)?;
+        // octetsToInlineQos counts from the end of its own field, which sits 4 octets into the
+        // submessage, and may point past the fixed fields when the writer adds extra ones.
         let octets_to_inline_qos = u16::try_read_from_bytes(&mut slice, endianness)? as usize + 4;
+        if octets_to_inline_qos < DATA_FIXED_FIELDS_LENGTH {
+            return Err(RtpsMessageError::InvalidData);
+        }
         let reader_id = EntityId::try_read_from_bytes(&mut slice, endianness)?;
         let writer_id = EntityId::try_read_from_bytes(&mut slice, endianness)?;
         let writer_sn = SequenceNumber::try_read_from_bytes(&mut slice, endianness)?;
@@ -79,6 +90,7 @@ impl DataSubmessage {
             writer_sn,
             inline_qos,
             serialized_payload,
+            endianness: *endianness,
         })
     }
 
@@ -104,6 +116,7 @@ impl DataSubmessage {
             writer_sn,
             inline_qos,
             serialized_payload,
+            endianness: Endianness::LittleEndian,
         }
     }
 
@@ -119,7 +132,9 @@ impl DataSubmessage {
         self.key_flag
     }
 
-    pub fn _non_standard_payload_flag(&self) -> bool {
+    /// Indicates that the serialized payload is not formatted as CDR data or key and should be
+    /// handled as a raw byte blob available through [`Self::serialized_payload`].
+    pub fn non_standard_payload_flag(&self) -> bool {
         self.non_standard_payload_flag
     }
 
@@ -142,6 +157,11 @@ impl DataSubmessage {
     pub fn serialized_payload(&self) -> &Data {
         &self.serialized_payload
     }
+
+    /// Byte order in which the values of the inline QoS parameters are encoded
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
 }
 
 impl Submessage for DataSubmessage {
@@ -176,6 +196,15 @@ impl Submessage for DataSubmessage {
     }
 }
 
+#[cfg(test)]
+impl DataSubmessage {
+    // Submessages read in different byte orders differ only in their endianness
+    pub fn with_endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +359,29 @@ mod tests {
         assert_eq!(&serialized_payload, data_submessage.serialized_payload());
     }
 
+    #[test]
+    fn deserialize_non_standard_serialized_payload() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x15, 0b_0001_0101, 24, 0, // Submessage header
+            0, 0, 16, 0, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            5, 0, 0, 0, // writerSN: low
+            0xca, 0xfe, 0xba, 0xbe, // SerializedPayload (not CDR)
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let data_submessage = DataSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        assert!(data_submessage.non_standard_payload_flag());
+        assert!(data_submessage._data_flag());
+        assert_eq!(
+            &Data::new(vec![0xca, 0xfe, 0xba, 0xbe].into()),
+            data_submessage.serialized_payload()
+        );
+    }
+
     #[test]
     fn deserialize_no_inline_qos_with_serialized_payload() {
         let expected_inline_qos = ParameterList::empty();
@@ -476,6 +528,49 @@ mod tests {
         assert_eq!(&expected_inline_qos, data_submessage.inline_qos());
     }
 
+    #[test]
+    fn deserialize_payload_after_non_default_octets_to_inline_qos() {
+        let expected_serialized_payload = Data::new(vec![1, 2, 3, 4].into());
+        #[rustfmt::skip]
+        let mut data = &[
+            0x15, 0b_0000_0101, 32, 0, // Submessage header
+            0, 0, 24, 0, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            5, 0, 0, 0, // writerSN: low
+            123, 123, 123, 123, // Unknown data
+            123, 123, 123, 123, // Unknown data
+            1, 2, 3, 4, // SerializedPayload
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let data_submessage = DataSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        assert_eq!(5, data_submessage.writer_sn());
+        assert_eq!(&ParameterList::empty(), data_submessage.inline_qos());
+        assert_eq!(
+            &expected_serialized_payload,
+            data_submessage.serialized_payload()
+        );
+    }
+
+    #[test]
+    fn deserialize_octets_to_inline_qos_inside_fixed_fields_is_rejected() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x15, 0b_0000_0101, 24, 0, // Submessage header
+            0, 0, 8, 0, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            5, 0, 0, 0, // writerSN: low
+            1, 2, 3, 4, // SerializedPayload
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+
+        assert!(DataSubmessage::try_from_bytes(&submessage_header, data).is_err());
+    }
+
     #[test]
     fn fuzz_test_input_1() {
         let mut data = &[
@@ -9,6 +9,11 @@ use super::super::{
     submessage_elements::{ParameterList, SerializedDataFragment},
     types::{FragmentNumber, SubmessageFlag, SubmessageKind},
 };
+
+// extraFlags, octetsToInlineQos, readerId, writerId, writerSN, fragmentStartingNum,
+// fragmentsInSubmessage, fragmentSize and sampleSize
+const DATA_FRAG_FIXED_FIELDS_LENGTH: usize = 32;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct DataFragSubmessage {
     inline_qos_flag: bool,
@@ -35,15 +40,21 @@ impl DataFragSubmessage {
         }
 
         let mut slice = data;
-        if data.len() >= 32 {
+        if data.len() >= DATA_FRAG_FIXED_FIELDS_LENGTH {
             let endianness = submessage_header.endianness();
-            let inline_qos_flag = submessage_header.flags()[1];
-            let key_flag = submessage_header.flags()[2];
-            let non_standard_payload_flag = submessage_header.flags()[3];
+            let flags = submessage_header.data_frag_flags();
+            let inline_qos_flag = flags.inline_qos_flag();
+            let key_flag = flags.key_flag();
+            let non_standard_payload_flag = flags.non_standard_payload_flag();
 
             let _extra_flags = u16::try_read_from_bytes(&mut slice, endianness)?;
+            // octetsToInlineQos counts from the end of its own field and may point past the
+            // fixed fields when the writer adds extra ones.
             let octets_to_inline_qos =
                 u16::try_read_from_bytes(&mut slice, endianness)? as usize + 4;
+            if octets_to_inline_qos < DATA_FRAG_FIXED_FIELDS_LENGTH {
+                return Err(RtpsMessageError::InvalidData);
+            }
             let reader_id = EntityId::try_read_from_bytes(&mut slice, endianness)?;
             let writer_id = EntityId::try_read_from_bytes(&mut slice, endianness)?;
             let writer_sn = SequenceNumber::try_read_from_bytes(&mut slice, endianness)?;
@@ -70,6 +81,17 @@ impl DataFragSubmessage {
             } else {
                 ParameterList::empty()
             };
+            // The payload must hold every fragment announced in the submessage. The last
+            // fragment of the sample may be shorter than fragment_size.
+            let fragment_offset =
+                fragment_starting_num.saturating_sub(1) as u64 * fragment_size as u64;
+            let expected_payload_length = core::cmp::min(
+                fragments_in_submessage as u64 * fragment_size as u64,
+                (data_size as u64).saturating_sub(fragment_offset),
+            );
+            if (data_starting_at_inline_qos.len() as u64) < expected_payload_length {
+                return Err(RtpsMessageError::InvalidData);
+            }
             let serialized_payload = SerializedDataFragment::from(data_starting_at_inline_qos);
 
             Ok(Self {
@@ -361,8 +383,7 @@ mod tests {
             1, 2, 3, 0, // serializedPayload
         ][..];
         let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
-        let submessage =
-            DataFragSubmessage::try_from_bytes(&submessage_header, data.into()).unwrap();
+        let submessage = DataFragSubmessage::try_from_bytes(&submessage_header, data).unwrap();
 
         let expected_inline_qos_flag = true;
         let expected_non_standard_payload_flag = false;
@@ -404,6 +425,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_fragments_exceeding_payload() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x16_u8, 0b_0000_0001, 40, 0, // Submessage header
+            0, 0, 28, 0, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            5, 0, 0, 0, // writerSN: low
+            1, 0, 0, 0, // fragmentStartingNum
+            2, 0, 8, 0, // fragmentsInSubmessage | fragmentSize
+            32, 0, 0, 0, // sampleSize
+            1, 2, 3, 4, // serializedPayload
+            5, 6, 7, 8, // serializedPayload
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+
+        assert!(matches!(
+            DataFragSubmessage::try_from_bytes(&submessage_header, data),
+            Err(RtpsMessageError::InvalidData)
+        ));
+    }
+
+    #[test]
+    fn deserialize_last_fragment_shorter_than_fragment_size() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x16_u8, 0b_0000_0001, 40, 0, // Submessage header
+            0, 0, 28, 0, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            5, 0, 0, 0, // writerSN: low
+            3, 0, 0, 0, // fragmentStartingNum
+            1, 0, 8, 0, // fragmentsInSubmessage | fragmentSize
+            22, 0, 0, 0, // sampleSize
+            1, 2, 3, 4, // serializedPayload
+            5, 6, 0, 0, // serializedPayload
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let submessage = DataFragSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        assert_eq!(submessage.fragment_starting_num(), 3);
+        assert_eq!(submessage.fragments_in_submessage(), 1);
+        assert_eq!(submessage.fragment_size(), 8);
+        assert_eq!(submessage.data_size(), 22);
+    }
+
+    #[test]
+    fn deserialize_inline_qos_after_non_default_octets_to_inline_qos() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x16_u8, 0b_0000_0011, 52, 0, // Submessage header
+            0, 0, 32, 0, // extraFlags | octetsToInlineQos
+            1, 2, 3, 4, // readerId
+            6, 7, 8, 9, // writerId
+            0, 0, 0, 0, // writerSN: high
+            6, 0, 0, 0, // writerSN: low
+            2, 0, 0, 0, // fragmentStartingNum
+            3, 0, 5, 0, // fragmentsInSubmessage | fragmentSize
+            8, 0, 0, 0, // sampleSize
+            123, 123, 123, 123, // Unknown data
+            8, 0, 4, 0, // inlineQos: parameterId, length
+            71, 72, 73, 74, // inlineQos: value[length]
+            1, 0, 0, 0, // inlineQos: Sentinel
+            1, 2, 3, 0, // serializedPayload
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let submessage = DataFragSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        let expected_inline_qos =
+            ParameterList::new(vec![Parameter::new(8, vec![71, 72, 73, 74].into())]);
+        let expected_serialized_payload = SerializedDataFragment::from(&[1, 2, 3, 0][..]);
+        assert_eq!(6, submessage.writer_sn());
+        assert_eq!(8, submessage.data_size());
+        assert_eq!(&expected_inline_qos, submessage.inline_qos());
+        assert_eq!(
+            &expected_serialized_payload,
+            submessage.serialized_payload()
+        );
+    }
+
+    #[test]
+    fn deserialize_octets_to_inline_qos_inside_fixed_fields_is_rejected() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x16_u8, 0b_0000_0001, 36, 0, // Submessage header
+            0, 0, 16, 0, // extraFlags | octetsToInlineQos
+            1, 2, 3, 4, // readerId
+            6, 7, 8, 9, // writerId
+            0, 0, 0, 0, // writerSN: high
+            5, 0, 0, 0, // writerSN: low
+            1, 0, 0, 0, // fragmentStartingNum
+            1, 0, 4, 0, // fragmentsInSubmessage | fragmentSize
+            4, 0, 0, 0, // sampleSize
+            1, 2, 3, 4, // serializedPayload
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+
+        assert!(matches!(
+            DataFragSubmessage::try_from_bytes(&submessage_header, data),
+            Err(RtpsMessageError::InvalidData)
+        ));
+    }
+
     #[test]
     fn fuzz_test_input_1() {
         let mut data = &[
@@ -413,6 +540,6 @@ mod tests {
         ][..];
         let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
         // Should not panic with this input
-        let _ = DataFragSubmessage::try_from_bytes(&submessage_header, data.into());
+        let _ = DataFragSubmessage::try_from_bytes(&submessage_header, data);
     }
 }
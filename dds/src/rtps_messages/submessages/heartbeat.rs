@@ -27,8 +27,8 @@ impl HeartbeatSubmessage {
     ) -> RtpsMessageResult<Self> {
         let endianness = submessage_header.endianness();
         Ok(Self {
-            final_flag: submessage_header.flags()[1],
-            liveliness_flag: submessage_header.flags()[2],
+            final_flag: submessage_header.heartbeat_flags().final_flag(),
+            liveliness_flag: submessage_header.heartbeat_flags().liveliness_flag(),
             reader_id: EntityId::try_read_from_bytes(&mut data, endianness)?,
             writer_id: EntityId::try_read_from_bytes(&mut data, endianness)?,
             first_sn: SequenceNumber::try_read_from_bytes(&mut data, endianness)?,
@@ -1,7 +1,7 @@
 use crate::transport::types::{EntityId, SequenceNumber};
 
 use super::super::{
-    error::RtpsMessageResult,
+    error::{RtpsMessageError, RtpsMessageResult},
     overall_structure::{
         Submessage, SubmessageHeaderRead, SubmessageHeaderWrite, TryReadFromBytes, Write,
         WriteIntoBytes,
@@ -23,6 +23,13 @@ impl HeartbeatFragSubmessage {
         submessage_header: &SubmessageHeaderRead,
         mut data: &[u8],
     ) -> RtpsMessageResult<Self> {
+        // The elements have a fixed size. A shorter submessage length would otherwise make the
+        // elements be read from the bytes of the following submessage.
+        const ELEMENTS_LENGTH: u16 = 24;
+        let submessage_length = submessage_header.submessage_length();
+        if submessage_length != 0 && submessage_length < ELEMENTS_LENGTH {
+            return Err(RtpsMessageError::NotEnoughData);
+        }
         let endianness = submessage_header.endianness();
         Ok(Self {
             reader_id: EntityId::try_read_from_bytes(&mut data, endianness)?,
@@ -144,4 +151,38 @@ mod tests {
         assert_eq!(expected_last_fragment_num, submessage._last_fragment_num());
         assert_eq!(expected_count, submessage.count());
     }
+
+    #[test]
+    fn deserialize_heart_beat_frag_shorter_than_elements() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x13_u8, 0b_0000_0001, 20, 0, // Submessage header
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: SequenceNumber: high
+            5, 0, 0, 0, // writerSN: SequenceNumber: low
+            7, 0, 0, 0, // lastFragmentNum
+            0x01, 0b_0000_0001, 0, 0, // Next submessage header
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+
+        assert!(matches!(
+            HeartbeatFragSubmessage::try_from_bytes(&submessage_header, data),
+            Err(RtpsMessageError::NotEnoughData)
+        ));
+    }
+
+    #[test]
+    fn deserialize_truncated_heart_beat_frag() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x13_u8, 0b_0000_0001, 24, 0, // Submessage header
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: SequenceNumber: high
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+
+        assert!(HeartbeatFragSubmessage::try_from_bytes(&submessage_header, data).is_err());
+    }
 }
@@ -22,7 +22,7 @@ impl InfoReplySubmessage {
         mut data: &[u8],
     ) -> RtpsMessageResult<Self> {
         let endianness = submessage_header.endianness();
-        let multicast_flag = submessage_header.flags()[1];
+        let multicast_flag = submessage_header.info_reply_flags().multicast_flag();
         let unicast_locator_list = LocatorList::try_read_from_bytes(&mut data, endianness)?;
         let multicast_locator_list = if multicast_flag {
             LocatorList::try_read_from_bytes(&mut data, endianness)?
@@ -36,23 +36,27 @@ impl InfoReplySubmessage {
         })
     }
 
-    pub fn _multicast_flag(&self) -> bool {
+    pub fn multicast_flag(&self) -> bool {
         self.multicast_flag
     }
 
-    pub fn _unicast_locator_list(&self) -> &LocatorList {
+    pub fn unicast_locator_list(&self) -> &LocatorList {
         &self.unicast_locator_list
     }
 
-    pub fn _multicast_locator_list(&self) -> &LocatorList {
+    pub fn multicast_locator_list(&self) -> &LocatorList {
         &self.multicast_locator_list
     }
 }
 
 impl Submessage for InfoReplySubmessage {
     fn write_submessage_header_into_bytes(&self, octets_to_next_header: u16, buf: &mut dyn Write) {
-        SubmessageHeaderWrite::new(SubmessageKind::INFO_REPLY, &[], octets_to_next_header)
-            .write_into_bytes(buf);
+        SubmessageHeaderWrite::new(
+            SubmessageKind::INFO_REPLY,
+            &[self.multicast_flag],
+            octets_to_next_header,
+        )
+        .write_into_bytes(buf);
     }
 
     fn write_submessage_elements_into_bytes(&self, buf: &mut dyn Write) {
@@ -64,7 +68,7 @@ impl Submessage for InfoReplySubmessage {
 }
 
 impl InfoReplySubmessage {
-    pub fn _new(
+    pub fn new(
         multicast_flag: SubmessageFlag,
         unicast_locator_list: LocatorList,
         multicast_locator_list: LocatorList,
@@ -88,7 +92,7 @@ mod tests {
     #[test]
     fn serialize_info_reply() {
         let locator = Locator::new(11, 12, [1; 16]);
-        let submessage = InfoReplySubmessage::_new(
+        let submessage = InfoReplySubmessage::new(
             false,
             LocatorList::new(vec![locator]),
             LocatorList::new(vec![]),
@@ -127,14 +131,14 @@ mod tests {
         let expected_unicast_locator_list = LocatorList::new(vec![locator]);
         let expected_multicast_locator_list = LocatorList::new(vec![]);
 
-        assert_eq!(expected_multicast_flag, submessage._multicast_flag());
+        assert_eq!(expected_multicast_flag, submessage.multicast_flag());
         assert_eq!(
             &expected_unicast_locator_list,
-            submessage._unicast_locator_list()
+            submessage.unicast_locator_list()
         );
         assert_eq!(
             &expected_multicast_locator_list,
-            submessage._multicast_locator_list()
+            submessage.multicast_locator_list()
         );
     }
 
@@ -166,14 +170,26 @@ mod tests {
         let expected_unicast_locator_list = LocatorList::new(vec![]);
         let expected_multicast_locator_list = LocatorList::new(vec![locator1, locator2]);
 
-        assert_eq!(expected_multicast_flag, submessage._multicast_flag());
+        assert_eq!(expected_multicast_flag, submessage.multicast_flag());
         assert_eq!(
             &expected_unicast_locator_list,
-            submessage._unicast_locator_list()
+            submessage.unicast_locator_list()
         );
         assert_eq!(
             &expected_multicast_locator_list,
-            submessage._multicast_locator_list()
+            submessage.multicast_locator_list()
         );
     }
+
+    #[test]
+    fn deserialize_info_reply_truncated_locator_list() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x0f, 0b_0000_0001, 8, 0, // Submessage header
+            1, 0, 0, 0, //numLocators
+            11, 0, 0, 0, //kind
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        assert!(InfoReplySubmessage::try_from_bytes(&submessage_header, data).is_err());
+    }
 }
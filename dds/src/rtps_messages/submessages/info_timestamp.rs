@@ -17,7 +17,7 @@ impl InfoTimestampSubmessage {
         submessage_header: &SubmessageHeaderRead,
         mut data: &[u8],
     ) -> RtpsMessageResult<Self> {
-        let invalidate_flag = submessage_header.flags()[1];
+        let invalidate_flag = submessage_header.info_timestamp_flags().invalidate_flag();
         let timestamp = if invalidate_flag {
             TIME_INVALID
         } else {
@@ -81,6 +81,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_info_timestamp_encodes_nanoseconds_as_fraction() {
+        let timestamp = crate::transport::types::Time::new(4, 500_000_000);
+        let submessage = InfoTimestampSubmessage::new(false, timestamp.into());
+        #[rustfmt::skip]
+        assert_eq!(write_submessage_into_bytes_vec(&submessage), vec![
+                0x09_u8, 0b_0000_0001, 8, 0, // Submessage header
+                4, 0, 0, 0, // Time
+                0x00, 0x00, 0x00, 0x80, // Time
+            ]
+        );
+    }
+
     #[test]
     fn serialize_info_timestamp_invalid_time() {
         let submessage = InfoTimestampSubmessage::new(true, TIME_INVALID);
@@ -109,6 +122,25 @@ mod tests {
         assert_eq!(expected_timestamp, submessage.timestamp());
     }
 
+    #[test]
+    fn deserialize_info_timestamp_decodes_fraction_as_nanoseconds() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x09_u8, 0b_0000_0001, 8, 0, // Submessage header
+            4, 0, 0, 0, // Time
+            0x00, 0x00, 0x00, 0x80, // Time
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let submessage = InfoTimestampSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        let expected_timestamp = crate::transport::types::Time::new(4, 500_000_000);
+
+        assert_eq!(
+            expected_timestamp,
+            crate::transport::types::Time::from(submessage.timestamp())
+        );
+    }
+
     #[test]
     fn deserialize_info_timestamp_invalid_time() {
         #[rustfmt::skip]
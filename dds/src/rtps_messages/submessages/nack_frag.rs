@@ -150,4 +150,77 @@ mod tests {
         );
         assert_eq!(expected_count, submessage.count());
     }
+
+    #[test]
+    fn serialize_nack_frag_with_missing_fragments() {
+        let submessage = NackFragSubmessage::new(
+            EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
+            EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP),
+            4,
+            FragmentNumberSet::new(3, [3, 5, 40]),
+            6,
+        );
+        #[rustfmt::skip]
+        assert_eq!(write_submessage_into_bytes_vec(&submessage), vec![
+                0x12_u8, 0b_0000_0001, 36, 0, // Submessage header
+                1, 2, 3, 4, // readerId: value[4]
+                6, 7, 8, 9, // writerId: value[4]
+                0, 0, 0, 0, // writerSN
+                4, 0, 0, 0, // writerSN
+                3, 0, 0, 0, // fragmentNumberState.base
+               38, 0, 0, 0, // fragmentNumberState.numBits
+                0, 0, 0, 0b_1010_0000, // fragmentNumberState.bitmap[0]
+                0, 0, 0, 0b_0000_0100, // fragmentNumberState.bitmap[1]
+                6, 0, 0, 0, // count
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_nack_frag_big_endian() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x12_u8, 0b_0000_0000, 0, 36, // Submessage header
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN
+            0, 0, 0, 4, // writerSN
+            0, 0, 0, 3, // fragmentNumberState.base
+            0, 0, 0, 38, // fragmentNumberState.numBits
+            0b_1010_0000, 0, 0, 0, // fragmentNumberState.bitmap[0]
+            0b_0000_0100, 0, 0, 0, // fragmentNumberState.bitmap[1]
+            0, 0, 0, 6, // count
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let submessage = NackFragSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        assert_eq!(
+            submessage,
+            NackFragSubmessage::new(
+                EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
+                EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP),
+                4,
+                FragmentNumberSet::new(3, [3, 5, 40]),
+                6,
+            )
+        );
+    }
+
+    #[test]
+    fn deserialize_nack_frag_with_invalid_fragment_number_set() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x12_u8, 0b_0000_0001, 28, 0, // Submessage header
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN
+            4, 0, 0, 0, // writerSN
+            0, 0, 0, 0, // fragmentNumberState.base
+            0, 0, 0, 0, // fragmentNumberState.numBits
+            6, 0, 0, 0, // count
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+
+        assert!(NackFragSubmessage::try_from_bytes(&submessage_header, data).is_err());
+    }
 }
@@ -182,7 +182,9 @@ impl From<Time> for crate::transport::types::Time {
 }
 
 fn fraction_to_nanosec(fraction: u32) -> u32 {
-    ((fraction as u64 * 1_000_000_000) / (1u64 << 32)) as u32
+    // Round to the closest nanosecond so that a value converted with nanosec_to_fraction
+    // is converted back to exactly the same number of nanoseconds
+    (((fraction as u64 * 1_000_000_000) + (1u64 << 31)) >> 32) as u32
 }
 
 fn nanosec_to_fraction(nanosec: u32) -> u32 {
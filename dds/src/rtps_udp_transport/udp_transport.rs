@@ -1,13 +1,14 @@
 use crate::{
     rtps::message_sender::{Clock, WriteMessage},
+    rtps_messages::{error::RtpsMessageError, overall_structure::RtpsMessageRead},
     std_runtime::executor::block_on,
     transport::{
         interface::{
-            HistoryCache, TransportParticipant, TransportParticipantFactory,
-            TransportStatefulReader, TransportStatefulWriter, TransportStatelessReader,
-            TransportStatelessWriter,
+            HistoryCache, TransportParticipant, TransportParticipantError,
+            TransportParticipantFactory, TransportStatefulReader, TransportStatefulWriter,
+            TransportStatelessReader, TransportStatelessWriter,
         },
-        types::{CacheChange, ReaderProxy, WriterProxy, LOCATOR_KIND_UDP_V6},
+        types::{CacheChange, ReaderProxy, TransportStatistics, WriterProxy, LOCATOR_KIND_UDP_V6},
     },
 };
 use async_lock::Mutex;
@@ -18,6 +19,7 @@ use core::{
 };
 use dust_dds::{
     rtps::{
+        behavior_types::Duration,
         stateful_reader::RtpsStatefulReader,
         stateful_writer::RtpsStatefulWriter,
         stateless_reader::RtpsStatelessReader,
@@ -33,7 +35,9 @@ use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
 use socket2::Socket;
 use std::{
     net::{ToSocketAddrs, UdpSocket},
+    panic::{catch_unwind, AssertUnwindSafe},
     sync::{
+        atomic::{AtomicU64, Ordering},
         mpsc::{channel, Sender},
         Arc,
     },
@@ -41,23 +45,87 @@ use std::{
 
 const MAX_DATAGRAM_SIZE: usize = 65507;
 
-type LocatorAddress = [u8; 16];
 // As of 9.6.1.4.1  Default multicast address
-const DEFAULT_MULTICAST_LOCATOR_ADDRESS: LocatorAddress =
-    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 255, 0, 1];
+const DEFAULT_MULTICAST_ADDRESS: Ipv4Addr = Ipv4Addr::new(239, 255, 0, 1);
 
 const PB: i32 = 7400;
 const DG: i32 = 250;
 #[allow(non_upper_case_globals)]
 const d0: i32 = 0;
+#[allow(non_upper_case_globals)]
+const d1: i32 = 10;
+const PG: i32 = 2;
+// Highest participant id tried when looking for a free well-known unicast port
+const MAX_PARTICIPANT_ID: i32 = 119;
 fn port_builtin_multicast(domain_id: i32) -> u16 {
     (PB + DG * domain_id + d0) as u16
 }
+fn port_builtin_unicast(domain_id: i32, participant_id: i32) -> u16 {
+    (PB + DG * domain_id + d1 + PG * participant_id) as u16
+}
+
+#[derive(Clone, Copy)]
+struct SocketBufferSizes {
+    send_buffer_size: Option<usize>,
+    receive_buffer_size: Option<usize>,
+}
+
+// The buffer sizes are set before binding so that no datagram is ever queued
+// in a socket buffer with the OS default size
+fn set_buffer_sizes(socket: &Socket, buffer_sizes: SocketBufferSizes) -> std::io::Result<()> {
+    if let Some(send_buffer_size) = buffer_sizes.send_buffer_size {
+        socket.set_send_buffer_size(send_buffer_size)?;
+    }
+    if let Some(receive_buffer_size) = buffer_sizes.receive_buffer_size {
+        socket.set_recv_buffer_size(receive_buffer_size)?;
+    }
+    Ok(())
+}
+
+fn get_unicast_socket(
+    port: u16,
+    buffer_sizes: SocketBufferSizes,
+) -> std::io::Result<std::net::UdpSocket> {
+    let socket = Socket::new(
+        socket2::Domain::IPV4,
+        socket2::Type::DGRAM,
+        Some(socket2::Protocol::UDP),
+    )?;
+    set_buffer_sizes(&socket, buffer_sizes)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)).into())?;
+    socket.set_nonblocking(false)?;
+
+    Ok(socket.into())
+}
+
+// Bind to the first free well-known metatraffic unicast port of the domain (as of
+// 9.6.1.1) so that the socket can be reached by participants using it as initial peer.
+// Returns the participant id corresponding to the bound port.
+fn get_well_known_unicast_socket(
+    domain_id: i32,
+    buffer_sizes: SocketBufferSizes,
+) -> Result<(i32, std::net::UdpSocket), TransportParticipantError> {
+    (0..=MAX_PARTICIPANT_ID)
+        .find_map(|participant_id| {
+            get_unicast_socket(
+                port_builtin_unicast(domain_id, participant_id),
+                buffer_sizes,
+            )
+            .ok()
+            .map(|socket| (participant_id, socket))
+        })
+        .ok_or_else(|| {
+            TransportParticipantError::OutOfResources(format!(
+                "No free well-known metatraffic unicast port for participant ids 0 to {MAX_PARTICIPANT_ID} on domain {domain_id}"
+            ))
+        })
+}
 
 fn get_multicast_socket(
-    multicast_address: LocatorAddress,
+    multicast_address: Ipv4Addr,
     port: u16,
-    interface_address_list: impl IntoIterator<Item = Addr>,
+    interface_address_list: &[Ipv4Addr],
+    buffer_sizes: SocketBufferSizes,
 ) -> std::io::Result<std::net::UdpSocket> {
     let socket_addr = SocketAddr::from((Ipv4Addr::UNSPECIFIED, port));
 
@@ -71,26 +139,16 @@ fn get_multicast_socket(
     #[cfg(target_family = "unix")]
     socket.set_reuse_port(true)?;
     socket.set_nonblocking(false)?;
+    set_buffer_sizes(&socket, buffer_sizes)?;
 
     socket.bind(&socket_addr.into())?;
-    let addr = Ipv4Addr::new(
-        multicast_address[12],
-        multicast_address[13],
-        multicast_address[14],
-        multicast_address[15],
-    );
     for interface_addr in interface_address_list {
-        match interface_addr {
-            Addr::V4(a) => {
-                let r = socket.join_multicast_v4(&addr, &a.ip);
-                if let Err(e) = r {
-                    println!(
-                        "Failed to join multicast group on address {} with error {}",
-                        a.ip, e
-                    )
-                }
-            }
-            Addr::V6(_) => (),
+        let r = socket.join_multicast_v4(&multicast_address, interface_addr);
+        if let Err(e) = r {
+            println!(
+                "Failed to join multicast group on address {} with error {}",
+                interface_addr, e
+            )
         }
     }
 
@@ -101,8 +159,17 @@ fn get_multicast_socket(
 
 pub struct RtpsUdpTransportParticipantFactoryBuilder {
     interface_name: Option<String>,
+    interface_address_list: Vec<Ipv4Addr>,
+    multicast_address: Ipv4Addr,
     fragment_size: usize,
     udp_receive_buffer_size: Option<usize>,
+    udp_send_buffer_size: Option<usize>,
+    unicast_only: bool,
+    initial_peers: Vec<Locator>,
+    writer_poll_interval: core::time::Duration,
+    heartbeat_period: core::time::Duration,
+    nack_suppression_duration: core::time::Duration,
+    heartbeat_on_reader_match: bool,
 }
 
 impl Default for RtpsUdpTransportParticipantFactoryBuilder {
@@ -116,8 +183,17 @@ impl RtpsUdpTransportParticipantFactoryBuilder {
     pub fn new() -> Self {
         Self {
             interface_name: None,
+            interface_address_list: Vec::new(),
+            multicast_address: DEFAULT_MULTICAST_ADDRESS,
             fragment_size: 1344,
             udp_receive_buffer_size: None,
+            udp_send_buffer_size: None,
+            unicast_only: false,
+            initial_peers: Vec::new(),
+            writer_poll_interval: core::time::Duration::from_millis(50),
+            heartbeat_period: core::time::Duration::from_millis(200),
+            nack_suppression_duration: core::time::Duration::ZERO,
+            heartbeat_on_reader_match: true,
         }
     }
 
@@ -127,18 +203,84 @@ impl RtpsUdpTransportParticipantFactoryBuilder {
         self
     }
 
+    /// Set the IPv4 addresses of the network interfaces used by the participants. If the list is empty
+    /// the addresses of the interfaces are scanned, restricted to the interface set by
+    /// [`interface_name`](Self::interface_name) if any.
+    pub fn interface_address_list(mut self, interface_address_list: Vec<Ipv4Addr>) -> Self {
+        self.interface_address_list = interface_address_list;
+        self
+    }
+
+    /// Set the multicast group used for the discovery of the participants. The default is 239.255.0.1.
+    pub fn multicast_address(mut self, multicast_address: Ipv4Addr) -> Self {
+        self.multicast_address = multicast_address;
+        self
+    }
+
     /// Set the maximum size for the data fragments. Types with serialized data above this size will be transmitted as fragments.
     pub fn fragment_size(mut self, fragment_size: usize) -> Self {
         self.fragment_size = fragment_size;
         self
     }
 
-    /// Set the value of the SO_RCVBUF option on the UDP socket. [`None`] corresponds to the OS default
+    /// Set the value of the SO_RCVBUF option on the UDP sockets. [`None`] corresponds to the OS default
     pub fn udp_receive_buffer_size(mut self, udp_receive_buffer_size: Option<usize>) -> Self {
         self.udp_receive_buffer_size = udp_receive_buffer_size;
         self
     }
 
+    /// Set the value of the SO_SNDBUF option on the UDP sockets. [`None`] corresponds to the OS default
+    pub fn udp_send_buffer_size(mut self, udp_send_buffer_size: Option<usize>) -> Self {
+        self.udp_send_buffer_size = udp_send_buffer_size;
+        self
+    }
+
+    /// Disable the use of multicast. The participants don't join the discovery multicast group and
+    /// announce themselves only to the `initial_peers`. Their metatraffic unicast socket is bound to the
+    /// first free well-known port of the domain, i.e. 7400 + 250 * domain_id + 10 + 2 * participant_id.
+    pub fn unicast_only(mut self, unicast_only: bool) -> Self {
+        self.unicast_only = unicast_only;
+        self
+    }
+
+    /// Set the list of locators to which the participant announcements are sent in addition to the
+    /// discovery multicast locator
+    pub fn initial_peers(mut self, initial_peers: Vec<Locator>) -> Self {
+        self.initial_peers = initial_peers;
+        self
+    }
+
+    /// Set the interval at which the reliable writers check whether heartbeats or repairs have to be
+    /// sent. The default is 50 ms.
+    pub fn writer_poll_interval(mut self, writer_poll_interval: core::time::Duration) -> Self {
+        self.writer_poll_interval = writer_poll_interval;
+        self
+    }
+
+    /// Set the period at which the reliable writers send heartbeats to their matched readers. The
+    /// default is 200 ms.
+    pub fn heartbeat_period(mut self, heartbeat_period: core::time::Duration) -> Self {
+        self.heartbeat_period = heartbeat_period;
+        self
+    }
+
+    /// Set the time during which the reliable writers ignore the NACKs for a change they have just
+    /// repaired. The default is zero, i.e. every NACK is answered.
+    pub fn nack_suppression_duration(
+        mut self,
+        nack_suppression_duration: core::time::Duration,
+    ) -> Self {
+        self.nack_suppression_duration = nack_suppression_duration;
+        self
+    }
+
+    /// Set whether the reliable writers send a heartbeat to a reader as soon as it is matched instead
+    /// of letting it wait for the next periodic heartbeat. The default is `true`.
+    pub fn heartbeat_on_reader_match(mut self, heartbeat_on_reader_match: bool) -> Self {
+        self.heartbeat_on_reader_match = heartbeat_on_reader_match;
+        self
+    }
+
     /// Build a new participant factory
     pub fn build(self) -> Result<RtpsUdpTransportParticipantFactory, String> {
         let fragment_size_range = 8..=65000;
@@ -149,8 +291,17 @@ impl RtpsUdpTransportParticipantFactoryBuilder {
         } else {
             Ok(RtpsUdpTransportParticipantFactory {
                 interface_name: self.interface_name,
+                interface_address_list: self.interface_address_list,
+                multicast_address: self.multicast_address,
                 fragment_size: self.fragment_size,
                 udp_receive_buffer_size: self.udp_receive_buffer_size,
+                udp_send_buffer_size: self.udp_send_buffer_size,
+                unicast_only: self.unicast_only,
+                initial_peers: self.initial_peers,
+                writer_poll_interval: self.writer_poll_interval,
+                heartbeat_period: self.heartbeat_period,
+                nack_suppression_duration: self.nack_suppression_duration,
+                heartbeat_on_reader_match: self.heartbeat_on_reader_match,
             })
         }
     }
@@ -158,8 +309,17 @@ impl RtpsUdpTransportParticipantFactoryBuilder {
 
 pub struct RtpsUdpTransportParticipantFactory {
     interface_name: Option<String>,
+    interface_address_list: Vec<Ipv4Addr>,
+    multicast_address: Ipv4Addr,
     fragment_size: usize,
     udp_receive_buffer_size: Option<usize>,
+    udp_send_buffer_size: Option<usize>,
+    unicast_only: bool,
+    initial_peers: Vec<Locator>,
+    writer_poll_interval: core::time::Duration,
+    heartbeat_period: core::time::Duration,
+    nack_suppression_duration: core::time::Duration,
+    heartbeat_on_reader_match: bool,
 }
 
 impl Default for RtpsUdpTransportParticipantFactory {
@@ -187,83 +347,104 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
         &self,
         guid_prefix: GuidPrefix,
         domain_id: i32,
-    ) -> Self::TransportParticipant {
-        let interface_address_list = NetworkInterface::show()
-            .expect("Could not scan interfaces")
-            .into_iter()
-            .filter(|x| {
-                if let Some(if_name) = &self.interface_name {
-                    &x.name == if_name
-                } else {
-                    true
-                }
-            })
-            .flat_map(|i| {
-                i.addr.into_iter().filter(|a| match a {
-                    #[rustfmt::skip]
-                Addr::V4(_) => true,
-                    _ => false,
+    ) -> Result<Self::TransportParticipant, TransportParticipantError> {
+        let interface_address_list: Vec<Ipv4Addr> = if self.interface_address_list.is_empty() {
+            NetworkInterface::show()
+                .map_err(|e| format!("Could not scan interfaces: {e}"))?
+                .into_iter()
+                .filter(|x| {
+                    if let Some(if_name) = &self.interface_name {
+                        &x.name == if_name
+                    } else {
+                        true
+                    }
                 })
-            });
-
-        let default_unicast_socket =
-            socket2::Socket::new(socket2::Domain::IPV4, socket2::Type::DGRAM, None).unwrap();
-        default_unicast_socket
-            .bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)).into())
-            .unwrap();
-        default_unicast_socket.set_nonblocking(false).unwrap();
-        if let Some(buffer_size) = self.udp_receive_buffer_size {
-            default_unicast_socket
-                .set_recv_buffer_size(buffer_size)
-                .unwrap();
+                .flat_map(|i| {
+                    i.addr.into_iter().filter_map(|a| match a {
+                        Addr::V4(v4) => Some(v4.ip),
+                        Addr::V6(_) => None,
+                    })
+                })
+                .collect()
+        } else {
+            self.interface_address_list.clone()
+        };
+        if interface_address_list.is_empty() {
+            return Err(TransportParticipantError::Error(String::from(
+                "No IPv4 address found on the network interfaces",
+            )));
         }
 
-        let default_unicast_socket = std::net::UdpSocket::from(default_unicast_socket);
-        let user_defined_unicast_port = default_unicast_socket.local_addr().unwrap().port().into();
+        let socket_error = |e: std::io::Error| format!("Could not open socket: {e}");
+        let buffer_sizes = SocketBufferSizes {
+            send_buffer_size: self.udp_send_buffer_size,
+            receive_buffer_size: self.udp_receive_buffer_size,
+        };
+
+        let default_unicast_socket = get_unicast_socket(0, buffer_sizes).map_err(socket_error)?;
+        let default_unicast_port = default_unicast_socket
+            .local_addr()
+            .map_err(socket_error)?
+            .port();
         let default_unicast_locator_list: Vec<_> = interface_address_list
-            .clone()
-            .map(|a| Locator::from_ip_and_port(&a, user_defined_unicast_port))
+            .iter()
+            .map(|&a| Locator::from_ip_and_port(IpAddr::V4(a), default_unicast_port.into()))
             .collect();
         // Open socket for unicast metatraffic data
-        let metatraffic_unicast_socket = Arc::new(
-            std::net::UdpSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0))).unwrap(),
-        );
+        let (participant_id, metatraffic_unicast_socket) = if self.unicast_only {
+            let (participant_id, socket) = get_well_known_unicast_socket(domain_id, buffer_sizes)?;
+            (Some(participant_id), Arc::new(socket))
+        } else {
+            let socket = get_unicast_socket(0, buffer_sizes).map_err(socket_error)?;
+            (None, Arc::new(socket))
+        };
 
-        metatraffic_unicast_socket.set_nonblocking(false).unwrap();
-        let metattrafic_unicast_locator_port = metatraffic_unicast_socket
+        let metatraffic_unicast_port = metatraffic_unicast_socket
             .local_addr()
-            .unwrap()
-            .port()
-            .into();
+            .map_err(socket_error)?
+            .port();
         let metatraffic_unicast_locator_list: Vec<Locator> = interface_address_list
-            .clone()
-            .map(|a| Locator::from_ip_and_port(&a, metattrafic_unicast_locator_port))
+            .iter()
+            .map(|&a| Locator::from_ip_and_port(IpAddr::V4(a), metatraffic_unicast_port.into()))
             .collect();
 
         // Open socket for multicast metatraffic data
-        let metatraffic_multicast_locator_list = vec![Locator::new(
-            LOCATOR_KIND_UDP_V4,
-            port_builtin_multicast(domain_id) as u32,
-            DEFAULT_MULTICAST_LOCATOR_ADDRESS,
-        )];
-
-        let metatraffic_multicast_socket = Arc::new(
-            get_multicast_socket(
-                DEFAULT_MULTICAST_LOCATOR_ADDRESS,
-                port_builtin_multicast(domain_id),
-                interface_address_list,
-            )
-            .unwrap(),
-        );
+        let (metatraffic_multicast_locator_list, metatraffic_multicast_socket) =
+            if self.unicast_only {
+                (Vec::new(), None)
+            } else {
+                let metatraffic_multicast_locator_list = vec![Locator::from_ip_and_port(
+                    IpAddr::V4(self.multicast_address),
+                    port_builtin_multicast(domain_id) as u32,
+                )];
+                let metatraffic_multicast_socket = get_multicast_socket(
+                    self.multicast_address,
+                    port_builtin_multicast(domain_id),
+                    &interface_address_list,
+                    buffer_sizes,
+                )
+                .map_err(socket_error)?;
+                (
+                    metatraffic_multicast_locator_list,
+                    Some(metatraffic_multicast_socket),
+                )
+            };
+        let discovery_locator_list = metatraffic_multicast_locator_list
+            .iter()
+            .chain(self.initial_peers.iter())
+            .copied()
+            .collect();
 
         let mut message_writer = MessageWriter::new(
             guid_prefix,
-            default_unicast_socket.try_clone().expect("Socket cloning"),
+            default_unicast_socket.try_clone().map_err(socket_error)?,
+            interface_address_list,
         );
 
         let guid = Guid::new(guid_prefix, ENTITYID_PARTICIPANT);
 
         let (chanel_message_sender, chanel_message_receiver) = channel();
+        let reception_statistics = Arc::new(ReceptionStatistics::default());
 
         let global_participant = RtpsUdpTransportParticipant {
             guid,
@@ -271,29 +452,40 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
             default_unicast_locator_list,
             metatraffic_unicast_locator_list,
             metatraffic_multicast_locator_list,
+            discovery_locator_list,
             fragment_size: self.fragment_size,
+            heartbeat_period: Duration::from_millis(self.heartbeat_period.as_millis() as u64),
+            nack_suppression_duration: Duration::from_millis(
+                self.nack_suppression_duration.as_millis() as u64,
+            ),
+            heartbeat_on_reader_match: self.heartbeat_on_reader_match,
+            participant_id,
+            metatraffic_unicast_port,
+            default_unicast_port,
             chanel_message_sender: chanel_message_sender.clone(),
+            reception_statistics: reception_statistics.clone(),
         };
 
-        let chanel_message_sender_clone = chanel_message_sender.clone();
-
-        std::thread::Builder::new()
-            .name("SomethingOnMetatrafficMulticastSocket".to_string())
-            .spawn(move || {
-                let mut buf = [0; MAX_DATAGRAM_SIZE];
-                loop {
-                    if let Ok(size) = metatraffic_multicast_socket.recv(&mut buf) {
-                        if size > 0 {
-                            chanel_message_sender_clone
-                                .send(ChannelMessageKind::MetatrafficMulticastSocket(
-                                    buf[..size].into(),
-                                ))
-                                .expect("chanel_message sender alive");
+        if let Some(metatraffic_multicast_socket) = metatraffic_multicast_socket {
+            let chanel_message_sender_clone = chanel_message_sender.clone();
+            std::thread::Builder::new()
+                .name("SomethingOnMetatrafficMulticastSocket".to_string())
+                .spawn(move || {
+                    let mut buf = [0; MAX_DATAGRAM_SIZE];
+                    loop {
+                        if let Ok(size) = metatraffic_multicast_socket.recv(&mut buf) {
+                            if size > 0 {
+                                chanel_message_sender_clone
+                                    .send(ChannelMessageKind::MetatrafficMulticastSocket(
+                                        buf[..size].into(),
+                                    ))
+                                    .expect("chanel_message sender alive");
+                            }
                         }
                     }
-                }
-            })
-            .expect("failed to spawn thread");
+                })
+                .expect("failed to spawn thread");
+        }
 
         let chanel_message_sender_clone = chanel_message_sender.clone();
         std::thread::Builder::new()
@@ -332,10 +524,11 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
             .expect("failed to spawn thread");
 
         let chanel_message_sender_clone = chanel_message_sender.clone();
+        let writer_poll_interval = self.writer_poll_interval;
         std::thread::Builder::new()
             .name("Regular poke".to_string())
             .spawn(move || loop {
-                std::thread::sleep(std::time::Duration::from_millis(50));
+                std::thread::sleep(writer_poll_interval);
                 chanel_message_sender_clone
                     .send(ChannelMessageKind::Poke)
                     .expect("chanel_message sender alive");
@@ -361,43 +554,34 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
                                 stateful_writer_list.push(stateful_writer)
                             }
                             ChannelMessageKind::MetatrafficMulticastSocket(datagram) => {
-                                block_on(async {
-                                    process_message(
-                                        &datagram,
-                                        &mut message_writer,
-                                        &RtpsUdpTransportClock,
-                                        &mut stateless_reader_list,
-                                        &stateful_reader_list,
-                                        &stateful_writer_list,
-                                    )
-                                    .await
-                                });
+                                process_datagram(
+                                    &datagram,
+                                    &reception_statistics,
+                                    &mut message_writer,
+                                    &mut stateless_reader_list,
+                                    &stateful_reader_list,
+                                    &stateful_writer_list,
+                                );
                             }
                             ChannelMessageKind::MetatrafficUnicastSocket(datagram) => {
-                                block_on(async {
-                                    process_message(
-                                        &datagram,
-                                        &mut message_writer,
-                                        &RtpsUdpTransportClock,
-                                        &mut stateless_reader_list,
-                                        &stateful_reader_list,
-                                        &stateful_writer_list,
-                                    )
-                                    .await
-                                });
+                                process_datagram(
+                                    &datagram,
+                                    &reception_statistics,
+                                    &mut message_writer,
+                                    &mut stateless_reader_list,
+                                    &stateful_reader_list,
+                                    &stateful_writer_list,
+                                );
                             }
                             ChannelMessageKind::DefaultUnicastSocket(datagram) => {
-                                block_on(async {
-                                    process_message(
-                                        &datagram,
-                                        &mut message_writer,
-                                        &RtpsUdpTransportClock,
-                                        &mut stateless_reader_list,
-                                        &stateful_reader_list,
-                                        &stateful_writer_list,
-                                    )
-                                    .await
-                                });
+                                process_datagram(
+                                    &datagram,
+                                    &reception_statistics,
+                                    &mut message_writer,
+                                    &mut stateless_reader_list,
+                                    &stateful_reader_list,
+                                    &stateful_writer_list,
+                                );
                             }
                             ChannelMessageKind::Poke => block_on(async {
                                 for rtps_stateful_writer in &stateful_writer_list {
@@ -414,18 +598,86 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
             })
             .expect("failed to spawn thread");
 
-        global_participant
+        Ok(global_participant)
+    }
+}
+
+// Counters shared between the participant and the thread processing the received datagrams
+#[derive(Default)]
+struct ReceptionStatistics {
+    malformed_packets: AtomicU64,
+    unknown_submessages: AtomicU64,
+}
+
+impl ReceptionStatistics {
+    fn count_datagram(&self, datagram: &[u8]) {
+        match RtpsMessageRead::iter_submessages(datagram) {
+            Ok(submessage_iter) => {
+                for submessage in submessage_iter {
+                    match submessage {
+                        Ok(_) => (),
+                        Err(RtpsMessageError::UnknownMessage) => {
+                            self.unknown_submessages.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(_) => {
+                            self.malformed_packets.fetch_add(1, Ordering::Relaxed);
+                            break;
+                        }
+                    }
+                }
+            }
+            Err(_) => {
+                self.malformed_packets.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn get(&self) -> TransportStatistics {
+        TransportStatistics {
+            malformed_packets: self.malformed_packets.load(Ordering::Relaxed),
+            unknown_submessages: self.unknown_submessages.load(Ordering::Relaxed),
+        }
+    }
+}
+
+// A panic while handling a datagram would end the receive thread and with it all the
+// communication of the participant. It is contained here and the datagram counted as malformed.
+fn process_datagram(
+    datagram: &[u8],
+    reception_statistics: &ReceptionStatistics,
+    message_writer: &mut MessageWriter,
+    stateless_reader_list: &mut [RtpsStatelessReader],
+    stateful_reader_list: &[Arc<Mutex<RtpsStatefulReader>>],
+    stateful_writer_list: &[Arc<Mutex<RtpsStatefulWriter>>],
+) {
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        block_on(process_message(
+            datagram,
+            reception_statistics,
+            message_writer,
+            &RtpsUdpTransportClock,
+            stateless_reader_list,
+            stateful_reader_list,
+            stateful_writer_list,
+        ))
+    }));
+    if result.is_err() {
+        reception_statistics
+            .malformed_packets
+            .fetch_add(1, Ordering::Relaxed);
     }
 }
 
 async fn process_message(
     datagram: &[u8],
+    reception_statistics: &ReceptionStatistics,
     message_writer: &mut MessageWriter,
     clock: &impl Clock,
     stateless_reader_list: &mut [RtpsStatelessReader],
     stateful_reader_list: &[Arc<Mutex<RtpsStatefulReader>>],
     stateful_writer_list: &[Arc<Mutex<RtpsStatefulWriter>>],
 ) {
+    reception_statistics.count_datagram(datagram);
     for stateless_reader in stateless_reader_list {
         stateless_reader.process_message(datagram).await.ok();
     }
@@ -448,8 +700,8 @@ async fn process_message(
 }
 
 impl Locator {
-    pub fn from_ip_and_port(ip_addr: &Addr, port: u32) -> Self {
-        match ip_addr.ip() {
+    pub fn from_ip_and_port(ip_addr: IpAddr, port: u32) -> Self {
+        match ip_addr {
             IpAddr::V4(a) => Locator::new(
                 LOCATOR_KIND_UDP_V4,
                 port,
@@ -523,6 +775,7 @@ impl UdpLocator {
 struct MessageWriter {
     guid_prefix: GuidPrefix,
     socket: UdpSocket,
+    interface_address_list: Arc<[Ipv4Addr]>,
 }
 
 impl Clone for MessageWriter {
@@ -530,15 +783,21 @@ impl Clone for MessageWriter {
         Self {
             guid_prefix: self.guid_prefix,
             socket: self.socket.try_clone().expect("Socket cloning"),
+            interface_address_list: self.interface_address_list.clone(),
         }
     }
 }
 
 impl MessageWriter {
-    fn new(guid_prefix: GuidPrefix, socket: UdpSocket) -> Self {
+    fn new(
+        guid_prefix: GuidPrefix,
+        socket: UdpSocket,
+        interface_address_list: Vec<Ipv4Addr>,
+    ) -> Self {
         Self {
             guid_prefix,
             socket,
+            interface_address_list: interface_address_list.into(),
         }
     }
 }
@@ -547,19 +806,8 @@ impl WriteMessage for MessageWriter {
         for &destination_locator in locator_list {
             if UdpLocator(destination_locator).is_multicast() {
                 let socket2: socket2::Socket = self.socket.try_clone().unwrap().into();
-                let interface_addresses = NetworkInterface::show();
-                let interface_addresses: Vec<_> = interface_addresses
-                    .expect("Could not scan interfaces")
-                    .into_iter()
-                    .flat_map(|i| {
-                        i.addr.into_iter().filter_map(|a| match a {
-                            Addr::V4(v4) => Some(v4.ip),
-                            _ => None,
-                        })
-                    })
-                    .collect();
-                for address in interface_addresses {
-                    if socket2.set_multicast_if_v4(&address).is_ok() {
+                for address in self.interface_address_list.iter() {
+                    if socket2.set_multicast_if_v4(address).is_ok() {
                         self.socket
                             .send_to(datagram, UdpLocator(destination_locator))
                             .ok();
@@ -594,8 +842,35 @@ pub struct RtpsUdpTransportParticipant {
     default_unicast_locator_list: Vec<Locator>,
     metatraffic_unicast_locator_list: Vec<Locator>,
     metatraffic_multicast_locator_list: Vec<Locator>,
+    discovery_locator_list: Vec<Locator>,
     fragment_size: usize,
+    heartbeat_period: Duration,
+    nack_suppression_duration: Duration,
+    heartbeat_on_reader_match: bool,
+    participant_id: Option<i32>,
+    metatraffic_unicast_port: u16,
+    default_unicast_port: u16,
     chanel_message_sender: Sender<ChannelMessageKind>,
+    reception_statistics: Arc<ReceptionStatistics>,
+}
+
+impl RtpsUdpTransportParticipant {
+    /// Participant id of the well-known metatraffic unicast port bound by the participant. This is
+    /// only [`Some`] when the factory is configured as `unicast_only`, otherwise the metatraffic
+    /// unicast socket is bound to a port chosen by the OS.
+    pub fn participant_id(&self) -> Option<i32> {
+        self.participant_id
+    }
+
+    /// Port of the socket on which the participant receives the unicast metatraffic
+    pub fn metatraffic_unicast_port(&self) -> u16 {
+        self.metatraffic_unicast_port
+    }
+
+    /// Port of the socket on which the participant receives the unicast user data
+    pub fn default_unicast_port(&self) -> u16 {
+        self.default_unicast_port
+    }
 }
 
 pub struct StatelessReader {
@@ -673,7 +948,8 @@ impl TransportStatefulWriter for StatefulWriter {
         self.rtps_stateful_writer
             .lock()
             .await
-            .add_matched_reader(&reader_proxy);
+            .add_matched_reader(&reader_proxy, &self.message_writer, &RtpsUdpTransportClock)
+            .await;
     }
     async fn remove_matched_reader(&mut self, remote_reader_guid: Guid) {
         self.rtps_stateful_writer
@@ -760,12 +1036,18 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
     fn metatraffic_multicast_locator_list(&self) -> &[Locator] {
         &self.metatraffic_multicast_locator_list
     }
+    fn discovery_locator_list(&self) -> &[Locator] {
+        &self.discovery_locator_list
+    }
     fn default_unicast_locator_list(&self) -> &[Locator] {
         &self.default_unicast_locator_list
     }
     fn default_multicast_locator_list(&self) -> &[Locator] {
         &[]
     }
+    fn statistics(&self) -> TransportStatistics {
+        self.reception_statistics.get()
+    }
     async fn create_stateless_reader(
         &mut self,
         entity_id: EntityId,
@@ -792,6 +1074,7 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
     async fn create_stateful_reader(
         &mut self,
         entity_id: EntityId,
+        _topic_name: &str,
         reliability_kind: ReliabilityKind,
         reader_history_cache: Box<dyn HistoryCache>,
     ) -> Self::StatefulReader {
@@ -815,13 +1098,15 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
     async fn create_stateful_writer(
         &mut self,
         entity_id: EntityId,
+        _topic_name: &str,
         _reliability_kind: ReliabilityKind,
     ) -> Self::StatefulWriter {
         let guid = Guid::new(self.guid.prefix(), entity_id);
-        let rtps_stateful_writer = Arc::new(Mutex::new(RtpsStatefulWriter::new(
-            guid,
-            self.fragment_size,
-        )));
+        let mut rtps_stateful_writer = RtpsStatefulWriter::new(guid, self.fragment_size);
+        rtps_stateful_writer.set_heartbeat_period(self.heartbeat_period);
+        rtps_stateful_writer.set_nack_suppression_duration(self.nack_suppression_duration);
+        rtps_stateful_writer.set_heartbeat_on_reader_match(self.heartbeat_on_reader_match);
+        let rtps_stateful_writer = Arc::new(Mutex::new(rtps_stateful_writer));
         self.chanel_message_sender
             .send(ChannelMessageKind::AddStatefulWriter(
                 rtps_stateful_writer.clone(),
@@ -851,7 +1136,7 @@ mod tests {
             .build()
             .unwrap();
         let mut participant =
-            block_on(async { transport.create_participant(guid_prefix, domain_id).await });
+            block_on(async { transport.create_participant(guid_prefix, domain_id).await }).unwrap();
 
         struct MockHistoryCache(SyncSender<CacheChange>);
 
@@ -885,7 +1170,7 @@ mod tests {
         let entity_id = EntityId::new([5, 6, 7], 8);
         let mut writer = block_on(async { participant.create_stateless_writer(entity_id).await });
         for locator in participant.default_unicast_locator_list() {
-            writer.add_reader_locator(locator.clone());
+            writer.add_reader_locator(*locator);
         }
 
         let cache_change = CacheChange {
@@ -895,6 +1180,8 @@ mod tests {
             source_timestamp: None,
             instance_handle: None,
             data_value: vec![0, 0, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
         };
         block_on(async {
             writer
@@ -909,6 +1196,77 @@ mod tests {
         assert_eq!(cache_change, received_cache_change);
     }
 
+    #[test]
+    fn participant_uses_the_configured_addresses() {
+        let guid_prefix = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let domain_id = 0;
+        let multicast_address = Ipv4Addr::new(239, 255, 0, 2);
+        let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+            .interface_address_list(vec![Ipv4Addr::LOCALHOST])
+            .multicast_address(multicast_address)
+            .build()
+            .unwrap();
+        let participant =
+            block_on(async { transport.create_participant(guid_prefix, domain_id).await }).unwrap();
+
+        for locator in participant
+            .default_unicast_locator_list()
+            .iter()
+            .chain(participant.metatraffic_unicast_locator_list())
+        {
+            assert_eq!(locator.address()[12..], Ipv4Addr::LOCALHOST.octets());
+        }
+        assert_eq!(
+            participant.metatraffic_multicast_locator_list(),
+            &[Locator::from_ip_and_port(
+                IpAddr::V4(multicast_address),
+                port_builtin_multicast(domain_id) as u32
+            )]
+        );
+    }
+
+    #[test]
+    fn unicast_only_participants_on_the_same_domain_get_distinct_participant_ids() {
+        let domain_id = 17;
+        let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+            .unicast_only(true)
+            .build()
+            .unwrap();
+
+        let participant1 =
+            block_on(async { transport.create_participant([1; 12], domain_id).await }).unwrap();
+        let participant2 =
+            block_on(async { transport.create_participant([2; 12], domain_id).await }).unwrap();
+
+        let participant_id1 = participant1.participant_id().unwrap();
+        let participant_id2 = participant2.participant_id().unwrap();
+        assert_ne!(participant_id1, participant_id2);
+        assert_eq!(
+            participant1.metatraffic_unicast_port(),
+            port_builtin_unicast(domain_id, participant_id1)
+        );
+        assert_eq!(
+            participant2.metatraffic_unicast_port(),
+            port_builtin_unicast(domain_id, participant_id2)
+        );
+        assert_ne!(
+            participant1.default_unicast_port(),
+            participant2.default_unicast_port()
+        );
+    }
+
+    #[test]
+    fn participant_without_usable_address_is_not_created() {
+        let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+            .interface_name(Some(String::from("not an interface")))
+            .build()
+            .unwrap();
+
+        let result = block_on(async { transport.create_participant([1; 12], 0).await });
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn basic_transport_stateful_reader_writer_usage() {
         let guid_prefix = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
@@ -917,7 +1275,7 @@ mod tests {
             .build()
             .unwrap();
         let mut participant =
-            block_on(async { transport.create_participant(guid_prefix, domain_id).await });
+            block_on(async { transport.create_participant(guid_prefix, domain_id).await }).unwrap();
 
         struct MockHistoryCache(SyncSender<CacheChange>);
 
@@ -945,14 +1303,14 @@ mod tests {
         let reader_history_cache = Box::new(MockHistoryCache(sender));
         let mut reader = block_on(async {
             participant
-                .create_stateful_reader(entity_id, reliability_kind, reader_history_cache)
+                .create_stateful_reader(entity_id, "Test", reliability_kind, reader_history_cache)
                 .await
         });
 
         let entity_id = EntityId::new([5, 6, 7], 8);
         let mut writer = block_on(async {
             participant
-                .create_stateful_writer(entity_id, reliability_kind)
+                .create_stateful_writer(entity_id, "Test", reliability_kind)
                 .await
         });
 
@@ -983,6 +1341,8 @@ mod tests {
             source_timestamp: None,
             instance_handle: None,
             data_value: vec![0, 0, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
         };
         block_on(async {
             writer
@@ -996,4 +1356,270 @@ mod tests {
             .unwrap();
         assert_eq!(cache_change, received_cache_change);
     }
+
+    #[test]
+    fn burst_of_datagrams_is_fully_received() {
+        let guid_prefix = [2, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let domain_id = 0;
+        let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+            .build()
+            .unwrap();
+        let mut participant =
+            block_on(async { transport.create_participant(guid_prefix, domain_id).await }).unwrap();
+
+        struct MockHistoryCache(SyncSender<CacheChange>);
+
+        impl HistoryCache for MockHistoryCache {
+            fn add_change(
+                &mut self,
+                cache_change: CacheChange,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                self.0.send(cache_change).unwrap();
+                Box::pin(async {})
+            }
+            fn remove_change(
+                &mut self,
+                _sequence_number: i64,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                Box::pin(async {
+                    unimplemented!();
+                })
+            }
+        }
+
+        const NUMBER_OF_DATAGRAMS: i64 = 20;
+        // Every change is received once per reader locator
+        let number_of_locators = participant.default_unicast_locator_list().len();
+        let (sender, receiver) = sync_channel(NUMBER_OF_DATAGRAMS as usize * number_of_locators);
+        let _reader = block_on(async {
+            participant
+                .create_stateless_reader(
+                    EntityId::new([1, 2, 3], 4),
+                    Box::new(MockHistoryCache(sender)),
+                )
+                .await
+        });
+        let mut writer = block_on(async {
+            participant
+                .create_stateless_writer(EntityId::new([5, 6, 7], 8))
+                .await
+        });
+        for locator in participant.default_unicast_locator_list() {
+            writer.add_reader_locator(*locator);
+        }
+
+        // Each change is sent in its own datagram without waiting in between
+        for sequence_number in 1..=NUMBER_OF_DATAGRAMS {
+            let cache_change = CacheChange {
+                kind: ChangeKind::Alive,
+                writer_guid: writer.guid(),
+                sequence_number,
+                source_timestamp: None,
+                instance_handle: None,
+                data_value: vec![0, 0, 0, 0, 1, 2, 3, 4].into(),
+                coherent_set: None,
+                coherent_set_end: false,
+            };
+            block_on(async { writer.history_cache().add_change(cache_change).await });
+        }
+
+        let mut received_sequence_numbers: Vec<_> = (0..NUMBER_OF_DATAGRAMS as usize
+            * number_of_locators)
+            .map(|_| {
+                receiver
+                    .recv_timeout(std::time::Duration::from_secs(30))
+                    .unwrap()
+                    .sequence_number
+            })
+            .collect();
+        received_sequence_numbers.sort();
+        assert_eq!(
+            received_sequence_numbers,
+            (1..=NUMBER_OF_DATAGRAMS)
+                .flat_map(|sn| core::iter::repeat_n(sn, number_of_locators))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn malformed_datagrams_do_not_stop_the_processing_of_valid_ones() {
+        let guid_prefix = [3, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let domain_id = 0;
+        let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+            .build()
+            .unwrap();
+        let mut participant =
+            block_on(async { transport.create_participant(guid_prefix, domain_id).await }).unwrap();
+
+        struct MockHistoryCache(SyncSender<CacheChange>);
+
+        impl HistoryCache for MockHistoryCache {
+            fn add_change(
+                &mut self,
+                cache_change: CacheChange,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                self.0.send(cache_change).unwrap();
+                Box::pin(async {})
+            }
+            fn remove_change(
+                &mut self,
+                _sequence_number: i64,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                Box::pin(async {
+                    unimplemented!();
+                })
+            }
+        }
+
+        let (sender, receiver) = sync_channel(1);
+        let _reader = block_on(async {
+            participant
+                .create_stateless_reader(
+                    EntityId::new([1, 2, 3], 4),
+                    Box::new(MockHistoryCache(sender)),
+                )
+                .await
+        });
+        let mut writer = block_on(async {
+            participant
+                .create_stateless_writer(EntityId::new([5, 6, 7], 8))
+                .await
+        });
+        let reader_locator = participant.default_unicast_locator_list()[0];
+        writer.add_reader_locator(reader_locator);
+
+        let rtps_header = [
+            b'R', b'T', b'P', b'S', 2, 3, 1, 2, 3, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12,
+        ];
+        let truncated_data_submessage = [
+            0x15, 0x05, 200, 0, // DATA | flags | length beyond the datagram
+            0, 0, 16, 0, // extra flags | octets to inline QoS
+            1, 2, 3, 4, // reader id
+        ];
+        let malformed_datagrams = [
+            b"RTPS".to_vec(),
+            [rtps_header.as_slice(), &truncated_data_submessage].concat(),
+            [rtps_header.as_slice(), &[0xff; 13]].concat(),
+            vec![0; 64],
+        ];
+        let socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+        for datagram in &malformed_datagrams {
+            socket
+                .send_to(datagram, UdpLocator(reader_locator))
+                .unwrap();
+        }
+
+        let cache_change = CacheChange {
+            kind: ChangeKind::Alive,
+            writer_guid: writer.guid(),
+            sequence_number: 1,
+            source_timestamp: None,
+            instance_handle: None,
+            data_value: vec![0, 0, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
+        };
+        block_on(async {
+            writer
+                .history_cache()
+                .add_change(cache_change.clone())
+                .await
+        });
+
+        let received_cache_change = receiver
+            .recv_timeout(std::time::Duration::from_secs(30))
+            .unwrap();
+        assert_eq!(cache_change, received_cache_change);
+    }
+
+    #[test]
+    fn panic_while_processing_a_datagram_does_not_stop_the_receive_thread() {
+        let guid_prefix = [3, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 13];
+        let domain_id = 0;
+        let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+            .build()
+            .unwrap();
+        let mut participant =
+            block_on(async { transport.create_participant(guid_prefix, domain_id).await }).unwrap();
+
+        // History cache panicking on the first change it receives
+        struct PanickingHistoryCache(SyncSender<CacheChange>);
+
+        impl HistoryCache for PanickingHistoryCache {
+            fn add_change(
+                &mut self,
+                cache_change: CacheChange,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                if cache_change.sequence_number == 1 {
+                    panic!("Change rejected by the history cache");
+                }
+                self.0.send(cache_change).unwrap();
+                Box::pin(async {})
+            }
+            fn remove_change(
+                &mut self,
+                _sequence_number: i64,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                Box::pin(async {
+                    unimplemented!();
+                })
+            }
+        }
+
+        let (sender, receiver) = sync_channel(1);
+        let _reader = block_on(async {
+            participant
+                .create_stateless_reader(
+                    EntityId::new([1, 2, 3], 4),
+                    Box::new(PanickingHistoryCache(sender)),
+                )
+                .await
+        });
+        let mut writer = block_on(async {
+            participant
+                .create_stateless_writer(EntityId::new([5, 6, 7], 8))
+                .await
+        });
+        writer.add_reader_locator(participant.default_unicast_locator_list()[0]);
+
+        let writer_guid = writer.guid();
+        let cache_change = |sequence_number| CacheChange {
+            kind: ChangeKind::Alive,
+            writer_guid,
+            sequence_number,
+            source_timestamp: None,
+            instance_handle: None,
+            data_value: vec![0, 0, 0, 0, 1, 2, 3, 4].into(),
+            coherent_set: None,
+            coherent_set_end: false,
+        };
+        let second_cache_change = cache_change(2);
+        block_on(async {
+            writer.history_cache().add_change(cache_change(1)).await;
+            writer
+                .history_cache()
+                .add_change(second_cache_change.clone())
+                .await;
+        });
+
+        let received_cache_change = receiver
+            .recv_timeout(std::time::Duration::from_secs(30))
+            .unwrap();
+        assert_eq!(second_cache_change, received_cache_change);
+        assert_eq!(participant.statistics().malformed_packets, 1);
+    }
+
+    #[test]
+    fn unicast_socket_uses_configured_buffer_sizes() {
+        let buffer_sizes = SocketBufferSizes {
+            send_buffer_size: Some(150_000),
+            receive_buffer_size: Some(150_000),
+        };
+        let socket = get_unicast_socket(0, buffer_sizes).unwrap();
+        let socket = socket2::SockRef::from(&socket);
+
+        // Some platforms, e.g. Linux, double the requested value for the bookkeeping overhead
+        assert!(socket.recv_buffer_size().unwrap() >= 150_000);
+        assert!(socket.send_buffer_size().unwrap() >= 150_000);
+    }
 }
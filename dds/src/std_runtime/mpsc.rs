@@ -117,6 +117,18 @@ where
     }
 }
 
+impl<T> Drop for MpscReceiver<T> {
+    fn drop(&mut self) {
+        let mut inner_lock = self.inner.lock().expect("Mutex shouldn't be poisoned");
+        inner_lock.is_closed = true;
+        // Release the pending values outside the lock so that the waiting
+        // reply receivers finish knowing they won't get any message
+        let pending_data = core::mem::take(&mut inner_lock.data);
+        drop(inner_lock);
+        drop(pending_data);
+    }
+}
+
 struct MpscReceiverFuture<T> {
     inner: Arc<Mutex<MpscInner<T>>>,
 }
@@ -1,18 +1,35 @@
 use super::types::{
     CacheChange, EntityId, Guid, GuidPrefix, Locator, ProtocolVersion, ReaderProxy,
-    ReliabilityKind, VendorId, WriterProxy,
+    ReliabilityKind, TransportStatistics, VendorId, WriterProxy,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String};
 use core::{future::Future, pin::Pin};
 
+/// Reason why a transport participant could not be created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransportParticipantError {
+    /// The resources the participant needs are all in use, for example every participant id of the domain
+    OutOfResources(String),
+    /// Any other reason, for example no network address is usable
+    Error(String),
+}
+
+impl From<String> for TransportParticipantError {
+    fn from(value: String) -> Self {
+        Self::Error(value)
+    }
+}
+
 pub trait TransportParticipantFactory: Send + 'static {
     type TransportParticipant: TransportParticipant;
 
+    /// Create the transport participant with the given prefix. The returned error describes why
+    /// the participant could not be created.
     fn create_participant(
         &self,
         guid_prefix: GuidPrefix,
         domain_id: i32,
-    ) -> impl Future<Output = Self::TransportParticipant> + Send;
+    ) -> impl Future<Output = Result<Self::TransportParticipant, TransportParticipantError>> + Send;
 }
 pub trait TransportStatelessWriter: Send + Sync {
     fn guid(&self) -> Guid;
@@ -25,6 +42,11 @@ pub trait TransportStatefulWriter: Send + Sync {
     fn guid(&self) -> Guid;
     fn history_cache(&mut self) -> &mut dyn HistoryCache;
     fn is_change_acknowledged(&self, sequence_number: i64) -> impl Future<Output = bool> + Send;
+    /// Locators on which this writer receives its messages. An empty list means the writer uses
+    /// the default unicast locators of the participant.
+    fn unicast_locator_list(&self) -> &[Locator] {
+        &[]
+    }
     fn add_matched_reader(&mut self, reader_proxy: ReaderProxy) -> impl Future<Output = ()> + Send;
     fn remove_matched_reader(
         &mut self,
@@ -39,6 +61,11 @@ pub trait TransportStatelessReader: Send + Sync {
 pub trait TransportStatefulReader: Send + Sync {
     fn guid(&self) -> Guid;
     fn is_historical_data_received(&self) -> impl Future<Output = bool> + Send;
+    /// Locators on which this reader receives its messages. An empty list means the reader uses
+    /// the default unicast locators of the participant.
+    fn unicast_locator_list(&self) -> &[Locator] {
+        &[]
+    }
     fn add_matched_writer(&mut self, writer_proxy: WriterProxy) -> impl Future<Output = ()> + Send;
     fn remove_matched_writer(
         &mut self,
@@ -66,8 +93,17 @@ pub trait TransportParticipant: Send {
     fn vendor_id(&self) -> VendorId;
     fn metatraffic_unicast_locator_list(&self) -> &[Locator];
     fn metatraffic_multicast_locator_list(&self) -> &[Locator];
+    /// Locators to which the participant announcements are sent
+    fn discovery_locator_list(&self) -> &[Locator] {
+        self.metatraffic_multicast_locator_list()
+    }
     fn default_unicast_locator_list(&self) -> &[Locator];
     fn default_multicast_locator_list(&self) -> &[Locator];
+    /// Counters of the received datagrams which could not be processed. Transports which don't
+    /// keep track of them report zero for all the counters.
+    fn statistics(&self) -> TransportStatistics {
+        TransportStatistics::default()
+    }
 
     fn create_stateless_reader(
         &mut self,
@@ -83,6 +119,7 @@ pub trait TransportParticipant: Send {
     fn create_stateful_reader(
         &mut self,
         entity_id: EntityId,
+        topic_name: &str,
         reliability_kind: ReliabilityKind,
         reader_history_cache: Box<dyn HistoryCache>,
     ) -> impl Future<Output = Self::StatefulReader> + Send;
@@ -90,6 +127,7 @@ pub trait TransportParticipant: Send {
     fn create_stateful_writer(
         &mut self,
         entity_id: EntityId,
+        topic_name: &str,
         reliability_kind: ReliabilityKind,
     ) -> impl Future<Output = Self::StatefulWriter> + Send;
 }
@@ -1,2 +1,3 @@
 pub mod interface;
+pub mod topic_routing;
 pub mod types;
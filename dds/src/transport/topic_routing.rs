@@ -0,0 +1,357 @@
+use super::{
+    interface::{
+        HistoryCache, TransportParticipant, TransportParticipantError, TransportParticipantFactory,
+        TransportStatefulReader, TransportStatefulWriter,
+    },
+    types::{
+        EntityId, Guid, GuidPrefix, Locator, ProtocolVersion, ReaderProxy, ReliabilityKind,
+        TransportStatistics, VendorId, WriterProxy, USER_DEFINED_READER_NO_KEY,
+        USER_DEFINED_READER_WITH_KEY, USER_DEFINED_WRITER_NO_KEY, USER_DEFINED_WRITER_WITH_KEY,
+    },
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+/// Transport factory which creates the endpoints of some topics on a different transport.
+///
+/// The participant discovery, the builtin endpoints and the endpoints of all other topics use the
+/// `default_transport`. The user-defined readers and writers of the topics in `topic_name_list` are
+/// created on the `topic_transport` and announce its default unicast locators, so that for example
+/// large-data topics can be sent over a different transport while discovery stays on UDP.
+pub struct TopicRoutingTransportParticipantFactory<D, T> {
+    default_transport: D,
+    topic_transport: T,
+    topic_name_list: Vec<String>,
+}
+
+impl<D, T> TopicRoutingTransportParticipantFactory<D, T> {
+    /// Construct a factory routing the topics in `topic_name_list` to the `topic_transport`.
+    pub fn new(default_transport: D, topic_transport: T, topic_name_list: Vec<String>) -> Self {
+        Self {
+            default_transport,
+            topic_transport,
+            topic_name_list,
+        }
+    }
+}
+
+impl<D, T> TransportParticipantFactory for TopicRoutingTransportParticipantFactory<D, T>
+where
+    D: TransportParticipantFactory + Sync,
+    T: TransportParticipantFactory + Sync,
+{
+    type TransportParticipant =
+        TopicRoutingTransportParticipant<D::TransportParticipant, T::TransportParticipant>;
+
+    async fn create_participant(
+        &self,
+        guid_prefix: GuidPrefix,
+        domain_id: i32,
+    ) -> Result<Self::TransportParticipant, TransportParticipantError> {
+        Ok(TopicRoutingTransportParticipant {
+            default_participant: self
+                .default_transport
+                .create_participant(guid_prefix, domain_id)
+                .await?,
+            topic_participant: self
+                .topic_transport
+                .create_participant(guid_prefix, domain_id)
+                .await?,
+            topic_name_list: self.topic_name_list.clone(),
+        })
+    }
+}
+
+/// Transport participant created by the [`TopicRoutingTransportParticipantFactory`].
+pub struct TopicRoutingTransportParticipant<D, T> {
+    default_participant: D,
+    topic_participant: T,
+    topic_name_list: Vec<String>,
+}
+
+impl<D, T> TopicRoutingTransportParticipant<D, T> {
+    fn is_routed(&self, entity_id: EntityId, topic_name: &str) -> bool {
+        let is_user_defined_endpoint = matches!(
+            entity_id.entity_kind(),
+            USER_DEFINED_READER_NO_KEY
+                | USER_DEFINED_READER_WITH_KEY
+                | USER_DEFINED_WRITER_NO_KEY
+                | USER_DEFINED_WRITER_WITH_KEY
+        );
+        is_user_defined_endpoint && self.topic_name_list.iter().any(|t| t == topic_name)
+    }
+}
+
+/// Stateful reader created on either of the transports of a [`TopicRoutingTransportParticipant`].
+pub enum TopicRoutingStatefulReader<D, T> {
+    /// Reader created on the default transport
+    Default(D),
+    /// Reader created on the topic transport
+    Routed {
+        /// Reader of the topic transport
+        reader: T,
+        /// Default unicast locators of the topic transport
+        unicast_locator_list: Vec<Locator>,
+    },
+}
+
+impl<D, T> TransportStatefulReader for TopicRoutingStatefulReader<D, T>
+where
+    D: TransportStatefulReader,
+    T: TransportStatefulReader,
+{
+    fn guid(&self) -> Guid {
+        match self {
+            TopicRoutingStatefulReader::Default(r) => r.guid(),
+            TopicRoutingStatefulReader::Routed { reader, .. } => reader.guid(),
+        }
+    }
+
+    async fn is_historical_data_received(&self) -> bool {
+        match self {
+            TopicRoutingStatefulReader::Default(r) => r.is_historical_data_received().await,
+            TopicRoutingStatefulReader::Routed { reader, .. } => {
+                reader.is_historical_data_received().await
+            }
+        }
+    }
+
+    fn unicast_locator_list(&self) -> &[Locator] {
+        match self {
+            TopicRoutingStatefulReader::Default(r) => r.unicast_locator_list(),
+            TopicRoutingStatefulReader::Routed {
+                unicast_locator_list,
+                ..
+            } => unicast_locator_list,
+        }
+    }
+
+    async fn add_matched_writer(&mut self, writer_proxy: WriterProxy) {
+        match self {
+            TopicRoutingStatefulReader::Default(r) => r.add_matched_writer(writer_proxy).await,
+            TopicRoutingStatefulReader::Routed { reader, .. } => {
+                reader.add_matched_writer(writer_proxy).await
+            }
+        }
+    }
+
+    async fn remove_matched_writer(&mut self, remote_writer_guid: Guid) {
+        match self {
+            TopicRoutingStatefulReader::Default(r) => {
+                r.remove_matched_writer(remote_writer_guid).await
+            }
+            TopicRoutingStatefulReader::Routed { reader, .. } => {
+                reader.remove_matched_writer(remote_writer_guid).await
+            }
+        }
+    }
+}
+
+/// Stateful writer created on either of the transports of a [`TopicRoutingTransportParticipant`].
+pub enum TopicRoutingStatefulWriter<D, T> {
+    /// Writer created on the default transport
+    Default(D),
+    /// Writer created on the topic transport
+    Routed {
+        /// Writer of the topic transport
+        writer: T,
+        /// Default unicast locators of the topic transport
+        unicast_locator_list: Vec<Locator>,
+    },
+}
+
+impl<D, T> TransportStatefulWriter for TopicRoutingStatefulWriter<D, T>
+where
+    D: TransportStatefulWriter,
+    T: TransportStatefulWriter,
+{
+    fn guid(&self) -> Guid {
+        match self {
+            TopicRoutingStatefulWriter::Default(w) => w.guid(),
+            TopicRoutingStatefulWriter::Routed { writer, .. } => writer.guid(),
+        }
+    }
+
+    fn history_cache(&mut self) -> &mut dyn HistoryCache {
+        match self {
+            TopicRoutingStatefulWriter::Default(w) => w.history_cache(),
+            TopicRoutingStatefulWriter::Routed { writer, .. } => writer.history_cache(),
+        }
+    }
+
+    async fn is_change_acknowledged(&self, sequence_number: i64) -> bool {
+        match self {
+            TopicRoutingStatefulWriter::Default(w) => {
+                w.is_change_acknowledged(sequence_number).await
+            }
+            TopicRoutingStatefulWriter::Routed { writer, .. } => {
+                writer.is_change_acknowledged(sequence_number).await
+            }
+        }
+    }
+
+    fn unicast_locator_list(&self) -> &[Locator] {
+        match self {
+            TopicRoutingStatefulWriter::Default(w) => w.unicast_locator_list(),
+            TopicRoutingStatefulWriter::Routed {
+                unicast_locator_list,
+                ..
+            } => unicast_locator_list,
+        }
+    }
+
+    async fn add_matched_reader(&mut self, reader_proxy: ReaderProxy) {
+        match self {
+            TopicRoutingStatefulWriter::Default(w) => w.add_matched_reader(reader_proxy).await,
+            TopicRoutingStatefulWriter::Routed { writer, .. } => {
+                writer.add_matched_reader(reader_proxy).await
+            }
+        }
+    }
+
+    async fn remove_matched_reader(&mut self, remote_reader_guid: Guid) {
+        match self {
+            TopicRoutingStatefulWriter::Default(w) => {
+                w.remove_matched_reader(remote_reader_guid).await
+            }
+            TopicRoutingStatefulWriter::Routed { writer, .. } => {
+                writer.remove_matched_reader(remote_reader_guid).await
+            }
+        }
+    }
+}
+
+impl<D, T> TransportParticipant for TopicRoutingTransportParticipant<D, T>
+where
+    D: TransportParticipant,
+    T: TransportParticipant,
+{
+    type StatelessReader = D::StatelessReader;
+    type StatefulReader = TopicRoutingStatefulReader<D::StatefulReader, T::StatefulReader>;
+    type StatelessWriter = D::StatelessWriter;
+    type StatefulWriter = TopicRoutingStatefulWriter<D::StatefulWriter, T::StatefulWriter>;
+
+    fn guid(&self) -> Guid {
+        self.default_participant.guid()
+    }
+
+    fn protocol_version(&self) -> ProtocolVersion {
+        self.default_participant.protocol_version()
+    }
+
+    fn vendor_id(&self) -> VendorId {
+        self.default_participant.vendor_id()
+    }
+
+    fn metatraffic_unicast_locator_list(&self) -> &[Locator] {
+        self.default_participant.metatraffic_unicast_locator_list()
+    }
+
+    fn metatraffic_multicast_locator_list(&self) -> &[Locator] {
+        self.default_participant
+            .metatraffic_multicast_locator_list()
+    }
+
+    fn discovery_locator_list(&self) -> &[Locator] {
+        self.default_participant.discovery_locator_list()
+    }
+
+    fn default_unicast_locator_list(&self) -> &[Locator] {
+        self.default_participant.default_unicast_locator_list()
+    }
+
+    fn default_multicast_locator_list(&self) -> &[Locator] {
+        self.default_participant.default_multicast_locator_list()
+    }
+
+    fn statistics(&self) -> TransportStatistics {
+        let default_statistics = self.default_participant.statistics();
+        let topic_statistics = self.topic_participant.statistics();
+        TransportStatistics {
+            malformed_packets: default_statistics.malformed_packets
+                + topic_statistics.malformed_packets,
+            unknown_submessages: default_statistics.unknown_submessages
+                + topic_statistics.unknown_submessages,
+        }
+    }
+
+    async fn create_stateless_reader(
+        &mut self,
+        entity_id: EntityId,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatelessReader {
+        self.default_participant
+            .create_stateless_reader(entity_id, reader_history_cache)
+            .await
+    }
+
+    async fn create_stateless_writer(&mut self, entity_id: EntityId) -> Self::StatelessWriter {
+        self.default_participant
+            .create_stateless_writer(entity_id)
+            .await
+    }
+
+    async fn create_stateful_reader(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatefulReader {
+        if self.is_routed(entity_id, topic_name) {
+            let reader = self
+                .topic_participant
+                .create_stateful_reader(
+                    entity_id,
+                    topic_name,
+                    reliability_kind,
+                    reader_history_cache,
+                )
+                .await;
+            TopicRoutingStatefulReader::Routed {
+                reader,
+                unicast_locator_list: self
+                    .topic_participant
+                    .default_unicast_locator_list()
+                    .to_vec(),
+            }
+        } else {
+            TopicRoutingStatefulReader::Default(
+                self.default_participant
+                    .create_stateful_reader(
+                        entity_id,
+                        topic_name,
+                        reliability_kind,
+                        reader_history_cache,
+                    )
+                    .await,
+            )
+        }
+    }
+
+    async fn create_stateful_writer(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+    ) -> Self::StatefulWriter {
+        if self.is_routed(entity_id, topic_name) {
+            let writer = self
+                .topic_participant
+                .create_stateful_writer(entity_id, topic_name, reliability_kind)
+                .await;
+            TopicRoutingStatefulWriter::Routed {
+                writer,
+                unicast_locator_list: self
+                    .topic_participant
+                    .default_unicast_locator_list()
+                    .to_vec(),
+            }
+        } else {
+            TopicRoutingStatefulWriter::Default(
+                self.default_participant
+                    .create_stateful_writer(entity_id, topic_name, reliability_kind)
+                    .await,
+            )
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use alloc::{sync::Arc, vec::Vec};
 use dust_dds_derive::XTypesDeserialize;
 
-use crate::infrastructure::type_support::TypeSupport;
+use crate::infrastructure::{error::DdsError, type_support::TypeSupport};
+use core::{
+    fmt::Display,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    str::FromStr,
+};
 
 pub type Octet = u8;
 pub type Short = i16;
@@ -231,6 +236,60 @@ impl Locator {
     }
 }
 
+/// Formats UDP locators as `udpv4://239.255.0.1:7400` or `udpv6://[::1]:7400`.
+/// Locators of any other kind are formatted with their raw kind and address octets.
+impl Display for Locator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            LOCATOR_KIND_UDP_V4 => {
+                let [.., a, b, c, d] = self.address;
+                write!(f, "udpv4://{}:{}", Ipv4Addr::new(a, b, c, d), self.port)
+            }
+            LOCATOR_KIND_UDP_V6 => {
+                write!(
+                    f,
+                    "udpv6://[{}]:{}",
+                    Ipv6Addr::from(self.address),
+                    self.port
+                )
+            }
+            kind => write!(f, "{kind}://{:?}:{}", self.address, self.port),
+        }
+    }
+}
+
+/// Parses UDP locators in the `udpv4://ip:port` and `udpv6://[ip]:port` forms.
+/// Any other input returns [`DdsError::BadParameter`].
+impl FromStr for Locator {
+    type Err = DdsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, socket_address) = if let Some(address) = s.strip_prefix("udpv4://") {
+            (LOCATOR_KIND_UDP_V4, address)
+        } else if let Some(address) = s.strip_prefix("udpv6://") {
+            (LOCATOR_KIND_UDP_V6, address)
+        } else {
+            return Err(DdsError::BadParameter);
+        };
+        match (kind, SocketAddr::from_str(socket_address)) {
+            (LOCATOR_KIND_UDP_V4, Ok(SocketAddr::V4(socket_address))) => {
+                let [a, b, c, d] = socket_address.ip().octets();
+                Ok(Locator::new(
+                    kind,
+                    socket_address.port() as UnsignedLong,
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, a, b, c, d],
+                ))
+            }
+            (LOCATOR_KIND_UDP_V6, Ok(SocketAddr::V6(socket_address))) => Ok(Locator::new(
+                kind,
+                socket_address.port() as UnsignedLong,
+                socket_address.ip().octets(),
+            )),
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+}
+
 /// ProtocolVersion_t
 /// Type used to represent the version of the RTPS protocol. The version is composed of a major and a minor version number. See also 8.6.
 /// The following values are reserved by the protocol: PROTOCOLVERSION PROTOCOLVERSION_1_0 PROTOCOLVERSION_1_1 PROTOCOLVERSION_2_0 PROTOCOLVERSION_2_1 PROTOCOLVERSION_2_2
@@ -248,10 +307,10 @@ impl ProtocolVersion {
             bytes: [major, minor],
         }
     }
-    pub const fn _major(&self) -> Octet {
+    pub const fn major(&self) -> Octet {
         self.bytes[0]
     }
-    pub const fn _minor(&self) -> Octet {
+    pub const fn minor(&self) -> Octet {
         self.bytes[1]
     }
 }
@@ -295,6 +354,16 @@ impl Time {
     }
 }
 
+/// Counters of the received datagrams which could not be fully processed by a transport participant
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct TransportStatistics {
+    /// Number of datagrams discarded, entirely or from the first invalid submessage on, because
+    /// they are not valid RTPS messages
+    pub malformed_packets: u64,
+    /// Number of submessages skipped because their kind is not known
+    pub unknown_submessages: u64,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct WriterProxy {
     pub remote_writer_guid: Guid,
@@ -313,6 +382,76 @@ pub struct CacheChange {
     pub source_timestamp: Option<Time>,
     pub instance_handle: Option<[u8; 16]>,
     pub data_value: Arc<[u8]>,
+    /// Sequence number of the first change of the coherent set this change belongs to, if any
+    pub coherent_set: Option<i64>,
+    /// Whether this change is the last one of its coherent set
+    pub coherent_set_end: bool,
+}
+
+impl CacheChange {
+    /// Start building a [`CacheChange`] of the given kind with `sequence_number` written by the writer
+    /// with `writer_guid`. Fields which are not set on the builder take their default value, i.e. no
+    /// source timestamp, no instance handle, an empty data value and no coherent set.
+    pub fn builder(
+        kind: ChangeKind,
+        writer_guid: Guid,
+        sequence_number: i64,
+    ) -> CacheChangeBuilder {
+        CacheChangeBuilder {
+            cache_change: CacheChange {
+                kind,
+                writer_guid,
+                sequence_number,
+                source_timestamp: None,
+                instance_handle: None,
+                data_value: Arc::from([]),
+                coherent_set: None,
+                coherent_set_end: false,
+            },
+        }
+    }
+}
+
+/// Builder for a [`CacheChange`] created with [`CacheChange::builder`]
+pub struct CacheChangeBuilder {
+    cache_change: CacheChange,
+}
+
+impl CacheChangeBuilder {
+    /// Set the source timestamp of the change
+    pub fn source_timestamp(mut self, source_timestamp: Time) -> Self {
+        self.cache_change.source_timestamp = Some(source_timestamp);
+        self
+    }
+
+    /// Set the instance handle of the change
+    pub fn instance_handle(mut self, instance_handle: [u8; 16]) -> Self {
+        self.cache_change.instance_handle = Some(instance_handle);
+        self
+    }
+
+    /// Set the serialized data value of the change
+    pub fn data_value(mut self, data_value: impl Into<Arc<[u8]>>) -> Self {
+        self.cache_change.data_value = data_value.into();
+        self
+    }
+
+    /// Set the sequence number of the first change of the coherent set the change belongs to
+    pub fn coherent_set(mut self, coherent_set: i64) -> Self {
+        self.cache_change.coherent_set = Some(coherent_set);
+        self
+    }
+
+    /// Mark the change as the last one of its coherent set
+    pub fn coherent_set_end(mut self) -> Self {
+        self.cache_change.coherent_set_end = true;
+        self
+    }
+
+    /// Create the [`CacheChange`]
+    pub fn build(self) -> CacheChange {
+        self.cache_change
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -325,3 +464,103 @@ pub struct ReaderProxy {
     pub multicast_locator_list: Vec<Locator>,
     pub expects_inline_qos: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn cache_change_builder_matches_struct_construction() {
+        let writer_guid = Guid::new(
+            [1; 12],
+            EntityId::new([2, 3, 4], USER_DEFINED_WRITER_WITH_KEY),
+        );
+        let expected = CacheChange {
+            kind: ChangeKind::NotAliveDisposed,
+            writer_guid,
+            sequence_number: 7,
+            source_timestamp: Some(Time::new(10, 20)),
+            instance_handle: Some([5; 16]),
+            data_value: vec![1, 2, 3, 4].into(),
+            coherent_set: Some(6),
+            coherent_set_end: true,
+        };
+
+        let cache_change = CacheChange::builder(ChangeKind::NotAliveDisposed, writer_guid, 7)
+            .source_timestamp(Time::new(10, 20))
+            .instance_handle([5; 16])
+            .data_value(vec![1, 2, 3, 4])
+            .coherent_set(6)
+            .coherent_set_end()
+            .build();
+
+        assert_eq!(cache_change, expected);
+    }
+
+    #[test]
+    fn cache_change_builder_defaults() {
+        let writer_guid = Guid::new(
+            [1; 12],
+            EntityId::new([2, 3, 4], USER_DEFINED_WRITER_NO_KEY),
+        );
+        let cache_change = CacheChange::builder(ChangeKind::Alive, writer_guid, 1).build();
+
+        assert_eq!(
+            cache_change,
+            CacheChange {
+                kind: ChangeKind::Alive,
+                writer_guid,
+                sequence_number: 1,
+                source_timestamp: None,
+                instance_handle: None,
+                data_value: Arc::from([]),
+                coherent_set: None,
+                coherent_set_end: false,
+            }
+        );
+    }
+
+    #[test]
+    fn udpv4_locator_string_round_trip() {
+        let locator: Locator = "udpv4://239.255.0.1:7400".parse().unwrap();
+
+        assert_eq!(
+            locator,
+            Locator::new(
+                LOCATOR_KIND_UDP_V4,
+                7400,
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 255, 0, 1]
+            )
+        );
+        assert_eq!(locator.to_string(), "udpv4://239.255.0.1:7400");
+    }
+
+    #[test]
+    fn udpv6_locator_string_round_trip() {
+        let locator: Locator = "udpv6://[::1]:7400".parse().unwrap();
+
+        assert_eq!(
+            locator,
+            Locator::new(
+                LOCATOR_KIND_UDP_V6,
+                7400,
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]
+            )
+        );
+        assert_eq!(locator.to_string(), "udpv6://[::1]:7400");
+    }
+
+    #[test]
+    fn malformed_locator_string_is_rejected() {
+        for s in [
+            "239.255.0.1:7400",
+            "udpv4://239.255.0.1",
+            "udpv4://[::1]:7400",
+            "udpv6://239.255.0.1:7400",
+            "tcpv4://239.255.0.1:7400",
+        ] {
+            assert_eq!(Locator::from_str(s), Err(DdsError::BadParameter), "{s}");
+        }
+    }
+}
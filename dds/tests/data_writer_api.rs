@@ -1,13 +1,49 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
+
 use dust_dds::{
+    dds_async::domain_participant_factory::DomainParticipantFactoryAsync,
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
         error::DdsError,
         qos::{DataWriterQos, QosKind},
-        qos_policy::{HistoryQosPolicy, HistoryQosPolicyKind, Length, ResourceLimitsQosPolicy},
-        status::NO_STATUS,
+        qos_policy::{
+            DeadlineQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind, Length, LivelinessQosPolicy,
+            LivelinessQosPolicyKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+            ResourceLimitsQosPolicy,
+        },
+        status::{LivelinessLostStatus, StatusKind, NO_STATUS},
+        time::{Duration, DurationKind, Time},
         type_support::DdsType,
     },
     listener::NO_LISTENER,
+    publication::data_writer::DataWriter,
+    rtps_memory_transport::memory_transport::{
+        RtpsMemoryTransportParticipant, RtpsMemoryTransportParticipantFactory,
+    },
+    runtime::{Clock, DdsRuntime, Timer},
+    std_runtime::{
+        executor::{Executor, ExecutorHandle},
+        timer::{TimerDriver, TimerHandle},
+        StdRuntime,
+    },
+    transport::{
+        interface::{
+            HistoryCache, TransportParticipant, TransportParticipantError,
+            TransportParticipantFactory, TransportStatefulWriter,
+        },
+        types::{
+            EntityId, Guid, GuidPrefix, Locator, ProtocolVersion, ReaderProxy, ReliabilityKind,
+            VendorId,
+        },
+    },
+    wait_set::{Condition, WaitSet},
 };
 
 mod utils;
@@ -95,6 +131,10 @@ fn data_writer_write_more_than_max_samples_per_instances_should_fail() {
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
         },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 0)),
+        },
         ..Default::default()
     };
     let data_writer = publisher
@@ -140,6 +180,10 @@ fn data_writer_write_more_than_max_samples_should_fail() {
             max_instances: Length::Unlimited,
             max_samples_per_instance: Length::Limited(1),
         },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 0)),
+        },
         ..Default::default()
     };
     let data_writer = publisher
@@ -159,3 +203,661 @@ fn data_writer_write_more_than_max_samples_should_fail() {
     let result = data_writer.write(data_instance3, None);
     assert_eq!(result, Err(DdsError::OutOfResources));
 }
+
+#[test]
+fn data_writer_with_keep_last_overwriting_instance_at_max_samples_should_succeed() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Limited(2),
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(1),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(1),
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    data_writer
+        .write(KeyedData { id: 1, value: 0 }, None)
+        .unwrap();
+    data_writer
+        .write(KeyedData { id: 2, value: 0 }, None)
+        .unwrap();
+
+    assert_eq!(
+        data_writer.write(KeyedData { id: 1, value: 1 }, None),
+        Ok(())
+    );
+    assert_eq!(
+        data_writer.write(KeyedData { id: 3, value: 0 }, None),
+        Err(DdsError::OutOfResources)
+    );
+}
+
+// Transport whose writers of the given topic report their changes as not acknowledged while
+// the acknowledgments are held, as if a matched reader had not received them yet
+struct AcknowledgmentHoldingTransportParticipantFactory {
+    transport: RtpsMemoryTransportParticipantFactory,
+    topic_name: &'static str,
+    hold_acknowledgments: Arc<AtomicBool>,
+}
+
+impl TransportParticipantFactory for AcknowledgmentHoldingTransportParticipantFactory {
+    type TransportParticipant = AcknowledgmentHoldingTransportParticipant;
+
+    async fn create_participant(
+        &self,
+        guid_prefix: GuidPrefix,
+        domain_id: i32,
+    ) -> Result<Self::TransportParticipant, TransportParticipantError> {
+        Ok(AcknowledgmentHoldingTransportParticipant {
+            participant: self
+                .transport
+                .create_participant(guid_prefix, domain_id)
+                .await?,
+            topic_name: self.topic_name,
+            hold_acknowledgments: self.hold_acknowledgments.clone(),
+        })
+    }
+}
+
+struct AcknowledgmentHoldingTransportParticipant {
+    participant: RtpsMemoryTransportParticipant,
+    topic_name: &'static str,
+    hold_acknowledgments: Arc<AtomicBool>,
+}
+
+impl TransportParticipant for AcknowledgmentHoldingTransportParticipant {
+    type StatelessReader =
+        <RtpsMemoryTransportParticipant as TransportParticipant>::StatelessReader;
+    type StatefulReader = <RtpsMemoryTransportParticipant as TransportParticipant>::StatefulReader;
+    type StatelessWriter =
+        <RtpsMemoryTransportParticipant as TransportParticipant>::StatelessWriter;
+    type StatefulWriter = AcknowledgmentHoldingStatefulWriter;
+
+    fn guid(&self) -> Guid {
+        self.participant.guid()
+    }
+    fn protocol_version(&self) -> ProtocolVersion {
+        self.participant.protocol_version()
+    }
+    fn vendor_id(&self) -> VendorId {
+        self.participant.vendor_id()
+    }
+    fn metatraffic_unicast_locator_list(&self) -> &[Locator] {
+        self.participant.metatraffic_unicast_locator_list()
+    }
+    fn metatraffic_multicast_locator_list(&self) -> &[Locator] {
+        self.participant.metatraffic_multicast_locator_list()
+    }
+    fn default_unicast_locator_list(&self) -> &[Locator] {
+        self.participant.default_unicast_locator_list()
+    }
+    fn default_multicast_locator_list(&self) -> &[Locator] {
+        self.participant.default_multicast_locator_list()
+    }
+    async fn create_stateless_reader(
+        &mut self,
+        entity_id: EntityId,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatelessReader {
+        self.participant
+            .create_stateless_reader(entity_id, reader_history_cache)
+            .await
+    }
+    async fn create_stateless_writer(&mut self, entity_id: EntityId) -> Self::StatelessWriter {
+        self.participant.create_stateless_writer(entity_id).await
+    }
+    async fn create_stateful_reader(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatefulReader {
+        self.participant
+            .create_stateful_reader(
+                entity_id,
+                topic_name,
+                reliability_kind,
+                reader_history_cache,
+            )
+            .await
+    }
+    async fn create_stateful_writer(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+    ) -> Self::StatefulWriter {
+        AcknowledgmentHoldingStatefulWriter {
+            writer: self
+                .participant
+                .create_stateful_writer(entity_id, topic_name, reliability_kind)
+                .await,
+            hold_acknowledgments: (topic_name == self.topic_name)
+                .then(|| self.hold_acknowledgments.clone()),
+        }
+    }
+}
+
+struct AcknowledgmentHoldingStatefulWriter {
+    writer: <RtpsMemoryTransportParticipant as TransportParticipant>::StatefulWriter,
+    hold_acknowledgments: Option<Arc<AtomicBool>>,
+}
+
+impl TransportStatefulWriter for AcknowledgmentHoldingStatefulWriter {
+    fn guid(&self) -> Guid {
+        self.writer.guid()
+    }
+    fn history_cache(&mut self) -> &mut dyn HistoryCache {
+        self.writer.history_cache()
+    }
+    async fn is_change_acknowledged(&self, sequence_number: i64) -> bool {
+        let is_held = self
+            .hold_acknowledgments
+            .as_ref()
+            .is_some_and(|x| x.load(Ordering::Relaxed));
+        !is_held && self.writer.is_change_acknowledged(sequence_number).await
+    }
+    async fn add_matched_reader(&mut self, reader_proxy: ReaderProxy) {
+        self.writer.add_matched_reader(reader_proxy).await
+    }
+    async fn remove_matched_reader(&mut self, remote_reader_guid: Guid) {
+        self.writer.remove_matched_reader(remote_reader_guid).await
+    }
+}
+
+fn create_acknowledgment_holding_data_writer(
+    data_writer_qos: DataWriterQos,
+    hold_acknowledgments: Arc<AtomicBool>,
+) -> DataWriter<StdRuntime, KeyedData> {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory_async: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            AcknowledgmentHoldingTransportParticipantFactory {
+                transport: RtpsMemoryTransportParticipantFactory::new(),
+                topic_name: "MyTopic",
+                hold_acknowledgments,
+            },
+        )));
+    let participant = DomainParticipantFactory::new(domain_participant_factory_async)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap()
+}
+
+#[test]
+fn reliable_data_writer_at_max_samples_per_instance_times_out_until_sample_is_acknowledged() {
+    let hold_acknowledgments = Arc::new(AtomicBool::new(true));
+    let data_writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 200_000_000)),
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Unlimited,
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(1),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let data_writer =
+        create_acknowledgment_holding_data_writer(data_writer_qos, hold_acknowledgments.clone());
+    data_writer
+        .write(KeyedData { id: 1, value: 0 }, None)
+        .unwrap();
+
+    let start_time = Instant::now();
+    assert_eq!(
+        data_writer.write(KeyedData { id: 1, value: 1 }, None),
+        Err(DdsError::Timeout)
+    );
+    assert!(start_time.elapsed() >= std::time::Duration::from_millis(200));
+
+    hold_acknowledgments.store(false, Ordering::Relaxed);
+    assert_eq!(
+        data_writer.write(KeyedData { id: 1, value: 1 }, None),
+        Ok(())
+    );
+}
+
+#[test]
+fn reliable_data_writer_at_max_samples_times_out_until_sample_is_acknowledged() {
+    let hold_acknowledgments = Arc::new(AtomicBool::new(true));
+    let data_writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 200_000_000)),
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Limited(2),
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(1),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let data_writer =
+        create_acknowledgment_holding_data_writer(data_writer_qos, hold_acknowledgments.clone());
+    data_writer
+        .write(KeyedData { id: 1, value: 0 }, None)
+        .unwrap();
+    data_writer
+        .write(KeyedData { id: 2, value: 0 }, None)
+        .unwrap();
+
+    let start_time = Instant::now();
+    assert_eq!(
+        data_writer.write(KeyedData { id: 3, value: 0 }, None),
+        Err(DdsError::Timeout)
+    );
+    assert!(start_time.elapsed() >= std::time::Duration::from_millis(200));
+
+    hold_acknowledgments.store(false, Ordering::Relaxed);
+    assert_eq!(
+        data_writer.write(KeyedData { id: 3, value: 0 }, None),
+        Ok(())
+    );
+}
+
+// Clock which only moves forward when the test advances it
+#[derive(Clone)]
+struct ManualClock(Arc<Mutex<std::time::Duration>>);
+
+impl ManualClock {
+    fn new(now: std::time::Duration) -> Self {
+        Self(Arc::new(Mutex::new(now)))
+    }
+
+    fn elapsed(&self) -> std::time::Duration {
+        *self.0.lock().unwrap()
+    }
+
+    fn advance(&self, duration: std::time::Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Time {
+        let now = self.elapsed();
+        Time::new(now.as_secs() as i32, now.subsec_nanos())
+    }
+}
+
+// Timer whose delays elapse once the manual clock is advanced past them
+#[derive(Clone)]
+struct ManualTimer {
+    clock: ManualClock,
+    timer_handle: TimerHandle,
+}
+
+impl Timer for ManualTimer {
+    fn delay(&mut self, duration: std::time::Duration) -> impl Future<Output = ()> + Send {
+        let deadline = self.clock.elapsed() + duration;
+        let clock = self.clock.clone();
+        let timer_handle = self.timer_handle.clone();
+        async move {
+            // Sleeping at least once lets the other tasks run even for zero delays
+            loop {
+                timer_handle
+                    .sleep(std::time::Duration::from_millis(1))
+                    .await;
+                if clock.elapsed() >= deadline {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+struct ManualClockRuntime {
+    std_runtime: StdRuntime,
+    clock: ManualClock,
+}
+
+impl DdsRuntime for ManualClockRuntime {
+    type ClockHandle = ManualClock;
+    type TimerHandle = ManualTimer;
+    type SpawnerHandle = ExecutorHandle;
+    type OneshotSender<T: Send> = <StdRuntime as DdsRuntime>::OneshotSender<T>;
+    type OneshotReceiver<T: Send> = <StdRuntime as DdsRuntime>::OneshotReceiver<T>;
+    type ChannelSender<T: Send> = <StdRuntime as DdsRuntime>::ChannelSender<T>;
+    type ChannelReceiver<T: Send + 'static> = <StdRuntime as DdsRuntime>::ChannelReceiver<T>;
+
+    fn timer(&self) -> Self::TimerHandle {
+        ManualTimer {
+            clock: self.clock.clone(),
+            timer_handle: self.std_runtime.timer(),
+        }
+    }
+
+    fn clock(&self) -> Self::ClockHandle {
+        self.clock.clone()
+    }
+
+    fn spawner(&self) -> Self::SpawnerHandle {
+        self.std_runtime.spawner()
+    }
+
+    fn oneshot<T: Send>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>) {
+        StdRuntime::oneshot()
+    }
+
+    fn channel<T: Send + 'static>() -> (Self::ChannelSender<T>, Self::ChannelReceiver<T>) {
+        StdRuntime::channel()
+    }
+
+    fn block_on<T>(f: impl Future<Output = T>) -> T {
+        StdRuntime::block_on(f)
+    }
+}
+
+#[test]
+fn data_writer_not_asserting_liveliness_within_lease_reports_liveliness_lost() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let clock = ManualClock::new(std::time::Duration::from_secs(1000));
+    let domain_participant_factory_async: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            ManualClockRuntime {
+                std_runtime: StdRuntime::new(Executor::new(), TimerDriver::new()),
+                clock: clock.clone(),
+            },
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            RtpsMemoryTransportParticipantFactory::new(),
+        )));
+    let participant = DomainParticipantFactory::new(domain_participant_factory_async)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::ManualByTopic,
+            lease_duration: DurationKind::Finite(Duration::new(10, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    assert_eq!(
+        writer.get_liveliness_lost_status().unwrap(),
+        LivelinessLostStatus {
+            total_count: 0,
+            total_count_change: 0
+        }
+    );
+
+    let lease_start = clock.elapsed();
+    let status = advance_until_liveliness_lost(&clock, &writer);
+    assert_eq!(
+        status,
+        LivelinessLostStatus {
+            total_count: 1,
+            total_count_change: 1
+        }
+    );
+    assert!(clock.elapsed() - lease_start >= std::time::Duration::from_secs(10));
+
+    writer.write(KeyedData { id: 1, value: 1 }, None).unwrap();
+    let lease_start = clock.elapsed();
+    let status = advance_until_liveliness_lost(&clock, &writer);
+    assert_eq!(
+        status,
+        LivelinessLostStatus {
+            total_count: 2,
+            total_count_change: 1
+        }
+    );
+    assert!(clock.elapsed() - lease_start >= std::time::Duration::from_secs(10));
+}
+
+fn advance_until_liveliness_lost(
+    clock: &ManualClock,
+    writer: &DataWriter<ManualClockRuntime, KeyedData>,
+) -> LivelinessLostStatus {
+    for _ in 0..1000 {
+        let status = writer.get_liveliness_lost_status().unwrap();
+        if status.total_count_change > 0 {
+            return status;
+        }
+        clock.advance(std::time::Duration::from_secs(1));
+    }
+    panic!("Liveliness not lost after the lease expired")
+}
+
+#[test]
+fn registered_instance_not_written_within_deadline_reports_offered_deadline_missed() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(0, 200_000_000)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let instance_handle = writer
+        .register_instance(KeyedData { id: 1, value: 0 })
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        writer
+            .get_offered_deadline_missed_status()
+            .unwrap()
+            .total_count,
+        0
+    );
+
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    let status = writer.get_offered_deadline_missed_status().unwrap();
+    assert!(status.total_count >= 1);
+    assert_eq!(status.total_count_change, status.total_count);
+    assert_eq!(status.last_instance_handle, instance_handle);
+}
+
+#[test]
+fn offered_deadline_missed_is_counted_once_per_period_for_an_instance_written_many_times() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(0, 300_000_000)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    for value in 0..5 {
+        writer.write(KeyedData { id: 1, value }, None).unwrap();
+    }
+
+    std::thread::sleep(std::time::Duration::from_millis(700));
+    let status = writer.get_offered_deadline_missed_status().unwrap();
+    assert!((1..=2).contains(&status.total_count));
+}
+
+#[test]
+fn publication_matched_is_reported_in_status_changes_until_read() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    assert!(!writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::PublicationMatched));
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let _reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    assert!(writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::PublicationMatched));
+
+    writer.get_publication_matched_status().unwrap();
+    assert!(!writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::PublicationMatched));
+}
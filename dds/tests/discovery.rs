@@ -1,21 +1,54 @@
 use std::time::Instant;
 
 use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    dcps::data_representation_builtin_endpoints::spdp_discovered_participant_data::BuiltinEndpointSet,
+    dds_async::domain_participant_factory::DomainParticipantFactoryAsync,
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
         qos::{DataReaderQos, DataWriterQos, PublisherQos, QosKind, SubscriberQos},
         qos_policy::{
             DataRepresentationQosPolicy, OwnershipQosPolicy, OwnershipQosPolicyKind,
-            PartitionQosPolicy, UserDataQosPolicy, XCDR2_DATA_REPRESENTATION,
-            XCDR_DATA_REPRESENTATION,
+            PartitionQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind, UserDataQosPolicy,
+            RELIABILITY_QOS_POLICY_ID, XCDR2_DATA_REPRESENTATION, XCDR_DATA_REPRESENTATION,
         },
-        status::{StatusKind, NO_STATUS},
-        time::Duration,
-        type_support::DdsType,
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{MatchDiagnostic, StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::{DdsDeserialize, DdsType},
     },
     listener::NO_LISTENER,
+    rtps_memory_transport::memory_transport::{
+        RtpsMemoryTransportParticipant, RtpsMemoryTransportParticipantFactory,
+    },
+    rtps_messages::overall_structure::{RtpsMessageRead, RtpsSubmessageReadKind},
+    rtps_udp_transport::udp_transport::{
+        RtpsUdpTransportParticipantFactory, RtpsUdpTransportParticipantFactoryBuilder,
+    },
+    std_runtime::{executor::Executor, timer::TimerDriver, StdRuntime},
+    transport::{
+        interface::{
+            HistoryCache, TransportParticipant, TransportParticipantError,
+            TransportParticipantFactory,
+        },
+        topic_routing::TopicRoutingTransportParticipantFactory,
+        types::{
+            CacheChange, EntityId, Guid, GuidPrefix, Locator, ProtocolVersion, ReliabilityKind,
+            VendorId, BUILT_IN_WRITER_WITH_KEY, LOCATOR_KIND_UDP_V4,
+        },
+    },
     wait_set::{Condition, WaitSet},
 };
+use std::{
+    future::Future,
+    net::Ipv4Addr,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
 
 mod utils;
 use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
@@ -23,6 +56,32 @@ use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
 #[derive(DdsType)]
 struct UserType(#[dust_dds(key)] i32);
 
+struct SerializedBuiltinTopicData<'a>(&'a [u8]);
+impl<'de> DdsDeserialize<'de> for SerializedBuiltinTopicData<'de> {
+    fn deserialize_data(
+        serialized_data: &'de [u8],
+    ) -> dust_dds::infrastructure::error::DdsResult<Self> {
+        Ok(Self(serialized_data))
+    }
+}
+
+impl SerializedBuiltinTopicData<'_> {
+    fn group_entity_id(&self) -> Option<[u8; 4]> {
+        const PID_GROUP_ENTITYID: u16 = 0x0053;
+        const PID_SENTINEL: u16 = 0x0001;
+        let mut parameters = &self.0[4..];
+        loop {
+            let pid = u16::from_le_bytes([parameters[0], parameters[1]]);
+            let length = u16::from_le_bytes([parameters[2], parameters[3]]) as usize;
+            match pid {
+                PID_GROUP_ENTITYID => return Some(parameters[4..8].try_into().unwrap()),
+                PID_SENTINEL => return None,
+                _ => parameters = &parameters[4 + length..],
+            }
+        }
+    }
+}
+
 #[test]
 fn writer_discovers_reader_in_same_participant() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -63,6 +122,278 @@ fn writer_discovers_reader_in_same_participant() {
     assert_eq!(data_writer.get_matched_subscriptions().unwrap().len(), 1);
 }
 
+#[test]
+fn own_endpoints_are_matched_once() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+    // Give the participant time to receive its own periodic announcement again
+    std::thread::sleep(std::time::Duration::from_secs(6));
+
+    let publication_matched_status = data_writer.get_publication_matched_status().unwrap();
+    let subscription_matched_status = data_reader.get_subscription_matched_status().unwrap();
+    assert_eq!(publication_matched_status.total_count, 1);
+    assert_eq!(publication_matched_status.current_count, 1);
+    assert_eq!(subscription_matched_status.total_count, 1);
+    assert_eq!(subscription_matched_status.current_count, 1);
+}
+
+#[test]
+fn discovered_endpoints_carry_their_group_entity_id() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_cond = data_writer.get_statuscondition();
+    writer_cond
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let reader_cond = data_reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(writer_cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let builtin_subscriber = dp.get_builtin_subscriber();
+    let publication_samples = builtin_subscriber
+        .lookup_datareader::<SerializedBuiltinTopicData>("DCPSPublication")
+        .unwrap()
+        .unwrap()
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let subscription_samples = builtin_subscriber
+        .lookup_datareader::<SerializedBuiltinTopicData>("DCPSSubscription")
+        .unwrap()
+        .unwrap()
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    let publisher_entity_id = <[u8; 16]>::from(publisher.get_instance_handle())[12..16]
+        .try_into()
+        .unwrap();
+    let subscriber_entity_id = <[u8; 16]>::from(subscriber.get_instance_handle())[12..16]
+        .try_into()
+        .unwrap();
+    assert_ne!(publisher_entity_id, [0; 4]);
+    assert_ne!(subscriber_entity_id, [0; 4]);
+    assert_eq!(
+        publication_samples[0].data().unwrap().group_entity_id(),
+        Some(publisher_entity_id)
+    );
+    assert_eq!(
+        subscription_samples[0].data().unwrap().group_entity_id(),
+        Some(subscriber_entity_id)
+    );
+}
+
+#[test]
+fn writer_matches_at_most_max_matched_readers_per_writer() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .build()
+        .unwrap();
+    let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime, [1, 2, 3, 4], [5, 6, 7, 8], transport),
+    ));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+    domain_participant_factory
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .max_matched_readers_per_writer(2)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let dp = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let _data_reader_list: Vec<_> = (0..3)
+        .map(|_| {
+            subscriber
+                .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+                .unwrap()
+        })
+        .collect();
+
+    let start_time = Instant::now();
+    while data_writer.get_matched_subscriptions().unwrap().len() < 2 {
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Readers not matched before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    // Give the third reader time to be discovered
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    assert_eq!(data_writer.get_matched_subscriptions().unwrap().len(), 2);
+    assert_eq!(
+        data_writer
+            .get_publication_matched_status()
+            .unwrap()
+            .current_count,
+        2
+    );
+}
+
+#[test]
+fn match_diagnostic_pinpoints_reliability_mismatch() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<UserType>(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<UserType>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::OfferedIncompatibleQos])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let expected_diagnostic = MatchDiagnostic {
+        is_topic_name_matched: true,
+        is_type_name_matched: true,
+        is_partition_matched: true,
+        is_ignored: false,
+        incompatible_qos_policy_list: vec![RELIABILITY_QOS_POLICY_ID],
+    };
+    let subscription_diagnostic = data_writer
+        .get_subscription_match_diagnostic(data_reader.get_instance_handle())
+        .unwrap();
+    assert_eq!(subscription_diagnostic, expected_diagnostic);
+    assert!(!subscription_diagnostic.is_matched());
+    assert_eq!(
+        data_reader.get_publication_match_diagnostic(data_writer.get_instance_handle()),
+        Ok(expected_diagnostic)
+    );
+}
+
 #[test]
 fn deleted_readers_are_disposed_from_writer() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -847,6 +1178,115 @@ fn discovered_participant_removed_after_deletion() {
     assert_eq!(participant1.get_discovered_participants().unwrap().len(), 1)
 }
 
+#[test]
+fn remote_endpoints_unmatched_on_participant_deletion() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic1 = participant1
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher1 = participant1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    publisher1
+        .create_datawriter::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber1 = participant1
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    subscriber1
+        .create_datareader::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let participant2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic2 = participant2
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher2 = participant2
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer2 = publisher2
+        .create_datawriter::<UserType>(&topic2, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber2 = participant2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader2 = subscriber2
+        .create_datareader::<UserType>(&topic2, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // Each endpoint matches the one in its own participant and the one in the other participant
+    let start_time = Instant::now();
+    loop {
+        let writer_matched = data_writer2
+            .get_publication_matched_status()
+            .unwrap()
+            .current_count;
+        let reader_matched = data_reader2
+            .get_subscription_matched_status()
+            .unwrap()
+            .current_count;
+        if writer_matched == 2 && reader_matched == 2 {
+            break;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Endpoints not matched before timeout")
+        }
+    }
+
+    participant1.delete_contained_entities().unwrap();
+    domain_participant_factory
+        .delete_participant(&participant1)
+        .unwrap();
+
+    // Well below the participant lease duration so the endpoints must be removed
+    // because of the deletion announcement
+    let start_time = Instant::now();
+    loop {
+        let writer_matched = data_writer2
+            .get_publication_matched_status()
+            .unwrap()
+            .current_count;
+        let reader_matched = data_reader2
+            .get_subscription_matched_status()
+            .unwrap()
+            .current_count;
+        if writer_matched == 1 && reader_matched == 1 {
+            break;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(2) {
+            panic!("Endpoints not unmatched after participant deletion")
+        }
+    }
+    assert!(!participant2
+        .get_discovered_participants()
+        .unwrap()
+        .contains(&participant1.get_instance_handle()));
+    // The deleted participant no longer processes any request
+    assert_eq!(
+        participant1.get_discovered_participants(),
+        Err(DdsError::AlreadyDeleted)
+    );
+}
+
 #[test]
 fn writer_offering_xcdr1_should_not_match_reader_requesting_xcdr2() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -1092,7 +1532,230 @@ fn reader_requesting_exclusive_ownership_should_not_match_writer_offering_shared
 }
 
 #[test]
-#[ignore]
+fn unicast_only_participants_discover_each_other_through_initial_peers() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    // Well-known metatraffic unicast ports of the first two participants of the domain
+    let initial_peers = (0..2)
+        .map(|participant_id| {
+            Locator::new(
+                LOCATOR_KIND_UDP_V4,
+                (7400 + 250 * domain_id + 10 + 2 * participant_id) as u32,
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 1],
+            )
+        })
+        .collect();
+    let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .unicast_only(true)
+        .initial_peers(initial_peers)
+        .build()
+        .unwrap();
+    let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime, [1, 2, 3, 4], [5, 6, 7, 8], transport),
+    ));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let start_time = Instant::now();
+    loop {
+        let participant1_discovered = participant1
+            .get_discovered_participants()
+            .unwrap()
+            .contains(&participant2.get_instance_handle());
+        let participant2_discovered = participant2
+            .get_discovered_participants()
+            .unwrap()
+            .contains(&participant1.get_instance_handle());
+        if participant1_discovered && participant2_discovered {
+            break;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Participant not discovered before timeout")
+        }
+    }
+}
+
+#[test]
+fn unicast_only_participant_is_discovered_by_participant_not_in_its_initial_peers() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    // The first participant has no initial peers and binds the well-known port of participant id 0
+    let transport1 = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .interface_address_list(vec![Ipv4Addr::LOCALHOST])
+        .unicast_only(true)
+        .build()
+        .unwrap();
+    let runtime1 = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async1: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime1, [1, 2, 3, 4], [5, 6, 7, 8], transport1),
+    ));
+    let participant1 = DomainParticipantFactory::new(domain_participant_factory_async1)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let transport2 = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .interface_address_list(vec![Ipv4Addr::LOCALHOST])
+        .unicast_only(true)
+        .initial_peers(vec![Locator::new(
+            LOCATOR_KIND_UDP_V4,
+            (7400 + 250 * domain_id + 10) as u32,
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 1],
+        )])
+        .build()
+        .unwrap();
+    let runtime2 = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async2: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime2, [1, 2, 3, 5], [5, 6, 7, 9], transport2),
+    ));
+    let participant2 = DomainParticipantFactory::new(domain_participant_factory_async2)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let start_time = Instant::now();
+    loop {
+        let participant1_discovered = participant2
+            .get_discovered_participants()
+            .unwrap()
+            .contains(&participant1.get_instance_handle());
+        let participant2_discovered = participant1
+            .get_discovered_participants()
+            .unwrap()
+            .contains(&participant2.get_instance_handle());
+        if participant1_discovered && participant2_discovered {
+            break;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Participant not discovered before timeout")
+        }
+    }
+}
+
+#[test]
+fn announce_sends_participant_announcement_immediately() {
+    const ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER: EntityId =
+        EntityId::new([0x00, 0x01, 0x00], BUILT_IN_WRITER_WITH_KEY);
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let mock_peer_socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let mock_peer_locator = Locator::new(
+        LOCATOR_KIND_UDP_V4,
+        mock_peer_socket.local_addr().unwrap().port() as u32,
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 1],
+    );
+    let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .unicast_only(true)
+        .initial_peers(vec![mock_peer_locator])
+        .build()
+        .unwrap();
+    let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime, [1, 2, 3, 4], [5, 6, 7, 8], transport),
+    ));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+    domain_participant_factory
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .participant_announcement_interval(std::time::Duration::from_secs(3600))
+                .participant_lease_duration(std::time::Duration::from_secs(7200))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // Drain the announcements sent when the participant got enabled
+    let mut buffer = [0; 65000];
+    mock_peer_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(500)))
+        .unwrap();
+    while mock_peer_socket.recv(&mut buffer).is_ok() {}
+
+    participant.announce().unwrap();
+
+    mock_peer_socket.set_nonblocking(true).unwrap();
+    let received_length = mock_peer_socket
+        .recv(&mut buffer)
+        .expect("SPDP message sent within announce()");
+    let message = RtpsMessageRead::try_from(&buffer[0..received_length]).unwrap();
+    let participant_guid_prefix = &<[u8; 16]>::from(participant.get_instance_handle())[0..12];
+    assert_eq!(&message.header().guid_prefix()[..], participant_guid_prefix);
+    assert!(message.submessages().iter().any(|submessage| matches!(
+        submessage,
+        RtpsSubmessageReadKind::Data(data_submessage)
+            if data_submessage.writer_id() == ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER
+    )));
+}
+
+#[test]
+fn participant_is_announced_at_the_configured_interval() {
+    const ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER: EntityId =
+        EntityId::new([0x00, 0x01, 0x00], BUILT_IN_WRITER_WITH_KEY);
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let mock_peer_socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let mock_peer_locator = Locator::new(
+        LOCATOR_KIND_UDP_V4,
+        mock_peer_socket.local_addr().unwrap().port() as u32,
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 1],
+    );
+    let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .unicast_only(true)
+        .initial_peers(vec![mock_peer_locator])
+        .build()
+        .unwrap();
+    let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime, [1, 2, 3, 4], [5, 6, 7, 8], transport),
+    ));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+    domain_participant_factory
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .participant_announcement_interval(std::time::Duration::from_millis(100))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let _participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // The default interval of 5 seconds would send a single announcement in this time
+    let mut buffer = [0; 65000];
+    let mut announcement_count = 0;
+    let start_time = Instant::now();
+    mock_peer_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(100)))
+        .unwrap();
+    while start_time.elapsed() < std::time::Duration::from_secs(1) {
+        if let Ok(received_length) = mock_peer_socket.recv(&mut buffer) {
+            let message = RtpsMessageRead::try_from(&buffer[0..received_length]).unwrap();
+            if message.submessages().iter().any(|submessage| {
+                matches!(
+                    submessage,
+                    RtpsSubmessageReadKind::Data(data_submessage)
+                        if data_submessage.writer_id() == ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER
+                )
+            }) {
+                announcement_count += 1;
+            }
+        }
+    }
+    assert!(announcement_count >= 5);
+}
+
+#[test]
+#[ignore]
 fn participant_removed_after_lease_duration() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
     let domain_participant_factory = DomainParticipantFactory::get_instance();
@@ -1117,3 +1780,955 @@ fn participant_removed_after_lease_duration() {
 
     assert_eq!(discovered_participant.len(), 1);
 }
+
+struct TopicRecordingTransportParticipantFactory {
+    transport: RtpsMemoryTransportParticipantFactory,
+    topic_name_list: Arc<Mutex<Vec<String>>>,
+}
+
+impl TransportParticipantFactory for TopicRecordingTransportParticipantFactory {
+    type TransportParticipant = TopicRecordingTransportParticipant;
+
+    async fn create_participant(
+        &self,
+        guid_prefix: GuidPrefix,
+        domain_id: i32,
+    ) -> Result<Self::TransportParticipant, TransportParticipantError> {
+        Ok(TopicRecordingTransportParticipant {
+            participant: self
+                .transport
+                .create_participant(guid_prefix, domain_id)
+                .await?,
+            topic_name_list: self.topic_name_list.clone(),
+        })
+    }
+}
+
+struct TopicRecordingTransportParticipant {
+    participant: RtpsMemoryTransportParticipant,
+    topic_name_list: Arc<Mutex<Vec<String>>>,
+}
+
+impl TransportParticipant for TopicRecordingTransportParticipant {
+    type StatelessReader =
+        <RtpsMemoryTransportParticipant as TransportParticipant>::StatelessReader;
+    type StatefulReader = <RtpsMemoryTransportParticipant as TransportParticipant>::StatefulReader;
+    type StatelessWriter =
+        <RtpsMemoryTransportParticipant as TransportParticipant>::StatelessWriter;
+    type StatefulWriter = <RtpsMemoryTransportParticipant as TransportParticipant>::StatefulWriter;
+
+    fn guid(&self) -> Guid {
+        self.participant.guid()
+    }
+    fn protocol_version(&self) -> ProtocolVersion {
+        self.participant.protocol_version()
+    }
+    fn vendor_id(&self) -> VendorId {
+        self.participant.vendor_id()
+    }
+    fn metatraffic_unicast_locator_list(&self) -> &[Locator] {
+        self.participant.metatraffic_unicast_locator_list()
+    }
+    fn metatraffic_multicast_locator_list(&self) -> &[Locator] {
+        self.participant.metatraffic_multicast_locator_list()
+    }
+    fn default_unicast_locator_list(&self) -> &[Locator] {
+        self.participant.default_unicast_locator_list()
+    }
+    fn default_multicast_locator_list(&self) -> &[Locator] {
+        self.participant.default_multicast_locator_list()
+    }
+    async fn create_stateless_reader(
+        &mut self,
+        entity_id: EntityId,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatelessReader {
+        self.participant
+            .create_stateless_reader(entity_id, reader_history_cache)
+            .await
+    }
+    async fn create_stateless_writer(&mut self, entity_id: EntityId) -> Self::StatelessWriter {
+        self.participant.create_stateless_writer(entity_id).await
+    }
+    async fn create_stateful_reader(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatefulReader {
+        self.topic_name_list
+            .lock()
+            .unwrap()
+            .push(topic_name.to_string());
+        self.participant
+            .create_stateful_reader(
+                entity_id,
+                topic_name,
+                reliability_kind,
+                reader_history_cache,
+            )
+            .await
+    }
+    async fn create_stateful_writer(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+    ) -> Self::StatefulWriter {
+        self.topic_name_list
+            .lock()
+            .unwrap()
+            .push(topic_name.to_string());
+        self.participant
+            .create_stateful_writer(entity_id, topic_name, reliability_kind)
+            .await
+    }
+}
+
+#[test]
+fn topic_configured_for_a_transport_sends_its_data_over_that_transport() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let routed_topic_name_list = Arc::new(Mutex::new(Vec::new()));
+    let topic_transport = TopicRecordingTransportParticipantFactory {
+        transport: RtpsMemoryTransportParticipantFactory::new(),
+        topic_name_list: routed_topic_name_list.clone(),
+    };
+    let transport = TopicRoutingTransportParticipantFactory::new(
+        RtpsUdpTransportParticipantFactory::default(),
+        topic_transport,
+        vec![String::from("RoutedTopic")],
+    );
+    let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime, [1, 2, 3, 4], [5, 6, 7, 8], transport),
+    ));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let routed_topic = participant
+        .create_topic::<UserType>(
+            "RoutedTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let other_topic = participant
+        .create_topic::<UserType>(
+            "OtherTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&routed_topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(
+            &routed_topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    publisher
+        .create_datawriter::<UserType>(&other_topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    subscriber
+        .create_datareader::<UserType>(&other_topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+    data_writer.write(UserType(7), None).unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    let samples = data_reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap().0, 7);
+    assert_eq!(
+        *routed_topic_name_list.lock().unwrap(),
+        vec![String::from("RoutedTopic"), String::from("RoutedTopic")]
+    );
+}
+
+#[test]
+fn participants_connected_by_memory_transport_exchange_data() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let (transport1, transport2) = RtpsMemoryTransportParticipantFactory::pair();
+    let domain_participant_factory_async1: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            transport1,
+        )));
+    let domain_participant_factory_async2: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [9, 10, 11, 12],
+            transport2,
+        )));
+    let participant1 = DomainParticipantFactory::new(domain_participant_factory_async1)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = DomainParticipantFactory::new(domain_participant_factory_async2)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic1 = participant1
+        .create_topic::<UserType>(
+            "MemoryTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let topic2 = participant2
+        .create_topic::<UserType>(
+            "MemoryTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<UserType>(
+            &topic2,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+    data_writer.write(UserType(3), None).unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    let samples = data_reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap().0, 3);
+}
+
+struct AnnouncementFilteringTransportParticipantFactory {
+    transport: RtpsMemoryTransportParticipantFactory,
+    ignore_announcements: Arc<AtomicBool>,
+}
+
+impl TransportParticipantFactory for AnnouncementFilteringTransportParticipantFactory {
+    type TransportParticipant = AnnouncementFilteringTransportParticipant;
+
+    async fn create_participant(
+        &self,
+        guid_prefix: GuidPrefix,
+        domain_id: i32,
+    ) -> Result<Self::TransportParticipant, TransportParticipantError> {
+        Ok(AnnouncementFilteringTransportParticipant {
+            participant: self
+                .transport
+                .create_participant(guid_prefix, domain_id)
+                .await?,
+            ignore_announcements: self.ignore_announcements.clone(),
+        })
+    }
+}
+
+struct AnnouncementFilteringTransportParticipant {
+    participant: RtpsMemoryTransportParticipant,
+    ignore_announcements: Arc<AtomicBool>,
+}
+
+impl TransportParticipant for AnnouncementFilteringTransportParticipant {
+    type StatelessReader =
+        <RtpsMemoryTransportParticipant as TransportParticipant>::StatelessReader;
+    type StatefulReader = <RtpsMemoryTransportParticipant as TransportParticipant>::StatefulReader;
+    type StatelessWriter =
+        <RtpsMemoryTransportParticipant as TransportParticipant>::StatelessWriter;
+    type StatefulWriter = <RtpsMemoryTransportParticipant as TransportParticipant>::StatefulWriter;
+
+    fn guid(&self) -> Guid {
+        self.participant.guid()
+    }
+    fn protocol_version(&self) -> ProtocolVersion {
+        self.participant.protocol_version()
+    }
+    fn vendor_id(&self) -> VendorId {
+        self.participant.vendor_id()
+    }
+    fn metatraffic_unicast_locator_list(&self) -> &[Locator] {
+        self.participant.metatraffic_unicast_locator_list()
+    }
+    fn metatraffic_multicast_locator_list(&self) -> &[Locator] {
+        self.participant.metatraffic_multicast_locator_list()
+    }
+    fn default_unicast_locator_list(&self) -> &[Locator] {
+        self.participant.default_unicast_locator_list()
+    }
+    fn default_multicast_locator_list(&self) -> &[Locator] {
+        self.participant.default_multicast_locator_list()
+    }
+    // The participant announcements are the only data received by a stateless reader
+    async fn create_stateless_reader(
+        &mut self,
+        entity_id: EntityId,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatelessReader {
+        let reader_history_cache = Box::new(AnnouncementFilteringHistoryCache {
+            history_cache: reader_history_cache,
+            ignore_announcements: self.ignore_announcements.clone(),
+        });
+        self.participant
+            .create_stateless_reader(entity_id, reader_history_cache)
+            .await
+    }
+    async fn create_stateless_writer(&mut self, entity_id: EntityId) -> Self::StatelessWriter {
+        self.participant.create_stateless_writer(entity_id).await
+    }
+    async fn create_stateful_reader(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+        reader_history_cache: Box<dyn HistoryCache>,
+    ) -> Self::StatefulReader {
+        self.participant
+            .create_stateful_reader(
+                entity_id,
+                topic_name,
+                reliability_kind,
+                reader_history_cache,
+            )
+            .await
+    }
+    async fn create_stateful_writer(
+        &mut self,
+        entity_id: EntityId,
+        topic_name: &str,
+        reliability_kind: ReliabilityKind,
+    ) -> Self::StatefulWriter {
+        self.participant
+            .create_stateful_writer(entity_id, topic_name, reliability_kind)
+            .await
+    }
+}
+
+struct AnnouncementFilteringHistoryCache {
+    history_cache: Box<dyn HistoryCache>,
+    ignore_announcements: Arc<AtomicBool>,
+}
+
+impl HistoryCache for AnnouncementFilteringHistoryCache {
+    fn add_change(
+        &mut self,
+        cache_change: CacheChange,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        if self.ignore_announcements.load(Ordering::Relaxed) {
+            Box::pin(async {})
+        } else {
+            self.history_cache.add_change(cache_change)
+        }
+    }
+
+    fn remove_change(&mut self, sequence_number: i64) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        self.history_cache.remove_change(sequence_number)
+    }
+}
+
+#[test]
+fn participant_is_removed_after_its_lease_expires() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let (transport1, transport2) = RtpsMemoryTransportParticipantFactory::pair();
+    // The first participant stops receiving the announcements of the second one on request,
+    // as if the second participant had silently left the network
+    let ignore_announcements = Arc::new(AtomicBool::new(false));
+    let transport1 = AnnouncementFilteringTransportParticipantFactory {
+        transport: transport1,
+        ignore_announcements: ignore_announcements.clone(),
+    };
+    let domain_participant_factory_async1: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            transport1,
+        )));
+    let domain_participant_factory_async2: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [9, 10, 11, 12],
+            transport2,
+        )));
+    let domain_participant_factory1 =
+        DomainParticipantFactory::new(domain_participant_factory_async1);
+    domain_participant_factory1
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .participant_announcement_interval(std::time::Duration::from_millis(50))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    let domain_participant_factory2 =
+        DomainParticipantFactory::new(domain_participant_factory_async2);
+    domain_participant_factory2
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .participant_announcement_interval(std::time::Duration::from_millis(100))
+                .participant_lease_duration(std::time::Duration::from_millis(500))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    let participant1 = domain_participant_factory1
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = domain_participant_factory2
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic1 = participant1
+        .create_topic::<UserType>(
+            "LeaseTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let topic2 = participant2
+        .create_topic::<UserType>(
+            "LeaseTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = participant1
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let publisher = participant2
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let _data_writer = publisher
+        .create_datawriter::<UserType>(&topic2, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+    assert!(participant1
+        .get_discovered_participants()
+        .unwrap()
+        .contains(&participant2.get_instance_handle()));
+    assert_eq!(data_reader.get_matched_publications().unwrap().len(), 1);
+
+    ignore_announcements.store(true, Ordering::Relaxed);
+    let start_time = Instant::now();
+    while participant1
+        .get_discovered_participants()
+        .unwrap()
+        .contains(&participant2.get_instance_handle())
+    {
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Participant not removed before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(data_reader.get_matched_publications().unwrap().len(), 0);
+}
+#[test]
+fn write_only_participant_creates_fewer_builtin_endpoints_and_still_publishes() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let (transport1, transport2) = RtpsMemoryTransportParticipantFactory::pair();
+    let domain_participant_factory_async1: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            transport1,
+        )));
+    let domain_participant_factory_async2: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [9, 10, 11, 12],
+            transport2,
+        )));
+    let domain_participant_factory1 =
+        DomainParticipantFactory::new(domain_participant_factory_async1);
+    domain_participant_factory1
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .builtin_endpoints(BuiltinEndpointSet::new(
+                    BuiltinEndpointSet::BUILTIN_ENDPOINT_PUBLICATIONS_ANNOUNCER
+                        | BuiltinEndpointSet::BUILTIN_ENDPOINT_SUBSCRIPTIONS_DETECTOR,
+                ))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    let participant1 = domain_participant_factory1
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = DomainParticipantFactory::new(domain_participant_factory_async2)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant1.get_builtin_subscriber();
+    assert!(builtin_subscriber
+        .lookup_datareader::<SerializedBuiltinTopicData>("DCPSParticipant")
+        .unwrap()
+        .is_some());
+    assert!(builtin_subscriber
+        .lookup_datareader::<SerializedBuiltinTopicData>("DCPSSubscription")
+        .unwrap()
+        .is_some());
+    assert!(builtin_subscriber
+        .lookup_datareader::<SerializedBuiltinTopicData>("DCPSPublication")
+        .unwrap()
+        .is_none());
+    assert!(builtin_subscriber
+        .lookup_datareader::<SerializedBuiltinTopicData>("DCPSTopic")
+        .unwrap()
+        .is_none());
+
+    let topic1 = participant1
+        .create_topic::<UserType>(
+            "WriteOnlyTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let topic2 = participant2
+        .create_topic::<UserType>(
+            "WriteOnlyTopic",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<UserType>(
+            &topic2,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+    data_writer.write(UserType(4), None).unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    let samples = data_reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap().0, 4);
+}
+
+#[test]
+fn participant_without_usable_network_address_is_not_created() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .interface_name(Some(String::from("not an interface")))
+        .build()
+        .unwrap();
+    let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime, [1, 2, 3, 4], [5, 6, 7, 8], transport),
+    ));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+
+    let result = domain_participant_factory.create_participant(
+        domain_id,
+        QosKind::Default,
+        NO_LISTENER,
+        NO_STATUS,
+    );
+
+    assert!(matches!(result, Err(DdsError::PreconditionNotMet(_))));
+}
+
+#[test]
+fn publisher_and_subscriber_intersecting_partitions_are_matched() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher_qos = PublisherQos {
+        partition: PartitionQosPolicy {
+            name: vec!["A".to_string(), "B".to_string()],
+        },
+        ..Default::default()
+    };
+    let publisher = dp
+        .create_publisher(QosKind::Specific(publisher_qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond_data_writer = data_writer.get_statuscondition();
+    cond_data_writer
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let subscriber_qos = SubscriberQos {
+        partition: PartitionQosPolicy {
+            name: vec!["B".to_string(), "C".to_string()],
+        },
+        ..Default::default()
+    };
+    let subscriber = dp
+        .create_subscriber(QosKind::Specific(subscriber_qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond_data_reader = data_reader.get_statuscondition();
+    cond_data_reader
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set_data_reader = WaitSet::new();
+    wait_set_data_reader
+        .attach_condition(Condition::StatusCondition(cond_data_reader))
+        .unwrap();
+
+    let mut wait_set_data_writer = WaitSet::new();
+    wait_set_data_writer
+        .attach_condition(Condition::StatusCondition(cond_data_writer))
+        .unwrap();
+
+    assert!(wait_set_data_reader.wait(Duration::new(10, 0)).is_ok());
+    assert!(wait_set_data_writer.wait(Duration::new(10, 0)).is_ok());
+}
+
+#[test]
+fn publisher_single_character_wildcard_partition_is_matched() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher_qos = PublisherQos {
+        partition: PartitionQosPolicy {
+            name: vec!["A?C".to_string()],
+        },
+        ..Default::default()
+    };
+    let publisher = dp
+        .create_publisher(QosKind::Specific(publisher_qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond_data_writer = data_writer.get_statuscondition();
+    cond_data_writer
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let subscriber_qos = SubscriberQos {
+        partition: PartitionQosPolicy {
+            name: vec!["ABC".to_string()],
+        },
+        ..Default::default()
+    };
+    let subscriber = dp
+        .create_subscriber(QosKind::Specific(subscriber_qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond_data_reader = data_reader.get_statuscondition();
+    cond_data_reader
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set_data_reader = WaitSet::new();
+    wait_set_data_reader
+        .attach_condition(Condition::StatusCondition(cond_data_reader))
+        .unwrap();
+
+    let mut wait_set_data_writer = WaitSet::new();
+    wait_set_data_writer
+        .attach_condition(Condition::StatusCondition(cond_data_writer))
+        .unwrap();
+
+    assert!(wait_set_data_reader.wait(Duration::new(10, 0)).is_ok());
+    assert!(wait_set_data_writer.wait(Duration::new(10, 0)).is_ok());
+}
+
+#[test]
+fn default_partition_is_matched_with_empty_partition_name() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond_data_writer = data_writer.get_statuscondition();
+    cond_data_writer
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let subscriber_qos = SubscriberQos {
+        partition: PartitionQosPolicy {
+            name: vec!["".to_string()],
+        },
+        ..Default::default()
+    };
+    let subscriber = dp
+        .create_subscriber(QosKind::Specific(subscriber_qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond_data_reader = data_reader.get_statuscondition();
+    cond_data_reader
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set_data_reader = WaitSet::new();
+    wait_set_data_reader
+        .attach_condition(Condition::StatusCondition(cond_data_reader))
+        .unwrap();
+
+    let mut wait_set_data_writer = WaitSet::new();
+    wait_set_data_writer
+        .attach_condition(Condition::StatusCondition(cond_data_writer))
+        .unwrap();
+
+    assert!(wait_set_data_reader.wait(Duration::new(10, 0)).is_ok());
+    assert!(wait_set_data_writer.wait(Duration::new(10, 0)).is_ok());
+}
+
+#[test]
+fn ignored_participant_endpoints_are_not_matched() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+    let reader_participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let ignored_participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let other_participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    reader_participant
+        .ignore_participant(ignored_participant.get_instance_handle())
+        .unwrap();
+
+    let topic = reader_participant
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = reader_participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let mut writer_list = Vec::new();
+    for participant in [&ignored_participant, &other_participant] {
+        let topic = participant
+            .create_topic::<UserType>(
+                "topic_name",
+                "UserType",
+                QosKind::Default,
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let publisher = participant
+            .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap();
+        let data_writer = publisher
+            .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap();
+        writer_list.push(data_writer);
+    }
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    assert_eq!(
+        data_reader.get_matched_publications().unwrap(),
+        vec![writer_list[1].get_instance_handle()]
+    );
+}
+
+#[test]
+fn malformed_datagram_is_counted_in_participant_statistics() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .unicast_only(true)
+        .build()
+        .unwrap();
+    let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+    let domain_participant_factory_async: &'static _ = Box::leak(Box::new(
+        DomainParticipantFactoryAsync::new(runtime, [1, 2, 3, 4], [5, 6, 7, 8], transport),
+    ));
+    let participant = DomainParticipantFactory::new(domain_participant_factory_async)
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    assert_eq!(participant.get_statistics().unwrap().malformed_packets, 0);
+
+    // The only participant of the domain uses the well-known metatraffic unicast port of participant id 0
+    let metatraffic_unicast_port = (7400 + 250 * domain_id + 10) as u16;
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .send_to(
+            b"not an rtps message",
+            ("127.0.0.1", metatraffic_unicast_port),
+        )
+        .unwrap();
+
+    let start_time = Instant::now();
+    while participant.get_statistics().unwrap().malformed_packets == 0 {
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Malformed datagram not counted before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(participant.get_statistics().unwrap().cache_full_drops, 0);
+}
@@ -955,3 +955,52 @@ fn create_delete_content_filtered_topic() {
         Err(DdsError::AlreadyDeleted)
     );
 }
+
+#[test]
+fn registered_type_key_is_discoverable_by_type_name() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    assert!(participant
+        .lookup_registered_type("MyData")
+        .unwrap()
+        .is_none());
+
+    let _keyed_topic = participant
+        .create_topic::<MyData>(
+            "KeyedTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let _no_key_topic = participant
+        .create_topic::<TestType>(
+            "NoKeyTopic",
+            "TestType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let has_key = |type_name: &str| {
+        let registered_type = participant
+            .lookup_registered_type(type_name)
+            .unwrap()
+            .unwrap();
+        (0..registered_type.get_member_count()).any(|index| {
+            registered_type
+                .get_member_by_index(index)
+                .unwrap()
+                .get_descriptor()
+                .unwrap()
+                .is_key
+        })
+    };
+    assert!(has_key("MyData"));
+    assert!(!has_key("TestType"));
+}
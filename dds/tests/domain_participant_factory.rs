@@ -1,12 +1,19 @@
 use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    dds_async::domain_participant_factory::DomainParticipantFactoryAsync,
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
+        instance::InstanceHandle,
         qos::{DomainParticipantQos, QosKind},
         qos_policy::UserDataQosPolicy,
         status::NO_STATUS,
         type_support::DdsType,
     },
     listener::NO_LISTENER,
+    rtps_memory_transport::memory_transport::RtpsMemoryTransportParticipantFactory,
+    rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactoryBuilder,
+    std_runtime::{executor::Executor, timer::TimerDriver, StdRuntime},
 };
 
 mod utils;
@@ -33,8 +40,14 @@ fn default_participant_qos() {
     };
 
     domain_participant_factory
-        .set_default_participant_qos(QosKind::Specific(qos))
+        .set_default_participant_qos(QosKind::Specific(qos.clone()))
         .unwrap();
+    assert_eq!(
+        domain_participant_factory
+            .get_default_participant_qos()
+            .unwrap(),
+        qos
+    );
 
     let participant = domain_participant_factory
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
@@ -45,6 +58,12 @@ fn default_participant_qos() {
         .unwrap();
 
     assert_eq!(participant.get_qos().unwrap().user_data.value, user_data);
+    assert_eq!(
+        domain_participant_factory
+            .get_default_participant_qos()
+            .unwrap(),
+        DomainParticipantQos::default()
+    );
 }
 
 #[test]
@@ -130,3 +149,132 @@ fn allowed_to_delete_participant_after_delete_contained_entities() {
         .delete_participant(&participant)
         .is_ok());
 }
+
+#[test]
+fn lookup_participant_returns_participant_of_requested_domain() {
+    let first_domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let second_domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let empty_domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let first_participant = domain_participant_factory
+        .create_participant(first_domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let second_participant = domain_participant_factory
+        .create_participant(second_domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let found_first_participant = domain_participant_factory
+        .lookup_participant(first_domain_id)
+        .unwrap()
+        .unwrap();
+    let found_second_participant = domain_participant_factory
+        .lookup_participant(second_domain_id)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(found_first_participant.get_domain_id(), first_domain_id);
+    assert_eq!(
+        found_first_participant.get_instance_handle(),
+        first_participant.get_instance_handle()
+    );
+    assert_eq!(found_second_participant.get_domain_id(), second_domain_id);
+    assert_eq!(
+        found_second_participant.get_instance_handle(),
+        second_participant.get_instance_handle()
+    );
+    assert!(domain_participant_factory
+        .lookup_participant(empty_domain_id)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn participant_uses_configured_guid_prefix() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let guid_prefix = [10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21];
+    let transport = RtpsMemoryTransportParticipantFactory::new();
+    let domain_participant_factory_async: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            transport,
+        )));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+    domain_participant_factory
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .guid_prefix(guid_prefix)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(
+        participant.get_instance_handle(),
+        InstanceHandle::new([10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 0, 0, 1, 0xc1])
+    );
+    assert!(matches!(
+        domain_participant_factory.create_participant(
+            domain_id,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS
+        ),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+}
+
+#[test]
+fn participant_id_exhaustion_is_reported_as_out_of_resources() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    // Occupy the well-known metatraffic unicast ports of every participant id of the domain
+    let _occupied_socket_list: Vec<_> = (0..=119)
+        .filter_map(|participant_id| {
+            let port = 7400 + 250 * domain_id + 10 + 2 * participant_id;
+            std::net::UdpSocket::bind(("0.0.0.0", port as u16)).ok()
+        })
+        .collect();
+    let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+        .unicast_only(true)
+        .build()
+        .unwrap();
+    let domain_participant_factory_async: &'static _ =
+        Box::leak(Box::new(DomainParticipantFactoryAsync::new(
+            StdRuntime::new(Executor::new(), TimerDriver::new()),
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            transport,
+        )));
+    let domain_participant_factory =
+        DomainParticipantFactory::new(domain_participant_factory_async);
+
+    assert_eq!(
+        domain_participant_factory
+            .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+            .err(),
+        Some(DdsError::OutOfResources)
+    );
+}
+
+#[test]
+fn configuration_with_lease_not_larger_than_announcement_interval_is_rejected() {
+    assert_eq!(
+        DustDdsConfigurationBuilder::new()
+            .participant_announcement_interval(std::time::Duration::from_secs(10))
+            .participant_lease_duration(std::time::Duration::from_secs(10))
+            .build()
+            .err(),
+        Some(DdsError::BadParameter)
+    );
+    assert!(DustDdsConfigurationBuilder::new()
+        .participant_announcement_interval(std::time::Duration::from_secs(10))
+        .participant_lease_duration(std::time::Duration::from_secs(11))
+        .build()
+        .is_ok());
+}
@@ -9,8 +9,10 @@ use dust_dds::{
     infrastructure::{
         qos::{DataReaderQos, DataWriterQos, QosKind},
         qos_policy::{
-            DeadlineQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind, Length,
+            DeadlineQosPolicy, DurabilityQosPolicy, DurabilityQosPolicyKind,
+            DurabilityServiceQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind, Length,
             ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+            DURABILITYSERVICE_QOS_POLICY_ID,
         },
         status::{
             OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
@@ -202,7 +204,6 @@ fn sample_rejected_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -225,7 +226,6 @@ fn sample_rejected_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         resource_limits: ResourceLimitsQosPolicy {
             max_samples: Length::Limited(2),
@@ -319,7 +319,6 @@ fn subscription_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -342,7 +341,6 @@ fn subscription_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -414,7 +412,6 @@ fn requested_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -437,7 +434,6 @@ fn requested_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -509,7 +505,6 @@ fn publication_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -534,7 +529,6 @@ fn publication_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -605,7 +599,6 @@ fn offered_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -630,7 +623,6 @@ fn offered_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1086,7 +1078,6 @@ fn data_reader_sample_rejected_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1109,7 +1100,6 @@ fn data_reader_sample_rejected_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         resource_limits: ResourceLimitsQosPolicy {
             max_samples: Length::Limited(2),
@@ -1198,7 +1188,6 @@ fn data_reader_subscription_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1221,7 +1210,6 @@ fn data_reader_subscription_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -1288,7 +1276,6 @@ fn data_reader_requested_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1311,7 +1298,6 @@ fn data_reader_requested_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -1336,6 +1322,96 @@ fn data_reader_requested_incompatible_qos_listener() {
     assert_eq!(status.total_count_change, 1);
 }
 
+#[test]
+fn data_reader_requested_incompatible_durability_service_listener() {
+    struct RequestedIncompatibleQosListener {
+        sender: std::sync::mpsc::SyncSender<RequestedIncompatibleQosStatus>,
+    }
+
+    impl<R: DdsRuntime> DataReaderListener<R, MyData> for RequestedIncompatibleQosListener {
+        async fn on_requested_incompatible_qos(
+            &mut self,
+            _the_reader: DataReaderAsync<R, MyData>,
+            status: RequestedIncompatibleQosStatus,
+        ) {
+            self.sender.send(status).ok();
+        }
+    }
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "DurabilityServiceIncompatibleTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Transient,
+        },
+        durability_service: DurabilityServiceQosPolicy {
+            history_kind: HistoryQosPolicyKind::KeepLast(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let _writer = publisher
+        .create_datawriter::<MyData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Transient,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(5),
+        },
+        ..Default::default()
+    };
+
+    let (sender, receiver) = std::sync::mpsc::sync_channel(5);
+    let reader_listener = RequestedIncompatibleQosListener { sender };
+
+    let _reader = subscriber
+        .create_datareader(
+            &topic,
+            QosKind::Specific(reader_qos),
+            Some(reader_listener),
+            &[StatusKind::RequestedIncompatibleQos],
+        )
+        .unwrap();
+
+    let status = receiver
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .unwrap();
+    assert_eq!(status.total_count, 1);
+    assert_eq!(status.last_policy_id, DURABILITYSERVICE_QOS_POLICY_ID);
+}
+
 #[test]
 fn publisher_publication_matched_listener() {
     struct PublicationMatchedListener {
@@ -1378,7 +1454,6 @@ fn publisher_publication_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -1409,7 +1484,6 @@ fn publisher_publication_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1472,7 +1546,6 @@ fn publisher_offered_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -1504,7 +1577,6 @@ fn publisher_offered_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1673,7 +1745,6 @@ fn subscriber_sample_rejected_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1703,7 +1774,6 @@ fn subscriber_sample_rejected_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         resource_limits: ResourceLimitsQosPolicy {
             max_samples: Length::Limited(2),
@@ -1788,7 +1858,6 @@ fn subscriber_subscription_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1818,7 +1887,6 @@ fn subscriber_subscription_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -1882,7 +1950,6 @@ fn subscriber_requested_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -1912,7 +1979,6 @@ fn subscriber_requested_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -1976,7 +2042,6 @@ fn data_writer_publication_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -2001,7 +2066,6 @@ fn data_writer_publication_matched_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -2067,7 +2131,6 @@ fn data_writer_offered_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
 
         ..Default::default()
@@ -2092,7 +2155,6 @@ fn data_writer_offered_incompatible_qos_listener() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
@@ -2495,3 +2557,157 @@ fn participant_offered_deadline_missed_listener() {
     assert_eq!(status.total_count, 1);
     assert_eq!(status.total_count_change, 1);
 }
+
+#[test]
+fn data_writer_get_listener_mask_reports_installed_listener_mask() {
+    struct NoOpListener;
+    impl<R: DdsRuntime> DataWriterListener<R, MyData> for NoOpListener {}
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(writer.get_listener_mask().unwrap(), None);
+    assert!(writer.get_listener().unwrap().is_none());
+
+    writer
+        .set_listener(Some(NoOpListener), &[StatusKind::PublicationMatched])
+        .unwrap();
+    assert_eq!(
+        writer.get_listener_mask().unwrap(),
+        Some(vec![StatusKind::PublicationMatched])
+    );
+    assert!(writer.get_listener().unwrap().is_some());
+
+    writer
+        .set_listener(None::<NoOpListener>, NO_STATUS)
+        .unwrap();
+    assert_eq!(writer.get_listener_mask().unwrap(), None);
+    assert!(writer.get_listener().unwrap().is_none());
+}
+
+#[test]
+fn notify_datareaders_calls_data_available_listener_of_readers_with_data() {
+    struct DataOnReadersListener {
+        sender: std::sync::mpsc::SyncSender<()>,
+    }
+
+    impl<R: DdsRuntime> SubscriberListener<R> for DataOnReadersListener {
+        async fn on_data_on_readers(&mut self, _the_subscriber: SubscriberAsync<R>) {
+            self.sender.send(()).unwrap();
+        }
+    }
+
+    struct DataAvailableListener {
+        id: u8,
+        sender: std::sync::mpsc::SyncSender<u8>,
+    }
+
+    impl<R: DdsRuntime> DataReaderListener<R, MyData> for DataAvailableListener {
+        async fn on_data_available(&mut self, _the_reader: DataReaderAsync<R, MyData>) {
+            self.sender.send(self.id).unwrap();
+        }
+    }
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let (data_on_readers_sender, data_on_readers_receiver) = std::sync::mpsc::sync_channel(5);
+    let subscriber = participant
+        .create_subscriber(
+            QosKind::Default,
+            Some(DataOnReadersListener {
+                sender: data_on_readers_sender,
+            }),
+            &[StatusKind::DataOnReaders],
+        )
+        .unwrap();
+    let (data_available_sender, data_available_receiver) = std::sync::mpsc::sync_channel(5);
+    for id in [1, 2] {
+        subscriber
+            .create_datareader::<MyData>(
+                &topic,
+                QosKind::Default,
+                Some(DataAvailableListener {
+                    id,
+                    sender: data_available_sender.clone(),
+                }),
+                &[StatusKind::DataAvailable],
+            )
+            .unwrap();
+    }
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    while writer.get_matched_subscriptions().unwrap().len() < 2 {
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+        writer.get_publication_matched_status().unwrap();
+    }
+
+    writer.write(MyData { id: 1, value: 1 }, None).unwrap();
+
+    // Both readers received the sample
+    for _ in 0..2 {
+        data_on_readers_receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .unwrap();
+    }
+    assert!(data_available_receiver.try_recv().is_err());
+
+    subscriber.notify_datareaders().unwrap();
+
+    let mut notified_reader_ids = [
+        data_available_receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .unwrap(),
+        data_available_receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .unwrap(),
+    ];
+    notified_reader_ids.sort();
+    assert_eq!(notified_reader_ids, [1, 2]);
+    assert!(data_available_receiver
+        .recv_timeout(std::time::Duration::from_millis(100))
+        .is_err());
+}
@@ -1,6 +1,7 @@
 use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
         qos::{DataWriterQos, QosKind},
         qos_policy::UserDataQosPolicy,
         status::NO_STATUS,
@@ -8,6 +9,7 @@ use dust_dds::{
     },
     listener::NO_LISTENER,
     topic_definition::topic_description::TopicDescription,
+    transport::types::{EntityId, USER_DEFINED_WRITER_NO_KEY, USER_DEFINED_WRITER_WITH_KEY},
 };
 
 mod utils;
@@ -168,3 +170,63 @@ fn data_writer_get_topic_should_return_same_topic_as_used_for_creation() {
         TopicDescription::ContentFilteredTopic(_) => unreachable!(),
     }
 }
+
+#[test]
+fn data_writer_created_with_entity_id_uses_it_in_its_guid() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<UserType>(
+            "data_writer_created_with_entity_id_uses_it_in_its_guid",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let entity_id = EntityId::new([0xab, 0xcd, 0xef], USER_DEFINED_WRITER_NO_KEY);
+
+    let writer = publisher
+        .create_datawriter_with_entity_id::<UserType>(
+            &topic,
+            entity_id,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let writer_handle: [u8; 16] = writer.get_instance_handle().into();
+    let participant_handle: [u8; 16] = participant.get_instance_handle().into();
+    assert_eq!(writer_handle[0..12], participant_handle[0..12]);
+    assert_eq!(
+        writer_handle[12..16],
+        [0xab, 0xcd, 0xef, USER_DEFINED_WRITER_NO_KEY]
+    );
+    assert!(matches!(
+        publisher.create_datawriter_with_entity_id::<UserType>(
+            &topic,
+            entity_id,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        ),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+    assert!(matches!(
+        publisher.create_datawriter_with_entity_id::<UserType>(
+            &topic,
+            EntityId::new([0xab, 0xcd, 0x00], USER_DEFINED_WRITER_WITH_KEY),
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        ),
+        Err(DdsError::BadParameter)
+    ));
+}
@@ -79,8 +79,8 @@ impl<'a> DynamicType<'a> {
         let mut pid = [0, 0];
         let mut length = [0, 0];
         loop {
-            reader.read(&mut pid).unwrap();
-            reader.read(&mut length).unwrap();
+            reader.read_exact(&mut pid).unwrap();
+            reader.read_exact(&mut length).unwrap();
             if i16::from_le_bytes(pid) == PID_METATRAFFIC_UNICAST_LOCATOR {
                 return u32::from_le_bytes([reader[4], reader[5], reader[6], reader[7]]);
             } else {
@@ -95,8 +95,8 @@ impl<'a> DynamicType<'a> {
         let mut pid = [0, 0];
         let mut length = [0, 0];
         loop {
-            reader.read(&mut pid).unwrap();
-            reader.read(&mut length).unwrap();
+            reader.read_exact(&mut pid).unwrap();
+            reader.read_exact(&mut length).unwrap();
             if i16::from_le_bytes(pid) == PID_DEFAULT_UNICAST_LOCATOR {
                 return u32::from_le_bytes([reader[4], reader[5], reader[6], reader[7]]);
             } else {
@@ -223,7 +223,11 @@ fn writer_should_send_heartbeat_periodically() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
@@ -265,6 +269,13 @@ fn writer_should_send_heartbeat_periodically() {
     mock_reader_socket
         .set_read_timeout(Some(std::time::Duration::from_secs(10)))
         .unwrap();
+    // A heartbeat is sent as soon as the reliable reader is matched
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    assert!(RtpsMessageRead::try_from(buffer.as_slice())
+        .unwrap()
+        .submessages()
+        .iter()
+        .any(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_))));
     mock_reader_socket.recv(&mut buffer).unwrap();
 
     let received_data_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
@@ -409,7 +420,11 @@ fn writer_should_not_send_heartbeat_after_acknack() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
@@ -451,6 +466,13 @@ fn writer_should_not_send_heartbeat_after_acknack() {
     mock_reader_socket
         .set_read_timeout(Some(std::time::Duration::from_secs(10)))
         .unwrap();
+    // A heartbeat is sent as soon as the reliable reader is matched
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    assert!(RtpsMessageRead::try_from(buffer.as_slice())
+        .unwrap()
+        .submessages()
+        .iter()
+        .any(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_))));
     mock_reader_socket.recv(&mut buffer).unwrap();
 
     let dcps_subscription_reader = builtin_subscriber
@@ -613,7 +635,11 @@ fn writer_should_resend_data_after_acknack_request() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
@@ -659,6 +685,13 @@ fn writer_should_resend_data_after_acknack_request() {
     mock_reader_socket
         .set_read_timeout(Some(std::time::Duration::from_secs(10)))
         .unwrap();
+    // A heartbeat is sent as soon as the reliable reader is matched
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    assert!(RtpsMessageRead::try_from(buffer.as_slice())
+        .unwrap()
+        .submessages()
+        .iter()
+        .any(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_))));
     mock_reader_socket.recv(&mut buffer).unwrap();
 
     let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
@@ -825,7 +858,11 @@ fn volatile_writer_should_send_gap_submessage_after_discovery() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
@@ -867,6 +904,13 @@ fn volatile_writer_should_send_gap_submessage_after_discovery() {
     mock_reader_socket
         .set_read_timeout(Some(std::time::Duration::from_secs(10)))
         .unwrap();
+    // A heartbeat is sent as soon as the reliable reader is matched
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    assert!(RtpsMessageRead::try_from(buffer.as_slice())
+        .unwrap()
+        .submessages()
+        .iter()
+        .any(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_))));
     mock_reader_socket.recv(&mut buffer).unwrap();
 
     let received_gap = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
@@ -1013,7 +1057,11 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
@@ -1055,6 +1103,13 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
     mock_reader_socket
         .set_read_timeout(Some(std::time::Duration::from_secs(10)))
         .unwrap();
+    // A heartbeat is sent as soon as the reliable reader is matched
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    assert!(RtpsMessageRead::try_from(buffer.as_slice())
+        .unwrap()
+        .submessages()
+        .iter()
+        .any(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_))));
     mock_reader_socket.recv(&mut buffer).unwrap();
 
     let received_data_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
@@ -1202,7 +1257,11 @@ fn reliable_writer_should_not_remove_unacked_sample_from_history() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
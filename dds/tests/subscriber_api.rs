@@ -1,8 +1,9 @@
 use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
-        qos::{DataReaderQos, QosKind},
-        qos_policy::UserDataQosPolicy,
+        error::DdsError,
+        qos::{DataReaderQos, QosKind, SubscriberQos},
+        qos_policy::{EntityFactoryQosPolicy, UserDataQosPolicy},
         status::NO_STATUS,
         type_support::DdsType,
     },
@@ -168,3 +169,40 @@ fn data_reader_get_topicdescription_should_return_same_topic_as_used_for_creatio
         TopicDescription::ContentFilteredTopic(_) => unreachable!(),
     }
 }
+
+#[test]
+fn not_enabled_data_reader_has_no_matched_publications() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<UserType>(
+            "not_enabled_data_reader",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber_qos = SubscriberQos {
+        entity_factory: EntityFactoryQosPolicy {
+            autoenable_created_entities: false,
+        },
+        ..Default::default()
+    };
+    let subscriber = participant
+        .create_subscriber(QosKind::Specific(subscriber_qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(reader.get_matched_publications(), Err(DdsError::NotEnabled));
+
+    reader.enable().unwrap();
+    assert_eq!(reader.get_matched_publications(), Ok(vec![]));
+}
@@ -1,14 +1,16 @@
 use dust_dds::{
+    dcps::xtypes_glue::key_and_instance_handle::key_hash_from_serialized_key,
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
         error::DdsError,
         instance::InstanceHandle,
-        qos::{DataReaderQos, DataWriterQos, QosKind, TopicQos},
+        qos::{DataReaderQos, DataWriterQos, PublisherQos, QosKind, SubscriberQos, TopicQos},
         qos_policy::{
             DeadlineQosPolicy, DestinationOrderQosPolicy, DestinationOrderQosPolicyKind,
             DurabilityQosPolicy, DurabilityQosPolicyKind, HistoryQosPolicy, HistoryQosPolicyKind,
             Length, LifespanQosPolicy, OwnershipQosPolicy, OwnershipQosPolicyKind,
-            OwnershipStrengthQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+            OwnershipStrengthQosPolicy, PresentationQosPolicy,
+            PresentationQosPolicyAccessScopeKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
             ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, WriterDataLifecycleQosPolicy,
         },
         sample_info::{
@@ -17,13 +19,15 @@ use dust_dds::{
         },
         status::{StatusKind, NO_STATUS},
         time::{Duration, DurationKind, Time},
-        type_support::DdsType,
+        type_support::{DdsDeserialize, DdsType},
     },
     listener::NO_LISTENER,
     topic_definition::topic_description::TopicDescription,
     wait_set::{Condition, WaitSet},
 };
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 mod utils;
 use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
 
@@ -37,6 +41,13 @@ struct KeyedData {
     value: u32,
 }
 
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct LongKeyData {
+    #[dust_dds(key)]
+    name: String,
+    value: u32,
+}
+
 #[derive(Clone, Debug, PartialEq, DdsType)]
 struct LargeData {
     #[dust_dds(key)]
@@ -457,7 +468,7 @@ fn writer_with_keep_last_3_should_send_last_3_samples_to_reader() {
 }
 
 #[test]
-fn samples_are_taken() {
+fn writer_with_keep_last_2_should_send_last_2_samples_and_last_disposal_to_reader() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -482,8 +493,15 @@ fn samples_are_taken() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(2),
+        },
         ..Default::default()
     };
+
     let writer = publisher
         .create_datawriter(
             &topic,
@@ -496,11 +514,28 @@ fn samples_are_taken() {
     let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
+
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 1, value: 2 };
+    let data3 = KeyedData { id: 1, value: 3 };
+
+    writer.write(data1, None).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+    writer.write(data3.clone(), None).unwrap();
+    writer.dispose(data3.clone(), None).unwrap();
+    writer.dispose(data3.clone(), None).unwrap();
+
     let reader_qos = DataReaderQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
     let reader = subscriber
@@ -512,8 +547,8 @@ fn samples_are_taken() {
         )
         .unwrap();
 
-    let cond = writer.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
         .unwrap();
 
     let mut wait_set = WaitSet::new();
@@ -522,42 +557,31 @@ fn samples_are_taken() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 10 };
-    let data3 = KeyedData { id: 3, value: 20 };
-    let data4 = KeyedData { id: 4, value: 30 };
-    let data5 = KeyedData { id: 5, value: 40 };
-
-    writer.write(data1.clone(), None).unwrap();
-    writer.write(data2.clone(), None).unwrap();
-    writer.write(data3.clone(), None).unwrap();
-    writer.write(data4.clone(), None).unwrap();
-    writer.write(data5.clone(), None).unwrap();
-
-    writer
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
-
-    let samples1 = reader
-        .take(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
         .unwrap();
 
-    let samples2 = reader
-        .take(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+    let samples = reader
+        .take(5, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
-    assert_eq!(samples1.len(), 3);
-    assert_eq!(samples1[0].data().unwrap(), data1);
-    assert_eq!(samples1[1].data().unwrap(), data2);
-    assert_eq!(samples1[2].data().unwrap(), data3);
-
-    assert_eq!(samples2.len(), 2);
-    assert_eq!(samples2[0].data().unwrap(), data4);
-    assert_eq!(samples2[1].data().unwrap(), data5);
+    assert_eq!(
+        samples.len(),
+        3,
+        "Received wrong number of samples. Received samples: {:?}",
+        samples
+    );
+    assert_eq!(samples[0].data().unwrap(), data2);
+    assert_eq!(samples[1].data().unwrap(), data3);
+    assert!(!samples[2].sample_info().valid_data);
+    assert_eq!(
+        samples[2].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
 }
 
 #[test]
-fn wait_for_samples_to_be_taken_best_effort() {
+fn samples_are_taken() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -579,7 +603,7 @@ fn wait_for_samples_to_be_taken_best_effort() {
         .unwrap();
     let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::BestEffort,
+            kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
@@ -598,7 +622,7 @@ fn wait_for_samples_to_be_taken_best_effort() {
         .unwrap();
     let reader_qos = DataReaderQos {
         reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::BestEffort,
+            kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
@@ -634,29 +658,30 @@ fn wait_for_samples_to_be_taken_best_effort() {
     writer.write(data4.clone(), None).unwrap();
     writer.write(data5.clone(), None).unwrap();
 
-    let start_time = std::time::Instant::now();
-    let mut samples = Vec::new();
-    while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if let Ok(mut sample) = reader.take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
-        {
-            samples.push(sample.remove(0))
-        }
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
 
-        if samples.len() >= 5 {
-            break;
-        }
-    }
+    let samples1 = reader
+        .take(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
 
-    assert_eq!(samples.len(), 5);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[1].data().unwrap(), data2);
-    assert_eq!(samples[2].data().unwrap(), data3);
-    assert_eq!(samples[3].data().unwrap(), data4);
-    assert_eq!(samples[4].data().unwrap(), data5);
+    let samples2 = reader
+        .take(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples1.len(), 3);
+    assert_eq!(samples1[0].data().unwrap(), data1);
+    assert_eq!(samples1[1].data().unwrap(), data2);
+    assert_eq!(samples1[2].data().unwrap(), data3);
+
+    assert_eq!(samples2.len(), 2);
+    assert_eq!(samples2[0].data().unwrap(), data4);
+    assert_eq!(samples2[1].data().unwrap(), data5);
 }
 
 #[test]
-fn read_only_unread_samples() {
+fn taken_samples_are_not_read_again() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -722,60 +747,28 @@ fn read_only_unread_samples() {
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
     let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 10 };
-    let data3 = KeyedData { id: 3, value: 20 };
-    let data4 = KeyedData { id: 4, value: 30 };
-    let data5 = KeyedData { id: 5, value: 40 };
-
+    let data2 = KeyedData { id: 2, value: 2 };
     writer.write(data1.clone(), None).unwrap();
     writer.write(data2.clone(), None).unwrap();
-    writer.write(data3.clone(), None).unwrap();
-    writer.write(data4.clone(), None).unwrap();
-    writer.write(data5.clone(), None).unwrap();
-
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples1 = reader
-        .read(
-            3,
-            &[SampleStateKind::NotRead],
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
+    let taken_samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
-    let samples2 = reader
-        .read(
-            3,
-            &[SampleStateKind::NotRead],
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
+    let read_samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
-    let samples3 = reader.read(
-        3,
-        &[SampleStateKind::NotRead],
-        ANY_VIEW_STATE,
-        ANY_INSTANCE_STATE,
-    );
-
-    assert_eq!(samples1.len(), 3);
-    assert_eq!(samples1[0].data().unwrap(), data1);
-    assert_eq!(samples1[1].data().unwrap(), data2);
-    assert_eq!(samples1[2].data().unwrap(), data3);
-
-    assert_eq!(samples2.len(), 2);
-    assert_eq!(samples2[0].data().unwrap(), data4);
-    assert_eq!(samples2[1].data().unwrap(), data5);
-
-    assert!(matches!(samples3, Err(DdsError::NoData)));
+    assert_eq!(taken_samples.len(), 1);
+    assert_eq!(taken_samples[0].data().unwrap(), data1);
+    assert_eq!(read_samples.len(), 1);
+    assert_eq!(read_samples[0].data().unwrap(), data2);
 }
 
 #[test]
-fn read_next_sample() {
+fn instance_written_again_after_its_disposal_was_taken_is_new() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -840,26 +833,35 @@ fn read_next_sample() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 10 };
-    let data3 = KeyedData { id: 3, value: 20 };
-
-    writer.write(data1.clone(), None).unwrap();
-    writer.write(data2.clone(), None).unwrap();
-    writer.write(data3.clone(), None).unwrap();
+    let data = KeyedData { id: 1, value: 1 };
+    writer.write(data.clone(), None).unwrap();
+    writer.dispose(data.clone(), None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
 
+    writer.write(data.clone(), None).unwrap();
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
 
-    assert_eq!(reader.read_next_sample().unwrap().data().unwrap(), data1);
-    assert_eq!(reader.read_next_sample().unwrap().data().unwrap(), data2);
-    assert_eq!(reader.read_next_sample().unwrap().data().unwrap(), data3);
-    assert!(matches!(reader.read_next_sample(), Err(DdsError::NoData)));
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].sample_info().view_state, ViewStateKind::New);
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::Alive
+    );
+    assert_eq!(samples[0].sample_info().disposed_generation_count, 0);
 }
 
 #[test]
-fn take_next_sample() {
+fn wait_for_samples_to_be_taken_best_effort() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -881,7 +883,7 @@ fn take_next_sample() {
         .unwrap();
     let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
+            kind: ReliabilityQosPolicyKind::BestEffort,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
@@ -900,7 +902,7 @@ fn take_next_sample() {
         .unwrap();
     let reader_qos = DataReaderQos {
         reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
+            kind: ReliabilityQosPolicyKind::BestEffort,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
@@ -922,28 +924,43 @@ fn take_next_sample() {
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
     let data1 = KeyedData { id: 1, value: 1 };
     let data2 = KeyedData { id: 2, value: 10 };
     let data3 = KeyedData { id: 3, value: 20 };
+    let data4 = KeyedData { id: 4, value: 30 };
+    let data5 = KeyedData { id: 5, value: 40 };
 
     writer.write(data1.clone(), None).unwrap();
     writer.write(data2.clone(), None).unwrap();
     writer.write(data3.clone(), None).unwrap();
+    writer.write(data4.clone(), None).unwrap();
+    writer.write(data5.clone(), None).unwrap();
 
-    writer
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
+    let start_time = std::time::Instant::now();
+    let mut samples = Vec::new();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if let Ok(mut sample) = reader.take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        {
+            samples.push(sample.remove(0))
+        }
 
-    assert_eq!(reader.take_next_sample().unwrap().data().unwrap(), data1);
-    assert_eq!(reader.take_next_sample().unwrap().data().unwrap(), data2);
-    assert_eq!(reader.take_next_sample().unwrap().data().unwrap(), data3);
-    assert!(matches!(reader.take_next_sample(), Err(DdsError::NoData)));
+        if samples.len() >= 5 {
+            break;
+        }
+    }
+
+    assert_eq!(samples.len(), 5);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[1].data().unwrap(), data2);
+    assert_eq!(samples[2].data().unwrap(), data3);
+    assert_eq!(samples[3].data().unwrap(), data4);
+    assert_eq!(samples[4].data().unwrap(), data5);
 }
 
 #[test]
-fn each_key_sample_is_read() {
+fn read_only_unread_samples() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -1011,45 +1028,58 @@ fn each_key_sample_is_read() {
     let data1 = KeyedData { id: 1, value: 1 };
     let data2 = KeyedData { id: 2, value: 10 };
     let data3 = KeyedData { id: 3, value: 20 };
+    let data4 = KeyedData { id: 4, value: 30 };
+    let data5 = KeyedData { id: 5, value: 40 };
 
     writer.write(data1.clone(), None).unwrap();
     writer.write(data2.clone(), None).unwrap();
     writer.write(data3.clone(), None).unwrap();
-
-    let data1_handle = writer.lookup_instance(data1.clone()).unwrap();
-    let data2_handle = writer.lookup_instance(data2.clone()).unwrap();
-    let data3_handle = writer.lookup_instance(data3.clone()).unwrap();
+    writer.write(data4.clone(), None).unwrap();
+    writer.write(data5.clone(), None).unwrap();
 
     writer
-        .wait_for_acknowledgments(Duration::new(1, 0))
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples = reader
-        .read(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+    let samples1 = reader
+        .read(
+            3,
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
 
-    assert_eq!(samples.len(), 3);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(
-        samples[0].sample_info().instance_handle,
-        data1_handle.unwrap(),
-    );
-
-    assert_eq!(samples[1].data().unwrap(), data2);
-    assert_eq!(
-        samples[1].sample_info().instance_handle,
-        data2_handle.unwrap(),
-    );
+    let samples2 = reader
+        .read(
+            3,
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
 
-    assert_eq!(samples[2].data().unwrap(), data3);
-    assert_eq!(
-        samples[2].sample_info().instance_handle,
-        data3_handle.unwrap(),
+    let samples3 = reader.read(
+        3,
+        &[SampleStateKind::NotRead],
+        ANY_VIEW_STATE,
+        ANY_INSTANCE_STATE,
     );
+
+    assert_eq!(samples1.len(), 3);
+    assert_eq!(samples1[0].data().unwrap(), data1);
+    assert_eq!(samples1[1].data().unwrap(), data2);
+    assert_eq!(samples1[2].data().unwrap(), data3);
+
+    assert_eq!(samples2.len(), 2);
+    assert_eq!(samples2[0].data().unwrap(), data4);
+    assert_eq!(samples2[1].data().unwrap(), data5);
+
+    assert!(matches!(samples3, Err(DdsError::NoData)));
 }
 
 #[test]
-fn read_specific_instance() {
+fn read_next_sample() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -1119,31 +1149,21 @@ fn read_specific_instance() {
     let data3 = KeyedData { id: 3, value: 20 };
 
     writer.write(data1.clone(), None).unwrap();
-    writer.write(data2, None).unwrap();
-    writer.write(data3, None).unwrap();
-
-    let data1_handle = writer.lookup_instance(data1.clone()).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+    writer.write(data3.clone(), None).unwrap();
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples = reader
-        .read_instance(
-            3,
-            data1_handle.unwrap(),
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
-        .unwrap();
-
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(reader.read_next_sample().unwrap().data().unwrap(), data1);
+    assert_eq!(reader.read_next_sample().unwrap().data().unwrap(), data2);
+    assert_eq!(reader.read_next_sample().unwrap().data().unwrap(), data3);
+    assert!(matches!(reader.read_next_sample(), Err(DdsError::NoData)));
 }
 
 #[test]
-fn read_next_instance() {
+fn peek_next_info_does_not_consume_the_sample() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -1206,66 +1226,37 @@ fn read_next_instance() {
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
     let data1 = KeyedData { id: 1, value: 1 };
     let data2 = KeyedData { id: 2, value: 10 };
-    let data3 = KeyedData { id: 3, value: 20 };
 
     writer.write(data1.clone(), None).unwrap();
     writer.write(data2.clone(), None).unwrap();
-    writer.write(data3.clone(), None).unwrap();
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples1 = reader
-        .read_next_instance(
-            3,
-            None,
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
-        .unwrap();
-
-    let samples2 = reader
-        .read_next_instance(
-            3,
-            Some(samples1[0].sample_info().instance_handle),
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
-        .unwrap();
+    let peeked_info = reader.peek_next_info().unwrap();
+    assert_eq!(peeked_info.sample_state, SampleStateKind::NotRead);
+    assert_eq!(peeked_info.view_state, ViewStateKind::New);
+    assert_eq!(reader.peek_next_info().unwrap(), peeked_info);
 
-    let samples3 = reader
-        .read_next_instance(
-            3,
-            Some(samples2[0].sample_info().instance_handle),
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
-        .unwrap();
+    let sample = reader.read_next_sample().unwrap();
+    assert_eq!(sample.data().unwrap(), data1);
+    assert_eq!(sample.sample_info(), peeked_info);
 
-    let samples4 = reader.read_next_instance(
-        3,
-        Some(samples3[0].sample_info().instance_handle),
-        ANY_SAMPLE_STATE,
-        ANY_VIEW_STATE,
-        ANY_INSTANCE_STATE,
-    );
+    let peeked_info = reader.peek_next_info().unwrap();
+    let sample = reader.read_next_sample().unwrap();
+    assert_eq!(sample.data().unwrap(), data2);
+    assert_eq!(sample.sample_info(), peeked_info);
 
-    assert_eq!(samples1[0].data().unwrap(), data1);
-    assert_eq!(samples2[0].data().unwrap(), data2);
-    assert_eq!(samples3[0].data().unwrap(), data3);
-    assert!(matches!(samples4, Err(DdsError::NoData)));
+    assert!(matches!(reader.peek_next_info(), Err(DdsError::NoData)));
 }
 
 #[test]
-fn take_next_instance() {
+fn received_sequence_numbers_are_reported_per_writer() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -1292,7 +1283,15 @@ fn take_next_instance() {
         },
         ..Default::default()
     };
-    let writer = publisher
+    let writer1 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let writer2 = publisher
         .create_datawriter(
             &topic,
             QosKind::Specific(writer_qos),
@@ -1309,6 +1308,9 @@ fn take_next_instance() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
     let reader = subscriber
@@ -1320,74 +1322,84 @@ fn take_next_instance() {
         )
         .unwrap();
 
-    let cond = writer.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
-        .unwrap();
+    for writer in [&writer1, &writer2] {
+        let cond = writer.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+    }
 
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
+    writer1.write(KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer1.write(KeyedData { id: 1, value: 2 }, None).unwrap();
+    writer1.write(KeyedData { id: 1, value: 3 }, None).unwrap();
+    writer2.write(KeyedData { id: 2, value: 10 }, None).unwrap();
+    writer2
+        .dispose(KeyedData { id: 2, value: 10 }, None)
         .unwrap();
-    wait_set.wait(Duration::new(10, 0)).unwrap();
-
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 10 };
-    let data3 = KeyedData { id: 3, value: 20 };
-
-    writer.write(data1.clone(), None).unwrap();
-    writer.write(data2.clone(), None).unwrap();
-    writer.write(data3.clone(), None).unwrap();
 
-    writer
+    writer1
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
-
-    let samples1 = reader
-        .take_next_instance(
-            3,
-            None,
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples2 = reader
-        .take_next_instance(
-            3,
-            Some(samples1[0].sample_info().instance_handle),
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
-        .unwrap();
+    assert_eq!(
+        reader
+            .get_received_sequence_numbers(writer1.get_instance_handle())
+            .unwrap(),
+        vec![
+            (1, InstanceStateKind::Alive),
+            (2, InstanceStateKind::Alive),
+            (3, InstanceStateKind::Alive),
+        ]
+    );
+    assert_eq!(
+        reader
+            .get_received_sequence_numbers(writer2.get_instance_handle())
+            .unwrap(),
+        vec![
+            (1, InstanceStateKind::NotAliveDisposed),
+            (2, InstanceStateKind::NotAliveDisposed),
+        ]
+    );
 
-    let samples3 = reader
-        .take_next_instance(
-            3,
-            Some(samples2[0].sample_info().instance_handle),
+    let writer1_instance = reader
+        .read(100, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap()
+        .iter()
+        .find(|s| s.sample_info().publication_handle == writer1.get_instance_handle())
+        .unwrap()
+        .sample_info()
+        .instance_handle;
+    reader
+        .take_instance(
+            1,
+            writer1_instance,
             ANY_SAMPLE_STATE,
             ANY_VIEW_STATE,
             ANY_INSTANCE_STATE,
         )
         .unwrap();
-
-    let samples4 = reader.take_next_instance(
-        3,
-        Some(samples3[0].sample_info().instance_handle),
-        ANY_SAMPLE_STATE,
-        ANY_VIEW_STATE,
-        ANY_INSTANCE_STATE,
+    assert_eq!(
+        reader
+            .get_received_sequence_numbers(writer1.get_instance_handle())
+            .unwrap(),
+        vec![(2, InstanceStateKind::Alive), (3, InstanceStateKind::Alive)]
     );
 
-    assert_eq!(samples1[0].data().unwrap(), data1);
-    assert_eq!(samples2[0].data().unwrap(), data2);
-    assert_eq!(samples3[0].data().unwrap(), data3);
-    assert!(matches!(samples4, Err(DdsError::NoData)));
+    assert_eq!(
+        reader.get_received_sequence_numbers(participant.get_instance_handle()),
+        Err(DdsError::BadParameter)
+    );
 }
 
 #[test]
-fn take_specific_instance() {
+fn take_next_sample() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -1450,37 +1462,28 @@ fn take_specific_instance() {
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(10, 0)).unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
 
     let data1 = KeyedData { id: 1, value: 1 };
     let data2 = KeyedData { id: 2, value: 10 };
     let data3 = KeyedData { id: 3, value: 20 };
 
     writer.write(data1.clone(), None).unwrap();
-    writer.write(data2, None).unwrap();
-    writer.write(data3, None).unwrap();
-    let data1_handle = writer.lookup_instance(data1.clone()).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+    writer.write(data3.clone(), None).unwrap();
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples = reader
-        .take_instance(
-            3,
-            data1_handle.unwrap(),
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        )
-        .unwrap();
-
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(reader.take_next_sample().unwrap().data().unwrap(), data1);
+    assert_eq!(reader.take_next_sample().unwrap().data().unwrap(), data2);
+    assert_eq!(reader.take_next_sample().unwrap().data().unwrap(), data3);
+    assert!(matches!(reader.take_next_sample(), Err(DdsError::NoData)));
 }
 
 #[test]
-fn take_specific_unknown_instance() {
+fn each_key_sample_is_read() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -1549,32 +1552,47 @@ fn take_specific_unknown_instance() {
     let data2 = KeyedData { id: 2, value: 10 };
     let data3 = KeyedData { id: 3, value: 20 };
 
-    writer.write(data1, None).unwrap();
-    writer.write(data2, None).unwrap();
-    writer.write(data3, None).unwrap();
+    writer.write(data1.clone(), None).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+    writer.write(data3.clone(), None).unwrap();
+
+    let data1_handle = writer.lookup_instance(data1.clone()).unwrap();
+    let data2_handle = writer.lookup_instance(data2.clone()).unwrap();
+    let data3_handle = writer.lookup_instance(data3.clone()).unwrap();
 
     writer
-        .wait_for_acknowledgments(Duration::new(10, 0))
+        .wait_for_acknowledgments(Duration::new(1, 0))
         .unwrap();
 
-    assert!(matches!(
-        reader.take_instance(
-            3,
-            InstanceHandle::new([99; 16]),
-            ANY_SAMPLE_STATE,
-            ANY_VIEW_STATE,
-            ANY_INSTANCE_STATE,
-        ),
-        Err(DdsError::BadParameter)
-    ));
+    let samples = reader
+        .read(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 3);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(
+        samples[0].sample_info().instance_handle,
+        data1_handle.unwrap(),
+    );
+
+    assert_eq!(samples[1].data().unwrap(), data2);
+    assert_eq!(
+        samples[1].sample_info().instance_handle,
+        data2_handle.unwrap(),
+    );
+
+    assert_eq!(samples[2].data().unwrap(), data3);
+    assert_eq!(
+        samples[2].sample_info().instance_handle,
+        data3_handle.unwrap(),
+    );
 }
 
 #[test]
-fn write_read_disposed_samples() {
+fn read_specific_instance() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = participant_factory
+    let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
@@ -1596,9 +1614,6 @@ fn write_read_disposed_samples() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         ..Default::default()
     };
     let writer = publisher
@@ -1618,12 +1633,8 @@ fn write_read_disposed_samples() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
             &topic,
@@ -1644,44 +1655,42 @@ fn write_read_disposed_samples() {
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
     let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 10 };
+    let data3 = KeyedData { id: 3, value: 20 };
 
     writer.write(data1.clone(), None).unwrap();
-    writer.dispose(data1, None).unwrap();
+    writer.write(data2, None).unwrap();
+    writer.write(data3, None).unwrap();
+
+    let data1_handle = writer.lookup_instance(data1.clone()).unwrap();
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
-        .read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .read_instance(
+            3,
+            data1_handle.unwrap(),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
 
-    assert_eq!(samples.len(), 2);
-    assert_eq!(
-        samples[0].sample_info().instance_state,
-        InstanceStateKind::NotAliveDisposed
-    );
-    assert_eq!(
-        samples[1].sample_info().instance_state,
-        InstanceStateKind::NotAliveDisposed
-    );
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data1);
 }
 
 #[test]
-#[ignore = "Dispose message not always sent after timing changes"]
-fn write_read_disposed_samples_when_writer_is_immediately_deleted() {
+fn read_next_instance() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-    let participant_factory = DomainParticipantFactory::get_instance();
-
-    let participant1 = participant_factory
-        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
-        .unwrap();
 
-    let participant2 = participant_factory
+    let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
-    let topic1 = participant1
+    let topic = participant
         .create_topic::<KeyedData>(
             "MyTopic",
             "KeyedData",
@@ -1691,47 +1700,38 @@ fn write_read_disposed_samples_when_writer_is_immediately_deleted() {
         )
         .unwrap();
 
-    let publisher = participant1
+    let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
     let writer = publisher
         .create_datawriter(
-            &topic1,
+            &topic,
             QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
         .unwrap();
 
-    let topic2 = participant2
-        .create_topic::<KeyedData>(
-            "MyTopic",
-            "KeyedData",
-            QosKind::Default,
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let subscriber = participant2
+    let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
-            &topic2,
+            &topic,
             QosKind::Specific(reader_qos),
             NO_LISTENER,
             NO_STATUS,
@@ -1746,51 +1746,75 @@ fn write_read_disposed_samples_when_writer_is_immediately_deleted() {
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(10, 0)).unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
 
     let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 10 };
+    let data3 = KeyedData { id: 3, value: 20 };
 
     writer.write(data1.clone(), None).unwrap();
-    writer.dispose(data1, None).unwrap();
-    publisher.delete_datawriter(&writer).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+    writer.write(data3.clone(), None).unwrap();
 
-    let start_loop_time = std::time::Instant::now();
-    let wait_for_disposed_timeout = std::time::Duration::from_secs(10);
-    let samples = loop {
-        if let Ok(samples) = reader.read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE) {
-            if samples.len() == 2 {
-                break samples;
-            }
-        }
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
 
-        if start_loop_time.elapsed() > wait_for_disposed_timeout {
-            panic!("Disposed sample not received within expected time.")
-        }
-    };
+    let samples1 = reader
+        .read_next_instance(
+            3,
+            None,
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
 
-    assert_eq!(samples.len(), 2);
-    assert_eq!(
-        samples[0].sample_info().instance_state,
-        InstanceStateKind::NotAliveDisposed
-    );
-    assert_eq!(
-        samples[1].sample_info().instance_state,
-        InstanceStateKind::NotAliveDisposed
+    let samples2 = reader
+        .read_next_instance(
+            3,
+            Some(samples1[0].sample_info().instance_handle),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
+
+    let samples3 = reader
+        .read_next_instance(
+            3,
+            Some(samples2[0].sample_info().instance_handle),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
+
+    let samples4 = reader.read_next_instance(
+        3,
+        Some(samples3[0].sample_info().instance_handle),
+        ANY_SAMPLE_STATE,
+        ANY_VIEW_STATE,
+        ANY_INSTANCE_STATE,
     );
+
+    assert_eq!(samples1[0].data().unwrap(), data1);
+    assert_eq!(samples2[0].data().unwrap(), data2);
+    assert_eq!(samples3[0].data().unwrap(), data3);
+    assert!(matches!(samples4, Err(DdsError::NoData)));
 }
 
 #[test]
-fn write_read_sample_view_state() {
+fn take_next_instance() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = participant_factory
+    let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
     let topic = participant
         .create_topic::<KeyedData>(
-            "OtherTopic",
+            "MyTopic",
             "KeyedData",
             QosKind::Default,
             NO_LISTENER,
@@ -1844,144 +1868,96 @@ fn write_read_sample_view_state() {
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
     let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 10 };
+    let data3 = KeyedData { id: 3, value: 20 };
 
-    writer.write(data1, None).unwrap();
+    writer.write(data1.clone(), None).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+    writer.write(data3.clone(), None).unwrap();
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    reader
-        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+    let samples1 = reader
+        .take_next_instance(
+            3,
+            None,
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
 
-    let data1_2 = KeyedData { id: 1, value: 2 };
-    let data2 = KeyedData { id: 2, value: 1 };
-
-    writer.write(data1_2, None).unwrap();
-    writer.write(data2, None).unwrap();
-
-    writer
-        .wait_for_acknowledgments(Duration::new(1, 0))
+    let samples2 = reader
+        .take_next_instance(
+            3,
+            Some(samples1[0].sample_info().instance_handle),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
 
-    let samples = reader
-        .read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+    let samples3 = reader
+        .take_next_instance(
+            3,
+            Some(samples2[0].sample_info().instance_handle),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
 
-    assert_eq!(samples.len(), 2);
-    assert_eq!(samples[0].sample_info().view_state, ViewStateKind::NotNew);
-    assert_eq!(samples[1].sample_info().view_state, ViewStateKind::New);
+    let samples4 = reader.take_next_instance(
+        3,
+        Some(samples3[0].sample_info().instance_handle),
+        ANY_SAMPLE_STATE,
+        ANY_VIEW_STATE,
+        ANY_INSTANCE_STATE,
+    );
+
+    assert_eq!(samples1[0].data().unwrap(), data1);
+    assert_eq!(samples2[0].data().unwrap(), data2);
+    assert_eq!(samples3[0].data().unwrap(), data3);
+    assert!(matches!(samples4, Err(DdsError::NoData)));
 }
 
 #[test]
-fn inconsistent_topic_status_condition() {
+fn take_specific_instance() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = participant_factory
+    let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
-    let best_effort_topic_qos = TopicQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::BestEffort,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ..Default::default()
-    };
-    let topic_best_effort = participant
+    let topic = participant
         .create_topic::<KeyedData>(
-            "Topic",
+            "MyTopic",
             "KeyedData",
-            QosKind::Specific(best_effort_topic_qos),
+            QosKind::Default,
             NO_LISTENER,
             NO_STATUS,
         )
         .unwrap();
 
-    let status_cond = match &topic_best_effort {
-        TopicDescription::Topic(topic) => topic.get_statuscondition(),
-        TopicDescription::ContentFilteredTopic(_) => unreachable!(),
-    };
-    status_cond
-        .set_enabled_statuses(&[StatusKind::InconsistentTopic])
-        .unwrap();
-
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(status_cond))
-        .unwrap();
-
-    let participant2 = participant_factory
-        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-
-    let reliable_topic_qos = TopicQos {
+    let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
-    let _topic_reliable = participant2
-        .create_topic::<KeyedData>(
-            "Topic",
-            "KeyedData",
-            QosKind::Specific(reliable_topic_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    wait_set.wait(Duration::new(10, 0)).unwrap();
-
-    match topic_best_effort {
-        TopicDescription::Topic(topic) => {
-            assert!(topic.get_inconsistent_topic_status().unwrap().total_count > 0)
-        }
-        TopicDescription::ContentFilteredTopic(_) => unreachable!(),
-    }
-}
-
-#[test]
-fn reader_with_minimum_time_separation_qos() {
-    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-
-    let participant = DomainParticipantFactory::get_instance()
-        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
-        .unwrap();
-
-    let topic = participant
-        .create_topic::<KeyedData>(
-            "MyTopic",
-            "KeyedData",
-            QosKind::Default,
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let publisher = participant
-        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
-        .unwrap();
-    let writer_qos = DataWriterQos {
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ..Default::default()
-    };
-    let writer = publisher
-        .create_datawriter(
-            &topic,
-            QosKind::Specific(writer_qos),
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
@@ -1991,16 +1967,10 @@ fn reader_with_minimum_time_separation_qos() {
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        time_based_filter: TimeBasedFilterQosPolicy {
-            minimum_separation: DurationKind::Finite(Duration::new(2, 0)),
-        },
         ..Default::default()
     };
     let reader = subscriber
@@ -2022,49 +1992,35 @@ fn reader_with_minimum_time_separation_qos() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data1_1 = KeyedData { id: 1, value: 1 };
-    let data1_2 = KeyedData { id: 1, value: 2 };
-    let data1_3 = KeyedData { id: 1, value: 3 };
-    let data2_1 = KeyedData { id: 2, value: 10 };
-    let data2_2 = KeyedData { id: 2, value: 20 };
-    let data2_3 = KeyedData { id: 2, value: 30 };
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 10 };
+    let data3 = KeyedData { id: 3, value: 20 };
 
-    writer
-        .write_w_timestamp(data1_1.clone(), None, Time::new(1, 0))
-        .unwrap();
-    writer
-        .write_w_timestamp(data1_2, None, Time::new(2, 0))
-        .unwrap();
-    writer
-        .write_w_timestamp(data1_3.clone(), None, Time::new(3, 0))
-        .unwrap();
-    writer
-        .write_w_timestamp(data2_1.clone(), None, Time::new(4, 0))
-        .unwrap();
-    writer
-        .write_w_timestamp(data2_2, None, Time::new(5, 0))
-        .unwrap();
-    writer
-        .write_w_timestamp(data2_3.clone(), None, Time::new(6, 0))
-        .unwrap();
+    writer.write(data1.clone(), None).unwrap();
+    writer.write(data2, None).unwrap();
+    writer.write(data3, None).unwrap();
+    let data1_handle = writer.lookup_instance(data1.clone()).unwrap();
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
-        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .take_instance(
+            3,
+            data1_handle.unwrap(),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
 
-    assert_eq!(samples.len(), 4);
-    assert_eq!(samples[0].data().unwrap(), data1_1);
-    assert_eq!(samples[1].data().unwrap(), data1_3);
-    assert_eq!(samples[2].data().unwrap(), data2_1);
-    assert_eq!(samples[3].data().unwrap(), data2_3);
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data1);
 }
 
 #[test]
-fn transient_local_writer_reader_wait_for_historical_data() {
+fn take_specific_unknown_instance() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -2085,12 +2041,6 @@ fn transient_local_writer_reader_wait_for_historical_data() {
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
@@ -2105,31 +2055,17 @@ fn transient_local_writer_reader_wait_for_historical_data() {
             NO_STATUS,
         )
         .unwrap();
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 2 };
-    writer.write(data1.clone(), None).unwrap();
-    writer.write(data2.clone(), None).unwrap();
 
     let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        time_based_filter: TimeBasedFilterQosPolicy {
-            minimum_separation: DurationKind::Finite(Duration::new(2, 0)),
-        },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
             &topic,
@@ -2139,30 +2075,42 @@ fn transient_local_writer_reader_wait_for_historical_data() {
         )
         .unwrap();
 
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
 
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    reader
-        .wait_for_historical_data(Duration::new(10, 0))
-        .unwrap();
-    let samples = reader
-        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 10 };
+    let data3 = KeyedData { id: 3, value: 20 };
+
+    writer.write(data1, None).unwrap();
+    writer.write(data2, None).unwrap();
+    writer.write(data3, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    assert_eq!(samples.len(), 2);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[1].data().unwrap(), data2);
+    assert!(matches!(
+        reader.take_instance(
+            3,
+            InstanceHandle::new([99; 16]),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        ),
+        Err(DdsError::BadParameter)
+    ));
 }
 
 #[test]
-fn volatile_writer_reader_receives_only_new_samples() {
+fn read_instance_of_registered_instance_without_samples() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -2183,12 +2131,6 @@ fn volatile_writer_reader_receives_only_new_samples() {
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::Volatile,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
@@ -2204,19 +2146,10 @@ fn volatile_writer_reader_receives_only_new_samples() {
         )
         .unwrap();
 
-    let data1 = KeyedData { id: 1, value: 1 };
-    writer.write(data1, None).unwrap();
-
     let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::Volatile,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
@@ -2240,36 +2173,80 @@ fn volatile_writer_reader_receives_only_new_samples() {
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
+    let data1 = KeyedData { id: 1, value: 1 };
     let data2 = KeyedData { id: 2, value: 10 };
+
+    writer.write(data1.clone(), None).unwrap();
     writer.write(data2.clone(), None).unwrap();
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
+    let data1_handle = writer.lookup_instance(data1.clone()).unwrap().unwrap();
+    let data2_handle = writer.lookup_instance(data2.clone()).unwrap().unwrap();
+
     let samples = reader
-        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .take_instance(
+            3,
+            data1_handle,
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data1);
+
+    // The instance is still known to the reader after all its samples were taken
+    assert_eq!(
+        reader.read_instance(
+            3,
+            data1_handle,
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        ),
+        Err(DdsError::NoData)
+    );
+    assert_eq!(
+        reader.read_instance(
+            3,
+            InstanceHandle::new([99; 16]),
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        ),
+        Err(DdsError::BadParameter)
+    );
 
+    let samples = reader
+        .read_instance(
+            3,
+            data2_handle,
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
     assert_eq!(samples.len(), 1);
     assert_eq!(samples[0].data().unwrap(), data2);
 }
 
 #[test]
-fn write_read_unkeyed_topic() {
+fn get_key_value_of_disposed_instance() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = participant_factory
+    let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
     let topic = participant
-        .create_topic::<UserData>(
-            "write_read_unkeyed_topic",
-            "UserData",
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
             QosKind::Default,
             NO_LISTENER,
             NO_STATUS,
@@ -2306,7 +2283,7 @@ fn write_read_unkeyed_topic() {
         ..Default::default()
     };
     let reader = subscriber
-        .create_datareader::<UserData>(
+        .create_datareader::<KeyedData>(
             &topic,
             QosKind::Specific(reader_qos),
             NO_LISTENER,
@@ -2324,19 +2301,36 @@ fn write_read_unkeyed_topic() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    writer.write(UserData(8), None).unwrap();
-
+    let data = KeyedData { id: 7, value: 1 };
+    writer.write(data.clone(), None).unwrap();
+    writer.dispose(data.clone(), None).unwrap();
     writer
-        .wait_for_acknowledgments(Duration::new(5, 0))
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples = reader.read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE);
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let disposed_sample_info = samples.last().unwrap().sample_info();
+    assert_eq!(
+        disposed_sample_info.instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
 
-    assert_eq!(samples.unwrap()[0].data().unwrap(), UserData(8));
+    let mut key_holder = KeyedData { id: 0, value: 0 };
+    reader
+        .get_key_value(&mut key_holder, disposed_sample_info.instance_handle)
+        .unwrap();
+    assert_eq!(key_holder.id, 7);
+
+    assert_eq!(
+        reader.get_key_value(&mut key_holder, InstanceHandle::new([99; 16])),
+        Err(DdsError::BadParameter)
+    );
 }
 
 #[test]
-fn data_reader_resource_limits() {
+fn write_read_disposed_samples() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
     let participant_factory = DomainParticipantFactory::get_instance();
 
@@ -2345,9 +2339,9 @@ fn data_reader_resource_limits() {
         .unwrap();
 
     let topic = participant
-        .create_topic::<UserData>(
-            "data_reader_resource_limits",
-            "UserData",
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
             QosKind::Default,
             NO_LISTENER,
             NO_STATUS,
@@ -2357,21 +2351,20 @@ fn data_reader_resource_limits() {
     let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-    let data_writer_qos = DataWriterQos {
+    let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
         },
         ..Default::default()
     };
     let writer = publisher
         .create_datawriter(
             &topic,
-            QosKind::Specific(data_writer_qos),
+            QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
@@ -2387,17 +2380,12 @@ fn data_reader_resource_limits() {
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
-        },
-        resource_limits: ResourceLimitsQosPolicy {
-            max_samples: Length::Limited(2),
-            max_instances: Length::Unlimited,
-            max_samples_per_instance: Length::Limited(2),
         },
         ..Default::default()
     };
+
     let reader = subscriber
-        .create_datareader::<UserData>(
+        .create_datareader::<KeyedData>(
             &topic,
             QosKind::Specific(reader_qos),
             NO_LISTENER,
@@ -2415,30 +2403,242 @@ fn data_reader_resource_limits() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    writer.write(UserData(1), None).unwrap();
-    writer.write(UserData(2), None).unwrap();
-    writer.write(UserData(3), None).unwrap();
+    let data1 = KeyedData { id: 1, value: 1 };
 
-    let reader_cond = reader.get_statuscondition();
-    reader_cond
-        .set_enabled_statuses(&[StatusKind::SampleRejected])
+    writer.write(data1.clone(), None).unwrap();
+    writer.dispose(data1, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
+    assert_eq!(
+        samples[1].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
+}
+
+#[test]
+#[ignore = "Dispose message not always sent after timing changes"]
+fn write_read_disposed_samples_when_writer_is_immediately_deleted() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant1 = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let participant2 = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic1 = participant1
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic1,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let topic2 = participant2
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic2,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
+
     let mut wait_set = WaitSet::new();
     wait_set
-        .attach_condition(Condition::StatusCondition(reader_cond))
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 1 };
+
+    writer.write(data1.clone(), None).unwrap();
+    writer.dispose(data1, None).unwrap();
+    publisher.delete_datawriter(&writer).unwrap();
+
+    let start_loop_time = std::time::Instant::now();
+    let wait_for_disposed_timeout = std::time::Duration::from_secs(10);
+    let samples = loop {
+        if let Ok(samples) = reader.read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE) {
+            if samples.len() == 2 {
+                break samples;
+            }
+        }
+
+        if start_loop_time.elapsed() > wait_for_disposed_timeout {
+            panic!("Disposed sample not received within expected time.")
+        }
+    };
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
+    assert_eq!(
+        samples[1].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
+}
+
+#[test]
+fn write_read_sample_view_state() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "OtherTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
 
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
     wait_set.wait(Duration::new(5, 0)).unwrap();
 
+    let data1 = KeyedData { id: 1, value: 1 };
+
+    writer.write(data1, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    let data1_2 = KeyedData { id: 1, value: 2 };
+    let data2 = KeyedData { id: 2, value: 1 };
+
+    writer.write(data1_2, None).unwrap();
+    writer.write(data2, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(1, 0))
+        .unwrap();
+
     let samples = reader
-        .read(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
     assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].sample_info().view_state, ViewStateKind::NotNew);
+    assert_eq!(samples[1].sample_info().view_state, ViewStateKind::New);
 }
 
 #[test]
-fn data_reader_order_by_source_timestamp() {
+fn inconsistent_topic_status_condition() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
     let participant_factory = DomainParticipantFactory::get_instance();
 
@@ -2446,10 +2646,2185 @@ fn data_reader_order_by_source_timestamp() {
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
-    let topic = participant
-        .create_topic::<UserData>(
-            "MyTopic",
-            "UserData",
+    let best_effort_topic_qos = TopicQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let topic_best_effort = participant
+        .create_topic::<KeyedData>(
+            "Topic",
+            "KeyedData",
+            QosKind::Specific(best_effort_topic_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let status_cond = match &topic_best_effort {
+        TopicDescription::Topic(topic) => topic.get_statuscondition(),
+        TopicDescription::ContentFilteredTopic(_) => unreachable!(),
+    };
+    status_cond
+        .set_enabled_statuses(&[StatusKind::InconsistentTopic])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(status_cond))
+        .unwrap();
+
+    let participant2 = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let reliable_topic_qos = TopicQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let _topic_reliable = participant2
+        .create_topic::<KeyedData>(
+            "Topic",
+            "KeyedData",
+            QosKind::Specific(reliable_topic_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    match topic_best_effort {
+        TopicDescription::Topic(topic) => {
+            assert!(topic.get_inconsistent_topic_status().unwrap().total_count > 0)
+        }
+        TopicDescription::ContentFilteredTopic(_) => unreachable!(),
+    }
+}
+
+#[test]
+fn reader_with_minimum_time_separation_qos() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        time_based_filter: TimeBasedFilterQosPolicy {
+            minimum_separation: DurationKind::Finite(Duration::new(2, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data1_1 = KeyedData { id: 1, value: 1 };
+    let data1_2 = KeyedData { id: 1, value: 2 };
+    let data1_3 = KeyedData { id: 1, value: 3 };
+    let data2_1 = KeyedData { id: 2, value: 10 };
+    let data2_2 = KeyedData { id: 2, value: 20 };
+    let data2_3 = KeyedData { id: 2, value: 30 };
+
+    writer
+        .write_w_timestamp(data1_1.clone(), None, Time::new(1, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(data1_2, None, Time::new(2, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(data1_3.clone(), None, Time::new(3, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(data2_1.clone(), None, Time::new(4, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(data2_2, None, Time::new(5, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(data2_3.clone(), None, Time::new(6, 0))
+        .unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 4);
+    assert_eq!(samples[0].data().unwrap(), data1_1);
+    assert_eq!(samples[1].data().unwrap(), data1_3);
+    assert_eq!(samples[2].data().unwrap(), data2_1);
+    assert_eq!(samples[3].data().unwrap(), data2_3);
+}
+
+#[test]
+fn transient_local_writer_reader_wait_for_historical_data() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 2 };
+    writer.write(data1.clone(), None).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        time_based_filter: TimeBasedFilterQosPolicy {
+            minimum_separation: DurationKind::Finite(Duration::new(2, 0)),
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
+        .unwrap();
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[1].data().unwrap(), data2);
+}
+
+#[test]
+fn volatile_writer_reader_receives_only_new_samples() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Volatile,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let data1 = KeyedData { id: 1, value: 1 };
+    writer.write(data1, None).unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Volatile,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data2 = KeyedData { id: 2, value: 10 };
+    writer.write(data2.clone(), None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data2);
+}
+
+#[test]
+fn write_read_unkeyed_topic() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<UserData>(
+            "write_read_unkeyed_topic",
+            "UserData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<UserData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer.write(UserData(8), None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    let samples = reader.read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE);
+
+    assert_eq!(samples.unwrap()[0].data().unwrap(), UserData(8));
+}
+
+#[test]
+fn data_reader_resource_limits() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<UserData>(
+            "data_reader_resource_limits",
+            "UserData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Limited(2),
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(2),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<UserData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer.write(UserData(1), None).unwrap();
+    writer.write(UserData(2), None).unwrap();
+    writer.write(UserData(3), None).unwrap();
+
+    let reader_cond = reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::SampleRejected])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond))
+        .unwrap();
+
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let samples = reader
+        .read(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+}
+
+#[test]
+fn data_reader_order_by_source_timestamp() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<UserData>(
+            "MyTopic",
+            "UserData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        destination_order: DestinationOrderQosPolicy {
+            kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        destination_order: DestinationOrderQosPolicy {
+            kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<UserData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer
+        .write_w_timestamp(UserData(1), None, Time::new(30, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(UserData(2), None, Time::new(20, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(UserData(3), None, Time::new(10, 0))
+        .unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(1, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    // Samples older than the first one received for the instance are discarded
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), UserData(1));
+}
+
+#[test]
+fn data_reader_by_source_timestamp_discards_older_sample_of_instance() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        destination_order: DestinationOrderQosPolicy {
+            kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(3),
+        },
+        destination_order: DestinationOrderQosPolicy {
+            kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer
+        .write_w_timestamp(KeyedData { id: 1, value: 1 }, None, Time::new(10, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(KeyedData { id: 1, value: 3 }, None, Time::new(30, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(KeyedData { id: 1, value: 2 }, None, Time::new(20, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(KeyedData { id: 2, value: 1 }, None, Time::new(15, 0))
+        .unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(1, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 3);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 1 });
+    assert_eq!(samples[1].data().unwrap(), KeyedData { id: 2, value: 1 });
+    assert_eq!(samples[2].data().unwrap(), KeyedData { id: 1, value: 3 });
+}
+#[test]
+fn data_reader_publication_handle_sample_info() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<UserData>(
+            "MyTopic",
+            "UserData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<UserData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer.write(UserData(1), None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert!(reader
+        .get_matched_publication_data(samples[0].sample_info().publication_handle)
+        .is_ok());
+}
+
+#[test]
+fn volatile_writer_with_reader_new_reader_receives_only_new_samples() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Volatile,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Volatile,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let _reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    writer.get_publication_matched_status().unwrap(); // To reset wait_set for subsequent calls
+
+    let data1 = KeyedData { id: 1, value: 1 };
+    writer.write(data1, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(3, 0))
+        .unwrap();
+
+    let reader_new = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Wait for writer to match reader
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data2 = KeyedData { id: 2, value: 10 };
+    writer.write(data2.clone(), None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader_new
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data2);
+}
+
+#[test]
+fn write_read_unregistered_samples_are_also_disposed() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        writer_data_lifecycle: WriterDataLifecycleQosPolicy {
+            autodispose_unregistered_instances: true,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 1 };
+
+    writer.write(data1.clone(), None).unwrap();
+    writer.unregister_instance(data1, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
+    assert_eq!(
+        samples[1].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
+}
+
+#[test]
+fn disposed_and_unregistered_instances_have_distinct_instance_states() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        writer_data_lifecycle: WriterDataLifecycleQosPolicy {
+            autodispose_unregistered_instances: false,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 2 };
+
+    writer.write(data1.clone(), None).unwrap();
+    writer.write(data2.clone(), None).unwrap();
+    writer.dispose(data1.clone(), None).unwrap();
+    writer.unregister_instance(data2.clone(), None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let disposed_samples = reader
+        .read(
+            10,
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            &[InstanceStateKind::NotAliveDisposed],
+        )
+        .unwrap();
+    let no_writers_samples = reader
+        .read(
+            10,
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            &[InstanceStateKind::NotAliveNoWriters],
+        )
+        .unwrap();
+
+    assert_eq!(disposed_samples.len(), 2);
+    assert_eq!(disposed_samples[0].data().unwrap(), data1);
+    assert_eq!(no_writers_samples.len(), 2);
+    assert_eq!(no_writers_samples[0].data().unwrap(), data2);
+}
+
+#[test]
+fn transient_local_writer_does_not_deliver_lifespan_expired_data_at_write() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        lifespan: LifespanQosPolicy {
+            duration: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 2 };
+    writer
+        .write_w_timestamp(data1, None, Time::new(0, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(data2.clone(), None, Time::new(i32::MAX, 0))
+        .unwrap(); // Never stale sample
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
+        .unwrap();
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data2);
+}
+
+#[test]
+fn transient_local_writer_does_not_deliver_lifespan_expired_data_after_write() {
+    const LIFESPAN_MS: u32 = 100;
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        lifespan: LifespanQosPolicy {
+            duration: DurationKind::Finite(Duration::new(0, LIFESPAN_MS * 1_000_000)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 2 };
+    writer.write(data1, None).unwrap();
+    writer
+        .write_w_timestamp(data2.clone(), None, Time::new(i32::MAX, 0))
+        .unwrap(); // Never stale sample
+
+    std::thread::sleep(std::time::Duration::from_millis(LIFESPAN_MS as u64 * 2));
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
+        .unwrap();
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data2);
+}
+
+#[test]
+fn reader_joining_after_writer_writes_many_samples() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    for value in 0..500 {
+        let data = KeyedData { id: 1, value };
+        writer.write(data, None).unwrap();
+    }
+
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let new_data = KeyedData { id: 1, value: 1000 };
+    writer.write(new_data.clone(), None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(20, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(5, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), new_data);
+}
+
+#[test]
+fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_writer() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer1_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer2_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() >= 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        reader.get_matched_publications().unwrap().len(),
+        2,
+        "Reader must have 2 matched writers"
+    );
+
+    let cond = writer1.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let cond = writer2.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 10 };
+    writer1.write(data1.clone(), None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let data2 = KeyedData { id: 1, value: 20 };
+    writer2.write(data2, None).unwrap();
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data1);
+}
+
+#[test]
+fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_higher_ownership_strength(
+) {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer1_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer2_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() >= 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        reader.get_matched_publications().unwrap().len(),
+        2,
+        "Reader must have 2 matched writers"
+    );
+
+    let cond = writer1.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let cond = writer2.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 10 };
+    writer1.write(data1.clone(), None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let data2 = KeyedData { id: 1, value: 20 };
+    writer2.write(data2.clone(), None).unwrap();
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[1].data().unwrap(), data2);
+}
+
+#[test]
+fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_deadline_missed() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer1_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer2_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() >= 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        reader.get_matched_publications().unwrap().len(),
+        2,
+        "Reader must have 2 matched writers"
+    );
+
+    let cond = writer1.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let cond = writer2.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 10 };
+    writer1.write(data1.clone(), None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::RequestedDeadlineMissed])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data2 = KeyedData { id: 1, value: 20 };
+    writer2.write(data2.clone(), None).unwrap();
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[1].data().unwrap(), data2);
+}
+
+#[test]
+#[ignore = "Broken after runtime refactor"]
+fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer_after_unregister()
+{
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer1_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer2_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() >= 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        reader.get_matched_publications().unwrap().len(),
+        2,
+        "Reader must have 2 matched writers"
+    );
+
+    let cond = writer1.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let cond = writer2.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 10 };
+    writer1.write(data1.clone(), None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    writer1.unregister_instance(data1.clone(), None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let data2 = KeyedData { id: 1, value: 20 };
+    writer2.write(data2.clone(), None).unwrap();
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 3);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[2].data().unwrap(), data2);
+}
+
+#[test]
+fn samples_are_transfered_between_two_participants() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant1 = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant1
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let participant2 = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let topic = participant2
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(100, 0)).unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data = KeyedData { id: 1, value: 1 };
+
+    writer.write(data.clone(), None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data);
+}
+
+static COUNTING_KEYED_DATA_DESERIALIZE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq)]
+struct CountingKeyedData(KeyedData);
+
+impl<'de> DdsDeserialize<'de> for CountingKeyedData {
+    fn deserialize_data(
+        serialized_data: &'de [u8],
+    ) -> dust_dds::infrastructure::error::DdsResult<Self> {
+        COUNTING_KEYED_DATA_DESERIALIZE_COUNT.fetch_add(1, Ordering::SeqCst);
+        Ok(Self(KeyedData::deserialize_data(serialized_data)?))
+    }
+}
+
+#[derive(Debug)]
+struct UndecodableKeyedData;
+
+impl<'de> DdsDeserialize<'de> for UndecodableKeyedData {
+    fn deserialize_data(
+        _serialized_data: &'de [u8],
+    ) -> dust_dds::infrastructure::error::DdsResult<Self> {
+        Err(dust_dds::infrastructure::error::DdsError::Error(
+            "Undecodable".to_string(),
+        ))
+    }
+}
+
+#[test]
+fn reading_same_sample_twice_deserializes_it_once() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
             QosKind::Default,
             NO_LISTENER,
             NO_STATUS,
@@ -2459,25 +4834,17 @@ fn data_reader_order_by_source_timestamp() {
     let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-
-    let data_writer_qos = DataWriterQos {
+    let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        destination_order: DestinationOrderQosPolicy {
-            kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-            ..Default::default()
-        },
         ..Default::default()
     };
     let writer = publisher
-        .create_datawriter(
+        .create_datawriter::<KeyedData>(
             &topic,
-            QosKind::Specific(data_writer_qos),
+            QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
@@ -2491,16 +4858,10 @@ fn data_reader_order_by_source_timestamp() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
-        destination_order: DestinationOrderQosPolicy {
-            kind: DestinationOrderQosPolicyKind::BySourceTimestamp,
-        },
         ..Default::default()
     };
     let reader = subscriber
-        .create_datareader::<UserData>(
+        .create_datareader::<CountingKeyedData>(
             &topic,
             QosKind::Specific(reader_qos),
             NO_LISTENER,
@@ -2518,43 +4879,98 @@ fn data_reader_order_by_source_timestamp() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
+    let data = KeyedData { id: 1, value: 10 };
+    writer.write(data.clone(), None).unwrap();
+
     writer
-        .write_w_timestamp(UserData(1), None, Time::new(30, 0))
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
-    writer
-        .write_w_timestamp(UserData(2), None, Time::new(20, 0))
+
+    let first_read = reader
+        .read_deserialized(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-    writer
-        .write_w_timestamp(UserData(3), None, Time::new(10, 0))
+    let second_read = reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
-    writer
-        .wait_for_acknowledgments(Duration::new(1, 0))
+    assert_eq!(first_read.len(), 1);
+    assert_eq!(second_read.len(), 1);
+    assert_eq!(
+        first_read[0].0.as_deref(),
+        Some(&CountingKeyedData(data.clone()))
+    );
+    assert_eq!(
+        second_read[0].shared_data().unwrap().as_ref(),
+        &CountingKeyedData(data.clone())
+    );
+    assert_eq!(
+        second_read[0].sample_info().sample_state,
+        SampleStateKind::Read
+    );
+    assert_eq!(
+        COUNTING_KEYED_DATA_DESERIALIZE_COUNT.load(Ordering::SeqCst),
+        1
+    );
+
+    // A handle of another type on the same reader can not use the cached value
+    let other_reader = subscriber
+        .lookup_datareader::<KeyedData>("MyTopic")
+        .unwrap()
         .unwrap();
+    let other_read = other_reader
+        .read_deserialized(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(other_read[0].0.as_deref(), Some(&data));
 
-    let samples = reader
-        .read(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+    // The cached value of the other type was replaced so it is deserialized again
+    reader
+        .read_deserialized(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
+    assert_eq!(
+        COUNTING_KEYED_DATA_DESERIALIZE_COUNT.load(Ordering::SeqCst),
+        2
+    );
 
-    assert_eq!(samples.len(), 3);
-    assert_eq!(samples[0].data().unwrap(), UserData(3));
-    assert_eq!(samples[1].data().unwrap(), UserData(2));
-    assert_eq!(samples[2].data().unwrap(), UserData(1));
+    // A sample which can not be deserialized is not marked as read
+    let new_data = KeyedData { id: 2, value: 20 };
+    writer.write(new_data.clone(), None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    let undecodable_reader = subscriber
+        .lookup_datareader::<UndecodableKeyedData>("MyTopic")
+        .unwrap()
+        .unwrap();
+    assert!(undecodable_reader
+        .read_deserialized(
+            1,
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE
+        )
+        .is_err());
+    let not_read = reader
+        .read_deserialized(
+            1,
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
+    assert_eq!(not_read[0].0.as_deref(), Some(&CountingKeyedData(new_data)));
 }
 
 #[test]
-fn data_reader_publication_handle_sample_info() {
+fn source_timestamp_is_preserved_across_the_wire() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-    let participant_factory = DomainParticipantFactory::get_instance();
-
-    let participant = participant_factory
+    let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
     let topic = participant
-        .create_topic::<UserData>(
+        .create_topic::<KeyedData>(
             "MyTopic",
-            "UserData",
+            "KeyedData",
             QosKind::Default,
             NO_LISTENER,
             NO_STATUS,
@@ -2564,16 +4980,18 @@ fn data_reader_publication_handle_sample_info() {
     let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-
     let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
     let writer = publisher
-        .create_datawriter(
+        .create_datawriter::<KeyedData>(
             &topic,
             QosKind::Specific(writer_qos),
             NO_LISTENER,
@@ -2584,16 +5002,18 @@ fn data_reader_publication_handle_sample_info() {
     let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-
     let reader_qos = DataReaderQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
     let reader = subscriber
-        .create_datareader::<UserData>(
+        .create_datareader::<KeyedData>(
             &topic,
             QosKind::Specific(reader_qos),
             NO_LISTENER,
@@ -2611,24 +5031,42 @@ fn data_reader_publication_handle_sample_info() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    writer.write(UserData(1), None).unwrap();
+    let timestamp_list = [
+        Time::new(1234, 1),
+        Time::new(1235, 123_456_789),
+        Time::new(1236, 500_000_000),
+        Time::new(1237, 999_999_999),
+    ];
+    for (i, &timestamp) in timestamp_list.iter().enumerate() {
+        writer
+            .write_w_timestamp(
+                KeyedData {
+                    id: i as u8,
+                    value: 0,
+                },
+                None,
+                timestamp,
+            )
+            .unwrap();
+    }
 
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
-        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
-    assert_eq!(samples.len(), 1);
-    assert!(reader
-        .get_matched_publication_data(samples[0].sample_info().publication_handle)
-        .is_ok());
+    let received_timestamp_list: Vec<_> = samples
+        .iter()
+        .map(|s| s.sample_info().source_timestamp.unwrap())
+        .collect();
+    assert_eq!(received_timestamp_list, timestamp_list);
 }
 
 #[test]
-fn volatile_writer_with_reader_new_reader_receives_only_new_samples() {
+fn writer_and_reader_compute_the_same_instance_handle_for_long_keys() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -2636,9 +5074,9 @@ fn volatile_writer_with_reader_new_reader_receives_only_new_samples() {
         .unwrap();
 
     let topic = participant
-        .create_topic::<KeyedData>(
+        .create_topic::<LongKeyData>(
             "MyTopic",
-            "KeyedData",
+            "LongKeyData",
             QosKind::Default,
             NO_LISTENER,
             NO_STATUS,
@@ -2649,12 +5087,6 @@ fn volatile_writer_with_reader_new_reader_receives_only_new_samples() {
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::Volatile,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
@@ -2674,22 +5106,19 @@ fn volatile_writer_with_reader_new_reader_receives_only_new_samples() {
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::Volatile,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
-    let _reader = subscriber
-        .create_datareader::<KeyedData>(
+    let reader = subscriber
+        .create_datareader::<LongKeyData>(
             &topic,
-            QosKind::Specific(reader_qos.clone()),
+            QosKind::Specific(reader_qos),
             NO_LISTENER,
             NO_STATUS,
         )
@@ -2704,51 +5133,50 @@ fn volatile_writer_with_reader_new_reader_receives_only_new_samples() {
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
-    writer.get_publication_matched_status().unwrap(); // To reset wait_set for subsequent calls
-
-    let data1 = KeyedData { id: 1, value: 1 };
-    writer.write(data1, None).unwrap();
-
-    writer
-        .wait_for_acknowledgments(Duration::new(3, 0))
-        .unwrap();
-
-    let reader_new = subscriber
-        .create_datareader::<KeyedData>(
-            &topic,
-            QosKind::Specific(reader_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    // Wait for writer to match reader
-    wait_set.wait(Duration::new(10, 0)).unwrap();
-
-    let data2 = KeyedData { id: 2, value: 10 };
-    writer.write(data2.clone(), None).unwrap();
 
+    // The serialized key is longer than 16 bytes so the key hash is computed with MD5
+    let name = "An instance name longer than 16 bytes";
+    let data = LongKeyData {
+        name: name.to_string(),
+        value: 1,
+    };
+    writer.write(data.clone(), None).unwrap();
+    let writer_handle = writer.lookup_instance(data.clone()).unwrap().unwrap();
+    writer.dispose(data, None).unwrap();
     writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples = reader_new
+    let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data2);
+    let serialized_key = [
+        &(name.len() as u32 + 1).to_be_bytes()[..],
+        name.as_bytes(),
+        &[0],
+    ]
+    .concat();
+    assert_eq!(
+        writer_handle,
+        InstanceHandle::new(key_hash_from_serialized_key(&serialized_key))
+    );
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].sample_info().instance_handle, writer_handle);
+    assert_eq!(samples[1].sample_info().instance_handle, writer_handle);
+    assert_eq!(
+        samples[1].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
 }
 
 #[test]
-fn write_read_unregistered_samples_are_also_disposed() {
+fn write_unblocks_pending_read_next_sample_with_timeout() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
     let participant_factory = DomainParticipantFactory::get_instance();
-
     let participant = participant_factory
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-
     let topic = participant
         .create_topic::<KeyedData>(
             "MyTopic",
@@ -2762,89 +5190,53 @@ fn write_read_unregistered_samples_are_also_disposed() {
     let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-    let writer_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
-        writer_data_lifecycle: WriterDataLifecycleQosPolicy {
-            autodispose_unregistered_instances: true,
-        },
-        ..Default::default()
-    };
     let writer = publisher
-        .create_datawriter(
-            &topic,
-            QosKind::Specific(writer_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
+        .create_datawriter::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
     let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-    let reader_qos = DataReaderQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
-        ..Default::default()
-    };
-
     let reader = subscriber
-        .create_datareader::<KeyedData>(
-            &topic,
-            QosKind::Specific(reader_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
 
     let cond = writer.get_statuscondition();
     cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
-
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data1 = KeyedData { id: 1, value: 1 };
-
-    writer.write(data1.clone(), None).unwrap();
-    writer.unregister_instance(data1, None).unwrap();
+    assert_eq!(
+        reader
+            .read_next_sample_with_timeout(Duration::new(0, 100_000_000))
+            .err(),
+        Some(DdsError::Timeout)
+    );
 
-    writer
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
+    let reading_thread = std::thread::spawn(move || {
+        let sample = reader
+            .read_next_sample_with_timeout(Duration::new(10, 0))
+            .unwrap();
+        (sample.data().unwrap(), sample.sample_info().sample_state)
+    });
 
-    let samples = reader
-        .read(2, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
-        .unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let data = KeyedData { id: 1, value: 1 };
+    writer.write(data.clone(), None).unwrap();
 
-    assert_eq!(samples.len(), 2);
-    assert_eq!(
-        samples[0].sample_info().instance_state,
-        InstanceStateKind::NotAliveDisposed
-    );
     assert_eq!(
-        samples[1].sample_info().instance_state,
-        InstanceStateKind::NotAliveDisposed
+        reading_thread.join().unwrap(),
+        (data, SampleStateKind::NotRead)
     );
 }
 
 #[test]
-fn transient_local_writer_does_not_deliver_lifespan_expired_data_at_write() {
+fn read_with_filter_returns_only_samples_satisfying_the_predicate() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-
     let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
@@ -2863,55 +5255,37 @@ fn transient_local_writer_does_not_deliver_lifespan_expired_data_at_write() {
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        lifespan: LifespanQosPolicy {
-            duration: DurationKind::Finite(Duration::new(1, 0)),
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
         },
         ..Default::default()
     };
     let writer = publisher
-        .create_datawriter(
+        .create_datawriter::<KeyedData>(
             &topic,
             QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
         .unwrap();
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 2 };
-    writer
-        .write_w_timestamp(data1, None, Time::new(0, 0))
-        .unwrap();
-    writer
-        .write_w_timestamp(data2.clone(), None, Time::new(i32::MAX, 0))
-        .unwrap(); // Never stale sample
 
     let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
             &topic,
@@ -2921,32 +5295,50 @@ fn transient_local_writer_does_not_deliver_lifespan_expired_data_at_write() {
         )
         .unwrap();
 
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
 
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    reader
-        .wait_for_historical_data(Duration::new(10, 0))
+    for value in 1..=4 {
+        writer.write(KeyedData { id: 1, value }, None).unwrap();
+    }
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
-    let samples = reader
-        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+
+    let filtered_samples = reader
+        .read_with_filter(10, |data: &KeyedData| data.value % 2 == 0)
+        .unwrap();
+    let filtered_values: Vec<u32> = filtered_samples
+        .iter()
+        .map(|s| s.data().unwrap().value)
+        .collect();
+    assert_eq!(filtered_values, vec![2, 4]);
+
+    let unread_samples = reader
+        .read(
+            10,
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
         .unwrap();
-
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data2);
+    let unread_values: Vec<u32> = unread_samples
+        .iter()
+        .map(|s| s.data().unwrap().value)
+        .collect();
+    assert_eq!(unread_values, vec![1, 3]);
 }
 
 #[test]
-fn transient_local_writer_does_not_deliver_lifespan_expired_data_after_write() {
-    const LIFESPAN_MS: u32 = 100;
+fn sample_info_reports_source_timestamp_states_and_sample_rank() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-
     let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
@@ -2965,55 +5357,37 @@ fn transient_local_writer_does_not_deliver_lifespan_expired_data_after_write() {
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        lifespan: LifespanQosPolicy {
-            duration: DurationKind::Finite(Duration::new(0, LIFESPAN_MS * 1_000_000)),
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
         },
         ..Default::default()
     };
     let writer = publisher
-        .create_datawriter(
+        .create_datawriter::<KeyedData>(
             &topic,
             QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
         .unwrap();
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 2 };
-    writer.write(data1, None).unwrap();
-    writer
-        .write_w_timestamp(data2.clone(), None, Time::new(i32::MAX, 0))
-        .unwrap(); // Never stale sample
-
-    std::thread::sleep(std::time::Duration::from_millis(LIFESPAN_MS as u64 * 2));
 
     let subscriber = participant
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
             &topic,
@@ -3023,31 +5397,88 @@ fn transient_local_writer_does_not_deliver_lifespan_expired_data_after_write() {
         )
         .unwrap();
 
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
 
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    reader
-        .wait_for_historical_data(Duration::new(10, 0))
+    let written_samples = [
+        (KeyedData { id: 1, value: 1 }, Time::new(100, 0)),
+        (KeyedData { id: 1, value: 2 }, Time::new(101, 0)),
+        (KeyedData { id: 2, value: 3 }, Time::new(102, 0)),
+    ];
+    for (data, timestamp) in written_samples.iter().cloned() {
+        writer.write_w_timestamp(data, None, timestamp).unwrap();
+    }
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
+
     let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data2);
+    let received_samples: Vec<_> = samples
+        .iter()
+        .map(|s| {
+            let sample_info = s.sample_info();
+            (
+                s.data().unwrap().value,
+                sample_info.source_timestamp,
+                sample_info.sample_rank,
+                sample_info.sample_state,
+                sample_info.view_state,
+                sample_info.instance_state,
+            )
+        })
+        .collect();
+    assert_eq!(
+        received_samples,
+        vec![
+            (
+                1,
+                Some(Time::new(100, 0)),
+                1,
+                SampleStateKind::NotRead,
+                ViewStateKind::New,
+                InstanceStateKind::Alive
+            ),
+            (
+                2,
+                Some(Time::new(101, 0)),
+                0,
+                SampleStateKind::NotRead,
+                ViewStateKind::New,
+                InstanceStateKind::Alive
+            ),
+            (
+                3,
+                Some(Time::new(102, 0)),
+                0,
+                SampleStateKind::NotRead,
+                ViewStateKind::New,
+                InstanceStateKind::Alive
+            ),
+        ]
+    );
+    assert_eq!(
+        samples[0].sample_info().instance_handle,
+        samples[1].sample_info().instance_handle
+    );
+    assert_ne!(
+        samples[0].sample_info().instance_handle,
+        samples[2].sample_info().instance_handle
+    );
 }
 
 #[test]
-fn reader_joining_after_writer_writes_many_samples() {
+fn coherent_changes_are_delivered_together_when_the_set_ends() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-
     let participant = DomainParticipantFactory::get_instance()
         .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
@@ -3063,18 +5494,31 @@ fn reader_joining_after_writer_writes_many_samples() {
         .unwrap();
 
     let publisher = participant
-        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .create_publisher(
+            QosKind::Specific(PublisherQos {
+                presentation: PresentationQosPolicy {
+                    access_scope: PresentationQosPolicyAccessScopeKind::Topic,
+                    coherent_access: true,
+                    ordered_access: false,
+                },
+                ..Default::default()
+            }),
+            NO_LISTENER,
+            NO_STATUS,
+        )
         .unwrap();
     let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
-
     let writer = publisher
-        .create_datawriter(
+        .create_datawriter::<KeyedData>(
             &topic,
             QosKind::Specific(writer_qos),
             NO_LISTENER,
@@ -3083,19 +5527,27 @@ fn reader_joining_after_writer_writes_many_samples() {
         .unwrap();
 
     let subscriber = participant
-        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .create_subscriber(
+            QosKind::Specific(SubscriberQos {
+                presentation: PresentationQosPolicy {
+                    access_scope: PresentationQosPolicyAccessScopeKind::Topic,
+                    coherent_access: true,
+                    ordered_access: false,
+                },
+                ..Default::default()
+            }),
+            NO_LISTENER,
+            NO_STATUS,
+        )
         .unwrap();
-
-    for value in 0..500 {
-        let data = KeyedData { id: 1, value };
-        writer.write(data, None).unwrap();
-    }
-
     let reader_qos = DataReaderQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         ..Default::default()
     };
     let reader = subscriber
@@ -3117,22 +5569,36 @@ fn reader_joining_after_writer_writes_many_samples() {
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let new_data = KeyedData { id: 1, value: 1000 };
-    writer.write(new_data.clone(), None).unwrap();
+    publisher.begin_coherent_changes().unwrap();
+    writer.write(KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer.write(KeyedData { id: 2, value: 2 }, None).unwrap();
     writer
-        .wait_for_acknowledgments(Duration::new(20, 0))
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
+    assert_eq!(
+        reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
 
+    publisher.end_coherent_changes().unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
     let samples = reader
-        .take(5, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), new_data);
+    let values: Vec<u32> = samples.iter().map(|s| s.data().unwrap().value).collect();
+    assert_eq!(values, vec![1, 2]);
+    assert!(matches!(
+        publisher.end_coherent_changes(),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
 }
 
 #[test]
-fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_writer() {
+fn minimum_time_separation_applies_after_samples_are_taken() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -3152,40 +5618,20 @@ fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_wr
     let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-    let writer1_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+    let writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
         },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
-        ..Default::default()
-    };
-    let writer1 = publisher
-        .create_datawriter(
-            &topic,
-            QosKind::Specific(writer1_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-    let writer2_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
         ..Default::default()
     };
-    let writer2 = publisher
+    let writer = publisher
         .create_datawriter(
             &topic,
-            QosKind::Specific(writer2_qos),
+            QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
@@ -3195,19 +5641,18 @@ fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_wr
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        time_based_filter: TimeBasedFilterQosPolicy {
+            minimum_separation: DurationKind::Finite(Duration::new(0, 10_000_000)),
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
             &topic,
@@ -3217,60 +5662,63 @@ fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_wr
         )
         .unwrap();
 
-    let start_time = std::time::Instant::now();
-    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
-    {
-        if reader.get_matched_publications().unwrap().len() >= 2 {
-            break;
-        }
-    }
-    assert_eq!(
-        reader.get_matched_publications().unwrap().len(),
-        2,
-        "Reader must have 2 matched writers"
-    );
-
-    let cond = writer1.get_statuscondition();
+    let cond = writer.get_statuscondition();
     cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
+
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let cond = writer2.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+    for (value, nanosec) in [(1, 0), (2, 1_000_000), (3, 2_000_000)] {
+        writer
+            .write_w_timestamp(KeyedData { id: 1, value }, None, Time::new(1, nanosec))
+            .unwrap();
+    }
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 1 });
 
-    let data1 = KeyedData { id: 1, value: 10 };
-    writer1.write(data1.clone(), None).unwrap();
-    writer1
+    writer
+        .write_w_timestamp(KeyedData { id: 1, value: 4 }, None, Time::new(1, 5_000_000))
+        .unwrap();
+    writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
+    assert_eq!(
+        reader
+            .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
 
-    let data2 = KeyedData { id: 1, value: 20 };
-    writer2.write(data2, None).unwrap();
-    writer2
+    writer
+        .write_w_timestamp(
+            KeyedData { id: 1, value: 5 },
+            None,
+            Time::new(1, 10_000_000),
+        )
+        .unwrap();
+    writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
-
     let samples = reader
-        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
     assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 5 });
 }
 
 #[test]
-fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_higher_ownership_strength(
-) {
+fn reader_removes_samples_after_writer_lifespan_elapses() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -3290,41 +5738,23 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_h
     let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-    let writer1_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+    let writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
         },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
-        ..Default::default()
-    };
-    let writer1 = publisher
-        .create_datawriter(
-            &topic,
-            QosKind::Specific(writer1_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let writer2_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        lifespan: LifespanQosPolicy {
+            duration: DurationKind::Finite(Duration::new(0, 500_000_000)),
         },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
         ..Default::default()
     };
-    let writer2 = publisher
+    let writer = publisher
         .create_datawriter(
             &topic,
-            QosKind::Specific(writer2_qos),
+            QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
@@ -3334,82 +5764,58 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_h
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
             &topic,
             QosKind::Specific(reader_qos),
             NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let start_time = std::time::Instant::now();
-    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
-    {
-        if reader.get_matched_publications().unwrap().len() >= 2 {
-            break;
-        }
-    }
-    assert_eq!(
-        reader.get_matched_publications().unwrap().len(),
-        2,
-        "Reader must have 2 matched writers"
-    );
-
-    let cond = writer1.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
-        .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
+            NO_STATUS,
+        )
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
 
-    let cond = writer2.get_statuscondition();
+    let cond = writer.get_statuscondition();
     cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
+
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
-
-    let data1 = KeyedData { id: 1, value: 10 };
-    writer1.write(data1.clone(), None).unwrap();
-    writer1
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data2 = KeyedData { id: 1, value: 20 };
-    writer2.write(data2.clone(), None).unwrap();
-    writer2
+    let data = KeyedData { id: 1, value: 1 };
+    writer.write(data.clone(), None).unwrap();
+    writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data);
 
-    assert_eq!(samples.len(), 2);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[1].data().unwrap(), data2);
+    std::thread::sleep(std::time::Duration::from_millis(700));
+
+    assert_eq!(
+        reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
 }
 
 #[test]
-fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_deadline_missed() {
+fn disposed_instance_is_forgotten_after_its_samples_expire() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -3429,47 +5835,23 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_
     let publisher = participant
         .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
-    let writer1_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
-        deadline: DeadlineQosPolicy {
-            period: DurationKind::Finite(Duration::new(1, 0)),
+    let writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
         },
-        ..Default::default()
-    };
-    let writer1 = publisher
-        .create_datawriter(
-            &topic,
-            QosKind::Specific(writer1_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let writer2_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
-        deadline: DeadlineQosPolicy {
-            period: DurationKind::Finite(Duration::new(1, 0)),
+        lifespan: LifespanQosPolicy {
+            duration: DurationKind::Finite(Duration::new(0, 500_000_000)),
         },
         ..Default::default()
     };
-    let writer2 = publisher
+    let writer = publisher
         .create_datawriter(
             &topic,
-            QosKind::Specific(writer2_qos),
+            QosKind::Specific(writer_qos),
             NO_LISTENER,
             NO_STATUS,
         )
@@ -3479,22 +5861,15 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_
         .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        deadline: DeadlineQosPolicy {
-            period: DurationKind::Finite(Duration::new(1, 0)),
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(
             &topic,
@@ -3504,71 +5879,53 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_
         )
         .unwrap();
 
-    let start_time = std::time::Instant::now();
-    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
-    {
-        if reader.get_matched_publications().unwrap().len() >= 2 {
-            break;
-        }
-    }
-    assert_eq!(
-        reader.get_matched_publications().unwrap().len(),
-        2,
-        "Reader must have 2 matched writers"
-    );
-
-    let cond = writer1.get_statuscondition();
+    let cond = writer.get_statuscondition();
     cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
-        .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
 
-    let cond = writer2.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
-        .unwrap();
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data1 = KeyedData { id: 1, value: 10 };
-    writer1.write(data1.clone(), None).unwrap();
-    writer1
+    let data = KeyedData { id: 1, value: 1 };
+    writer.write(data.clone(), None).unwrap();
+    writer.dispose(data.clone(), None).unwrap();
+    writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::RequestedDeadlineMissed])
-        .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    assert_eq!(
+        samples[1].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
 
-    let data2 = KeyedData { id: 1, value: 20 };
-    writer2.write(data2.clone(), None).unwrap();
-    writer2
+    std::thread::sleep(std::time::Duration::from_millis(700));
+
+    // Once all the samples of the disposed instance expired it starts over as a new instance
+    let new_data = KeyedData { id: 1, value: 2 };
+    writer.write(new_data.clone(), None).unwrap();
+    writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
-    assert_eq!(samples.len(), 2);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[1].data().unwrap(), data2);
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), new_data);
+    let sample_info = samples[0].sample_info();
+    assert_eq!(sample_info.instance_state, InstanceStateKind::Alive);
+    assert_eq!(sample_info.view_state, ViewStateKind::New);
+    assert_eq!(sample_info.disposed_generation_count, 0);
 }
 
 #[test]
-#[ignore = "Broken after runtime refactor"]
-fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer_after_unregister()
-{
+fn reader_with_exclusive_ownership_should_read_samples_from_weaker_writer_after_owner_is_deleted() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -3690,125 +6047,31 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    writer1.unregister_instance(data1.clone(), None).unwrap();
-    writer1
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
-
     let data2 = KeyedData { id: 1, value: 20 };
-    writer2.write(data2.clone(), None).unwrap();
+    writer2.write(data2, None).unwrap();
     writer2
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let samples = reader
-        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
-        .unwrap();
-
-    assert_eq!(samples.len(), 3);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[2].data().unwrap(), data2);
-}
-
-#[test]
-fn samples_are_transfered_between_two_participants() {
-    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
-
-    let participant1 = DomainParticipantFactory::get_instance()
-        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
-        .unwrap();
-
-    let topic = participant1
-        .create_topic::<KeyedData>(
-            "MyTopic",
-            "KeyedData",
-            QosKind::Default,
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let publisher = participant1
-        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
-        .unwrap();
-    let writer_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ..Default::default()
-    };
-    let writer = publisher
-        .create_datawriter(
-            &topic,
-            QosKind::Specific(writer_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let participant2 = DomainParticipantFactory::get_instance()
-        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
-        .unwrap();
-    let subscriber = participant2
-        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
-        .unwrap();
-    let reader_qos = DataReaderQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ..Default::default()
-    };
-    let topic = participant2
-        .create_topic::<KeyedData>(
-            "MyTopic",
-            "KeyedData",
-            QosKind::Default,
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-    let reader = subscriber
-        .create_datareader::<KeyedData>(
-            &topic,
-            QosKind::Specific(reader_qos),
-            NO_LISTENER,
-            NO_STATUS,
-        )
-        .unwrap();
-
-    let cond = writer.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
-        .unwrap();
-
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
-        .unwrap();
-    wait_set.wait(Duration::new(100, 0)).unwrap();
-
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
-        .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
-        .unwrap();
-    wait_set.wait(Duration::new(10, 0)).unwrap();
-
-    let data = KeyedData { id: 1, value: 1 };
-
-    writer.write(data.clone(), None).unwrap();
+    publisher.delete_datawriter(&writer1).unwrap();
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() == 1 {
+            break;
+        }
+    }
+    assert_eq!(reader.get_matched_publications().unwrap().len(), 1);
 
-    writer
+    let data3 = KeyedData { id: 1, value: 30 };
+    writer2.write(data3.clone(), None).unwrap();
+    writer2
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
-        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data);
+    let received_data: Vec<_> = samples.iter().filter_map(|s| s.data().ok()).collect();
+    assert_eq!(received_data, vec![data1, data3]);
 }
@@ -67,16 +67,7 @@ pub fn get_field_attributes(field: &Field) -> Result<FieldAttributes> {
                 } else if ident.to_string().eq_ignore_ascii_case("optional") {
                     attrs.optional = true;
                 } else if ident == "id" {
-                    let expr: Expr = meta.value()?.parse()?;
-                    if let Expr::Lit(expr_lit) = &expr {
-                        if let syn::Lit::Int(_) = &expr_lit.lit {
-                            attrs.id = Some(expr);
-                        } else {
-                            return Err(syn::Error::new(expr.span(), "Expected integer literal for `id`"));
-                        }
-                    } else {
-                        return Err(syn::Error::new(expr.span(), "Expected literal expression for `id`"));
-                    }
+                    attrs.id = Some(meta.value()?.parse()?);
                 } else if ident == "default_value" {
                     attrs.default_value = Some(meta.value()?.parse()?);
                 } else if ident == "non_serialized" {
@@ -1,21 +1,11 @@
 use super::{
     attributes::{get_field_attributes, get_input_extensibility, Extensibility},
-    enum_support::{
-        get_enum_bitbound, is_enum_xtypes_union, read_enum_variant_discriminant_mapping, BitBound,
-    },
+    enum_support::{is_enum_xtypes_union, read_enum_variant_discriminant_mapping},
 };
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{spanned::Spanned, DeriveInput, Fields, Index, Result};
 
-fn get_discriminant_type(max_discriminant: &usize) -> TokenStream {
-    match get_enum_bitbound(max_discriminant) {
-        BitBound::Bit8 => quote! {u8},
-        BitBound::Bit16 => quote! {u16},
-        BitBound::Bit32 => quote! {u32},
-    }
-}
-
 pub fn expand_xtypes_deserialize(input: &DeriveInput) -> Result<TokenStream> {
     let ident = &input.ident;
     let (_, type_generics, where_clause) = input.generics.split_for_impl();
@@ -175,13 +165,8 @@ pub fn expand_xtypes_deserialize(input: &DeriveInput) -> Result<TokenStream> {
                         }
                     }
                 } else {
+                    // The discriminant is read as the INT32 declared by the TypeSupport derive
                     let discriminant_mapping = read_enum_variant_discriminant_mapping(data_enum);
-                    let max_discriminant = discriminant_mapping
-                        .iter()
-                        .map(|(_, v)| v)
-                        .max()
-                        .expect("Map contains at least a value");
-                    let discriminant_type = get_discriminant_type(max_discriminant);
                     let clauses: Vec<_> = discriminant_mapping
                         .iter()
                         .map(|(v, d)| {
@@ -191,7 +176,7 @@ pub fn expand_xtypes_deserialize(input: &DeriveInput) -> Result<TokenStream> {
                         .collect();
 
                     quote! {
-                        let discriminant : #discriminant_type =  dust_dds::xtypes::deserialize::XTypesDeserialize::deserialize(deserializer)?;
+                        let discriminant : i32 =  dust_dds::xtypes::deserialize::XTypesDeserialize::deserialize(deserializer)?;
 
                         match discriminant {
                             #(#clauses)*
@@ -262,8 +247,8 @@ mod tests {
             result,
             expected,
             "\n R: {:?} \n \n L: {:?} \n ",
-            result.clone().into_token_stream().to_string(),
-            expected.clone().into_token_stream().to_string()
+            result.clone().into_token_stream(),
+            expected.clone().into_token_stream()
         );
     }
 
@@ -306,8 +291,8 @@ mod tests {
             result,
             expected,
             "\n R: {:?} \n \n L: {:?} \n ",
-            result.clone().into_token_stream().to_string(),
-            expected.clone().into_token_stream().to_string()
+            result.clone().into_token_stream(),
+            expected.clone().into_token_stream()
         );
     }
 
@@ -346,8 +331,8 @@ mod tests {
             result,
             expected,
             "\n\n L: {} \n\n R: {}",
-            result.clone().into_token_stream().to_string(),
-            expected.clone().into_token_stream().to_string()
+            result.clone().into_token_stream(),
+            expected.clone().into_token_stream()
         );
     }
 
@@ -392,8 +377,8 @@ mod tests {
             result,
             expected,
             "\n R: {:?} \n \n L: {:?} \n ",
-            result.clone().into_token_stream().to_string(),
-            expected.clone().into_token_stream().to_string()
+            result.clone().into_token_stream(),
+            expected.clone().into_token_stream()
         );
     }
 
@@ -419,7 +404,7 @@ mod tests {
             #[automatically_derived]
             impl<'__de> dust_dds::xtypes::deserialize::XTypesDeserialize<'__de> for SimpleEnum {
                 fn deserialize(deserializer: impl dust_dds::xtypes::deserializer::XTypesDeserializer<'__de>) -> Result<Self, dust_dds::xtypes::error::XTypesError> {
-                    let discriminant: u16 = dust_dds::xtypes::deserialize::XTypesDeserialize::deserialize(deserializer)?;
+                    let discriminant: i32 = dust_dds::xtypes::deserialize::XTypesDeserialize::deserialize(deserializer)?;
 
                     match discriminant {
                         10 => Ok(SimpleEnum::a),
@@ -439,8 +424,8 @@ mod tests {
             result,
             expected,
             "\n R: {:?} \n \n L: {:?} \n ",
-            result.clone().into_token_stream().to_string(),
-            expected.clone().into_token_stream().to_string()
+            result.clone().into_token_stream(),
+            expected.clone().into_token_stream()
         );
     }
 
@@ -495,8 +480,8 @@ mod tests {
             result,
             expected,
             "\n R: {:?} \n \n L: {:?} \n ",
-            result.clone().into_token_stream().to_string(),
-            expected.clone().into_token_stream().to_string()
+            result.clone().into_token_stream(),
+            expected.clone().into_token_stream()
         );
     }
 }
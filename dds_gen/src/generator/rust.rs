@@ -315,7 +315,7 @@ fn struct_def(pair: IdlPair, writer: &mut String) {
 
     let has_derive = annotations.iter().any(|(ann_name, _)| ann_name == "derive");
     if !has_derive {
-        writer.push_str(&format!("#[derive(Debug, dust_dds::infrastructure::type_support::DdsType)]\n"));
+        writer.push_str("#[derive(Debug, dust_dds::infrastructure::type_support::DdsType)]\n");
     }
     for (ann_name, ann_params) in &annotations {
         match ann_name.as_str() {
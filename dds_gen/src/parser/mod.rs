@@ -28,7 +28,7 @@ pub struct StructDef {
     pub members: Vec<Member>,
 }
 
-pub fn parse_idl(source: &str, rule: Rule) -> Result<(Vec<StructDef>, IdlPair), Box<pest::error::Error<Rule>>> {
+pub fn parse_idl(source: &str, rule: Rule) -> Result<(Vec<StructDef>, IdlPair<'_>), Box<pest::error::Error<Rule>>> {
     let file = IdlParser::parse(rule, source)?
         .next()
         .expect("Expected a matching rule");
@@ -218,7 +218,7 @@ fn test_generate_rust_code_with_psm_annotations() {
     for ann in &expected_annotations {
         let normalized_keywords: Vec<&str> = ann
             .trim_matches(|c| c == '#' || c == '[' || c == ']')
-            .split(|c| c == '(' || c == ')' || c == '=' || c == '"' || c == ' ')
+            .split(['(', ')', '=', '"', ' '])
             .filter(|s| !s.is_empty())
             .collect();
 